@@ -1,4 +1,12 @@
-use axum::Router;
+use crate::common::api_token;
+use axum::{
+    extract::Request,
+    http::{header, StatusCode},
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
+    Json, Router,
+};
+use serde_json::json;
 use std::net::SocketAddr;
 
 mod health;
@@ -8,9 +16,7 @@ mod register;
 
 /// 启动 Web API 服务器
 pub async fn start_server() -> Result<(), Box<dyn std::error::Error>> {
-    let app = Router::new()
-        // 健康检查
-        .nest("/health", health::routes())
+    let api = Router::new()
         // 航司 API 服务
         .nest("/api/airline", airline::routes())
         // 验证码识别服务
@@ -18,7 +24,13 @@ pub async fn start_server() -> Result<(), Box<dyn std::error::Error>> {
         // 验证码识别服务（直接访问，兼容浏览器）
         .nest("/api", captcha::direct_routes())
         // 注册机服务
-        .nest("/api/register", register::routes());
+        .nest("/api/register", register::routes())
+        .layer(middleware::from_fn(require_token));
+
+    let app = Router::new()
+        // 健康检查（不需要令牌）
+        .nest("/health", health::routes())
+        .merge(api);
 
     let addr = SocketAddr::from(([127, 0, 0, 1], 8080));
     println!("🚀 Web API 服务器启动在 http://{}", addr);
@@ -28,6 +40,10 @@ pub async fn start_server() -> Result<(), Box<dyn std::error::Error>> {
     println!("   - POST /api/captcha/solve         验证码识别（7种类型）");
     println!("   - POST /api/register/create       注册账号");
     println!();
+    if matches!(api_token::load(), Ok(Some(_))) {
+        println!("🔑 已启用 API 令牌：请求需带 Authorization: Bearer <令牌> 或 X-Api-Token 请求头");
+        println!();
+    }
     println!("📖 验证码识别支持的类型:");
     println!("   • ocr                - 英数验证码");
     println!("   • ocr_old            - 旧版 OCR 模型");
@@ -44,3 +60,30 @@ pub async fn start_server() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+/// 设置了 API 令牌时校验请求头（令牌每次请求时读取，轮换后立即生效）
+async fn require_token(request: Request, next: Next) -> Response {
+    let expected = match api_token::load() {
+        Ok(token) => token,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(json!({ "error": format!("读取 API 令牌失败: {}", e) })),
+            )
+                .into_response();
+        }
+    };
+
+    let headers = request.headers();
+    let header_value = |name| headers.get(name).and_then(|value| value.to_str().ok());
+    let provided = api_token::from_headers(
+        header_value(header::AUTHORIZATION.as_str()),
+        header_value(api_token::TOKEN_HEADER),
+    );
+
+    if api_token::verify(expected.as_deref(), provided.as_deref()) {
+        next.run(request).await
+    } else {
+        (StatusCode::UNAUTHORIZED, Json(json!({ "error": "API 令牌无效或缺失" }))).into_response()
+    }
+}
@@ -0,0 +1,694 @@
+//! 命令行维护工具
+//!
+//! `fact-bot <子命令>` 不打开窗口，直接操作数据库，便于通过 SSH 维护。
+//! 不带子命令时照常启动图形界面。所有子命令都支持 `--json` 输出，退出码见 `EXIT_*`。
+
+use crate::common::settings_archive::{self, ArchiveSection, ImportMode, SectionDiff, SettingsSnapshot};
+use crate::common::log_query::{LogQuery, TimeRange};
+use crate::common::timestamp::DisplayZone;
+use crate::common::diagnostics::{self, BundleOptions};
+use crate::common::{api_token, timestamp, AppError, Result};
+use crate::db::{self, health, location, repo, LogEntry, LogFilter};
+use chrono::{DateTime, Duration, Utc};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::path::PathBuf;
+
+/// 成功
+pub const EXIT_OK: i32 = 0;
+/// 执行失败（数据库、文件、口令错误等）
+pub const EXIT_FAILURE: i32 = 1;
+/// 参数错误
+pub const EXIT_USAGE: i32 = 2;
+/// `db check` 发现数据库有问题
+pub const EXIT_UNHEALTHY: i32 = 3;
+
+const SUBCOMMANDS: &[&str] = &["logs", "db", "config", "api-token", "diagnostics", "help", "--help", "-h"];
+/// 不带值的开关，其余 `--xxx` 选项都需要一个值
+const SWITCHES: &[&str] = &["--json", "--asc", "--dry-run", "--include-debug"];
+/// 备份口令默认从该环境变量读取，未设置时从标准输入读取一行
+const PASSPHRASE_ENV: &str = "FACTBOT_BACKUP_PASSPHRASE";
+
+const USAGE: &str = "用法: fact-bot [--data-dir <目录>] <子命令> [选项] [--json]
+
+不带子命令时启动图形界面。
+
+日志:
+  logs query   [筛选条件] [--limit <N>]          查询日志
+  logs export  [筛选条件] [--output <文件>] [--format json|csv]
+                                                 导出日志，未指定 --output 时输出到标准输出
+  logs prune   (--before <日期或时间> | --days <N>) [--dry-run]
+                                                 删除早于指定本地时间（或 N 天前）的日志
+  筛选条件: --task <任务名> --uuid <任务UUID> --level <级别[,级别]> --keyword <关键词> --asc --include-debug
+            --since <时间> --until <时间>
+  时间为 RFC 3339、本地时间 YYYY-MM-DD[ HH:MM:SS]，或日志页面的快捷范围 15m、today、7d（取范围起点）
+
+数据库:
+  db check                                       完整性检查和写入测试，有问题时退出码为 3
+  db migrate                                     创建缺失的表并迁移旧格式配置
+
+配置:
+  config backup  [--output <文件>] [--passphrase-env <变量名>]
+                                                 导出加密的设置备份
+  config restore <文件> [--mode merge|replace] [--passphrase-env <变量名>] [--dry-run]
+                                                 导入设置备份，--dry-run 只预览变化
+  备份口令默认读取环境变量 FACTBOT_BACKUP_PASSPHRASE，未设置时从标准输入读取一行
+
+API:
+  api-token rotate                               生成新的 Web API 令牌，旧令牌立即失效
+
+诊断:
+  diagnostics bundle [--task <任务名>] [--uuid <任务UUID>] [--limit <N>] [--output <文件>]
+                                                 生成脱敏的诊断包（zip），默认包含最近 500 条日志
+
+退出码: 0 成功，1 执行失败，2 参数错误，3 数据库检查未通过";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ExportFormat {
+    Json,
+    Csv,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Command {
+    Help,
+    LogsQuery { filter: LogFilter, limit: Option<usize> },
+    LogsExport { filter: LogFilter, output: Option<PathBuf>, format: ExportFormat },
+    LogsPrune { before: String, dry_run: bool },
+    DbCheck,
+    DbMigrate,
+    ConfigBackup { output: Option<PathBuf>, passphrase_env: String },
+    ConfigRestore { input: PathBuf, mode: ImportMode, passphrase_env: String, dry_run: bool },
+    ApiTokenRotate,
+    DiagnosticsBundle { options: BundleOptions, output: Option<PathBuf> },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Invocation {
+    command: Command,
+    json: bool,
+}
+
+/// 命令执行结果：同时准备 JSON 和文本两种输出
+struct Outcome {
+    json: Value,
+    text: String,
+    code: i32,
+}
+
+impl Outcome {
+    fn ok(json: Value, text: impl Into<String>) -> Self {
+        Self { json, text: text.into(), code: EXIT_OK }
+    }
+}
+
+// ==================== 参数解析 ====================
+
+/// 子命令的选项：`--name value`、`--name=value`、开关和位置参数
+#[derive(Debug, Default)]
+struct Options {
+    values: BTreeMap<String, String>,
+    switches: Vec<String>,
+    positional: Vec<String>,
+}
+
+impl Options {
+    fn parse(args: &[String]) -> std::result::Result<Self, String> {
+        let mut options = Self::default();
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            if !arg.starts_with("--") {
+                options.positional.push(arg.clone());
+            } else if SWITCHES.contains(&arg.as_str()) {
+                options.switches.push(arg.clone());
+            } else if let Some((name, value)) = arg.split_once('=') {
+                options.values.insert(name.to_string(), value.to_string());
+            } else {
+                let value = iter.next().ok_or_else(|| format!("选项 {} 缺少参数值", arg))?;
+                options.values.insert(arg.clone(), value.clone());
+            }
+        }
+        Ok(options)
+    }
+
+    fn value(&mut self, name: &str) -> Option<String> {
+        self.values.remove(name)
+    }
+
+    fn switch(&mut self, name: &str) -> bool {
+        let before = self.switches.len();
+        self.switches.retain(|switch| switch != name);
+        self.switches.len() != before
+    }
+
+    fn positional(&mut self) -> Option<String> {
+        (!self.positional.is_empty()).then(|| self.positional.remove(0))
+    }
+
+    fn parsed<T: std::str::FromStr>(&mut self, name: &str) -> std::result::Result<Option<T>, String> {
+        self.value(name)
+            .map(|value| value.parse().map_err(|_| format!("选项 {} 的值无效：{}", name, value)))
+            .transpose()
+    }
+
+    /// 确认没有多余的参数
+    fn finish(self) -> std::result::Result<(), String> {
+        if let Some(name) = self.values.keys().chain(self.switches.iter()).next() {
+            return Err(format!("未知选项：{}", name));
+        }
+        if let Some(arg) = self.positional.first() {
+            return Err(format!("多余的参数：{}", arg));
+        }
+        Ok(())
+    }
+}
+
+/// 去掉全局选项 `--data-dir`（由 `db::location` 直接从命令行读取）
+fn strip_data_dir(args: &[String]) -> Vec<String> {
+    let mut result = Vec::new();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if arg == location::DATA_DIR_FLAG {
+            iter.next();
+        } else if !arg.starts_with(&format!("{}=", location::DATA_DIR_FLAG)) {
+            result.push(arg.clone());
+        }
+    }
+    result
+}
+
+fn log_filter(options: &mut Options) -> std::result::Result<LogFilter, String> {
+    let include_debug = options.switch("--include-debug");
+    let now = Utc::now();
+    Ok(LogFilter {
+        task_name: options.value("--task"),
+        task_uuid: options.value("--uuid"),
+        levels: options
+            .value("--level")
+            .map(|levels| levels.split(',').map(|level| level.trim().to_uppercase()).filter(|level| !level.is_empty()).collect())
+            .unwrap_or_default(),
+        keyword: options.value("--keyword"),
+        since: time_bound(options, "--since", now)?,
+        until: time_bound(options, "--until", now)?,
+        include_debug: include_debug || cfg!(feature = "dev"),
+        ascending: options.switch("--asc"),
+    })
+}
+
+/// 返回存储格式的时间：接受 RFC 3339、本地时间 `YYYY-MM-DD` 或 `YYYY-MM-DD HH:MM:SS`，
+/// 以及日志页面的快捷范围 `15m`、`today`、`7d`（取该范围的起点）
+fn time_bound(options: &mut Options, name: &str, now: DateTime<Utc>) -> std::result::Result<Option<String>, String> {
+    options
+        .value(name)
+        .map(|value| {
+            TimeRange::from_id(&value)
+                .and_then(|range| LogQuery { range, ..Default::default() }.bounds(now, DisplayZone::Local).0)
+                .or_else(|| timestamp::parse(&value))
+                .map(timestamp::to_storage)
+                .ok_or_else(|| {
+                    format!(
+                        "{} 的格式应为 RFC 3339、YYYY-MM-DD、\"YYYY-MM-DD HH:MM:SS\" 或 15m、today、7d：{}",
+                        name, value
+                    )
+                })
+        })
+        .transpose()
+}
+
+/// 返回存储格式的截止时间：`--before` 接受本地时间 `YYYY-MM-DD` 或 `YYYY-MM-DD HH:MM:SS`，
+/// `--days N` 表示 N 天前的此刻
+fn prune_cutoff(before: Option<String>, days: Option<i64>) -> std::result::Result<String, String> {
+    match (before, days) {
+        (Some(before), None) => timestamp::parse_local(&before)
+            .map(timestamp::to_storage)
+            .ok_or_else(|| format!("--before 的格式应为 YYYY-MM-DD 或 \"YYYY-MM-DD HH:MM:SS\"：{}", before)),
+        (None, Some(days)) if days >= 0 => Ok(timestamp::to_storage(Utc::now() - Duration::days(days))),
+        (None, Some(_)) => Err("--days 不能为负数".to_string()),
+        _ => Err("logs prune 需要 --before 或 --days 其中之一".to_string()),
+    }
+}
+
+fn parse_command(args: &[String]) -> std::result::Result<Invocation, String> {
+    let (group, rest) = args.split_first().ok_or("缺少子命令")?;
+    if matches!(group.as_str(), "help" | "--help" | "-h") {
+        return Ok(Invocation { command: Command::Help, json: false });
+    }
+    let (action, rest) = rest.split_first().ok_or_else(|| format!("{} 缺少子命令", group))?;
+
+    let mut options = Options::parse(rest)?;
+    let json = options.switch("--json");
+    let passphrase_env = |options: &mut Options| {
+        options.value("--passphrase-env").unwrap_or_else(|| PASSPHRASE_ENV.to_string())
+    };
+
+    let command = match (group.as_str(), action.as_str()) {
+        ("logs", "query") => Command::LogsQuery {
+            filter: log_filter(&mut options)?,
+            limit: options.parsed("--limit")?,
+        },
+        ("logs", "export") => Command::LogsExport {
+            filter: log_filter(&mut options)?,
+            output: options.value("--output").map(PathBuf::from),
+            format: match options.value("--format").as_deref() {
+                None | Some("json") => ExportFormat::Json,
+                Some("csv") => ExportFormat::Csv,
+                Some(other) => return Err(format!("不支持的导出格式：{}", other)),
+            },
+        },
+        ("logs", "prune") => {
+            let before = options.value("--before");
+            let days = options.parsed("--days")?;
+            Command::LogsPrune {
+                before: prune_cutoff(before, days)?,
+                dry_run: options.switch("--dry-run"),
+            }
+        }
+        ("db", "check") => Command::DbCheck,
+        ("db", "migrate") => Command::DbMigrate,
+        ("config", "backup") => Command::ConfigBackup {
+            output: options.value("--output").map(PathBuf::from),
+            passphrase_env: passphrase_env(&mut options),
+        },
+        ("config", "restore") => Command::ConfigRestore {
+            input: options.positional().map(PathBuf::from).ok_or("config restore 需要备份文件路径")?,
+            mode: match options.value("--mode").as_deref() {
+                None | Some("merge") => ImportMode::Merge,
+                Some("replace") => ImportMode::Replace,
+                Some(other) => return Err(format!("--mode 只能是 merge 或 replace：{}", other)),
+            },
+            passphrase_env: passphrase_env(&mut options),
+            dry_run: options.switch("--dry-run"),
+        },
+        ("api-token", "rotate") => Command::ApiTokenRotate,
+        ("diagnostics", "bundle") => Command::DiagnosticsBundle {
+            options: BundleOptions {
+                task_name: options.value("--task"),
+                task_uuid: options.value("--uuid"),
+                log_limit: options.parsed("--limit")?.unwrap_or(diagnostics::DEFAULT_LOG_LIMIT),
+            },
+            output: options.value("--output").map(PathBuf::from),
+        },
+        _ => return Err(format!("未知子命令：{} {}", group, action)),
+    };
+
+    options.finish()?;
+    Ok(Invocation { command, json })
+}
+
+/// 解析命令行；没有子命令时返回 None（启动图形界面）
+fn parse(args: &[String]) -> Option<std::result::Result<Invocation, String>> {
+    let args = strip_data_dir(args);
+    let first = args.first()?;
+    SUBCOMMANDS.contains(&first.as_str()).then(|| parse_command(&args))
+}
+
+// ==================== 执行 ====================
+
+/// 读取备份口令：优先环境变量，否则从标准输入读取一行
+fn read_passphrase(env_name: &str) -> Result<String> {
+    if let Ok(passphrase) = std::env::var(env_name) {
+        return Ok(passphrase);
+    }
+    if io::stdin().is_terminal() {
+        eprint!("请输入备份口令: ");
+        io::stderr().flush()?;
+    }
+    let mut line = String::new();
+    io::stdin().lock().read_line(&mut line)?;
+    Ok(line.trim_end_matches(['\r', '\n']).to_string())
+}
+
+fn format_log_line(log: &LogEntry) -> String {
+    format!("{} [{}] {} ({}) {}", log.timestamp, log.log_level, log.task_name, log.task_uuid, log.message)
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn logs_to_csv(logs: &[LogEntry]) -> String {
+    let mut csv = String::from("id,timestamp,level,task_name,task_uuid,message\n");
+    for log in logs {
+        let id = log.id.map(|id| id.to_string()).unwrap_or_default();
+        let fields = [id.as_str(), &log.timestamp, &log.log_level, &log.task_name, &log.task_uuid, &log.message];
+        csv.push_str(&fields.map(csv_field).join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+fn section_json(section: ArchiveSection, diff: &SectionDiff, mode: ImportMode) -> Value {
+    let removed = if mode == ImportMode::Replace { diff.local_only.clone() } else { Vec::new() };
+    json!({
+        "section": section.id(),
+        "added": diff.added,
+        "updated": diff.updated,
+        "removed": removed,
+        "unchanged": diff.unchanged,
+    })
+}
+
+fn run(command: &Command) -> Result<Outcome> {
+    match command {
+        Command::Help => Ok(Outcome::ok(json!({ "usage": USAGE }), USAGE)),
+
+        Command::LogsQuery { filter, limit } => {
+            let mut logs = db::query_logs(filter)?;
+            if let Some(limit) = limit {
+                logs.truncate(*limit);
+            }
+            let text = logs.iter().map(format_log_line).collect::<Vec<_>>().join("\n");
+            Ok(Outcome::ok(json!(logs), text))
+        }
+
+        Command::LogsExport { filter, output, format } => {
+            let logs = db::query_logs(filter)?;
+            let contents = match format {
+                ExportFormat::Json => serde_json::to_string_pretty(&logs)?,
+                ExportFormat::Csv => logs_to_csv(&logs),
+            };
+            match output {
+                // 输出到标准输出时内容本身就是结果
+                None => Ok(Outcome::ok(serde_json::from_str(&serde_json::to_string(&logs)?)?, contents)),
+                Some(path) => {
+                    fs::write(path, contents)?;
+                    Ok(Outcome::ok(
+                        json!({ "output": path, "count": logs.len() }),
+                        format!("已导出 {} 条日志到 {}", logs.len(), path.display()),
+                    ))
+                }
+            }
+        }
+
+        Command::LogsPrune { before, dry_run } => {
+            let removed = if *dry_run { db::count_logs_before(before)? } else { db::prune_logs(before)? };
+            let verb = if *dry_run { "将删除" } else { "已删除" };
+            Ok(Outcome::ok(
+                json!({ "before": before, "removed": removed, "dry_run": dry_run }),
+                format!("{} {} 条早于 {} 的日志", verb, removed, DisplayZone::Local.format(before)),
+            ))
+        }
+
+        Command::DbCheck => {
+            let current = location::current()?;
+            let report = health::run_startup_checks();
+            let databases: Vec<Value> = report
+                .databases
+                .iter()
+                .map(|status| {
+                    json!({
+                        "database": match status.kind {
+                            health::DbKind::Config => "config",
+                            health::DbKind::Logs => "logs",
+                        },
+                        "path": status.path,
+                        "healthy": status.problem.is_none(),
+                        "problem": status.problem.as_ref().map(|problem| problem.description()),
+                        "remedy": status.problem.as_ref().map(|problem| problem.remedy(status.kind)),
+                    })
+                })
+                .collect();
+
+            let mut lines = vec![format!("数据目录来源：{}", current.source.label())];
+            for status in &report.databases {
+                let path = status.path.as_ref().map(|path| path.display().to_string()).unwrap_or_default();
+                match &status.problem {
+                    None => lines.push(format!("✔ {}  {}", status.kind.label(), path)),
+                    Some(problem) => {
+                        lines.push(format!("✘ {}  {}  {}", status.kind.label(), path, problem.description()));
+                        lines.push(format!("  建议：{}", problem.remedy(status.kind)));
+                    }
+                }
+            }
+
+            Ok(Outcome {
+                json: json!({
+                    "healthy": report.is_healthy(),
+                    "data_dir_source": current.source.label(),
+                    "databases": databases,
+                }),
+                text: lines.join("\n"),
+                code: if report.is_healthy() { EXIT_OK } else { EXIT_UNHEALTHY },
+            })
+        }
+
+        Command::DbMigrate => {
+            db::init_config_db()?;
+            let log_rows = db::migrate_log_db()?;
+            let migrated = repo::migrate_all_legacy()?;
+
+            let mut lines = Vec::new();
+            if !migrated.is_empty() {
+                lines.push(format!("已迁移旧格式配置：{}", migrated.join("、")));
+            }
+            if log_rows > 0 {
+                lines.push(format!("已将 {} 条日志的时间戳转换为 UTC", log_rows));
+            }
+            if lines.is_empty() {
+                lines.push("数据库结构已是最新".to_string());
+            }
+            Ok(Outcome::ok(json!({ "migrated": migrated, "log_timestamps": log_rows }), lines.join("\n")))
+        }
+
+        Command::ConfigBackup { output, passphrase_env } => {
+            let path = output
+                .clone()
+                .or_else(settings_archive::default_export_path)
+                .ok_or_else(|| AppError::InvalidConfig("无法确定默认导出位置，请使用 --output 指定".into()))?;
+            let passphrase = read_passphrase(passphrase_env)?;
+            let snapshot = settings_archive::export_to_file(&path, &passphrase)?;
+            Ok(Outcome::ok(
+                json!({ "output": path, "created_at": snapshot.created_at }),
+                format!("已导出到 {}（{}）", path.display(), snapshot.summary()),
+            ))
+        }
+
+        Command::ConfigRestore { input, mode, passphrase_env, dry_run } => {
+            let passphrase = read_passphrase(passphrase_env)?;
+            let incoming = settings_archive::read_file(input, &passphrase)?;
+            let local = SettingsSnapshot::capture()?;
+            let diffs: Vec<(ArchiveSection, SectionDiff)> = ArchiveSection::ALL
+                .iter()
+                .map(|&section| (section, settings_archive::diff_section(section, &local, &incoming)))
+                .collect();
+
+            if !dry_run {
+                let modes = ArchiveSection::ALL.iter().map(|&section| (section, *mode)).collect();
+                settings_archive::import(&incoming, &modes)?;
+            }
+
+            let mut lines = vec![format!(
+                "{}备份（创建于 {}，方式：{}）",
+                if *dry_run { "预览" } else { "已导入" },
+                incoming.created_at,
+                mode.label()
+            )];
+            lines.extend(
+                diffs
+                    .iter()
+                    .map(|(section, diff)| format!("  {}：{}", section.label(), diff.describe(*mode))),
+            );
+            Ok(Outcome::ok(
+                json!({
+                    "dry_run": dry_run,
+                    "mode": mode.value(),
+                    "created_at": incoming.created_at,
+                    "sections": diffs
+                        .iter()
+                        .map(|(section, diff)| section_json(*section, diff, *mode))
+                        .collect::<Vec<_>>(),
+                }),
+                lines.join("\n"),
+            ))
+        }
+
+        Command::ApiTokenRotate => {
+            let token = api_token::rotate()?;
+            Ok(Outcome::ok(
+                json!({ "token": token }),
+                format!(
+                    "新的 API 令牌（旧令牌已失效）：\n{}\n请求时使用 Authorization: Bearer <令牌> 或 X-Api-Token 请求头",
+                    token
+                ),
+            ))
+        }
+
+        Command::DiagnosticsBundle { options, output } => {
+            let path = output
+                .clone()
+                .or_else(diagnostics::default_bundle_path)
+                .ok_or_else(|| AppError::InvalidConfig("无法确定默认保存位置，请使用 --output 指定".into()))?;
+            diagnostics::create_bundle(&path, options)?;
+            Ok(Outcome::ok(json!({ "output": path }), format!("诊断包已保存到 {}", path.display())))
+        }
+    }
+}
+
+fn execute(invocation: Invocation) -> i32 {
+    match run(&invocation.command) {
+        Ok(outcome) => {
+            if invocation.json {
+                println!("{}", outcome.json);
+            } else if !outcome.text.is_empty() {
+                println!("{}", outcome.text);
+            }
+            outcome.code
+        }
+        Err(e) => {
+            if invocation.json {
+                println!("{}", json!({ "error": e.user_message(), "details": e.to_string() }));
+            } else {
+                eprintln!("错误：{}", e.user_message());
+                eprintln!("详情：{}", e);
+            }
+            EXIT_FAILURE
+        }
+    }
+}
+
+/// 命令行带子命令时执行并返回退出码；否则返回 None，由调用方启动图形界面
+pub fn run_from_args() -> Option<i32> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match parse(&args)? {
+        Ok(invocation) => Some(execute(invocation)),
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, USAGE);
+            Some(EXIT_USAGE)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(str::to_string).collect()
+    }
+
+    #[test]
+    fn test_data_location_shared_with_library() {
+        // 界面（本程序）迁移数据目录后，API 服务使用的库中的 `location::current()` 也看到新位置
+        let dir = std::env::temp_dir().join(format!("factbot-shared-location-{}", uuid::Uuid::new_v4()));
+        let moved = location::DataLocation {
+            config_dir: dir.clone(),
+            log_dir: dir,
+            source: location::DataDirSource::Setting,
+            log_fallback: false,
+        };
+        location::set_current(moved.clone());
+        assert_eq!(fact_bot::db::location::current().unwrap(), moved);
+    }
+
+    #[test]
+    fn test_gui_launch_without_subcommand() {
+        assert!(parse(&args("")).is_none());
+        assert!(parse(&args("--data-dir /tmp/factbot")).is_none());
+    }
+
+    #[test]
+    fn test_parse_logs_query_filters() {
+        let invocation = parse(&args(
+            "--data-dir=/tmp/x logs query --task Airline --level error,warn --limit 20 --asc --include-debug --json",
+        ))
+            .unwrap()
+            .unwrap();
+        assert!(invocation.json);
+        match invocation.command {
+            Command::LogsQuery { filter, limit } => {
+                assert_eq!(filter.task_name.as_deref(), Some("Airline"));
+                assert_eq!(filter.levels, vec!["ERROR", "WARN"]);
+                assert!(filter.ascending);
+                assert!(filter.include_debug);
+                assert_eq!(filter.since, None);
+                assert_eq!(limit, Some(20));
+            }
+            other => panic!("unexpected command {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_logs_time_range() {
+        let invocation = parse(&args("logs export --since 2024-05-01T10:00:00+08:00 --until 2024-05-02T00:00:00Z"))
+            .unwrap()
+            .unwrap();
+        match invocation.command {
+            Command::LogsExport { filter, .. } => {
+                assert_eq!(filter.since.as_deref(), Some("2024-05-01T02:00:00.000Z"));
+                assert_eq!(filter.until.as_deref(), Some("2024-05-02T00:00:00.000Z"));
+            }
+            other => panic!("unexpected command {:?}", other),
+        }
+
+        // 快捷范围按当前时间计算
+        let before = Utc::now();
+        let invocation = parse(&args("logs query --since 15m")).unwrap().unwrap();
+        match invocation.command {
+            Command::LogsQuery { filter, .. } => {
+                let since = timestamp::parse(filter.since.as_deref().unwrap()).unwrap();
+                assert!(since <= Utc::now() - Duration::minutes(15));
+                assert!(since >= before - Duration::minutes(15) - Duration::seconds(1));
+                assert_eq!(filter.until, None);
+            }
+            other => panic!("unexpected command {:?}", other),
+        }
+
+        assert!(parse(&args("logs query --since yesterday")).unwrap().is_err());
+        assert!(parse(&args("logs query --until all")).unwrap().is_err());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse(&args("logs query --bogus 1")).unwrap().is_err());
+        assert!(parse(&args("logs prune")).unwrap().is_err());
+        assert!(parse(&args("logs prune --before yesterday")).unwrap().is_err());
+        assert!(parse(&args("config restore")).unwrap().is_err());
+        assert!(parse(&args("db vacuum")).unwrap().is_err());
+
+        let invocation = parse(&args("diagnostics bundle --task Airline --limit 50")).unwrap().unwrap();
+        assert_eq!(
+            invocation.command,
+            Command::DiagnosticsBundle {
+                options: BundleOptions {
+                    task_name: Some("Airline".to_string()),
+                    task_uuid: None,
+                    log_limit: 50,
+                },
+                output: None,
+            }
+        );
+
+        let invocation = parse(&args("config restore backup.fbak --mode replace --dry-run")).unwrap().unwrap();
+        assert_eq!(
+            invocation.command,
+            Command::ConfigRestore {
+                input: PathBuf::from("backup.fbak"),
+                mode: ImportMode::Replace,
+                passphrase_env: PASSPHRASE_ENV.to_string(),
+                dry_run: true,
+            }
+        );
+    }
+
+    #[test]
+    fn test_csv_escaping() {
+        let log = LogEntry {
+            id: Some(1),
+            task_name: "Airline".to_string(),
+            log_level: "INFO".to_string(),
+            task_uuid: "u-1".to_string(),
+            timestamp: "2024-05-01T02:00:00.000Z".to_string(),
+            message: "said \"hi\", then left".to_string(),
+        };
+        assert_eq!(
+            logs_to_csv(&[log]).lines().nth(1).unwrap(),
+            "1,2024-05-01T02:00:00.000Z,INFO,Airline,u-1,\"said \"\"hi\"\", then left\""
+        );
+    }
+}
@@ -0,0 +1,67 @@
+use crate::common::Result;
+use crate::db::{load_config, save_config};
+use argon2::password_hash::rand_core::{OsRng, RngCore};
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine as Base64Engine;
+
+/// Web API 访问令牌，未设置时 API 不校验令牌
+pub const API_TOKEN_KEY: &str = "api_token";
+/// 令牌随机字节数
+const TOKEN_BYTES: usize = 32;
+
+/// 请求头：`Authorization: Bearer <令牌>` 或 `X-Api-Token: <令牌>`
+pub const TOKEN_HEADER: &str = "x-api-token";
+const BEARER_PREFIX: &str = "Bearer ";
+
+/// 当前令牌
+pub fn load() -> Result<Option<String>> {
+    load_config(API_TOKEN_KEY)
+}
+
+/// 生成新令牌并立即替换旧令牌，返回新令牌
+pub fn rotate() -> Result<String> {
+    let mut bytes = [0u8; TOKEN_BYTES];
+    OsRng.fill_bytes(&mut bytes);
+    let token = URL_SAFE_NO_PAD.encode(bytes);
+    save_config(API_TOKEN_KEY, &token)?;
+    Ok(token)
+}
+
+/// 从 `Authorization` 或 `X-Api-Token` 请求头中取出令牌
+pub fn from_headers(authorization: Option<&str>, api_token: Option<&str>) -> Option<String> {
+    authorization
+        .and_then(|value| value.strip_prefix(BEARER_PREFIX))
+        .or(api_token)
+        .map(|token| token.trim().to_string())
+}
+
+/// 校验请求令牌；未设置令牌时全部放行
+pub fn verify(expected: Option<&str>, provided: Option<&str>) -> bool {
+    match (expected, provided) {
+        (None, _) => true,
+        (Some(expected), Some(provided)) => constant_time_eq(expected.as_bytes(), provided.as_bytes()),
+        (Some(_), None) => false,
+    }
+}
+
+/// 逐字节比较全部内容，耗时不随第一个不同字节的位置变化
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify_and_headers() {
+        assert!(verify(None, None));
+        assert!(verify(Some("abc"), Some("abc")));
+        assert!(!verify(Some("abc"), Some("abd")));
+        assert!(!verify(Some("abc"), None));
+
+        assert_eq!(from_headers(Some("Bearer abc"), None).as_deref(), Some("abc"));
+        assert_eq!(from_headers(Some("Basic xyz"), Some(" abc ")).as_deref(), Some("abc"));
+        assert_eq!(from_headers(None, None), None);
+    }
+}
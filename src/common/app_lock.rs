@@ -0,0 +1,158 @@
+use crate::common::{AppError, Reason, Result};
+use crate::db::{delete_config, load_config, save_config};
+use argon2::password_hash::rand_core::OsRng;
+use argon2::password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+use argon2::Argon2;
+
+/// 口令哈希（Argon2 PHC 字符串），存在即表示已启用应用锁
+pub const LOCK_HASH_KEY: &str = "app_lock_hash";
+/// 空闲自动锁定时间（分钟），0 表示不自动锁定
+const LOCK_IDLE_MINUTES_KEY: &str = "app_lock_idle_minutes";
+/// 窗口最小化时是否锁定
+const LOCK_ON_MINIMIZE_KEY: &str = "app_lock_on_minimize";
+
+/// 口令最短长度
+pub const MIN_PASSPHRASE_LEN: usize = 6;
+
+/// 应用锁设置
+#[derive(Debug, Clone, PartialEq)]
+pub struct AppLockSettings {
+    pub enabled: bool,
+    pub idle_minutes: u32,
+    pub lock_on_minimize: bool,
+}
+
+impl Default for AppLockSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            idle_minutes: 10,
+            lock_on_minimize: true,
+        }
+    }
+}
+
+impl AppLockSettings {
+    /// 从配置数据库读取设置，缺失的项使用默认值
+    pub fn load() -> Result<Self> {
+        let defaults = Self::default();
+
+        let enabled = load_config(LOCK_HASH_KEY)?.is_some();
+        let idle_minutes = load_config(LOCK_IDLE_MINUTES_KEY)?
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(defaults.idle_minutes);
+        let lock_on_minimize = load_config(LOCK_ON_MINIMIZE_KEY)?
+            .map(|v| v == "true")
+            .unwrap_or(defaults.lock_on_minimize);
+
+        Ok(Self {
+            enabled,
+            idle_minutes,
+            lock_on_minimize,
+        })
+    }
+
+    /// 读取失败时使用的设置：无法确认是否设置了口令，按已启用处理，启动时保持锁定
+    pub fn fail_closed() -> Self {
+        Self {
+            enabled: true,
+            ..Self::default()
+        }
+    }
+
+    /// 保存空闲时间和最小化锁定选项（口令通过 `set_passphrase` 单独设置）
+    pub fn save(&self) -> Result<()> {
+        save_config(LOCK_IDLE_MINUTES_KEY, &self.idle_minutes.to_string())?;
+        save_config(LOCK_ON_MINIMIZE_KEY, &self.lock_on_minimize.to_string())?;
+        Ok(())
+    }
+}
+
+/// 生成口令的 Argon2 哈希
+pub fn hash_passphrase(passphrase: &str) -> Result<String> {
+    let salt = SaltString::generate(&mut OsRng);
+    Argon2::default()
+        .hash_password(passphrase.as_bytes(), &salt)
+        .map(|hash| hash.to_string())
+        .map_err(|e| AppError::Unknown(format!("口令哈希失败: {}", e)))
+}
+
+/// 校验口令与哈希是否匹配，哈希格式错误视为不匹配
+pub fn verify_hash(passphrase: &str, hash: &str) -> bool {
+    PasswordHash::new(hash)
+        .map(|parsed| {
+            Argon2::default()
+                .verify_password(passphrase.as_bytes(), &parsed)
+                .is_ok()
+        })
+        .unwrap_or(false)
+}
+
+/// 是否已设置应用锁口令
+pub fn is_enabled() -> bool {
+    matches!(load_config(LOCK_HASH_KEY), Ok(Some(_)))
+}
+
+/// 校验口令；未启用应用锁时始终返回 true
+pub fn verify_passphrase(passphrase: &str) -> Result<bool> {
+    match load_config(LOCK_HASH_KEY)? {
+        Some(hash) => Ok(verify_hash(passphrase, &hash)),
+        None => Ok(true),
+    }
+}
+
+/// 设置或修改口令，已启用时需要提供当前口令
+pub fn set_passphrase(current: Option<&str>, new_passphrase: &str) -> Result<()> {
+    if new_passphrase.chars().count() < MIN_PASSPHRASE_LEN {
+        return Err(AppError::InvalidConfig(Reason::new(
+            "error.passphrase_too_short",
+            &[&MIN_PASSPHRASE_LEN],
+        )));
+    }
+    if is_enabled() && !verify_passphrase(current.unwrap_or_default())? {
+        return Err(AppError::InvalidConfig(Reason::new("error.current_passphrase_wrong", &[])));
+    }
+
+    save_config(LOCK_HASH_KEY, &hash_passphrase(new_passphrase)?)
+}
+
+/// 关闭应用锁，需要提供当前口令
+pub fn disable(passphrase: &str) -> Result<()> {
+    if !verify_passphrase(passphrase)? {
+        return Err(AppError::InvalidConfig(Reason::new("error.current_passphrase_wrong", &[])));
+    }
+    delete_config(LOCK_HASH_KEY)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hash_and_verify() {
+        let hash = hash_passphrase("correct horse").unwrap();
+
+        assert!(hash.starts_with("$argon2"));
+        assert!(verify_hash("correct horse", &hash));
+        assert!(!verify_hash("wrong horse", &hash));
+    }
+
+    #[test]
+    fn test_fail_closed_is_enabled() {
+        assert!(AppLockSettings::fail_closed().enabled);
+        assert!(!AppLockSettings::default().enabled);
+    }
+
+    #[test]
+    fn test_hash_is_salted() {
+        let first = hash_passphrase("same-passphrase").unwrap();
+        let second = hash_passphrase("same-passphrase").unwrap();
+
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn test_malformed_hash_rejected() {
+        assert!(!verify_hash("anything", "not-a-phc-string"));
+    }
+}
@@ -0,0 +1,214 @@
+use crate::common::i18n::{self, Lang};
+use crate::common::{config_store, Result};
+use crate::db::{self, load_config, save_config};
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// 每个配置 key 最多保留的历史版本数
+pub const MAX_HISTORY_PER_KEY: usize = 50;
+
+/// 保存配置，并把修改前的版本写入 `config_history`
+///
+/// 内容未变化时不产生历史记录
+pub fn save_with_history(key: &str, value: &str) -> Result<()> {
+    if let Some(previous) = load_config(key)? {
+        record(key, &previous, value)?;
+    }
+    save_config(key, value)
+}
+
+/// 记录一次修改：内容变化时保存修改前的版本和变更摘要
+pub fn record(key: &str, previous: &str, value: &str) -> Result<()> {
+    record_in(&db::init_config_db()?, key, previous, value)
+}
+
+/// 在指定连接（或事务）中记录一次修改
+pub(crate) fn record_in(conn: &Connection, key: &str, previous: &str, value: &str) -> Result<()> {
+    if previous != value {
+        let summary = serde_json::to_string(&summarize_change(previous, value))?;
+        db::save_config_history_in(conn, key, previous, &summary, MAX_HISTORY_PER_KEY)?;
+    }
+    Ok(())
+}
+
+/// 取出分组中的记录数组（分组结构为 `{id, name, <records>: [...]}`）
+fn group_records(group: &Value) -> Vec<&Value> {
+    group
+        .as_object()
+        .and_then(|obj| obj.values().find_map(Value::as_array))
+        .map(|items| items.iter().collect())
+        .unwrap_or_default()
+}
+
+fn index_by_id<'a>(values: impl IntoIterator<Item = &'a Value>) -> HashMap<String, &'a Value> {
+    values
+        .into_iter()
+        .enumerate()
+        .map(|(index, value)| {
+            let id = value
+                .get("id")
+                .map(Value::to_string)
+                .unwrap_or_else(|| format!("#{}", index));
+            (id, value)
+        })
+        .collect()
+}
+
+/// 两个版本之间的变更统计
+///
+/// 以 JSON 保存在 `config_history.summary` 中，显示时按界面语言格式化
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChangeSummary {
+    /// 无法解析其中一个版本，只能视为整体替换
+    pub replaced: bool,
+    pub groups_added: usize,
+    pub groups_removed: usize,
+    pub groups_renamed: usize,
+    pub records_added: usize,
+    pub records_removed: usize,
+    pub records_modified: usize,
+}
+
+impl ChangeSummary {
+    /// 指定语言的描述，例如「删除分组 1，删除记录 12」
+    pub fn describe_in(&self, lang: Lang) -> String {
+        if self.replaced {
+            return lang.t("history.replaced").to_string();
+        }
+        let parts: Vec<String> = [
+            ("history.groups_added", self.groups_added),
+            ("history.groups_removed", self.groups_removed),
+            ("history.groups_renamed", self.groups_renamed),
+            ("history.records_added", self.records_added),
+            ("history.records_removed", self.records_removed),
+            ("history.records_modified", self.records_modified),
+        ]
+        .into_iter()
+        .filter(|(_, count)| *count > 0)
+        .map(|(key, count)| lang.t_args(key, &[&count]))
+        .collect();
+
+        if parts.is_empty() {
+            lang.t("history.unchanged").to_string()
+        } else {
+            parts.join(lang.t("history.separator"))
+        }
+    }
+}
+
+/// 按当前界面语言显示保存的摘要；旧版本保存的是中文文字，原样显示
+pub fn describe_summary(stored: &str) -> String {
+    match serde_json::from_str::<ChangeSummary>(stored) {
+        Ok(summary) => summary.describe_in(i18n::current()),
+        Err(_) => stored.to_string(),
+    }
+}
+
+/// 计算两个分组配置版本之间的变更统计
+pub fn summarize_change(old_json: &str, new_json: &str) -> ChangeSummary {
+    let groups = |json: &str| {
+        config_store::parse_stored(json)
+            .ok()
+            .and_then(|(_, data)| serde_json::from_value::<Vec<Value>>(data).ok())
+    };
+    let (Some(old_groups), Some(new_groups)) = (groups(old_json), groups(new_json)) else {
+        return ChangeSummary { replaced: true, ..Default::default() };
+    };
+
+    let old_index = index_by_id(&old_groups);
+    let new_index = index_by_id(&new_groups);
+
+    let mut summary = ChangeSummary::default();
+
+    for (id, old_group) in &old_index {
+        match new_index.get(id) {
+            None => {
+                summary.groups_removed += 1;
+                summary.records_removed += group_records(old_group).len();
+            }
+            Some(new_group) => {
+                if old_group.get("name") != new_group.get("name") {
+                    summary.groups_renamed += 1;
+                }
+
+                let old_records = index_by_id(group_records(old_group));
+                let new_records = index_by_id(group_records(new_group));
+                for (record_id, old_record) in &old_records {
+                    match new_records.get(record_id) {
+                        None => summary.records_removed += 1,
+                        Some(new_record) if new_record != old_record => summary.records_modified += 1,
+                        Some(_) => {}
+                    }
+                }
+                summary.records_added += new_records
+                    .keys()
+                    .filter(|record_id| !old_records.contains_key(*record_id))
+                    .count();
+            }
+        }
+    }
+    for (id, new_group) in &new_index {
+        if !old_index.contains_key(id) {
+            summary.groups_added += 1;
+            summary.records_added += group_records(new_group).len();
+        }
+    }
+
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summarize_bulk_delete() {
+        let old = r#"[
+            {"id":1,"name":"默认分组","proxies":[{"id":1,"ip":"1.1.1.1"},{"id":2,"ip":"2.2.2.2"}]},
+            {"id":2,"name":"备用","proxies":[{"id":1,"ip":"3.3.3.3"}]}
+        ]"#;
+        let new = r#"[{"id":1,"name":"默认分组","proxies":[]}]"#;
+
+        let summary = summarize_change(old, new);
+        assert_eq!(summary, ChangeSummary { groups_removed: 1, records_removed: 3, ..Default::default() });
+        assert_eq!(summary.describe_in(Lang::Zh), "删除分组 1，删除记录 3");
+        assert_eq!(summary.describe_in(Lang::En), "1 group(s) deleted, 3 record(s) deleted");
+    }
+
+    #[test]
+    fn test_summarize_edits() {
+        let old = r#"[{"id":1,"name":"A","cards":[{"id":1,"cvv":"111"},{"id":2,"cvv":"222"}]}]"#;
+        let new = r#"[{"id":1,"name":"B","cards":[{"id":1,"cvv":"999"},{"id":2,"cvv":"222"},{"id":3,"cvv":"333"}]},{"id":2,"name":"C","cards":[]}]"#;
+
+        assert_eq!(
+            summarize_change(old, new).describe_in(Lang::Zh),
+            "新增分组 1，重命名分组 1，新增记录 1，修改记录 1"
+        );
+    }
+
+    #[test]
+    fn test_summarize_unchanged_and_unparseable() {
+        let json = r#"[{"id":1,"name":"A","configs":[]}]"#;
+
+        assert_eq!(summarize_change(json, json).describe_in(Lang::Zh), "无变化");
+        assert!(summarize_change("{broken", json).replaced);
+
+        // 旧格式升级为版本封装时内容相同，不应视为变更
+        let enveloped = format!(r#"{{"version":2,"data":{}}}"#, json);
+        assert_eq!(summarize_change(json, &enveloped), ChangeSummary::default());
+    }
+
+    #[test]
+    fn test_stored_summary() {
+        let summary = ChangeSummary { records_added: 2, ..Default::default() };
+        let stored = serde_json::to_string(&summary).unwrap();
+        assert_eq!(describe_summary(&stored), summary.describe_in(i18n::current()));
+        assert_eq!(summary.describe_in(Lang::En), "2 record(s) added");
+
+        // 旧版本保存的中文摘要原样显示
+        assert_eq!(describe_summary("删除记录 12"), "删除记录 12");
+    }
+}
@@ -0,0 +1,233 @@
+use crate::common::{config_history, timestamp, AppError, Result};
+use crate::db::{self, delete_config, load_config, save_config};
+use chrono::Local;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+
+/// 当前配置格式版本
+///
+/// - 版本 1：未封装的裸 JSON（早期版本直接保存 `Vec<...Group>`）
+/// - 版本 2：`{"version": 2, "data": ...}` 封装，卡片 / 购票人记录带 `expires_at`
+pub const CURRENT_CONFIG_VERSION: u32 = 2;
+
+/// 未封装的旧格式视为版本 1
+const LEGACY_CONFIG_VERSION: u32 = 1;
+
+/// 隔离备份 key 的中缀：`<key>.quarantine.<时间戳>`
+pub const QUARANTINE_INFIX: &str = ".quarantine.";
+
+const AUDIT_TASK_NAME: &str = "Config";
+const AUDIT_TASK_UUID: &str = "config-store";
+
+/// 版本升级函数：`UPGRADES[n]` 把版本 `n + 1` 的数据升级到版本 `n + 2`
+type Upgrade = fn(&str, Value) -> Value;
+
+const UPGRADES: &[Upgrade] = &[upgrade_v1_to_v2];
+
+/// 版本 1 -> 2：为卡片和购票人记录补上 `expires_at`
+fn upgrade_v1_to_v2(key: &str, mut data: Value) -> Value {
+    let items_field = match key {
+        "card_groups" => "cards",
+        "passenger_groups" => "passengers",
+        _ => return data,
+    };
+
+    if let Some(groups) = data.as_array_mut() {
+        for group in groups {
+            if let Some(Value::Array(items)) = group.get_mut(items_field) {
+                for item in items.iter_mut().filter_map(Value::as_object_mut) {
+                    item.entry("expires_at").or_insert_with(|| json!(""));
+                }
+            }
+        }
+    }
+    data
+}
+
+/// 解析存储内容，返回 (版本, 数据)
+///
+/// 带 `version` 和 `data` 字段的对象视为封装格式，其余一律按版本 1 的裸 JSON 处理
+pub fn parse_stored(raw: &str) -> Result<(u32, Value)> {
+    let value: Value = serde_json::from_str(raw)?;
+    if let Value::Object(obj) = &value {
+        if let (Some(version), Some(data)) = (obj.get("version").and_then(Value::as_u64), obj.get("data")) {
+            let version = u32::try_from(version).unwrap_or(u32::MAX);
+            return Ok((version, data.clone()));
+        }
+    }
+    Ok((LEGACY_CONFIG_VERSION, value))
+}
+
+/// 按原有版本写回存储格式（版本 1 保持裸 JSON，不擅自升级）
+pub fn to_stored(version: u32, data: &Value) -> Result<String> {
+    if version == LEGACY_CONFIG_VERSION {
+        return Ok(serde_json::to_string(data)?);
+    }
+    let mut envelope = Map::new();
+    envelope.insert("version".to_string(), json!(version));
+    envelope.insert("data".to_string(), data.clone());
+    Ok(serde_json::to_string(&Value::Object(envelope))?)
+}
+
+/// 把任意版本的数据升级到当前版本
+pub fn upgrade(key: &str, version: u32, mut data: Value) -> Result<Value> {
+    if version == 0 || version > CURRENT_CONFIG_VERSION {
+        return Err(AppError::UnsupportedConfigVersion {
+            key: key.to_string(),
+            found: version,
+            supported: CURRENT_CONFIG_VERSION,
+        });
+    }
+    for step in &UPGRADES[(version - 1) as usize..] {
+        data = step(key, data);
+    }
+    Ok(data)
+}
+
+/// 解析并升级存储内容为目标类型，不修改数据库
+pub fn decode<T: DeserializeOwned>(key: &str, raw: &str) -> Result<T> {
+    let (version, data) = parse_stored(raw)?;
+    let data = upgrade(key, version, data)?;
+    Ok(serde_json::from_value(data)?)
+}
+
+/// 序列化为当前版本的封装格式
+pub fn encode<T: Serialize>(value: &T) -> Result<String> {
+    to_stored(CURRENT_CONFIG_VERSION, &serde_json::to_value(value)?)
+}
+
+/// 读取配置（只读），解析失败时返回错误但不做隔离
+pub fn read<T: DeserializeOwned>(key: &str) -> Result<Option<T>> {
+    load_config(key)?.map(|raw| decode(key, &raw)).transpose()
+}
+
+/// 读取配置，无法解析的数据移到隔离备份 key 后返回 `ConfigQuarantined`
+///
+/// 原 key 被清空，之后保存默认配置也不会覆盖用户数据
+pub fn load<T: DeserializeOwned>(key: &str) -> Result<Option<T>> {
+    let Some(raw) = load_config(key)? else {
+        return Ok(None);
+    };
+    match decode(key, &raw) {
+        Ok(value) => Ok(Some(value)),
+        Err(e) => {
+            let reason = e.to_string();
+            let backup_key = quarantine(key, &raw, &reason)?;
+            Err(AppError::ConfigQuarantined {
+                key: key.to_string(),
+                backup_key,
+                reason,
+            })
+        }
+    }
+}
+
+/// 以当前版本保存配置，并记录历史版本
+pub fn save<T: Serialize>(key: &str, value: &T) -> Result<()> {
+    config_history::save_with_history(key, &encode(value)?)
+}
+
+/// 把原始内容移到 `<key>.quarantine.<时间戳>`，返回备份 key
+pub fn quarantine(key: &str, raw: &str, reason: &str) -> Result<String> {
+    let backup_key = format!("{}{}{}", key, QUARANTINE_INFIX, Local::now().format("%Y%m%d%H%M%S"));
+    save_config(&backup_key, raw)?;
+    delete_config(key)?;
+
+    let _ = db::save_log(
+        AUDIT_TASK_NAME,
+        "ERROR",
+        AUDIT_TASK_UUID,
+        &timestamp::now(),
+        &format!("配置 {} 无法解析，已备份到 {}：{}", key, backup_key, reason),
+    );
+    Ok(backup_key)
+}
+
+/// 获取指定配置的全部隔离备份 key
+pub fn quarantined_keys(key: &str) -> Result<Vec<String>> {
+    db::list_config_keys(&format!("{}{}", key, QUARANTINE_INFIX))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Card {
+        id: usize,
+        card_number: String,
+        expires_at: String,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct CardGroup {
+        id: usize,
+        name: String,
+        cards: Vec<Card>,
+    }
+
+    /// 早期版本保存的卡片分组：裸数组，没有 `expires_at`
+    const LEGACY_CARD_GROUPS: &str = r#"[
+        {"id":1,"name":"默认分组","cards":[{"id":1,"card_number":"4111111111111111"}]},
+        {"id":2,"name":"备用","cards":[]}
+    ]"#;
+
+    /// 早期版本保存的代理分组
+    const LEGACY_PROXY_GROUPS: &str = r#"[
+        {"id":1,"name":"默认分组","proxies":[{"id":1,"ip":"1.1.1.1","port":"8080","username":"","password":""}]}
+    ]"#;
+
+    #[test]
+    fn test_decode_legacy_fixture() {
+        let groups: Vec<CardGroup> = decode("card_groups", LEGACY_CARD_GROUPS).unwrap();
+
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].cards[0].card_number, "4111111111111111");
+        assert_eq!(groups[0].cards[0].expires_at, "");
+
+        let (version, data) = parse_stored(LEGACY_PROXY_GROUPS).unwrap();
+        assert_eq!(version, LEGACY_CONFIG_VERSION);
+        let upgraded = upgrade("proxy_groups", version, data.clone()).unwrap();
+        assert_eq!(upgraded, data);
+    }
+
+    #[test]
+    fn test_encode_roundtrip() {
+        let groups = vec![CardGroup {
+            id: 1,
+            name: "默认分组".to_string(),
+            cards: vec![Card { id: 1, card_number: "5500000000000004".to_string(), expires_at: "2030-01-01".to_string() }],
+        }];
+
+        let stored = encode(&groups).unwrap();
+        let (version, _) = parse_stored(&stored).unwrap();
+        assert_eq!(version, CURRENT_CONFIG_VERSION);
+        assert_eq!(decode::<Vec<CardGroup>>("card_groups", &stored).unwrap(), groups);
+    }
+
+    #[test]
+    fn test_to_stored_keeps_version() {
+        let (version, data) = parse_stored(LEGACY_PROXY_GROUPS).unwrap();
+        let stored = to_stored(version, &data).unwrap();
+        assert!(stored.starts_with('['));
+
+        let enveloped = to_stored(CURRENT_CONFIG_VERSION, &data).unwrap();
+        assert_eq!(parse_stored(&enveloped).unwrap(), (CURRENT_CONFIG_VERSION, data));
+    }
+
+    #[test]
+    fn test_decode_rejects_future_and_broken() {
+        let future = r#"{"version":99,"data":[]}"#;
+        assert!(matches!(
+            decode::<Vec<CardGroup>>("card_groups", future),
+            Err(AppError::UnsupportedConfigVersion { found: 99, .. })
+        ));
+
+        // 字段类型变化（id 变成字符串）同样视为无法解析
+        let mismatched = r#"[{"id":"a","name":"x","cards":[]}]"#;
+        assert!(decode::<Vec<CardGroup>>("card_groups", mismatched).is_err());
+        assert!(decode::<Vec<CardGroup>>("card_groups", "{broken").is_err());
+    }
+}
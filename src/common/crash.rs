@@ -0,0 +1,211 @@
+//! 崩溃记录
+//!
+//! 全局 panic hook 把 panic 信息、位置和调用栈写入 logs.db（`System` / ERROR）和数据目录下的
+//! `crashes/crash-<时间>.json`。下次启动时界面读取未确认的崩溃文件并提示用户。
+
+use crate::common::http_task::Redactor;
+use crate::common::{timestamp, Result};
+use crate::db::{self, location};
+use serde::{Deserialize, Serialize};
+use std::backtrace::Backtrace;
+use std::fs;
+use std::panic::{self, PanicHookInfo};
+use std::path::{Path, PathBuf};
+
+const CRASH_DIR_NAME: &str = "crashes";
+const CRASH_PREFIX: &str = "crash-";
+const CRASH_EXTENSION: &str = ".json";
+/// 已在界面中确认过的崩溃文件后缀
+const SEEN_EXTENSION: &str = ".seen.json";
+/// 保留的崩溃文件数量
+const MAX_CRASH_FILES: usize = 10;
+
+const LOG_TASK_NAME: &str = "System";
+const LOG_TASK_UUID: &str = "crash";
+
+/// 一次崩溃的记录
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CrashReport {
+    /// RFC 3339 UTC 时间戳
+    pub occurred_at: String,
+    pub app_version: String,
+    pub thread: String,
+    pub message: String,
+    pub location: Option<String>,
+    pub backtrace: String,
+}
+
+impl CrashReport {
+    fn from_panic(info: &PanicHookInfo) -> Self {
+        let message = info
+            .payload_as_str()
+            .unwrap_or("(非字符串的 panic 信息)")
+            .to_string();
+        let thread = std::thread::current();
+
+        Self {
+            occurred_at: timestamp::now(),
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+            thread: thread.name().unwrap_or("<unnamed>").to_string(),
+            // panic 信息可能带有请求参数等内容，和日志一样先脱敏
+            message: Redactor::default().redact(&message),
+            location: info
+                .location()
+                .map(|location| format!("{}:{}:{}", location.file(), location.line(), location.column())),
+            backtrace: Backtrace::force_capture().to_string(),
+        }
+    }
+
+    /// 一行摘要：`线程 'main' panic：<信息>（<位置>）`
+    pub fn summary(&self) -> String {
+        match &self.location {
+            Some(location) => format!("线程 '{}' panic：{}（{}）", self.thread, self.message, location),
+            None => format!("线程 '{}' panic：{}", self.thread, self.message),
+        }
+    }
+}
+
+fn crash_dir() -> Result<PathBuf> {
+    Ok(location::current()?.config_dir.join(CRASH_DIR_NAME))
+}
+
+/// 写入 logs.db 和崩溃文件，任何一步失败都不影响另一步
+fn record(report: &CrashReport) {
+    let _ = db::save_log(
+        LOG_TASK_NAME,
+        "ERROR",
+        LOG_TASK_UUID,
+        &report.occurred_at,
+        &format!("{}\n{}", report.summary(), report.backtrace),
+    );
+    if let Ok(dir) = crash_dir() {
+        let _ = write_crash_file(&dir, report);
+    }
+}
+
+fn write_crash_file(dir: &Path, report: &CrashReport) -> Result<PathBuf> {
+    fs::create_dir_all(dir)?;
+    let stamp = report.occurred_at.replace([':', '.'], "-");
+    let path = dir.join(format!("{}{}{}", CRASH_PREFIX, stamp, CRASH_EXTENSION));
+    fs::write(&path, serde_json::to_string_pretty(report)?)?;
+
+    for old in crash_files_in(dir).into_iter().skip(MAX_CRASH_FILES) {
+        let _ = fs::remove_file(old);
+    }
+    Ok(path)
+}
+
+/// 目录中的全部崩溃文件（含已确认的，最新在前）
+fn crash_files_in(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut files: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with(CRASH_PREFIX) && name.ends_with(CRASH_EXTENSION))
+        })
+        .collect();
+    // 文件名中的时间戳固定长度，按名称倒序即最新在前
+    files.sort_by(|a, b| b.file_name().cmp(&a.file_name()));
+    files
+}
+
+fn is_seen(path: &Path) -> bool {
+    path.to_string_lossy().ends_with(SEEN_EXTENSION)
+}
+
+fn mark_seen(path: &Path) -> Result<()> {
+    let seen = path.to_string_lossy().trim_end_matches(CRASH_EXTENSION).to_string() + SEEN_EXTENSION;
+    fs::rename(path, seen)?;
+    Ok(())
+}
+
+fn latest_unseen_in(dir: &Path) -> Option<(PathBuf, CrashReport)> {
+    crash_files_in(dir)
+        .into_iter()
+        .filter(|path| !is_seen(path))
+        .find_map(|path| {
+            let report = serde_json::from_str(&fs::read_to_string(&path).ok()?).ok()?;
+            Some((path, report))
+        })
+}
+
+/// 安装全局 panic hook（保留默认的控制台输出）
+pub fn install_panic_hook() {
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        record(&CrashReport::from_panic(info));
+        default_hook(info);
+    }));
+}
+
+/// 最近一次尚未在界面中确认的崩溃
+pub fn pending_crash() -> Option<(PathBuf, CrashReport)> {
+    latest_unseen_in(&crash_dir().ok()?)
+}
+
+/// 标记为已确认（更早的未确认崩溃一并标记，下次启动不再提示）
+pub fn acknowledge_crashes() -> Result<()> {
+    crash_files_in(&crash_dir()?)
+        .iter()
+        .filter(|path| !is_seen(path))
+        .try_for_each(|path| mark_seen(path))
+}
+
+/// 全部崩溃记录（最新在前，用于诊断包）
+pub fn recent_crashes() -> Vec<CrashReport> {
+    let Ok(dir) = crash_dir() else {
+        return Vec::new();
+    };
+    crash_files_in(&dir)
+        .into_iter()
+        .filter_map(|path| serde_json::from_str(&fs::read_to_string(path).ok()?).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(occurred_at: &str) -> CrashReport {
+        CrashReport {
+            occurred_at: occurred_at.to_string(),
+            app_version: "0.1.0".to_string(),
+            thread: "main".to_string(),
+            message: "index out of bounds".to_string(),
+            location: Some("src/views/logs.rs:10:5".to_string()),
+            backtrace: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_crash_files_rotate_and_acknowledge() {
+        let dir = std::env::temp_dir().join(format!("factbot-crash-test-{}", uuid::Uuid::new_v4()));
+
+        for second in 0..(MAX_CRASH_FILES + 2) {
+            write_crash_file(&dir, &report(&format!("2024-05-01T10:00:{:02}.000Z", second))).unwrap();
+        }
+        assert_eq!(crash_files_in(&dir).len(), MAX_CRASH_FILES);
+
+        let (path, latest) = latest_unseen_in(&dir).unwrap();
+        assert_eq!(latest.occurred_at, "2024-05-01T10:00:11.000Z");
+
+        // 确认后不再提示
+        mark_seen(&path).unwrap();
+        assert_eq!(latest_unseen_in(&dir).unwrap().1.occurred_at, "2024-05-01T10:00:10.000Z");
+        assert_eq!(crash_files_in(&dir).len(), MAX_CRASH_FILES);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_summary() {
+        assert_eq!(
+            report("2024-05-01T10:00:00.000Z").summary(),
+            "线程 'main' panic：index out of bounds（src/views/logs.rs:10:5）"
+        );
+    }
+}
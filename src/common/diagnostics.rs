@@ -0,0 +1,319 @@
+//! 诊断包
+//!
+//! 把版本、启用的功能、数据库结构版本、自检结果、日志、崩溃记录和脱敏后的配置结构（含分组表中的
+//! 代理、OTP、卡片、乘客分组）打包为 zip，
+//! 用户反馈问题时附上即可，不必截图或手动复制日志。
+//!
+//! 配置按「默认脱敏」处理：只有 `KEPT_FIELDS` / `KEPT_SETTINGS` 中列出的字段保留原值，
+//! 其余字符串和数字一律替换为 `[REDACTED]`，新增的配置字段不会意外泄露。
+
+use crate::common::http_task::Redactor;
+use crate::common::{config_store, crash, timestamp, Result};
+use crate::db::health::{self, DbProblem};
+use crate::db::repo::{self, CardGroup, ConfigGroup, OtpGroup, PassengerGroup, ProxyGroup};
+use crate::db::{self, location, LogEntry, LogFilter};
+use chrono::Local;
+use directories::UserDirs;
+use rusqlite::Connection;
+use serde_json::{json, Map, Value};
+use std::fs::File;
+use std::io::{Seek, Write};
+use std::path::{Path, PathBuf, MAIN_SEPARATOR};
+use zip::write::SimpleFileOptions;
+use zip::ZipWriter;
+
+/// 默认包含的日志条数
+pub const DEFAULT_LOG_LIMIT: usize = 500;
+/// 诊断包中最多包含的系统日志条数
+const MAX_SYSTEM_LOGS: usize = 200;
+const SYSTEM_TASK_NAME: &str = "System";
+
+const REDACTED: &str = "[REDACTED]";
+/// 配置 JSON 中保留原值的字段（不含个人信息或密钥）
+const KEPT_FIELDS: &[&str] = &["id", "card_type", "service_provider", "expires_at"];
+/// 保留原值的纯文本配置项
+const KEPT_SETTINGS: &[&str] = &[
+    "app_launched",
+    "app_lock_idle_minutes",
+    "app_lock_on_minimize",
+    "log_display_timezone",
+];
+
+/// 编译时可选的功能
+const FEATURES: &[(&str, bool)] = &[
+    ("desktop", cfg!(feature = "desktop")),
+    ("web", cfg!(feature = "web")),
+    ("mobile", cfg!(feature = "mobile")),
+    ("server", cfg!(feature = "server")),
+    ("dev", cfg!(feature = "dev")),
+];
+
+/// 诊断包中日志的范围
+#[derive(Debug, Clone, PartialEq)]
+pub struct BundleOptions {
+    /// 任务名称，未指定时为全部任务
+    pub task_name: Option<String>,
+    /// 任务 UUID（包含即可）
+    pub task_uuid: Option<String>,
+    /// 最近的日志条数
+    pub log_limit: usize,
+}
+
+impl Default for BundleOptions {
+    fn default() -> Self {
+        Self {
+            task_name: None,
+            task_uuid: None,
+            log_limit: DEFAULT_LOG_LIMIT,
+        }
+    }
+}
+
+/// 默认保存位置：文档目录（没有时为用户目录）下的 `FactBot-diagnostics-<时间>.zip`
+pub fn default_bundle_path() -> Option<PathBuf> {
+    let dirs = UserDirs::new()?;
+    let dir = dirs.document_dir().unwrap_or(dirs.home_dir());
+    Some(dir.join(format!("FactBot-diagnostics-{}.zip", Local::now().format("%Y%m%d-%H%M%S"))))
+}
+
+/// 生成诊断包
+///
+/// 单项信息读取失败（如日志库损坏）时记录错误信息并继续，诊断包本身仍然生成
+pub fn create_bundle(path: &Path, options: &BundleOptions) -> Result<()> {
+    let entries = [
+        ("manifest.json", manifest()),
+        ("schema.json", schema_versions()),
+        ("health.json", health_report()),
+        ("config.json", or_error(redacted_config())),
+        ("logs.json", or_error(task_logs(options))),
+        ("system-logs.json", or_error(system_logs())),
+        ("crashes.json", json!(crash::recent_crashes())),
+    ];
+
+    let mut files = Vec::with_capacity(entries.len());
+    for (name, value) in entries {
+        files.push((name, serde_json::to_string_pretty(&value)?));
+    }
+    write_bundle(File::create(path)?, &files)
+}
+
+fn write_bundle<W: Write + Seek>(writer: W, entries: &[(&str, String)]) -> Result<()> {
+    let mut zip = ZipWriter::new(writer);
+    for (name, content) in entries {
+        zip.start_file(*name, SimpleFileOptions::default())?;
+        zip.write_all(content.as_bytes())?;
+    }
+    zip.finish()?;
+    Ok(())
+}
+
+fn or_error(result: Result<Value>) -> Value {
+    result.unwrap_or_else(|e| json!({ "error": e.to_string() }))
+}
+
+// ==================== 各部分内容 ====================
+
+fn manifest() -> Value {
+    let features: Vec<&str> = FEATURES.iter().filter(|(_, enabled)| *enabled).map(|(name, _)| *name).collect();
+    let data_dir = location::current().map(|current| {
+        json!({
+            "source": current.source.label(),
+            "config_dir": redact_path(&current.config_dir),
+            "log_dir": redact_path(&current.log_dir),
+            "log_fallback": current.log_fallback,
+        })
+    });
+
+    json!({
+        "created_at": timestamp::now(),
+        "app_version": env!("CARGO_PKG_VERSION"),
+        "os": std::env::consts::OS,
+        "arch": std::env::consts::ARCH,
+        "features": features,
+        "data_dir": or_error(data_dir),
+    })
+}
+
+fn schema_versions() -> Value {
+    let logs = db::log_schema_versions().map(|(stored, supported)| json!({ "stored": stored, "supported": supported }));
+    json!({
+        "logs_db": or_error(logs),
+        "config_format": { "supported": config_store::CURRENT_CONFIG_VERSION },
+    })
+}
+
+fn health_report() -> Value {
+    let report = health::run_startup_checks();
+    let databases: Vec<Value> = report
+        .databases
+        .iter()
+        .map(|status| {
+            json!({
+                "database": status.kind.label(),
+                "path": status.path.as_deref().map(redact_path),
+                "problem": status.problem.as_ref().map(DbProblem::description),
+            })
+        })
+        .collect();
+    json!({ "healthy": report.is_healthy(), "databases": databases })
+}
+
+/// 全部配置项和分组，JSON 配置保留结构和格式版本，值按默认脱敏处理
+fn redacted_config() -> Result<Value> {
+    let mut config = Map::new();
+    for key in db::list_config_keys("")? {
+        let raw = db::load_config(&key)?.unwrap_or_default();
+        config.insert(key.clone(), redacted_config_entry(&key, &raw));
+    }
+    config.extend(redacted_groups(&db::init_config_db()?)?);
+    Ok(Value::Object(config))
+}
+
+/// 分组表中的各类分组，以分组的 `CONFIG_KEY` 为名，保留分组和记录的结构
+fn redacted_groups(conn: &Connection) -> Result<Map<String, Value>> {
+    fn insert<G: ConfigGroup>(conn: &Connection, groups: &mut Map<String, Value>) -> Result<()> {
+        let data = serde_json::to_value(repo::load_groups_from::<G>(conn)?)?;
+        groups.insert(G::CONFIG_KEY.to_string(), json!({ "storage": "config_groups", "data": redact_value(&data) }));
+        Ok(())
+    }
+
+    let mut groups = Map::new();
+    insert::<ProxyGroup>(conn, &mut groups)?;
+    insert::<OtpGroup>(conn, &mut groups)?;
+    insert::<CardGroup>(conn, &mut groups)?;
+    insert::<PassengerGroup>(conn, &mut groups)?;
+    Ok(groups)
+}
+
+fn redacted_config_entry(key: &str, raw: &str) -> Value {
+    if KEPT_SETTINGS.contains(&key) {
+        return json!({ "value": raw });
+    }
+    match serde_json::from_str::<Value>(raw) {
+        Ok(Value::Object(_) | Value::Array(_)) => match config_store::parse_stored(raw) {
+            Ok((version, data)) => json!({ "format_version": version, "data": redact_value(&data) }),
+            Err(e) => json!({ "error": e.to_string() }),
+        },
+        _ => json!({ "value": redact_value(&Value::String(raw.to_string())) }),
+    }
+}
+
+/// 保留对象结构和数组长度，字符串和数字替换为 `[REDACTED]`（空字符串保留，便于看出未填写）
+fn redact_value(value: &Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(field, value)| {
+                    let kept = KEPT_FIELDS.contains(&field.as_str()) && !value.is_object() && !value.is_array();
+                    (field.clone(), if kept { value.clone() } else { redact_value(value) })
+                })
+                .collect(),
+        ),
+        Value::Array(items) => Value::Array(items.iter().map(redact_value).collect()),
+        Value::String(text) if text.is_empty() => value.clone(),
+        Value::String(_) | Value::Number(_) => Value::String(REDACTED.to_string()),
+        Value::Bool(_) | Value::Null => value.clone(),
+    }
+}
+
+/// 用户目录替换为 `~`，避免带出用户名
+fn redact_path(path: &Path) -> String {
+    let home = UserDirs::new().map(|dirs| dirs.home_dir().to_path_buf());
+    match home.as_deref().and_then(|home| path.strip_prefix(home).ok()) {
+        Some(relative) => format!("~{}{}", MAIN_SEPARATOR, relative.display()),
+        None => path.display().to_string(),
+    }
+}
+
+/// 日志写入时可能关闭了脱敏，打包时再按默认规则处理一遍
+fn redacted_logs(logs: Vec<LogEntry>, limit: usize) -> Value {
+    let redactor = Redactor::default();
+    let logs: Vec<LogEntry> = logs
+        .into_iter()
+        .take(limit)
+        .map(|log| LogEntry { message: redactor.redact(&log.message), ..log })
+        .collect();
+    json!(logs)
+}
+
+fn task_logs(options: &BundleOptions) -> Result<Value> {
+    let filter = LogFilter {
+        task_name: options.task_name.clone(),
+        task_uuid: options.task_uuid.clone(),
+        include_debug: true,
+        ..LogFilter::default()
+    };
+    Ok(redacted_logs(db::query_logs(&filter)?, options.log_limit))
+}
+
+fn system_logs() -> Result<Value> {
+    let filter = LogFilter {
+        task_name: Some(SYSTEM_TASK_NAME.to_string()),
+        include_debug: true,
+        ..LogFilter::default()
+    };
+    Ok(redacted_logs(db::query_logs(&filter)?, MAX_SYSTEM_LOGS))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Cursor, Read};
+    use zip::ZipArchive;
+
+    #[test]
+    fn test_write_bundle() {
+        let mut buffer = Cursor::new(Vec::new());
+        let manifest = manifest().to_string();
+        write_bundle(&mut buffer, &[("manifest.json", manifest), ("crashes.json", "[]".to_string())]).unwrap();
+
+        let mut archive = ZipArchive::new(buffer).unwrap();
+        assert_eq!(archive.len(), 2);
+
+        let mut content = String::new();
+        archive.by_name("manifest.json").unwrap().read_to_string(&mut content).unwrap();
+        assert!(content.contains(env!("CARGO_PKG_VERSION")));
+    }
+
+    #[test]
+    fn test_config_redaction() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        repo::create_tables(&conn).unwrap();
+        let cards = vec![CardGroup {
+            id: 1,
+            name: "主卡".to_string(),
+            cards: vec![repo::CardConfig {
+                id: 3,
+                card_type: "Visa".to_string(),
+                card_number: "4111111111111111".to_string(),
+                cvv: "123".to_string(),
+                expiry: String::new(),
+                holder_name: "ZHANG SAN".to_string(),
+                expires_at: "2030-01-01".to_string(),
+            }],
+        }];
+        repo::replace_groups_in(&mut conn, &cards).unwrap();
+
+        let groups = redacted_groups(&conn).unwrap();
+        assert_eq!(groups["proxy_groups"]["data"], json!([]));
+        let group = &groups["card_groups"]["data"][0];
+        assert_eq!(group["id"], 1);
+        assert_eq!(group["name"], REDACTED);
+        let card = &group["cards"][0];
+        assert_eq!(card["card_type"], "Visa");
+        assert_eq!(card["card_number"], REDACTED);
+        assert_eq!(card["cvv"], REDACTED);
+        assert_eq!(card["holder_name"], REDACTED);
+        assert_eq!(card["expiry"], "");
+        assert_eq!(card["expires_at"], "2030-01-01");
+
+        // config 表中的 JSON 配置保留格式版本
+        let entry = redacted_config_entry("interceptor", r#"{"version":1,"data":{"rules":[{"pattern":"example.com"}]}}"#);
+        assert_eq!(entry["format_version"], 1);
+        assert_eq!(entry["data"]["rules"][0]["pattern"], REDACTED);
+
+        // 纯文本配置默认脱敏，白名单中的保留
+        assert_eq!(redacted_config_entry("api_token", "secret")["value"], REDACTED);
+        assert_eq!(redacted_config_entry("log_display_timezone", "Asia/Tokyo")["value"], "Asia/Tokyo");
+    }
+}
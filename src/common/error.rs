@@ -1,5 +1,56 @@
+use crate::common::i18n::{self, Lang};
+use std::fmt;
 use thiserror::Error;
 
+/// 错误原因
+///
+/// - 面向用户的原因保存消息目录的 key 和参数，显示时按界面语言翻译
+/// - 第三方库的错误信息等技术细节保存原文，不翻译
+#[derive(Debug, Clone, PartialEq)]
+pub enum Reason {
+    Message { key: &'static str, args: Vec<String> },
+    Text(String),
+}
+
+impl Reason {
+    pub fn new(key: &'static str, args: &[&dyn fmt::Display]) -> Self {
+        Reason::Message {
+            key,
+            args: args.iter().map(|arg| arg.to_string()).collect(),
+        }
+    }
+
+    /// 指定语言的原因
+    pub fn in_lang(&self, lang: Lang) -> String {
+        match self {
+            Reason::Message { key, args } => {
+                let args: Vec<&dyn fmt::Display> = args.iter().map(|arg| arg as &dyn fmt::Display).collect();
+                lang.t_args(key, &args)
+            }
+            Reason::Text(text) => text.clone(),
+        }
+    }
+}
+
+/// `to_string()` 用于日志，与其他日志内容一样使用中文
+impl fmt::Display for Reason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.in_lang(Lang::Zh))
+    }
+}
+
+impl From<String> for Reason {
+    fn from(text: String) -> Self {
+        Reason::Text(text)
+    }
+}
+
+impl From<&str> for Reason {
+    fn from(text: &str) -> Self {
+        Reason::Text(text.to_string())
+    }
+}
+
 /// 全局错误类型，包含所有模块的错误
 #[derive(Debug, Error)]
 pub enum AppError {
@@ -14,17 +65,32 @@ pub enum AppError {
     RequestFailed(String),
 
     #[error("Invalid configuration: {0}")]
-    InvalidConfig(String),
+    InvalidConfig(Reason),
 
     #[error("Cookie parse error: {0}")]
     CookieParseError(String),
 
+    #[error("Task stopped: {0}")]
+    TaskStopped(String),
+
     // 数据库相关错误
     #[error("Database error: {0}")]
-    DatabaseError(String),
+    DatabaseError(Reason),
 
     #[error("Config not found: {0}")]
-    ConfigNotFound(String),
+    ConfigNotFound(Reason),
+
+    #[error("Unsupported config version {found} for {key} (max {supported})")]
+    UnsupportedConfigVersion { key: String, found: u32, supported: u32 },
+
+    #[error("Config {key} could not be parsed and was moved to {backup_key}: {reason}")]
+    ConfigQuarantined { key: String, backup_key: String, reason: String },
+
+    #[error("Settings backup could not be decrypted")]
+    BackupDecryptFailed,
+
+    #[error("Unsupported settings backup: {0}")]
+    UnsupportedBackup(Reason),
 
     // 日志相关错误
     #[error("Log initialization failed: {0}")]
@@ -49,10 +115,96 @@ pub enum AppError {
     #[error("SQLite error: {0}")]
     SqliteError(#[from] rusqlite::Error),
 
+    #[error("Zip archive error: {0}")]
+    ZipError(#[from] zip::result::ZipError),
+
     #[error("Unknown error: {0}")]
     Unknown(String),
 }
 
+impl AppError {
+    /// 面向用户的简短说明（界面提示使用，按当前界面语言；技术细节见 `to_string()`）
+    pub fn user_message(&self) -> String {
+        self.user_message_in(i18n::current())
+    }
+
+    /// 指定语言的用户说明
+    pub fn user_message_in(&self, lang: Lang) -> String {
+        let text = |key| lang.t(key).to_string();
+        match self {
+            AppError::Blocked(_) => text("error.blocked"),
+            AppError::NoProxyAvailable => text("error.no_proxy"),
+            AppError::RequestFailed(_) => text("error.request_failed"),
+            AppError::InvalidConfig(reason) => lang.t_args("error.invalid_config", &[&reason.in_lang(lang)]),
+            AppError::CookieParseError(_) => text("error.cookie"),
+            AppError::TaskStopped(_) => text("error.task_stopped"),
+            AppError::DatabaseError(reason) => lang.t_args("error.database", &[&reason.in_lang(lang)]),
+            AppError::ConfigNotFound(name) => lang.t_args("error.config_not_found", &[&name.in_lang(lang)]),
+            AppError::UnsupportedConfigVersion { .. } => text("error.config_version"),
+            AppError::ConfigQuarantined { backup_key, .. } => lang.t_args("error.config_quarantined", &[backup_key]),
+            AppError::BackupDecryptFailed => text("error.backup_decrypt"),
+            AppError::UnsupportedBackup(reason) => lang.t_args("error.unsupported_backup", &[&reason.in_lang(lang)]),
+            AppError::LogInitError(_) | AppError::LogWriteError(_) => text("error.log_write"),
+            AppError::InvalidLogLevel(level) => lang.t_args("error.log_level", &[level]),
+            AppError::IoError(e) => match e.kind() {
+                std::io::ErrorKind::PermissionDenied => text("error.io_permission"),
+                std::io::ErrorKind::NotFound => text("error.io_not_found"),
+                _ => text("error.io"),
+            },
+            AppError::JsonError(_) => text("error.json"),
+            AppError::UrlParseError(_) => text("error.url"),
+            AppError::SqliteError(e) => match e.sqlite_error_code() {
+                Some(rusqlite::ErrorCode::DatabaseBusy) | Some(rusqlite::ErrorCode::DatabaseLocked) => {
+                    text("error.db_busy")
+                }
+                Some(rusqlite::ErrorCode::DiskFull) => text("error.disk_full"),
+                Some(rusqlite::ErrorCode::ReadOnly) | Some(rusqlite::ErrorCode::PermissionDenied) => {
+                    text("error.db_permission")
+                }
+                Some(rusqlite::ErrorCode::DatabaseCorrupt) | Some(rusqlite::ErrorCode::NotADatabase) => {
+                    text("error.db_corrupt")
+                }
+                _ => text("error.db"),
+            },
+            AppError::ZipError(_) => text("error.zip"),
+            AppError::Unknown(_) => text("error.unknown"),
+        }
+    }
+}
+
 /// 全局 Result 类型别名
 /// 使用方式：Result<T> 而不是 Result<T, AppError>
 pub type Result<T> = std::result::Result<T, AppError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_user_message() {
+        let busy = rusqlite::Error::SqliteFailure(
+            rusqlite::ffi::Error::new(rusqlite::ffi::SQLITE_BUSY),
+            None,
+        );
+        assert_eq!(AppError::from(busy).user_message_in(Lang::Zh), "数据库正被占用，请稍后重试");
+
+        let denied = std::io::Error::new(std::io::ErrorKind::PermissionDenied, "denied");
+        let denied = AppError::from(denied);
+        assert_eq!(denied.user_message_in(Lang::Zh), "没有访问文件的权限");
+        // 不指定语言时按当前界面语言（不假定其他测试没有切换过语言）
+        assert_eq!(denied.user_message(), i18n::t("error.io_permission"));
+
+        // 技术细节保留在 Display 中，不翻译
+        let error = AppError::InvalidConfig("port 0".into());
+        assert_eq!(error.user_message_in(Lang::Zh), "配置有误：port 0");
+        assert_eq!(error.to_string(), "Invalid configuration: port 0");
+        assert_eq!(error.user_message_in(Lang::En), "Invalid configuration: port 0");
+
+        // 面向用户的原因按界面语言翻译，日志中为中文
+        let error = AppError::InvalidConfig(Reason::new("error.current_passphrase_wrong", &[]));
+        assert_eq!(error.user_message_in(Lang::En), "Invalid configuration: The current passphrase is incorrect");
+        assert_eq!(error.user_message_in(Lang::Zh), "配置有误：当前口令错误");
+        assert_eq!(error.to_string(), "Invalid configuration: 当前口令错误");
+        assert_eq!(AppError::NoProxyAvailable.user_message_in(Lang::En), "No proxy is available; add one in Settings first");
+    }
+}
@@ -1,3 +1,5 @@
+use crate::common::http_task::{InterceptorConfig, RedactionConfig};
+use crate::common::{AppError, Reason, Result};
 use std::time::Duration;
 
 #[derive(Debug, Clone)]
@@ -214,9 +216,73 @@ impl Emulation {
     }
 }
 
+/// 按主机限速：令牌桶 + 并发上限
+///
+/// 同一主机平均每秒最多 `requests_per_second` 个请求，允许瞬时突发 `burst` 个；
+/// 为 0 表示不限制
+#[derive(Debug, Clone, PartialEq)]
+pub struct RateLimitConfig {
+    pub requests_per_second: f64,
+    pub burst: u32,
+    /// 同一主机同时进行的请求数上限
+    pub max_concurrent_per_host: usize,
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        Self {
+            requests_per_second: 0.0,
+            burst: 1,
+            max_concurrent_per_host: 0,
+        }
+    }
+}
+
+impl RateLimitConfig {
+    /// 每秒请求数需为有限的非负数（0 表示不限制），否则计算等待时间时会出错
+    pub fn validate(&self) -> Result<()> {
+        let rate = self.requests_per_second;
+        if !rate.is_finite() || rate < 0.0 {
+            return Err(AppError::InvalidConfig(Reason::new("error.rate_invalid", &[&rate])));
+        }
+        Ok(())
+    }
+}
+
+/// 重试与熔断策略
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// 单个请求的最大重试次数（不含第一次）
+    pub max_retries: u32,
+    /// 指数退避的基础间隔：第 n 次重试等待 `base_delay * 2^n`（带随机抖动）
+    pub base_delay: Duration,
+    /// 单次等待的上限；`Retry-After` 超过此值时不再重试
+    pub max_delay: Duration,
+    /// 需要重试的状态码
+    pub retry_statuses: Vec<u16>,
+    /// 连续出现多少次服务器错误（5xx 或连接失败）后停止任务，0 表示不停止
+    pub max_consecutive_server_errors: u32,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 2,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            retry_statuses: vec![429, 502, 503, 504],
+            max_consecutive_server_errors: 5,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct TaskConfig {
+    /// 拦截规则；界面中编辑的规则用 `InterceptorConfig::load()` 读取
+    pub interceptor: InterceptorConfig,
+    /// 追加在 `interceptor` 规则之后的拦截域名（含子域名）
     pub intercept_domains: Vec<String>,
+    /// 追加在 `interceptor` 规则之后的 URL 关键词
     pub intercept_keywords: Vec<String>,
     pub ip_mode: IPMode,
     pub emulation: Emulation,
@@ -224,11 +290,15 @@ pub struct TaskConfig {
     pub enable_cookie_management: bool,
     pub allow_redirect: bool,
     pub verify_cert: bool,
+    pub redaction: RedactionConfig,
+    pub rate_limit: RateLimitConfig,
+    pub retry: RetryPolicy,
 }
 
 impl Default for TaskConfig {
     fn default() -> Self {
         Self {
+            interceptor: InterceptorConfig::default(),
             intercept_domains: Vec::new(),
             intercept_keywords: Vec::new(),
             ip_mode: IPMode::None,
@@ -237,6 +307,9 @@ impl Default for TaskConfig {
             enable_cookie_management: false,
             allow_redirect: true,
             verify_cert: true,
+            redaction: RedactionConfig::default(),
+            rate_limit: RateLimitConfig::default(),
+            retry: RetryPolicy::default(),
         }
     }
 }
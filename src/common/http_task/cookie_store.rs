@@ -1,15 +1,29 @@
+//! Cookie 存储（RFC 6265）
+//!
+//! Cookie 以 (domain, path, name) 区分。收到 `Set-Cookie` 时按请求 URL 补全默认域名和路径，
+//! 并拒绝 `Domain` 与请求主机不匹配的 Cookie；发送时按请求 URL 匹配域名、路径、`Secure` 和过期时间，
+//! 路径更长的在前，同长度时先创建的在前（RFC 6265 5.4）。
+//!
+//! 未实现公共后缀列表检查，`Domain=com` 这类设置不会被拒绝。
+
+use chrono::{NaiveDate, NaiveTime};
 use std::collections::HashMap;
-use std::time::SystemTime;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use url::Url;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Cookie {
     pub name: String,
     pub value: String,
+    /// `Domain` 属性（已去掉开头的点并转为小写）；存入 `CookieStore` 后为实际生效的域名
     pub domain: Option<String>,
     pub path: Option<String>,
+    /// 过期时间；`None` 为会话 Cookie。`Max-Age` 优先于 `Expires`
     pub expires: Option<SystemTime>,
     pub secure: bool,
     pub http_only: bool,
+    /// 没有 `Domain` 属性时只发送给设置它的主机，不包括子域名
+    pub host_only: bool,
 }
 
 impl Cookie {
@@ -22,47 +36,202 @@ impl Cookie {
             expires: None,
             secure: false,
             http_only: false,
+            host_only: false,
         }
     }
 
+    /// 解析一个 `Set-Cookie` 值（RFC 6265 5.2），没有 `=` 或名称为空时返回 None
     pub fn parse(cookie_str: &str) -> Option<Self> {
-        let parts: Vec<&str> = cookie_str.split(';').collect();
-        if parts.is_empty() {
-            return None;
-        }
+        Self::parse_at(cookie_str, SystemTime::now())
+    }
 
-        let name_value: Vec<&str> = parts[0].trim().splitn(2, '=').collect();
-        if name_value.len() != 2 {
+    fn parse_at(cookie_str: &str, now: SystemTime) -> Option<Self> {
+        let (name_value, attributes) = cookie_str.split_once(';').unwrap_or((cookie_str, ""));
+        let (name, value) = name_value.split_once('=')?;
+        let name = name.trim();
+        if name.is_empty() {
             return None;
         }
 
-        let mut cookie = Cookie::new(
-            name_value[0].trim().to_string(),
-            name_value[1].trim().to_string(),
-        );
-
-        for part in parts.iter().skip(1) {
-            let kv: Vec<&str> = part.trim().splitn(2, '=').collect();
-            if kv.is_empty() {
-                continue;
-            }
+        let mut cookie = Cookie::new(name.to_string(), value.trim().to_string());
+        let mut expires = None;
+        let mut max_age = None;
 
-            let key = kv[0].trim().to_lowercase();
-            match key.as_str() {
-                "domain" if kv.len() == 2 => cookie.domain = Some(kv[1].trim().to_string()),
-                "path" if kv.len() == 2 => cookie.path = Some(kv[1].trim().to_string()),
+        for attribute in attributes.split(';') {
+            let (key, value) = attribute.split_once('=').unwrap_or((attribute, ""));
+            let value = value.trim();
+            match key.trim().to_ascii_lowercase().as_str() {
+                "expires" => {
+                    if let Some(time) = parse_cookie_date(value) {
+                        expires = Some(time);
+                    }
+                }
+                "max-age" => {
+                    if let Some(seconds) = parse_max_age(value) {
+                        // 0 或负数表示立即过期
+                        max_age = Some(match u64::try_from(seconds) {
+                            Ok(seconds) if seconds > 0 => now + Duration::from_secs(seconds),
+                            _ => UNIX_EPOCH,
+                        });
+                    }
+                }
+                "domain" if !value.is_empty() => {
+                    cookie.domain = Some(value.trim_start_matches('.').to_ascii_lowercase());
+                }
+                "path" => {
+                    // 不以 `/` 开头的路径按未设置处理，使用默认路径
+                    cookie.path = value.starts_with('/').then(|| value.to_string());
+                }
                 "secure" => cookie.secure = true,
                 "httponly" => cookie.http_only = true,
                 _ => {}
             }
         }
 
+        cookie.expires = max_age.or(expires);
         Some(cookie)
     }
+
+    fn is_expired(&self, now: SystemTime) -> bool {
+        self.expires.is_some_and(|expires| expires <= now)
+    }
+}
+
+/// `Max-Age`：可带负号的整数，其他格式忽略
+fn parse_max_age(value: &str) -> Option<i64> {
+    let digits = value.strip_prefix('-').unwrap_or(value);
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    // 超出范围的大数按最大值处理
+    Some(value.parse().unwrap_or(if value.starts_with('-') { i64::MIN } else { i64::MAX }))
+}
+
+/// 开头的 `min..=max` 位数字，之后不能紧跟数字
+fn leading_number(token: &str, min: usize, max: usize) -> Option<u32> {
+    let count = token.bytes().take_while(u8::is_ascii_digit).count();
+    (min..=max).contains(&count).then(|| token[..count].parse().ok()).flatten()
+}
+
+/// `hh:mm:ss`，每段 1~2 位数字，秒之后可以有非数字字符
+fn parse_time_token(token: &str) -> Option<(u32, u32, u32)> {
+    let mut parts = token.splitn(3, ':');
+    let hour = parts.next()?;
+    let minute = parts.next()?;
+    let second = parts.next()?;
+    let whole = |part: &str| (part.len() <= 2).then(|| leading_number(part, 1, 2)).flatten();
+    Some((whole(hour)?, whole(minute)?, leading_number(second, 1, 2)?))
+}
+
+/// RFC 6265 5.1.1 的宽松日期解析，兼容 `Wed, 09 Jun 2021 10:18:14 GMT`、
+/// `Wednesday, 09-Jun-21 10:18:14 GMT`、`Wed Jun  9 10:18:14 2021` 等格式
+fn parse_cookie_date(value: &str) -> Option<SystemTime> {
+    const MONTHS: [&str; 12] = ["jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec"];
+    let is_delimiter = |c: char| {
+        matches!(c, '\t' | ' '..='/' | ';'..='@' | '['..='`' | '{'..='~')
+    };
+
+    let (mut time, mut day, mut month, mut year) = (None, None, None, None);
+    for token in value.split(is_delimiter).filter(|token| !token.is_empty()) {
+        if time.is_none() {
+            if let Some(parsed) = parse_time_token(token) {
+                time = Some(parsed);
+                continue;
+            }
+        }
+        if day.is_none() {
+            if let Some(parsed) = leading_number(token, 1, 2) {
+                day = Some(parsed);
+                continue;
+            }
+        }
+        // 按字节截取前 3 个字符，非 ASCII 字符跨边界时不是月份
+        if let Some(prefix) = token.get(..3).filter(|_| month.is_none()) {
+            if let Some(index) = MONTHS.iter().position(|name| name.eq_ignore_ascii_case(prefix)) {
+                month = Some(index as u32 + 1);
+                continue;
+            }
+        }
+        if year.is_none() {
+            if let Some(parsed) = leading_number(token, 2, 4) {
+                year = Some(parsed);
+            }
+        }
+    }
+
+    let ((hour, minute, second), day, month, mut year) = (time?, day?, month?, year?);
+    match year {
+        70..=99 => year += 1900,
+        0..=69 => year += 2000,
+        _ => {}
+    }
+    if year < 1601 {
+        return None;
+    }
+
+    let date = NaiveDate::from_ymd_opt(year as i32, month, day)?;
+    let time = NaiveTime::from_hms_opt(hour, minute, second)?;
+    let timestamp = date.and_time(time).and_utc().timestamp();
+    match u64::try_from(timestamp) {
+        Ok(seconds) => Some(UNIX_EPOCH + Duration::from_secs(seconds)),
+        Err(_) => Some(UNIX_EPOCH),
+    }
+}
+
+/// RFC 6265 5.1.3：相同，或 `host` 以 `.domain` 结尾且不是 IP 地址
+fn domain_match(host: &str, domain: &str) -> bool {
+    host == domain
+        || (host.len() > domain.len()
+            && host.ends_with(domain)
+            && host.as_bytes()[host.len() - domain.len() - 1] == b'.'
+            && host.parse::<std::net::IpAddr>().is_err())
+}
+
+/// RFC 6265 5.1.4：相同，或 `cookie_path` 是前缀且在 `/` 处断开
+fn path_match(request_path: &str, cookie_path: &str) -> bool {
+    request_path == cookie_path
+        || (request_path.starts_with(cookie_path)
+            && (cookie_path.ends_with('/') || request_path[cookie_path.len()..].starts_with('/')))
+}
+
+/// RFC 6265 5.1.4：请求路径去掉最后一段
+fn default_path(request_path: &str) -> String {
+    match request_path.rfind('/') {
+        Some(index) if request_path.starts_with('/') && index > 0 => request_path[..index].to_string(),
+        _ => "/".to_string(),
+    }
+}
+
+struct RequestTarget {
+    host: String,
+    path: String,
+    secure: bool,
+}
+
+impl RequestTarget {
+    fn parse(url: &str) -> Option<Self> {
+        let url = Url::parse(url).ok()?;
+        Some(Self {
+            // IPv6 地址去掉方括号
+            host: url.host_str()?.trim_matches(['[', ']']).to_ascii_lowercase(),
+            path: url.path().to_string(),
+            secure: matches!(url.scheme(), "https" | "wss"),
+        })
+    }
+}
+
+/// (domain, path, name)
+type CookieKey = (String, String, String);
+
+struct StoredCookie {
+    cookie: Cookie,
+    /// 创建顺序；替换同名 Cookie 时保留原值
+    created: u64,
 }
 
 pub struct CookieStore {
-    cookies: HashMap<String, Cookie>,
+    cookies: HashMap<CookieKey, StoredCookie>,
+    next_created: u64,
 }
 
 impl Default for CookieStore {
@@ -75,29 +244,128 @@ impl CookieStore {
     pub fn new() -> Self {
         Self {
             cookies: HashMap::new(),
+            next_created: 0,
         }
     }
 
-    pub fn add(&mut self, cookie: Cookie) {
-        self.cookies.insert(cookie.name.clone(), cookie);
+    /// 手动添加 Cookie（如用户粘贴的 Cookie）
+    ///
+    /// 没有设置 `domain` 时发送给所有主机，没有设置 `path` 时为 `/`
+    pub fn add(&mut self, mut cookie: Cookie) {
+        if cookie.path.is_none() {
+            cookie.path = Some("/".to_string());
+        }
+        self.insert(cookie);
     }
 
+    /// 删除所有同名 Cookie
     pub fn remove(&mut self, name: &str) {
-        self.cookies.remove(name);
+        self.cookies.retain(|(_, _, cookie_name), _| cookie_name != name);
+    }
+
+    /// 未过期的 Cookie 数量
+    pub fn len(&self) -> usize {
+        let now = SystemTime::now();
+        self.cookies.values().filter(|stored| !stored.cookie.is_expired(now)).count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
     }
 
-    pub fn get_header(&self) -> String {
-        self.cookies
+    fn insert(&mut self, cookie: Cookie) {
+        let key = (
+            cookie.domain.clone().unwrap_or_default(),
+            cookie.path.clone().unwrap_or_default(),
+            cookie.name.clone(),
+        );
+        let created = match self.cookies.get(&key) {
+            Some(existing) => existing.created,
+            None => {
+                self.next_created += 1;
+                self.next_created
+            }
+        };
+        self.cookies.insert(key, StoredCookie { cookie, created });
+    }
+
+    /// 请求 `request_url` 时应发送的 `Cookie` 请求头，没有匹配的 Cookie 时为空字符串
+    pub fn header_for(&self, request_url: &str) -> String {
+        self.header_at(request_url, SystemTime::now())
+    }
+
+    fn header_at(&self, request_url: &str, now: SystemTime) -> String {
+        let Some(target) = RequestTarget::parse(request_url) else {
+            return String::new();
+        };
+
+        let mut matched: Vec<&StoredCookie> = self
+            .cookies
             .values()
-            .map(|c| format!("{}={}", c.name, c.value))
+            .filter(|stored| {
+                let cookie = &stored.cookie;
+                let domain_ok = match cookie.domain.as_deref() {
+                    None | Some("") => true,
+                    Some(domain) if cookie.host_only => target.host == domain,
+                    Some(domain) => domain_match(&target.host, domain),
+                };
+                domain_ok
+                    && path_match(&target.path, cookie.path.as_deref().unwrap_or("/"))
+                    && (!cookie.secure || target.secure)
+                    && !cookie.is_expired(now)
+            })
+            .collect();
+        matched.sort_by(|a, b| {
+            let path_len = |stored: &StoredCookie| stored.cookie.path.as_deref().map_or(0, str::len);
+            path_len(b).cmp(&path_len(a)).then(a.created.cmp(&b.created))
+        });
+
+        matched
+            .iter()
+            .map(|stored| format!("{}={}", stored.cookie.name, stored.cookie.value))
             .collect::<Vec<_>>()
             .join("; ")
     }
 
-    pub fn update_from_set_cookie(&mut self, set_cookie_header: &str) {
-        if let Some(cookie) = Cookie::parse(set_cookie_header) {
-            self.add(cookie);
+    /// 处理请求 `request_url` 收到的一个 `Set-Cookie` 值，返回是否被接受（RFC 6265 5.3）
+    pub fn update_from_set_cookie(&mut self, request_url: &str, set_cookie_header: &str) -> bool {
+        self.update_at(request_url, set_cookie_header, SystemTime::now())
+    }
+
+    fn update_at(&mut self, request_url: &str, set_cookie_header: &str, now: SystemTime) -> bool {
+        let (Some(target), Some(mut cookie)) =
+            (RequestTarget::parse(request_url), Cookie::parse_at(set_cookie_header, now))
+        else {
+            return false;
+        };
+
+        match cookie.domain.take() {
+            // Domain 必须是请求主机本身或其上级域名
+            Some(domain) if !domain_match(&target.host, &domain) => return false,
+            Some(domain) => {
+                cookie.domain = Some(domain);
+                cookie.host_only = false;
+            }
+            None => {
+                cookie.domain = Some(target.host.clone());
+                cookie.host_only = true;
+            }
+        }
+        if cookie.path.is_none() {
+            cookie.path = Some(default_path(&target.path));
         }
+
+        // 过期时间已过表示删除
+        if cookie.is_expired(now) {
+            self.cookies.remove(&(
+                cookie.domain.clone().unwrap_or_default(),
+                cookie.path.clone().unwrap_or_default(),
+                cookie.name.clone(),
+            ));
+        } else {
+            self.insert(cookie);
+        }
+        true
     }
 }
 
@@ -105,9 +373,18 @@ impl CookieStore {
 mod tests {
     use super::*;
 
+    /// RFC 6265 示例中的时间之前（2021-01-01）
+    fn before_examples() -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(1_609_459_200)
+    }
+
+    fn date(value: &str) -> u64 {
+        parse_cookie_date(value).unwrap().duration_since(UNIX_EPOCH).unwrap().as_secs()
+    }
+
     #[test]
     fn test_cookie_parse() {
-        let cookie_str = "session=abc123; Domain=example.com; Path=/; Secure; HttpOnly";
+        let cookie_str = "session=abc123; Domain=.Example.com; Path=/; Secure; HttpOnly";
         let cookie = Cookie::parse(cookie_str).unwrap();
 
         assert_eq!(cookie.name, "session");
@@ -116,36 +393,143 @@ mod tests {
         assert_eq!(cookie.path, Some("/".to_string()));
         assert!(cookie.secure);
         assert!(cookie.http_only);
+
+        assert!(Cookie::parse("no-equals-sign").is_none());
+        assert!(Cookie::parse("=value").is_none());
+        assert_eq!(Cookie::parse("a=b; Path=relative").unwrap().path, None);
+    }
+
+    #[test]
+    fn test_cookie_dates() {
+        // RFC 6265 / RFC 2616 中出现的三种格式
+        assert_eq!(date("Wed, 09 Jun 2021 10:18:14 GMT"), 1_623_233_894);
+        assert_eq!(date("Wednesday, 09-Jun-21 10:18:14 GMT"), 1_623_233_894);
+        assert_eq!(date("Wed Jun  9 10:18:14 2021"), 1_623_233_894);
+        assert_eq!(date("Sun, 06 Nov 1994 08:49:37 GMT"), 784_111_777);
+
+        assert!(parse_cookie_date("Wed, 30 Feb 2021 10:18:14 GMT").is_none());
+        assert!(parse_cookie_date("Wed, 09 Jun 2021 25:18:14 GMT").is_none());
+        assert!(parse_cookie_date("Wed, 09 Jun 1600 10:18:14 GMT").is_none());
+        assert!(parse_cookie_date("tomorrow").is_none());
+        // 非 ASCII 字符不会在截取月份时出错
+        assert!(parse_cookie_date("éé").is_none());
+        assert!(parse_cookie_date("09 Jüne 2021 10:18:14").is_none());
+        let cookie = Cookie::parse_at("a=b; Expires=éé", before_examples()).unwrap();
+        assert_eq!(cookie.expires, None);
     }
 
     #[test]
-    fn test_cookie_store_add_and_get() {
+    fn test_max_age_overrides_expires() {
+        let now = before_examples();
+        let cookie = Cookie::parse_at("a=b; Max-Age=60; Expires=Wed, 09 Jun 2021 10:18:14 GMT", now).unwrap();
+        assert_eq!(cookie.expires, Some(now + Duration::from_secs(60)));
+
+        let cookie = Cookie::parse_at("a=b; Max-Age=-1", now).unwrap();
+        assert!(cookie.is_expired(now));
+
+        let cookie = Cookie::parse_at("a=b; Max-Age=soon; Expires=Wed, 09 Jun 2021 10:18:14 GMT", now).unwrap();
+        assert_eq!(cookie.expires, parse_cookie_date("Wed, 09 Jun 2021 10:18:14 GMT"));
+    }
+
+    #[test]
+    fn test_rfc6265_section_3_1_examples() {
+        let now = before_examples();
+        let url = "https://www.example.com/";
         let mut store = CookieStore::new();
-        store.add(Cookie::new("key1".to_string(), "value1".to_string()));
-        store.add(Cookie::new("key2".to_string(), "value2".to_string()));
 
-        let header = store.get_header();
-        assert!(header.contains("key1=value1"));
-        assert!(header.contains("key2=value2"));
+        // 最简单的 Cookie
+        assert!(store.update_at(url, "SID=31d4d96e407aad42", now));
+        assert_eq!(store.header_at(url, now), "SID=31d4d96e407aad42");
+
+        // Path 和 Domain：与已有的 host-only Cookie 不同，是新的一条
+        assert!(store.update_at(url, "SID=31d4d96e407aad42; Path=/; Domain=example.com", now));
+        assert!(store.update_at(url, "lang=en-US; Path=/; Domain=example.com", now));
+        assert_eq!(store.cookies.len(), 3);
+        assert_eq!(
+            store.header_at("https://sub.example.com/", now),
+            "SID=31d4d96e407aad42; lang=en-US"
+        );
+
+        // Expires：在过期时间之前发送，之后不再发送
+        assert!(store.update_at(url, "lang=en-US; Expires=Wed, 09 Jun 2021 10:18:14 GMT", now));
+        assert_eq!(store.header_at(url, now).matches("lang=en-US").count(), 2);
+        let after = UNIX_EPOCH + Duration::from_secs(1_623_233_894);
+        assert_eq!(store.header_at(url, after).matches("lang=en-US").count(), 1);
+
+        // 过去的 Expires 删除 Cookie
+        assert!(store.update_at(url, "lang=; Expires=Sun, 06 Nov 1994 08:49:37 GMT", now));
+        assert_eq!(store.header_at("https://www.example.com/", now).matches("lang=").count(), 1);
+    }
+
+    #[test]
+    fn test_secure_http_only_and_host_only() {
+        let now = before_examples();
+        let mut store = CookieStore::new();
+        assert!(store.update_at("https://www.example.com/", "SID=31d4d96e407aad42; Path=/; Secure; HttpOnly", now));
+
+        // Secure 只通过 https 发送；host-only 不发给子域名和上级域名
+        assert_eq!(store.header_at("https://www.example.com/", now), "SID=31d4d96e407aad42");
+        assert_eq!(store.header_at("http://www.example.com/", now), "");
+        assert_eq!(store.header_at("https://sub.www.example.com/", now), "");
+        assert_eq!(store.header_at("https://example.com/", now), "");
+    }
+
+    #[test]
+    fn test_domain_attribute_must_match_request_host() {
+        let now = before_examples();
+        let mut store = CookieStore::new();
+
+        assert!(!store.update_at("https://www.example.com/", "a=1; Domain=other.com", now));
+        assert!(!store.update_at("https://www.example.com/", "a=1; Domain=sub.www.example.com", now));
+        assert!(!store.update_at("https://notexample.com/", "a=1; Domain=example.com", now));
+        assert!(!store.update_at("https://192.168.0.1/", "a=1; Domain=0.1", now));
+        assert!(store.update_at("https://www.example.com/", "a=1; Domain=.EXAMPLE.com", now));
+
+        assert_eq!(store.header_at("https://example.com/", now), "a=1");
+        assert_eq!(store.header_at("https://notexample.com/", now), "");
+    }
+
+    #[test]
+    fn test_path_matching_and_order() {
+        let now = before_examples();
+        let mut store = CookieStore::new();
+        // 默认路径为请求路径去掉最后一段
+        assert!(store.update_at("https://example.com/docs/guide/intro", "deep=1", now));
+        assert!(store.update_at("https://example.com/", "root=1; Path=/", now));
+        assert!(store.update_at("https://example.com/", "docs=1; Path=/docs", now));
+
+        assert_eq!(store.header_at("https://example.com/docs/guide/page", now), "deep=1; docs=1; root=1");
+        assert_eq!(store.header_at("https://example.com/docs", now), "docs=1; root=1");
+        assert_eq!(store.header_at("https://example.com/docsearch", now), "root=1");
+
+        assert_eq!(default_path(""), "/");
+        assert_eq!(default_path("/file"), "/");
+        assert_eq!(default_path("/a/b/c"), "/a/b");
     }
 
     #[test]
-    fn test_cookie_store_update() {
+    fn test_replacing_keeps_creation_order() {
+        let now = before_examples();
         let mut store = CookieStore::new();
-        store.add(Cookie::new("session".to_string(), "old_value".to_string()));
-        store.add(Cookie::new("session".to_string(), "new_value".to_string()));
+        assert!(store.update_at("https://example.com/", "first=1", now));
+        assert!(store.update_at("https://example.com/", "second=1", now));
+        assert!(store.update_at("https://example.com/", "first=2", now));
 
-        let header = store.get_header();
-        assert_eq!(header, "session=new_value");
+        assert_eq!(store.header_at("https://example.com/", now), "first=2; second=1");
     }
 
     #[test]
-    fn test_cookie_store_remove() {
+    fn test_cookie_store_add_and_remove() {
         let mut store = CookieStore::new();
         store.add(Cookie::new("key1".to_string(), "value1".to_string()));
-        store.remove("key1");
+        store.add(Cookie::new("key2".to_string(), "value2".to_string()));
+        assert_eq!(store.header_for("https://any.example/"), "key1=value1; key2=value2");
+
+        store.add(Cookie::new("key1".to_string(), "new_value".to_string()));
+        assert_eq!(store.header_for("https://any.example/"), "key1=new_value; key2=value2");
 
-        let header = store.get_header();
-        assert_eq!(header, "");
+        store.remove("key1");
+        assert_eq!(store.header_for("https://any.example/"), "key2=value2");
+        assert_eq!(store.len(), 1);
     }
 }
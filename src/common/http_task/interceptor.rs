@@ -1,43 +1,354 @@
+//! 请求拦截规则
+//!
+//! 规则按列表顺序逐条检查，第一条命中的启用规则决定放行或拦截；都没有命中时按模式处理：
+//! 黑名单模式放行，白名单模式拦截。每条规则记录命中次数。
+
+use crate::common::i18n::t;
+use crate::common::{config_store, AppError, Reason, Result};
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
 use url::Url;
 
-#[derive(Debug, Clone)]
-pub struct Interceptor {
-    domains: Vec<String>,
-    url_keywords: Vec<String>,
+/// 保存拦截规则的配置 key
+pub const INTERCEPTOR_CONFIG_KEY: &str = "interceptor_rules";
+
+/// 没有规则命中时的处理方式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InterceptMode {
+    /// 默认放行，只拦截命中拦截规则的请求
+    #[default]
+    Blocklist,
+    /// 默认拦截，只放行命中放行规则的请求
+    Allowlist,
 }
 
-impl Interceptor {
-    pub fn new(domains: Vec<String>, url_keywords: Vec<String>) -> Self {
+impl InterceptMode {
+    pub fn label(&self) -> &'static str {
+        match self {
+            InterceptMode::Blocklist => t("intercept.blocklist"),
+            InterceptMode::Allowlist => t("intercept.allowlist"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RuleAction {
+    #[default]
+    Block,
+    Allow,
+}
+
+impl RuleAction {
+    pub fn label(&self) -> &'static str {
+        match self {
+            RuleAction::Block => t("intercept.block"),
+            RuleAction::Allow => t("intercept.allow"),
+        }
+    }
+}
+
+/// 匹配方式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PatternKind {
+    /// 主机名完全相同（不区分大小写）
+    Host,
+    /// 主机名相同或是其子域名：`example.com` 匹配 `a.example.com`，不匹配 `notexample.com`
+    #[default]
+    DomainSuffix,
+    /// 完整 URL 通配符：`*` 匹配任意字符，`?` 匹配单个字符（不区分大小写）
+    Wildcard,
+    /// 完整 URL 正则表达式（部分匹配，需要整体匹配时自行加 `^...$`）
+    Regex,
+    /// URL 包含关键词
+    Keyword,
+}
+
+impl PatternKind {
+    pub const ALL: [PatternKind; 5] = [
+        PatternKind::Host,
+        PatternKind::DomainSuffix,
+        PatternKind::Wildcard,
+        PatternKind::Regex,
+        PatternKind::Keyword,
+    ];
+
+    pub fn id(&self) -> &'static str {
+        match self {
+            PatternKind::Host => "host",
+            PatternKind::DomainSuffix => "domain_suffix",
+            PatternKind::Wildcard => "wildcard",
+            PatternKind::Regex => "regex",
+            PatternKind::Keyword => "keyword",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Self {
+        Self::ALL.into_iter().find(|kind| kind.id() == id).unwrap_or_default()
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            PatternKind::Host => t("intercept.host"),
+            PatternKind::DomainSuffix => t("intercept.domain_suffix"),
+            PatternKind::Wildcard => t("intercept.wildcard"),
+            PatternKind::Regex => t("intercept.regex"),
+            PatternKind::Keyword => t("intercept.keyword"),
+        }
+    }
+}
+
+/// 一条拦截规则
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InterceptRule {
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    #[serde(default)]
+    pub action: RuleAction,
+    pub kind: PatternKind,
+    pub pattern: String,
+    /// 只对这些请求方法生效（不区分大小写），为空表示所有方法
+    #[serde(default)]
+    pub methods: Vec<String>,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+impl InterceptRule {
+    pub fn new(action: RuleAction, kind: PatternKind, pattern: &str) -> Self {
         Self {
-            domains,
-            url_keywords,
+            enabled: true,
+            action,
+            kind,
+            pattern: pattern.to_string(),
+            methods: Vec::new(),
         }
     }
 
-    pub fn should_block(&self, url: &str) -> bool {
-        // 检查域名匹配
-        if let Ok(parsed_url) = Url::parse(url) {
-            if let Some(domain) = parsed_url.domain() {
-                for blocked_domain in &self.domains {
-                    if domain.contains(blocked_domain) || blocked_domain.contains(domain) {
-                        return true;
-                    }
-                }
+    pub fn methods(mut self, methods: &[&str]) -> Self {
+        self.methods = methods.iter().map(|method| method.to_string()).collect();
+        self
+    }
+
+    /// `TaskConfig` 中的域名 / 关键词列表：域名按「域名及子域名」、关键词按「URL 关键词」拦截
+    pub fn from_lists(domains: &[String], url_keywords: &[String]) -> Vec<Self> {
+        domains
+            .iter()
+            .map(|domain| Self::new(RuleAction::Block, PatternKind::DomainSuffix, domain))
+            .chain(url_keywords.iter().map(|keyword| Self::new(RuleAction::Block, PatternKind::Keyword, keyword)))
+            .filter(|rule| !rule.pattern.trim().is_empty())
+            .collect()
+    }
+
+    /// 日志和界面中显示的规则说明，如 `拦截 POST 域名及子域名 example.com`
+    pub fn describe(&self) -> String {
+        let methods = if self.methods.is_empty() {
+            String::new()
+        } else {
+            format!("{} ", self.methods.join("/").to_uppercase())
+        };
+        format!("{} {}{} {}", self.action.label(), methods, self.kind.label(), self.pattern)
+    }
+}
+
+/// 拦截配置：模式和有序规则列表
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct InterceptorConfig {
+    #[serde(default)]
+    pub mode: InterceptMode,
+    #[serde(default)]
+    pub rules: Vec<InterceptRule>,
+}
+
+impl InterceptorConfig {
+    /// 读取保存的拦截规则，没有保存过时为空
+    pub fn load() -> Result<Self> {
+        Ok(config_store::load(INTERCEPTOR_CONFIG_KEY)?.unwrap_or_default())
+    }
+
+    /// 校验后保存，规则无效时返回错误且不保存
+    pub fn save(&self) -> Result<()> {
+        Interceptor::with_config(self.clone())?;
+        config_store::save(INTERCEPTOR_CONFIG_KEY, self)
+    }
+}
+
+#[derive(Debug)]
+enum Matcher {
+    Host(String),
+    DomainSuffix(String),
+    Url(Regex),
+    Keyword(String),
+}
+
+impl Matcher {
+    fn compile(rule: &InterceptRule) -> Result<Self> {
+        let pattern = rule.pattern.trim();
+        if pattern.is_empty() {
+            return Err(AppError::InvalidConfig(Reason::new("error.intercept_rule_empty", &[&rule.describe()])));
+        }
+        let invalid = |e: regex::Error| AppError::InvalidConfig(Reason::new("error.intercept_rule_invalid", &[&rule.describe(), &e]));
+
+        Ok(match rule.kind {
+            PatternKind::Host => Matcher::Host(normalize_host(pattern)),
+            PatternKind::DomainSuffix => {
+                Matcher::DomainSuffix(normalize_host(pattern.trim_start_matches("*.").trim_start_matches('.')))
+            }
+            PatternKind::Wildcard => {
+                let translated = pattern
+                    .chars()
+                    .map(|c| match c {
+                        '*' => ".*".to_string(),
+                        '?' => ".".to_string(),
+                        c => regex::escape(&c.to_string()),
+                    })
+                    .collect::<String>();
+                let regex = RegexBuilder::new(&format!("^{}$", translated))
+                    .case_insensitive(true)
+                    .build()
+                    .map_err(invalid)?;
+                Matcher::Url(regex)
             }
+            PatternKind::Regex => Matcher::Url(Regex::new(pattern).map_err(invalid)?),
+            PatternKind::Keyword => Matcher::Keyword(pattern.to_string()),
+        })
+    }
+
+    fn matches(&self, host: Option<&str>, url: &str) -> bool {
+        match self {
+            Matcher::Host(expected) => host == Some(expected.as_str()),
+            Matcher::DomainSuffix(domain) => host.is_some_and(|host| {
+                host == domain || host.strip_suffix(domain.as_str()).is_some_and(|prefix| prefix.ends_with('.'))
+            }),
+            Matcher::Url(regex) => regex.is_match(url),
+            Matcher::Keyword(keyword) => url.contains(keyword.as_str()),
         }
+    }
+}
 
-        // 检查 URL 关键词匹配
-        for keyword in &self.url_keywords {
-            if url.contains(keyword) {
-                return true;
+/// 主机名统一为小写、去掉末尾的点
+fn normalize_host(host: &str) -> String {
+    host.trim_end_matches('.').to_ascii_lowercase()
+}
+
+#[derive(Debug)]
+struct CompiledRule {
+    rule: InterceptRule,
+    matcher: Matcher,
+    hits: AtomicU64,
+}
+
+impl CompiledRule {
+    fn applies_to(&self, method: &str) -> bool {
+        self.rule.enabled
+            && (self.rule.methods.is_empty()
+                || self.rule.methods.iter().any(|allowed| allowed.trim().eq_ignore_ascii_case(method)))
+    }
+}
+
+/// 一次检查的结果
+#[derive(Debug, Clone, PartialEq)]
+pub struct InterceptDecision {
+    pub allowed: bool,
+    /// 命中的规则序号，`None` 表示没有规则命中、按模式处理
+    pub rule: Option<usize>,
+}
+
+/// 规则及其命中次数
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuleHits {
+    pub rule: InterceptRule,
+    pub hits: u64,
+}
+
+/// 编译后的拦截器，克隆后共享命中计数
+#[derive(Debug, Clone)]
+pub struct Interceptor {
+    mode: InterceptMode,
+    rules: Arc<[CompiledRule]>,
+}
+
+impl Interceptor {
+    /// 只拦截指定域名和 URL 关键词的黑名单拦截器（见 `InterceptRule::from_lists`）
+    pub fn new(domains: Vec<String>, url_keywords: Vec<String>) -> Self {
+        let rules = InterceptRule::from_lists(&domains, &url_keywords);
+        Self::with_config(InterceptorConfig { mode: InterceptMode::Blocklist, rules })
+            .expect("domain and keyword rules always compile")
+    }
+
+    /// 编译规则，模式为空或正则无效时返回 `InvalidConfig`
+    pub fn with_config(config: InterceptorConfig) -> Result<Self> {
+        let rules = config
+            .rules
+            .into_iter()
+            .map(|rule| {
+                Ok(CompiledRule {
+                    matcher: Matcher::compile(&rule)?,
+                    rule,
+                    hits: AtomicU64::new(0),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self { mode: config.mode, rules: rules.into() })
+    }
+
+    pub fn mode(&self) -> InterceptMode {
+        self.mode
+    }
+
+    /// 检查请求并累计命中次数
+    pub fn check(&self, method: &str, url: &str) -> InterceptDecision {
+        let host = Url::parse(url).ok().and_then(|parsed| parsed.host_str().map(normalize_host));
+        for (index, compiled) in self.rules.iter().enumerate() {
+            if compiled.applies_to(method) && compiled.matcher.matches(host.as_deref(), url) {
+                compiled.hits.fetch_add(1, Ordering::Relaxed);
+                return InterceptDecision {
+                    allowed: compiled.rule.action == RuleAction::Allow,
+                    rule: Some(index),
+                };
             }
         }
+        InterceptDecision {
+            allowed: self.mode == InterceptMode::Blocklist,
+            rule: None,
+        }
+    }
 
-        false
+    pub fn should_block(&self, method: &str, url: &str) -> bool {
+        !self.check(method, url).allowed
     }
 
+    /// 第 `index` 条规则
+    pub fn rule(&self, index: usize) -> Option<&InterceptRule> {
+        self.rules.get(index).map(|compiled| &compiled.rule)
+    }
+
+    /// 按顺序列出规则和命中次数
+    pub fn hits(&self) -> Vec<RuleHits> {
+        self.rules
+            .iter()
+            .map(|compiled| RuleHits {
+                rule: compiled.rule.clone(),
+                hits: compiled.hits.load(Ordering::Relaxed),
+            })
+            .collect()
+    }
+
+    pub fn reset_hits(&self) {
+        for compiled in self.rules.iter() {
+            compiled.hits.store(0, Ordering::Relaxed);
+        }
+    }
+
+    /// 黑名单模式且没有规则时不做任何拦截
     pub fn is_empty(&self) -> bool {
-        self.domains.is_empty() && self.url_keywords.is_empty()
+        self.mode == InterceptMode::Blocklist && self.rules.is_empty()
     }
 }
 
@@ -45,6 +356,10 @@ impl Interceptor {
 mod tests {
     use super::*;
 
+    fn interceptor(mode: InterceptMode, rules: Vec<InterceptRule>) -> Interceptor {
+        Interceptor::with_config(InterceptorConfig { mode, rules }).unwrap()
+    }
+
     #[test]
     fn test_domain_blocking() {
         let interceptor = Interceptor::new(
@@ -52,9 +367,13 @@ mod tests {
             vec![],
         );
 
-        assert!(interceptor.should_block("https://example.com/path"));
-        assert!(interceptor.should_block("https://sub.example.com/path"));
-        assert!(!interceptor.should_block("https://other.com/path"));
+        assert!(interceptor.should_block("GET", "https://example.com/path"));
+        assert!(interceptor.should_block("GET", "https://sub.example.com/path"));
+        assert!(!interceptor.should_block("GET", "https://other.com/path"));
+        // 不再按子串匹配
+        assert!(!interceptor.should_block("GET", "https://notexample.com/path"));
+        let interceptor = Interceptor::new(vec!["sub.a.com".to_string()], vec![]);
+        assert!(!interceptor.should_block("GET", "https://a.com/"));
     }
 
     #[test]
@@ -64,8 +383,8 @@ mod tests {
             vec!["blocked".to_string()],
         );
 
-        assert!(interceptor.should_block("https://example.com/blocked/path"));
-        assert!(!interceptor.should_block("https://example.com/allowed/path"));
+        assert!(interceptor.should_block("GET", "https://example.com/blocked/path"));
+        assert!(!interceptor.should_block("GET", "https://example.com/allowed/path"));
     }
 
     #[test]
@@ -73,6 +392,82 @@ mod tests {
         let interceptor = Interceptor::new(vec![], vec![]);
 
         assert!(interceptor.is_empty());
-        assert!(!interceptor.should_block("https://example.com/path"));
+        assert!(!interceptor.should_block("GET", "https://example.com/path"));
+    }
+
+    #[test]
+    fn test_pattern_kinds() {
+        let interceptor = interceptor(
+            InterceptMode::Blocklist,
+            vec![
+                InterceptRule::new(RuleAction::Block, PatternKind::Host, "Tracker.Example.org"),
+                InterceptRule::new(RuleAction::Block, PatternKind::DomainSuffix, "*.ads.net"),
+                InterceptRule::new(RuleAction::Block, PatternKind::Wildcard, "https://cdn.*/pixel?.gif"),
+                InterceptRule::new(RuleAction::Block, PatternKind::Regex, r"/api/v\d+/telemetry"),
+            ],
+        );
+
+        assert!(interceptor.should_block("GET", "https://tracker.example.org/x"));
+        assert!(!interceptor.should_block("GET", "https://a.tracker.example.org/x"));
+        assert!(interceptor.should_block("GET", "https://ads.net/"));
+        assert!(interceptor.should_block("GET", "https://x.y.ads.net/"));
+        assert!(!interceptor.should_block("GET", "https://badads.net/"));
+        assert!(interceptor.should_block("GET", "https://CDN.example.com/pixel1.gif"));
+        assert!(!interceptor.should_block("GET", "https://cdn.example.com/pixel12.gif"));
+        assert!(interceptor.should_block("GET", "https://example.com/api/v2/telemetry?x=1"));
+        assert!(!interceptor.should_block("GET", "https://example.com/api/vx/telemetry"));
+    }
+
+    #[test]
+    fn test_order_methods_and_allowlist() {
+        // 第一条命中的规则生效：放行规则在前时覆盖后面的拦截规则
+        let blocklist = interceptor(
+            InterceptMode::Blocklist,
+            vec![
+                InterceptRule::new(RuleAction::Allow, PatternKind::Keyword, "/health"),
+                InterceptRule::new(RuleAction::Block, PatternKind::DomainSuffix, "example.com").methods(&["post", "PUT"]),
+            ],
+        );
+        assert_eq!(blocklist.check("POST", "https://example.com/health"), InterceptDecision { allowed: true, rule: Some(0) });
+        assert_eq!(blocklist.check("POST", "https://example.com/order"), InterceptDecision { allowed: false, rule: Some(1) });
+        assert_eq!(blocklist.check("GET", "https://example.com/order"), InterceptDecision { allowed: true, rule: None });
+
+        let allowlist = interceptor(
+            InterceptMode::Allowlist,
+            vec![InterceptRule::new(RuleAction::Allow, PatternKind::DomainSuffix, "airline.com")],
+        );
+        assert!(!allowlist.is_empty());
+        assert!(!allowlist.should_block("GET", "https://www.airline.com/"));
+        assert!(allowlist.should_block("GET", "https://google-analytics.com/collect"));
+    }
+
+    #[test]
+    fn test_hit_counters_and_disabled_rules() {
+        let mut disabled = InterceptRule::new(RuleAction::Block, PatternKind::Keyword, "a");
+        disabled.enabled = false;
+        let interceptor = interceptor(
+            InterceptMode::Blocklist,
+            vec![disabled, InterceptRule::new(RuleAction::Block, PatternKind::Host, "a.com")],
+        );
+
+        interceptor.check("GET", "https://a.com/");
+        interceptor.clone().check("GET", "https://a.com/b");
+        interceptor.check("GET", "https://b.com/");
+        let hits: Vec<u64> = interceptor.hits().iter().map(|rule| rule.hits).collect();
+        assert_eq!(hits, [0, 2]);
+
+        interceptor.reset_hits();
+        assert!(interceptor.hits().iter().all(|rule| rule.hits == 0));
+    }
+
+    #[test]
+    fn test_invalid_rules_rejected() {
+        let invalid = |rule| Interceptor::with_config(InterceptorConfig { mode: InterceptMode::Blocklist, rules: vec![rule] });
+        assert!(invalid(InterceptRule::new(RuleAction::Block, PatternKind::Regex, "(unclosed")).is_err());
+        assert!(invalid(InterceptRule::new(RuleAction::Block, PatternKind::Host, "  ")).is_err());
+
+        let config: InterceptorConfig =
+            serde_json::from_str(r#"{"mode": "allowlist", "rules": [{"kind": "host", "pattern": "a.com"}]}"#).unwrap();
+        assert_eq!(config.rules[0], InterceptRule::new(RuleAction::Block, PatternKind::Host, "a.com"));
     }
 }
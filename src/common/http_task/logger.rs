@@ -1,4 +1,5 @@
-use chrono::Local;
+use crate::common::http_task::Redactor;
+use crate::common::timestamp::{self, DisplayZone};
 use colored::*;
 use std::sync::{Arc, Mutex};
 
@@ -16,6 +17,7 @@ pub enum LogLevel {
 pub struct LogEntry {
     pub level: LogLevel,
     pub message: String,
+    /// RFC 3339 UTC 时间戳（与数据库中一致）
     pub timestamp: String,
 }
 
@@ -24,11 +26,14 @@ pub struct LogEntry {
 /// 
 /// 日志不会自动打印，而是存储在内存和数据库中，需要时才打印或获取
 /// 颜色：INFO=白色, WARN=黄色, ERROR=红色, DEBUG=蓝色
+///
+/// 所有日志内容在写入前都会经过 `Redactor` 脱敏
 pub struct Logger {
     task_name: String,
     task_id: String,        // 完整 UUID
     task_id_short: String,  // UUID 前8位（用于显示）
     logs: Arc<Mutex<Vec<LogEntry>>>,
+    redactor: Redactor,
 }
 
 impl Logger {
@@ -37,7 +42,14 @@ impl Logger {
     /// # 参数
     /// - `task_name`: 任务名称
     /// - `task_id`: 完整的任务 UUID
+    ///
+    /// 使用默认脱敏规则（所有内置检测器开启）
     pub fn new(task_name: String, task_id: String) -> Self {
+        Self::with_redactor(task_name, task_id, Redactor::default())
+    }
+
+    /// 创建使用指定脱敏器的日志记录器
+    pub fn with_redactor(task_name: String, task_id: String, redactor: Redactor) -> Self {
         // 取 UUID 的前 8 位用于显示
         let task_id_short = task_id.chars().take(8).collect();
         
@@ -46,6 +58,7 @@ impl Logger {
             task_id: task_id.clone(),
             task_id_short,
             logs: Arc::new(Mutex::new(Vec::new())),
+            redactor,
         }
     }
 
@@ -59,9 +72,10 @@ impl Logger {
             LogLevel::Error => "ERROR",
         };
         
+        // 控制台按本机时区显示
         let formatted = format!(
             "[{}] [{}] [{}] [{}] : {}",
-            self.task_name, level_str, self.task_id_short, timestamp, message
+            self.task_name, level_str, self.task_id_short, DisplayZone::Local.format(timestamp), message
         );
         
         match level {
@@ -78,7 +92,11 @@ impl Logger {
     /// - dev 模式：所有日志都保存到内存和数据库
     /// - 正式版：DEBUG 日志只入库，不保存到内存（UI 不展示）
     fn add_log(&self, level: LogLevel, message: &str) {
-        let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+        let timestamp = timestamp::now();
+        
+        // 脱敏后再进入内存、控制台和数据库
+        let message = self.redactor.redact(message);
+        let message = message.as_str();
         
         // 正式版：DEBUG 日志不保存到内存（UI 不展示）
         #[cfg(not(feature = "dev"))]
@@ -297,6 +315,21 @@ mod tests {
         assert_eq!(info_logs[0].message, "信息日志");
     }
 
+    #[test]
+    fn test_log_redaction() {
+        let logger = Logger::new(
+            "脱敏测试".to_string(),
+            "redact12-5678-90ab-cdef-ghijklmnopqr".to_string(),
+        );
+
+        logger.debug("Request body: {\"email\":\"li.si@example.com\"}");
+        logger.debug("Cookies: session=abc123");
+
+        let logs = logger.get_logs();
+        assert_eq!(logs[0].message, "Request body: {\"email\":\"l***@example.com\"}");
+        assert_eq!(logs[1].message, "Cookies: session=***");
+    }
+
     #[test]
     fn test_log_clear() {
         let logger = Logger::new(
@@ -3,15 +3,26 @@ mod cookie_store;
 mod interceptor;
 mod ip_manager;
 mod logger;
+mod redactor;
 mod task_manager;
+mod throttle;
+mod transport;
 mod types;
 
-pub use config::{Emulation, IPMode, ProxyConfig, TaskConfig};
+pub use config::{Emulation, IPMode, ProxyConfig, RateLimitConfig, RetryPolicy, TaskConfig};
 pub use cookie_store::{Cookie, CookieStore};
-pub use interceptor::Interceptor;
+pub use interceptor::{
+    InterceptDecision, InterceptMode, InterceptRule, Interceptor, InterceptorConfig, PatternKind, RuleAction, RuleHits,
+    INTERCEPTOR_CONFIG_KEY,
+};
 pub use ip_manager::IPManager;
 pub use logger::{LogEntry, LogLevel as LoggerLevel, Logger};
+pub use redactor::{RedactionConfig, Redactor};
 pub use task_manager::TaskManager;
+pub use throttle::RateLimiter;
+pub use transport::{
+    Exchange, HttpRequest, HttpResponse, HttpTransport, RecordingTransport, ReplayTransport, ReqwestTransport,
+};
 // 重新导出全局错误类型，保持向后兼容
 pub use crate::common::{AppError, Result};
 pub type TaskError = AppError;
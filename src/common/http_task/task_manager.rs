@@ -1,11 +1,16 @@
 use crate::common::{AppError, Result};
 use crate::common::http_task::{
-    CookieStore, IPManager, Interceptor, Logger, ProxyConfig, TaskConfig,
+    CookieStore, HttpRequest, HttpResponse, HttpTransport, IPManager, InterceptRule, Interceptor, Logger,
+    ProxyConfig, RateLimiter, Redactor, ReqwestTransport, RetryPolicy, RuleHits, TaskConfig,
 };
+use crate::common::http_task::throttle;
+use chrono::Utc;
 use std::collections::HashMap;
+use std::time::Duration;
 use uuid::Uuid;
 
-pub struct TaskManager {
+/// 任务管理器，`T` 为实际收发请求的传输层（默认真实发出请求，测试中可换成回放）
+pub struct TaskManager<T: HttpTransport = ReqwestTransport> {
     task_name: String,
     task_id: String,
     config: TaskConfig,
@@ -13,6 +18,12 @@ pub struct TaskManager {
     ip_manager: IPManager,
     cookie_store: Option<CookieStore>,
     logger: Logger,
+    transport: T,
+    rate_limiter: RateLimiter,
+    /// 连续的服务器错误（5xx 或连接失败）次数
+    consecutive_server_errors: u32,
+    /// 连续服务器错误达到上限后停止，之后的请求直接失败
+    stopped: bool,
 }
 
 impl TaskManager {
@@ -22,12 +33,22 @@ impl TaskManager {
     /// - `task_name`: 任务名称，用于日志标识
     /// - `config`: 任务配置
     pub fn new(task_name: String, config: TaskConfig) -> Result<TaskManager> {
+        let transport = ReqwestTransport::new(&config);
+        TaskManager::with_transport(task_name, config, transport)
+    }
+}
+
+impl<T: HttpTransport> TaskManager<T> {
+    /// 使用指定的传输层创建任务管理器（如 `RecordingTransport` / `ReplayTransport`）
+    pub fn with_transport(task_name: String, config: TaskConfig, transport: T) -> Result<TaskManager<T>> {
         let task_id = Uuid::new_v4().to_string();
         
-        let interceptor = Interceptor::new(
-            config.intercept_domains.clone(),
-            config.intercept_keywords.clone(),
-        );
+        // 界面规则在前，域名 / 关键词列表追加在后
+        let mut intercept_config = config.interceptor.clone();
+        intercept_config
+            .rules
+            .extend(InterceptRule::from_lists(&config.intercept_domains, &config.intercept_keywords));
+        let interceptor = Interceptor::with_config(intercept_config)?;
         
         let ip_manager = IPManager::new(config.ip_mode.clone())?;
         
@@ -37,10 +58,12 @@ impl TaskManager {
             None
         };
         
-        let logger = Logger::new(task_name.clone(), task_id.clone());
+        let redactor = Redactor::new(&config.redaction)?;
+        let logger = Logger::with_redactor(task_name.clone(), task_id.clone(), redactor);
         
         logger.info(&format!("任务管理器初始化完成，ID: {}", task_id));
         
+        let rate_limiter = RateLimiter::new(config.rate_limit.clone())?;
         Ok(TaskManager {
             task_name,
             task_id,
@@ -49,6 +72,10 @@ impl TaskManager {
             ip_manager,
             cookie_store,
             logger,
+            transport,
+            rate_limiter,
+            consecutive_server_errors: 0,
+            stopped: false,
         })
     }
     
@@ -78,6 +105,35 @@ impl TaskManager {
     pub fn config(&self) -> &TaskConfig {
         &self.config
     }
+
+    /// 获取传输层的引用
+    pub fn transport(&self) -> &T {
+        &self.transport
+    }
+
+    /// 当前使用的限速器
+    pub fn rate_limiter(&self) -> &RateLimiter {
+        &self.rate_limiter
+    }
+
+    /// 与其他任务共享限速器（同一主机的限速和并发上限合并计算）
+    pub fn set_rate_limiter(&mut self, rate_limiter: RateLimiter) {
+        self.rate_limiter = rate_limiter;
+    }
+
+    /// 是否因连续服务器错误而停止
+    pub fn is_stopped(&self) -> bool {
+        self.stopped
+    }
+
+    /// 清除停止状态和错误计数，继续发送请求
+    pub fn resume(&mut self) {
+        if self.stopped {
+            self.logger.info("任务已恢复，重新允许发送请求");
+        }
+        self.stopped = false;
+        self.consecutive_server_errors = 0;
+    }
     
     /// 构建 HTTP 客户端（带 TLS 指纹和浏览器模拟）
     /// 
@@ -143,16 +199,28 @@ impl TaskManager {
         self.logger.error(message);
     }
     
-    fn check_interceptor(&self, url: &str) -> Result<()> {
-        if self.interceptor.should_block(url) {
-            self.logger.warn(&format!("Request blocked by interceptor: {}", url));
-            return Err(AppError::Blocked(url.to_string()));
+    /// 按顺序列出拦截规则（含域名 / 关键词列表）和本任务中的命中次数
+    pub fn interceptor_hits(&self) -> Vec<RuleHits> {
+        self.interceptor.hits()
+    }
+
+    fn check_interceptor(&self, method: &str, url: &str) -> Result<()> {
+        if self.interceptor.is_empty() {
+            return Ok(());
         }
-        
-        if !self.interceptor.is_empty() {
-            self.logger.debug(&format!("Request passed interceptor check: {}", url));
+
+        let decision = self.interceptor.check(method, url);
+        let matched = decision.rule.and_then(|index| Some((index, self.interceptor.rule(index)?)));
+        let reason = match matched {
+            Some((index, rule)) => format!("规则 #{} {}", index + 1, rule.describe()),
+            None => format!("无规则命中，{}", self.interceptor.mode().label()),
+        };
+        if !decision.allowed {
+            self.logger.warn(&format!("Request blocked by interceptor: {} {} | {}", method, url, reason));
+            return Err(AppError::Blocked(url.to_string()));
         }
-        
+
+        self.logger.debug(&format!("Request passed interceptor check: {} {} | {}", method, url, reason));
         Ok(())
     }
     
@@ -167,69 +235,87 @@ impl TaskManager {
         }
     }
     
-    // 注意：这里使用占位符实现，实际应该使用 wreq::Client
-    // 您需要根据实际的 wreq::Client API 来实现这些方法
-    pub async fn get(&mut self, url: &str) -> Result<MockResponse> {
-        self.execute_request("GET", url, None).await
+    pub async fn get(&mut self, url: &str) -> Result<HttpResponse> {
+        self.execute_request(HttpRequest::new("GET", url)).await
     }
     
     pub async fn post_json(
         &mut self,
         url: &str,
         body: serde_json::Value,
-    ) -> Result<MockResponse> {
-        self.execute_request("POST", url, Some(body.to_string())).await
+    ) -> Result<HttpResponse> {
+        let request = HttpRequest::new("POST", url)
+            .header("content-type", "application/json")
+            .body(body.to_string());
+        self.execute_request(request).await
     }
     
     pub async fn post_form(
         &mut self,
         url: &str,
         form: HashMap<String, String>,
-    ) -> Result<MockResponse> {
-        let body = form
-            .iter()
-            .map(|(k, v)| format!("{}={}", k, v))
-            .collect::<Vec<_>>()
-            .join("&");
-        self.execute_request("POST", url, Some(body)).await
+    ) -> Result<HttpResponse> {
+        // 按字段名排序，保证请求体稳定（回放按请求体匹配）
+        let mut fields: Vec<_> = form.iter().collect();
+        fields.sort();
+        let body = url::form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(fields)
+            .finish();
+        let request = HttpRequest::new("POST", url)
+            .header("content-type", "application/x-www-form-urlencoded")
+            .body(body);
+        self.execute_request(request).await
     }
     
+    /// 获取响应文本，非 2xx 状态码视为失败
     pub async fn fetch_text(&mut self, url: &str) -> Result<String> {
-        let response = self.get(url).await?;
-        Ok(response.body)
+        Ok(self.fetch_success(url).await?.text().into_owned())
     }
     
     pub async fn fetch_json(&mut self, url: &str) -> Result<serde_json::Value> {
-        let text = self.fetch_text(url).await?;
-        Ok(serde_json::from_str(&text)?)
+        Ok(serde_json::from_slice(&self.fetch_success(url).await?.body)?)
     }
     
+    /// 获取原始响应体（图片、验证码等），非 2xx 状态码视为失败
     pub async fn fetch_bytes(&mut self, url: &str) -> Result<Vec<u8>> {
+        Ok(self.fetch_success(url).await?.body)
+    }
+
+    async fn fetch_success(&mut self, url: &str) -> Result<HttpResponse> {
         let response = self.get(url).await?;
-        Ok(response.body.into_bytes())
+        if !response.is_success() {
+            return Err(AppError::RequestFailed(format!("HTTP {}: {}", response.status, url)));
+        }
+        Ok(response)
     }
     
-    async fn execute_request(
-        &mut self,
-        method: &str,
-        url: &str,
-        body: Option<String>,
-    ) -> Result<MockResponse> {
+    async fn execute_request(&mut self, mut request: HttpRequest) -> Result<HttpResponse> {
+        let method = request.method.clone();
+        let url = request.url.clone();
+
+        if self.stopped {
+            self.logger.warn(&format!("任务已停止，拒绝请求: {} {}", method, url));
+            return Err(AppError::TaskStopped(format!(
+                "连续 {} 次服务器错误",
+                self.consecutive_server_errors
+            )));
+        }
+
         // 步骤 1: 拦截器检查
-        self.check_interceptor(url)?;
+        self.check_interceptor(&method, &url)?;
         
-        // 步骤 2: 获取代理
-        let _proxy = self.get_ip();
-        
-        // 步骤 3: 配置指纹
+        // 步骤 2: 配置指纹
         // 注意：实际的TLS和浏览器指纹配置需要在HTTP客户端实现中完成
         
-        // 步骤 4: 插入 Cookie
-        let cookie_header = self.cookie_store.as_ref().map(|store| store.get_header());
+        // 步骤 3: 插入 Cookie
+        let cookie_header = self.cookie_store.as_ref().map(|store| store.header_for(&url));
+        if let Some(cookies) = cookie_header.as_deref().filter(|cookies| !cookies.is_empty()) {
+            request = request.header("cookie", cookies);
+        }
         
-        // 步骤 5: 记录请求详情
-        self.log_request_details(url, method);
-        if let Some(ref b) = body {
+        // 步骤 4: 记录请求详情
+        self.log_request_details(&url, &method);
+        if let Some(ref b) = request.body {
             self.logger.debug(&format!("Request body: {}", b));
         }
         if let Some(ref cookies) = cookie_header {
@@ -238,39 +324,169 @@ impl TaskManager {
             }
         }
         
-        // 步骤 6: 发起请求（模拟实现）
-        // 注意：实际项目中需要使用真实的HTTP客户端
-        let response = MockResponse {
-            status: 200,
-            body: "Mock response".to_string(),
-            headers: HashMap::new(),
-        };
+        // 步骤 5: 限速后通过当前代理发起请求，按重试策略处理 429 / 5xx
+        let response = self.send_with_retry(&request).await?;
         
-        // 步骤 7: 更新 Cookie
+        // 步骤 6: 更新 Cookie
         if let Some(ref mut store) = self.cookie_store {
-            if let Some(set_cookie) = response.headers.get("set-cookie") {
-                store.update_from_set_cookie(set_cookie);
+            for set_cookie in response.header_values("set-cookie") {
+                if !store.update_from_set_cookie(&url, set_cookie) {
+                    self.logger.warn(&format!("忽略 Set-Cookie：{}", set_cookie));
+                }
             }
         }
         
-        // 步骤 8: 记录日志
-        self.logger.info(&format!("请求完成: {} {}", method, url));
+        // 步骤 7: 记录日志
+        self.logger.info(&format!("请求完成: {} {} | {}", method, url, response.status));
         
         Ok(response)
     }
-}
 
-// 占位符响应结构，实际应该使用 wreq::Response
-#[derive(Debug)]
-pub struct MockResponse {
-    pub status: u16,
-    pub body: String,
-    pub headers: HashMap<String, String>,
+    async fn send_with_retry(&mut self, request: &HttpRequest) -> Result<HttpResponse> {
+        let host = throttle::host_of(&request.url);
+        let policy = self.config.retry.clone();
+        // 连接失败只对幂等请求重试，避免重复提交
+        let idempotent = matches!(request.method.as_str(), "GET" | "HEAD" | "OPTIONS");
+        let mut retries = 0;
+
+        loop {
+            let result = self.send_throttled(&host, request).await;
+            let delay = match result {
+                Ok(response) => {
+                    self.track_server_error(response.status >= 500, &format!("HTTP {}", response.status))?;
+                    match self.retry_delay(&policy, &response, retries) {
+                        Some(delay) => delay,
+                        None => return Ok(response),
+                    }
+                }
+                Err(e) => {
+                    // 只有最后一次失败记为"请求失败"（请求结束），会重试的失败记为 WARN
+                    let stopped = self.track_server_error(true, &e.to_string());
+                    if stopped.is_err() || !idempotent || retries >= policy.max_retries {
+                        self.logger.error(&format!("请求失败: {} {} | {}", request.method, request.url, e));
+                        stopped?;
+                        return Err(e);
+                    }
+                    let delay = throttle::backoff_delay(&policy, retries, throttle::random_jitter());
+                    self.logger.warn(&format!(
+                        "连接失败，{} ms 后第 {} 次重试: {} {} | {}",
+                        delay.as_millis(),
+                        retries + 1,
+                        request.method,
+                        request.url,
+                        e
+                    ));
+                    delay
+                }
+            };
+            tokio::time::sleep(delay).await;
+            retries += 1;
+        }
+    }
+
+    /// 等待令牌和并发名额后发送，名额在响应返回后释放
+    async fn send_throttled(&self, host: &str, request: &HttpRequest) -> Result<HttpResponse> {
+        let wait = self.rate_limiter.reserve(host);
+        if !wait.is_zero() {
+            self.logger.info(&format!("限速: {} 等待 {} ms", host, wait.as_millis()));
+            tokio::time::sleep(wait).await;
+        }
+
+        let _slot = match self.rate_limiter.try_slot(host) {
+            Ok(slot) => slot,
+            Err(permits) => {
+                self.logger.info(&format!(
+                    "并发上限: {} 已有 {} 个请求进行中，排队等待",
+                    host,
+                    self.rate_limiter.config().max_concurrent_per_host
+                ));
+                Some(
+                    permits
+                        .acquire_owned()
+                        .await
+                        .map_err(|e| AppError::RequestFailed(e.to_string()))?,
+                )
+            }
+        };
+
+        self.transport.send(request, self.ip_manager.get_current()).await
+    }
+
+    /// 需要重试时返回等待时间：优先使用 `Retry-After`，否则指数退避
+    fn retry_delay(&self, policy: &RetryPolicy, response: &HttpResponse, retries: u32) -> Option<Duration> {
+        if !policy.retry_statuses.contains(&response.status) {
+            return None;
+        }
+        if retries >= policy.max_retries {
+            self.logger.warn(&format!("HTTP {}，已重试 {} 次，不再重试", response.status, retries));
+            return None;
+        }
+
+        let retry_after = response
+            .header("retry-after")
+            .and_then(|value| throttle::parse_retry_after(value, Utc::now()));
+        let delay = match retry_after {
+            Some(delay) if delay > policy.max_delay => {
+                self.logger.warn(&format!(
+                    "HTTP {}，Retry-After {} 秒超过上限 {} 秒，不再重试",
+                    response.status,
+                    delay.as_secs(),
+                    policy.max_delay.as_secs()
+                ));
+                return None;
+            }
+            Some(delay) => delay,
+            None => throttle::backoff_delay(policy, retries, throttle::random_jitter()),
+        };
+
+        self.logger.warn(&format!(
+            "HTTP {}，{} ms 后第 {} 次重试（{}）",
+            response.status,
+            delay.as_millis(),
+            retries + 1,
+            if retry_after.is_some() { "Retry-After" } else { "指数退避" }
+        ));
+        Some(delay)
+    }
+
+    /// 统计连续服务器错误，达到上限时停止任务
+    fn track_server_error(&mut self, is_error: bool, detail: &str) -> Result<()> {
+        if !is_error {
+            self.consecutive_server_errors = 0;
+            return Ok(());
+        }
+
+        self.consecutive_server_errors += 1;
+        let limit = self.config.retry.max_consecutive_server_errors;
+        if limit > 0 && self.consecutive_server_errors >= limit {
+            self.stopped = true;
+            self.logger.error(&format!(
+                "连续 {} 次服务器错误（最近一次: {}），任务已停止",
+                self.consecutive_server_errors, detail
+            ));
+            return Err(AppError::TaskStopped(format!("连续 {} 次服务器错误", self.consecutive_server_errors)));
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::common::http_task::{
+        Exchange, InterceptMode, InterceptorConfig, PatternKind, ReplayTransport, RuleAction,
+    };
+
+    fn exchange(request: HttpRequest, status: u16, headers: &[(&str, &str)], body: &str) -> Exchange {
+        Exchange {
+            request,
+            response: HttpResponse {
+                status,
+                headers: headers.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect(),
+                body: body.as_bytes().to_vec(),
+            },
+        }
+    }
 
     #[test]
     fn test_task_manager_uuid_uniqueness() {
@@ -296,7 +512,126 @@ mod tests {
         let tm = TaskManager::new("测试任务".to_string(), config).unwrap();
         
         assert!(tm.cookie_store.is_some());
-        assert!(tm.check_interceptor("https://blocked.com/path").is_err());
+        assert!(tm.check_interceptor("GET", "https://blocked.com/path").is_err());
         assert_eq!(tm.task_name(), "测试任务");
     }
+
+    #[test]
+    fn test_interceptor_rules_and_hits() {
+        let config = TaskConfig {
+            interceptor: InterceptorConfig {
+                mode: InterceptMode::Blocklist,
+                rules: vec![InterceptRule::new(RuleAction::Allow, PatternKind::Wildcard, "https://blocked.com/public/*")],
+            },
+            intercept_domains: vec!["blocked.com".to_string()],
+            ..Default::default()
+        };
+        let tm = TaskManager::new("规则任务".to_string(), config).unwrap();
+
+        // 界面规则先于域名列表检查
+        assert!(tm.check_interceptor("GET", "https://blocked.com/public/a").is_ok());
+        assert!(tm.check_interceptor("GET", "https://api.blocked.com/x").is_err());
+        assert!(tm.check_interceptor("POST", "https://blocked.com/private").is_err());
+        assert!(tm.check_interceptor("GET", "https://other.com/").is_ok());
+
+        let hits: Vec<u64> = tm.interceptor_hits().iter().map(|rule| rule.hits).collect();
+        assert_eq!(hits, [1, 2]);
+
+        let invalid = TaskConfig {
+            interceptor: InterceptorConfig {
+                mode: InterceptMode::Allowlist,
+                rules: vec![InterceptRule::new(RuleAction::Allow, PatternKind::Regex, "[")],
+            },
+            ..Default::default()
+        };
+        assert!(TaskManager::new("无效规则".to_string(), invalid).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_task_runs_against_replayed_responses() {
+        let login_form: HashMap<String, String> =
+            [("user".to_string(), "demo".to_string()), ("pass".to_string(), "a&b".to_string())].into();
+        let replay = ReplayTransport::new(vec![
+            exchange(
+                HttpRequest::new("POST", "https://example.com/login").body("pass=a%26b&user=demo"),
+                200,
+                &[("set-cookie", "session=abc; Path=/"), ("Set-Cookie", "theme=dark; Path=/quote")],
+                "ok",
+            ),
+            exchange(HttpRequest::new("GET", "https://example.com/quote"), 200, &[], r#"{"price": 1280}"#),
+            exchange(
+                HttpRequest::new("POST", "https://example.com/order").body(r#"{"price":1280}"#),
+                201,
+                &[],
+                "created",
+            ),
+            exchange(HttpRequest::new("GET", "https://example.com/status"), 503, &[], "busy"),
+        ]);
+        let config = TaskConfig {
+            enable_cookie_management: true,
+            intercept_domains: vec!["tracker.example.org".to_string()],
+            retry: RetryPolicy { max_retries: 0, ..Default::default() },
+            ..Default::default()
+        };
+        let mut task = TaskManager::with_transport("回放任务".to_string(), config, replay).unwrap();
+
+        task.post_form("https://example.com/login", login_form).await.unwrap();
+        let cookies = task.cookie_store.as_ref().unwrap();
+        assert_eq!(cookies.header_for("https://example.com/quote"), "theme=dark; session=abc");
+        assert_eq!(cookies.header_for("https://example.com/order"), "session=abc");
+        assert_eq!(cookies.header_for("https://other.example.com/"), "");
+
+        let quote = task.fetch_json("https://example.com/quote").await.unwrap();
+        let order = task
+            .post_json("https://example.com/order", serde_json::json!({ "price": quote["price"] }))
+            .await
+            .unwrap();
+        assert_eq!(order.status, 201);
+
+        // 非 2xx、未录制和被拦截的请求都返回错误
+        assert!(task.fetch_text("https://example.com/status").await.is_err());
+        assert!(task.get("https://example.com/unknown").await.is_err());
+        assert!(matches!(task.get("https://tracker.example.org/t").await, Err(AppError::Blocked(_))));
+        assert_eq!(task.transport().remaining(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_retry_after_backoff_and_stop() {
+        let get = |url: &str| HttpRequest::new("GET", url);
+        let replay = ReplayTransport::new(vec![
+            // 429 按 Retry-After（0 秒）重试后成功
+            exchange(get("https://example.com/a"), 429, &[("retry-after", "0")], ""),
+            exchange(get("https://example.com/a"), 200, &[], "ok"),
+            // Retry-After 超过上限时直接返回
+            exchange(get("https://example.com/b"), 503, &[("retry-after", "3600")], ""),
+            // 连续服务器错误达到上限后停止
+            exchange(get("https://example.com/c"), 500, &[], ""),
+            exchange(get("https://example.com/c"), 502, &[], ""),
+            exchange(get("https://example.com/d"), 200, &[], "resumed"),
+        ]);
+        let config = TaskConfig {
+            retry: RetryPolicy {
+                max_retries: 1,
+                base_delay: Duration::from_millis(1),
+                max_delay: Duration::from_millis(10),
+                max_consecutive_server_errors: 3,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut task = TaskManager::with_transport("重试任务".to_string(), config, replay).unwrap();
+
+        assert_eq!(task.fetch_text("https://example.com/a").await.unwrap(), "ok");
+        assert_eq!(task.get("https://example.com/b").await.unwrap().status, 503);
+        assert_eq!(task.get("https://example.com/c").await.unwrap().status, 500);
+
+        // 第 3 次连续服务器错误触发停止
+        let result = task.get("https://example.com/c").await;
+        assert!(matches!(result, Err(AppError::TaskStopped(_))), "{:?}", result.map(|r| r.status));
+        assert!(task.is_stopped());
+        assert!(matches!(task.get("https://example.com/d").await, Err(AppError::TaskStopped(_))));
+
+        task.resume();
+        assert_eq!(task.fetch_text("https://example.com/d").await.unwrap(), "resumed");
+    }
 }
@@ -0,0 +1,221 @@
+//! 限速与退避
+//!
+//! `RateLimiter` 按主机维护令牌桶和并发名额，可在多个 `TaskManager` 之间共享（克隆即共享同一状态）。
+//! 退避间隔和 `Retry-After` 的计算与等待分开，便于测试。
+
+use crate::common::http_task::{RateLimitConfig, RetryPolicy};
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use url::Url;
+
+struct HostState {
+    tokens: f64,
+    updated: Instant,
+    permits: Option<Arc<Semaphore>>,
+}
+
+/// 按主机限速
+#[derive(Clone)]
+pub struct RateLimiter {
+    config: RateLimitConfig,
+    hosts: Arc<Mutex<HashMap<String, HostState>>>,
+}
+
+impl RateLimiter {
+    /// 创建限速器，`requests_per_second` 无效（NaN、无穷大或负数）时返回错误
+    pub fn new(config: RateLimitConfig) -> crate::common::Result<Self> {
+        config.validate()?;
+        Ok(Self {
+            config,
+            hosts: Arc::new(Mutex::new(HashMap::new())),
+        })
+    }
+
+    pub fn config(&self) -> &RateLimitConfig {
+        &self.config
+    }
+
+    fn with_host<R>(&self, host: &str, now: Instant, f: impl FnOnce(&mut HostState) -> R) -> R {
+        let mut hosts = self.hosts.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let state = hosts.entry(host.to_string()).or_insert_with(|| HostState {
+            tokens: f64::from(self.config.burst.max(1)),
+            updated: now,
+            permits: (self.config.max_concurrent_per_host > 0)
+                .then(|| Arc::new(Semaphore::new(self.config.max_concurrent_per_host))),
+        });
+        f(state)
+    }
+
+    /// 预约一个令牌，返回发出请求前需要等待的时间（不限速时为 0）
+    pub fn reserve(&self, host: &str) -> Duration {
+        self.reserve_at(host, Instant::now())
+    }
+
+    fn reserve_at(&self, host: &str, now: Instant) -> Duration {
+        let rate = self.config.requests_per_second;
+        if rate <= 0.0 {
+            return Duration::ZERO;
+        }
+        let burst = f64::from(self.config.burst.max(1));
+        self.with_host(host, now, |state| {
+            let elapsed = now.saturating_duration_since(state.updated).as_secs_f64();
+            state.tokens = (state.tokens + elapsed * rate).min(burst);
+            state.updated = now;
+            // 令牌可以预支为负数，后来的请求按顺序排在后面
+            state.tokens -= 1.0;
+            if state.tokens >= 0.0 {
+                Duration::ZERO
+            } else {
+                // 速率极小时等待时间可能超出 Duration 的范围
+                Duration::try_from_secs_f64(-state.tokens / rate).unwrap_or(Duration::MAX)
+            }
+        })
+    }
+
+    /// 尝试占用一个并发名额
+    ///
+    /// - `Ok(None)`：不限制并发
+    /// - `Ok(Some(permit))`：已占用，请求结束后释放
+    /// - `Err(semaphore)`：已达上限，需要等待 `semaphore.acquire_owned()`
+    pub fn try_slot(&self, host: &str) -> Result<Option<OwnedSemaphorePermit>, Arc<Semaphore>> {
+        let Some(permits) = self.with_host(host, Instant::now(), |state| state.permits.clone()) else {
+            return Ok(None);
+        };
+        permits.clone().try_acquire_owned().map(Some).map_err(|_| permits)
+    }
+}
+
+/// 限速按主机区分；无法解析的 URL 整体作为一个主机
+pub fn host_of(url: &str) -> String {
+    Url::parse(url)
+        .ok()
+        .and_then(|url| url.host_str().map(str::to_lowercase))
+        .unwrap_or_else(|| url.to_string())
+}
+
+/// 第 `retry` 次重试（从 0 开始）的退避间隔：`base_delay * 2^retry`，不超过 `max_delay`，
+/// 一半固定一半随机（`jitter` 取值 0~1），避免多个任务同时重试
+pub fn backoff_delay(policy: &RetryPolicy, retry: u32, jitter: f64) -> Duration {
+    let exponential = policy
+        .base_delay
+        .saturating_mul(2u32.saturating_pow(retry))
+        .min(policy.max_delay);
+    exponential / 2 + exponential.mul_f64(jitter.clamp(0.0, 1.0) / 2.0)
+}
+
+/// 0~1 之间的抖动系数
+///
+/// 只用于错开重试时间，不需要密码学随机数：由当前时间的纳秒数和调用次数混合得到，
+/// 同一时刻的多次调用也不会相同
+pub fn random_jitter() -> f64 {
+    static CALLS: AtomicU64 = AtomicU64::new(0);
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_nanos() as u64)
+        .unwrap_or_default();
+    let calls = CALLS.fetch_add(1, Ordering::Relaxed);
+    // SplitMix64 的混合函数，让相近的输入得到分散的结果
+    let mut mixed = nanos ^ calls.wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    mixed = (mixed ^ (mixed >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    mixed = (mixed ^ (mixed >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    mixed ^= mixed >> 31;
+    // 取高 53 位，结果在 [0, 1) 之间
+    (mixed >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// 解析 `Retry-After`：秒数或 HTTP 日期（已过去的日期视为 0）
+pub fn parse_retry_after(value: &str, now: DateTime<Utc>) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = DateTime::parse_from_rfc2822(value).ok()?.with_timezone(&Utc);
+    Some((date - now).to_std().unwrap_or(Duration::ZERO))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::AppError;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_token_bucket_per_host() {
+        let limiter = RateLimiter::new(RateLimitConfig {
+            requests_per_second: 2.0,
+            burst: 2,
+            max_concurrent_per_host: 0,
+        })
+        .unwrap();
+        let start = Instant::now();
+
+        // 突发 2 个不等待，之后按每秒 2 个排队
+        assert_eq!(limiter.reserve_at("a.com", start), Duration::ZERO);
+        assert_eq!(limiter.reserve_at("a.com", start), Duration::ZERO);
+        assert_eq!(limiter.reserve_at("a.com", start), Duration::from_millis(500));
+        assert_eq!(limiter.reserve_at("a.com", start), Duration::from_millis(1000));
+        // 其他主机互不影响
+        assert_eq!(limiter.reserve_at("b.com", start), Duration::ZERO);
+        // 时间过去后令牌恢复（两个预支的令牌先还清）
+        assert_eq!(limiter.reserve_at("a.com", start + Duration::from_secs(2)), Duration::ZERO);
+
+        assert_eq!(RateLimiter::new(RateLimitConfig::default()).unwrap().reserve("a.com"), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_rejects_invalid_rate() {
+        for rate in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY, -1.0] {
+            let config = RateLimitConfig { requests_per_second: rate, ..Default::default() };
+            assert!(matches!(RateLimiter::new(config), Err(AppError::InvalidConfig(_))), "{}", rate);
+        }
+
+        // 速率极小时等待时间封顶，不会溢出
+        let config = RateLimitConfig { requests_per_second: f64::MIN_POSITIVE, ..Default::default() };
+        let limiter = RateLimiter::new(config).unwrap();
+        let start = Instant::now();
+        assert_eq!(limiter.reserve_at("a.com", start), Duration::ZERO);
+        assert_eq!(limiter.reserve_at("a.com", start), Duration::MAX);
+    }
+
+    #[test]
+    fn test_concurrency_cap() {
+        let limiter = RateLimiter::new(RateLimitConfig { max_concurrent_per_host: 1, ..Default::default() }).unwrap();
+        let first = limiter.try_slot("a.com").unwrap();
+        assert!(first.is_some());
+        assert!(limiter.try_slot("a.com").is_err());
+        assert!(limiter.clone().try_slot("b.com").unwrap().is_some());
+
+        drop(first);
+        assert!(limiter.try_slot("a.com").unwrap().is_some());
+        assert!(RateLimiter::new(RateLimitConfig::default()).unwrap().try_slot("a.com").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_backoff_and_retry_after() {
+        let policy = RetryPolicy {
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(1000),
+            ..Default::default()
+        };
+        assert_eq!(backoff_delay(&policy, 0, 0.0), Duration::from_millis(50));
+        assert_eq!(backoff_delay(&policy, 0, 1.0), Duration::from_millis(100));
+        assert_eq!(backoff_delay(&policy, 2, 1.0), Duration::from_millis(400));
+        assert_eq!(backoff_delay(&policy, 10, 1.0), Duration::from_millis(1000));
+
+        // 连续取值都在 0~1 之间且不全相同
+        let jitters: Vec<f64> = (0..32).map(|_| random_jitter()).collect();
+        assert!(jitters.iter().all(|jitter| (0.0..1.0).contains(jitter)));
+        assert!(jitters.iter().any(|jitter| *jitter != jitters[0]));
+
+        let now = Utc.with_ymd_and_hms(2015, 10, 21, 7, 28, 0).unwrap();
+        assert_eq!(parse_retry_after(" 120 ", now), Some(Duration::from_secs(120)));
+        assert_eq!(parse_retry_after("Wed, 21 Oct 2015 07:28:30 GMT", now), Some(Duration::from_secs(30)));
+        assert_eq!(parse_retry_after("Wed, 21 Oct 2015 07:00:00 GMT", now), Some(Duration::ZERO));
+        assert_eq!(parse_retry_after("soon", now), None);
+        assert_eq!(host_of("https://API.Example.com:8443/x"), "api.example.com");
+    }
+}
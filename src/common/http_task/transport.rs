@@ -0,0 +1,435 @@
+//! HTTP 传输层
+//!
+//! `TaskManager` 只负责拦截、代理、Cookie 和日志，实际收发交给 `HttpTransport`：
+//! - `ReqwestTransport`：默认实现，真实发出请求
+//! - `RecordingTransport`：包装另一个传输层，把每次请求和响应追加保存到夹具文件
+//! - `ReplayTransport`：从夹具文件回放响应，不访问网络，用于测试
+
+use crate::common::http_task::{ProxyConfig, TaskConfig};
+use crate::common::{AppError, Reason, Result};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine as Base64Engine;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// 夹具文件格式版本（2：响应头改为可重复的列表；3：响应体改为 base64 保存的原始字节）
+const FIXTURE_VERSION: u32 = 3;
+
+/// 一次请求（请求头名称统一为小写）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HttpRequest {
+    pub method: String,
+    pub url: String,
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    #[serde(default)]
+    pub body: Option<String>,
+}
+
+impl HttpRequest {
+    pub fn new(method: &str, url: &str) -> Self {
+        Self {
+            method: method.to_string(),
+            url: url.to_string(),
+            headers: BTreeMap::new(),
+            body: None,
+        }
+    }
+
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.insert(name.to_lowercase(), value.to_string());
+        self
+    }
+
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// 回放时判断是否为同一请求（不比较请求头，Cookie 等可能随运行变化）
+    fn same_target(&self, other: &HttpRequest) -> bool {
+        self.method == other.method && self.url == other.url && self.body == other.body
+    }
+}
+
+/// 响应
+///
+/// 响应头按收到的顺序保存，同名响应头（如多个 `Set-Cookie`）各占一项；
+/// 响应体保存原始字节，图片、验证码等二进制内容不经过文本解码
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(into = "StoredResponse", try_from = "StoredResponse")]
+pub struct HttpResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl HttpResponse {
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    /// 响应体按 UTF-8 解码的文本，无效字节替换为 U+FFFD
+    pub fn text(&self) -> Cow<'_, str> {
+        String::from_utf8_lossy(&self.body)
+    }
+
+    /// 第一个同名响应头（名称不区分大小写）
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// 所有同名响应头（名称不区分大小写）
+    pub fn header_values<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        self.headers
+            .iter()
+            .filter(move |(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// 夹具中的响应：版本 3 起响应体为 `body_base64`，之前的版本为文本 `body`
+#[derive(Serialize, Deserialize)]
+struct StoredResponse {
+    status: u16,
+    #[serde(default, deserialize_with = "deserialize_headers")]
+    headers: Vec<(String, String)>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body_base64: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body: Option<String>,
+}
+
+impl From<HttpResponse> for StoredResponse {
+    fn from(response: HttpResponse) -> Self {
+        Self {
+            status: response.status,
+            headers: response.headers,
+            body_base64: Some(BASE64.encode(&response.body)),
+            body: None,
+        }
+    }
+}
+
+impl TryFrom<StoredResponse> for HttpResponse {
+    type Error = String;
+
+    fn try_from(stored: StoredResponse) -> std::result::Result<Self, Self::Error> {
+        let body = match (stored.body_base64, stored.body) {
+            (Some(encoded), _) => BASE64.decode(encoded).map_err(|e| format!("响应体 base64 无效：{}", e))?,
+            (None, Some(text)) => text.into_bytes(),
+            (None, None) => Vec::new(),
+        };
+        Ok(Self { status: stored.status, headers: stored.headers, body })
+    }
+}
+
+/// 兼容版本 1 夹具中 `{"name": "value"}` 形式的响应头
+fn deserialize_headers<'de, D>(deserializer: D) -> std::result::Result<Vec<(String, String)>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Headers {
+        List(Vec<(String, String)>),
+        Map(BTreeMap<String, String>),
+    }
+
+    Ok(match Headers::deserialize(deserializer)? {
+        Headers::List(headers) => headers,
+        Headers::Map(headers) => headers.into_iter().collect(),
+    })
+}
+
+/// 一组请求和响应
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Exchange {
+    pub request: HttpRequest,
+    pub response: HttpResponse,
+}
+
+/// 夹具文件（当前版本 `FIXTURE_VERSION`），旧版本的文件仍可读取：
+///
+/// ```json
+/// {
+///   "version": 3,
+///   "exchanges": [{
+///     "request": {"method": "GET", "url": "https://example.com/", "headers": {}, "body": null},
+///     "response": {"status": 200, "headers": [["content-type", "text/plain"]], "body_base64": "b2s="}
+///   }]
+/// }
+/// ```
+#[derive(Debug, Serialize, Deserialize)]
+struct Fixture {
+    version: u32,
+    exchanges: Vec<Exchange>,
+}
+
+/// HTTP 传输层
+///
+/// `proxy` 为 `TaskManager` 当前选中的代理，不使用代理的实现可以忽略
+pub trait HttpTransport: Send + Sync {
+    fn send(
+        &self,
+        request: &HttpRequest,
+        proxy: Option<&ProxyConfig>,
+    ) -> impl Future<Output = Result<HttpResponse>> + Send;
+}
+
+// ==================== reqwest ====================
+
+/// 基于 reqwest 的默认传输层，每个代理地址复用一个客户端
+pub struct ReqwestTransport {
+    timeout: Duration,
+    allow_redirect: bool,
+    verify_cert: bool,
+    clients: Mutex<HashMap<Option<String>, reqwest::Client>>,
+}
+
+impl ReqwestTransport {
+    pub fn new(config: &TaskConfig) -> Self {
+        Self {
+            timeout: config.timeout,
+            allow_redirect: config.allow_redirect,
+            verify_cert: config.verify_cert,
+            clients: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn client(&self, proxy: Option<&ProxyConfig>) -> Result<reqwest::Client> {
+        let proxy_url = proxy.map(ProxyConfig::http);
+        let mut clients = self.clients.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        if let Some(client) = clients.get(&proxy_url) {
+            return Ok(client.clone());
+        }
+
+        let mut builder = reqwest::Client::builder()
+            .timeout(self.timeout)
+            .danger_accept_invalid_certs(!self.verify_cert)
+            .redirect(if self.allow_redirect {
+                reqwest::redirect::Policy::default()
+            } else {
+                reqwest::redirect::Policy::none()
+            });
+        if let Some(url) = &proxy_url {
+            let proxy = reqwest::Proxy::all(url).map_err(|e| AppError::InvalidConfig(Reason::new("error.proxy_url_invalid", &[&e])))?;
+            builder = builder.proxy(proxy);
+        }
+        let client = builder.build().map_err(|e| AppError::RequestFailed(e.to_string()))?;
+        clients.insert(proxy_url, client.clone());
+        Ok(client)
+    }
+}
+
+impl HttpTransport for ReqwestTransport {
+    fn send(
+        &self,
+        request: &HttpRequest,
+        proxy: Option<&ProxyConfig>,
+    ) -> impl Future<Output = Result<HttpResponse>> + Send {
+        let prepared = self.client(proxy).and_then(|client| {
+            let method = reqwest::Method::from_bytes(request.method.as_bytes())
+                .map_err(|_| AppError::InvalidConfig(Reason::new("error.request_method_invalid", &[&request.method])))?;
+            let mut builder = client.request(method, &request.url);
+            for (name, value) in &request.headers {
+                builder = builder.header(name, value);
+            }
+            if let Some(body) = &request.body {
+                builder = builder.body(body.clone());
+            }
+            Ok(builder)
+        });
+
+        async move {
+            let response = prepared?.send().await.map_err(|e| AppError::RequestFailed(e.to_string()))?;
+            let status = response.status().as_u16();
+            let headers = response
+                .headers()
+                .iter()
+                .filter_map(|(name, value)| Some((name.as_str().to_string(), value.to_str().ok()?.to_string())))
+                .collect();
+            let body = response.bytes().await.map_err(|e| AppError::RequestFailed(e.to_string()))?.to_vec();
+            Ok(HttpResponse { status, headers, body })
+        }
+    }
+}
+
+// ==================== 录制 / 回放 ====================
+
+/// 录制传输层：请求交给内部传输层，成功的请求和响应追加写入夹具文件
+///
+/// 夹具中保存的是原始内容（回放需要逐字匹配），录制真实账号的流量后请勿提交到仓库
+pub struct RecordingTransport<T: HttpTransport> {
+    inner: T,
+    path: PathBuf,
+    exchanges: Mutex<Vec<Exchange>>,
+}
+
+impl<T: HttpTransport> RecordingTransport<T> {
+    /// 录制到 `path`，文件已存在时覆盖
+    pub fn new(inner: T, path: impl Into<PathBuf>) -> Self {
+        Self {
+            inner,
+            path: path.into(),
+            exchanges: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// 已录制的请求和响应
+    pub fn exchanges(&self) -> Vec<Exchange> {
+        self.exchanges.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).clone()
+    }
+
+    fn record(&self, exchange: Exchange) -> Result<()> {
+        let mut exchanges = self.exchanges.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        exchanges.push(exchange);
+        save_fixture(&self.path, &exchanges)
+    }
+}
+
+impl<T: HttpTransport> HttpTransport for RecordingTransport<T> {
+    fn send(
+        &self,
+        request: &HttpRequest,
+        proxy: Option<&ProxyConfig>,
+    ) -> impl Future<Output = Result<HttpResponse>> + Send {
+        let sending = self.inner.send(request, proxy);
+        async move {
+            let response = sending.await?;
+            self.record(Exchange { request: request.clone(), response: response.clone() })?;
+            Ok(response)
+        }
+    }
+}
+
+/// 回放传输层：按录制顺序为每个请求返回第一个尚未使用、方法 / URL / 请求体相同的响应
+pub struct ReplayTransport {
+    remaining: Mutex<Vec<Exchange>>,
+}
+
+impl ReplayTransport {
+    pub fn new(exchanges: Vec<Exchange>) -> Self {
+        Self { remaining: Mutex::new(exchanges) }
+    }
+
+    /// 读取夹具文件
+    pub fn load(path: &Path) -> Result<Self> {
+        let fixture: Fixture = serde_json::from_str(&fs::read_to_string(path)?)?;
+        if fixture.version > FIXTURE_VERSION {
+            return Err(AppError::InvalidConfig(Reason::new(
+                "error.fixture_version_newer",
+                &[&fixture.version, &FIXTURE_VERSION],
+            )));
+        }
+        Ok(Self::new(fixture.exchanges))
+    }
+
+    /// 尚未被请求的录制内容
+    pub fn remaining(&self) -> usize {
+        self.remaining.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).len()
+    }
+
+    fn take(&self, request: &HttpRequest) -> Result<HttpResponse> {
+        let mut remaining = self.remaining.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let index = remaining
+            .iter()
+            .position(|exchange| exchange.request.same_target(request))
+            .ok_or_else(|| AppError::RequestFailed(format!("没有录制的响应：{} {}", request.method, request.url)))?;
+        Ok(remaining.remove(index).response)
+    }
+}
+
+impl HttpTransport for ReplayTransport {
+    fn send(
+        &self,
+        request: &HttpRequest,
+        _proxy: Option<&ProxyConfig>,
+    ) -> impl Future<Output = Result<HttpResponse>> + Send {
+        std::future::ready(self.take(request))
+    }
+}
+
+fn save_fixture(path: &Path, exchanges: &[Exchange]) -> Result<()> {
+    if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        fs::create_dir_all(dir)?;
+    }
+    let fixture = Fixture { version: FIXTURE_VERSION, exchanges: exchanges.to_vec() };
+    fs::write(path, serde_json::to_string_pretty(&fixture)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn exchange(method: &str, url: &str, status: u16, body: &str) -> Exchange {
+        Exchange {
+            request: HttpRequest::new(method, url),
+            response: HttpResponse { status, headers: Vec::new(), body: body.as_bytes().to_vec() },
+        }
+    }
+
+    #[tokio::test]
+    async fn test_record_then_replay() {
+        let path = std::env::temp_dir().join(format!("factbot-fixture-{}.json", uuid::Uuid::new_v4()));
+        let upstream = ReplayTransport::new(vec![
+            exchange("GET", "https://example.com/a", 200, "first"),
+            exchange("GET", "https://example.com/a", 200, "second"),
+        ]);
+
+        let recorder = RecordingTransport::new(upstream, &path);
+        let request = HttpRequest::new("GET", "https://example.com/a");
+        assert_eq!(recorder.send(&request, None).await.unwrap().text(), "first");
+        assert_eq!(recorder.send(&request, None).await.unwrap().text(), "second");
+        assert_eq!(recorder.exchanges().len(), 2);
+
+        // 相同请求按录制顺序依次返回，用完后报错
+        let replay = ReplayTransport::load(&path).unwrap();
+        assert_eq!(replay.send(&request, None).await.unwrap().text(), "first");
+        assert_eq!(replay.send(&request, None).await.unwrap().text(), "second");
+        assert!(replay.send(&request, None).await.is_err());
+        assert!(replay.send(&HttpRequest::new("POST", "https://example.com/a"), None).await.is_err());
+
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_multi_valued_headers() {
+        let response: HttpResponse = serde_json::from_str(
+            r#"{"status": 200, "headers": [["Set-Cookie", "a=1"], ["set-cookie", "b=2"]], "body": ""}"#,
+        )
+        .unwrap();
+        assert_eq!(response.header_values("set-cookie").collect::<Vec<_>>(), ["a=1", "b=2"]);
+        assert_eq!(response.header("SET-COOKIE"), Some("a=1"));
+        assert_eq!(response.header("retry-after"), None);
+
+        // 版本 1 的夹具
+        let legacy: HttpResponse =
+            serde_json::from_str(r#"{"status": 429, "headers": {"retry-after": "5"}, "body": ""}"#).unwrap();
+        assert_eq!(legacy.header("Retry-After"), Some("5"));
+    }
+
+    #[test]
+    fn test_binary_body_round_trip() {
+        // PNG 文件头，不是有效的 UTF-8
+        let body = vec![0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a, 0xff, 0x00];
+        let response = HttpResponse { status: 200, headers: Vec::new(), body: body.clone() };
+        let json = serde_json::to_string(&response).unwrap();
+        assert!(json.contains("body_base64"));
+        assert_eq!(serde_json::from_str::<HttpResponse>(&json).unwrap().body, body);
+
+        // 版本 2 的夹具保存的是文本
+        let legacy: HttpResponse = serde_json::from_str(r#"{"status": 200, "headers": [], "body": "你好"}"#).unwrap();
+        assert_eq!(legacy.text(), "你好");
+    }
+}
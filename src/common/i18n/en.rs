@@ -0,0 +1,625 @@
+//! English
+
+pub(super) const MESSAGES: &[(&str, &str)] = &[
+    // 组件
+    ("common.close", "Close"),
+    ("crash.title", "💥 FactBot crashed the last time it ran"),
+    ("crash.saved_to_logs", "The crash details were saved to the log (task System). When reporting the problem you can attach a diagnostic bundle."),
+    ("crash.occurred_at", "Time: {0}"),
+    ("crash.version", "Version: {0}"),
+    ("crash.hide_backtrace", "Hide backtrace"),
+    ("crash.show_backtrace", "Show backtrace"),
+    ("crash.no_documents_dir", "Could not determine the documents folder"),
+    ("crash.bundle_failed", "Failed to create the diagnostic bundle"),
+    ("crash.bundle_saved", "Diagnostic bundle saved to {0}"),
+    ("crash.create_bundle", "Create diagnostic bundle"),
+    ("crash.acknowledge_failed", "Failed to save the crash report state"),
+    ("lock.wrong_passphrase", "Wrong passphrase, please try again"),
+    ("lock.read_failed", "Could not read the passphrase settings: {0}"),
+    ("lock.settings_failed", "Could not read the app lock settings, so the app stays locked to protect your data: {0}"),
+    ("lock.title", "FactBot is locked"),
+    ("lock.prompt", "Enter the app lock passphrase to continue"),
+    ("lock.passphrase", "Passphrase"),
+    ("lock.unlock", "Unlock"),
+    ("startup.title", "⚠️ Startup check failed"),
+    ("startup.description", "FactBot checked its local databases on startup and found the problems below. To avoid losing data, the main window stays closed until they are resolved."),
+    ("startup.restored", "Restored from backup: {0}"),
+    ("startup.restore_failed", "Restore failed: {0}"),
+    ("startup.rebuilt", "The log database was rebuilt"),
+    ("startup.rebuild_failed", "Rebuild failed: {0}"),
+    ("startup.ignore", "Ignore and continue (logs will not be saved)"),
+    ("startup.recheck", "Check again"),
+    ("startup.unknown_path", "(unknown path)"),
+    ("startup.ok", "✅ OK"),
+    ("startup.broken", "❌ Problem"),
+    ("startup.restore_latest", "Restore the latest automatic backup"),
+    ("startup.no_backups", "No automatic backups found"),
+    ("startup.rebuild_logs", "Rebuild an empty log database"),
+    ("startup.backup_failed", "Automatic settings backup failed"),
+    ("startup.db_init_failed", "Failed to initialise the databases"),
+    ("nav.home", "Home"),
+    ("nav.airline", "Airline fares"),
+    ("nav.captcha", "Captcha solving"),
+    ("nav.config", "Settings"),
+    ("nav.logs", "Logs"),
+    ("nav.notice", "Notifications"),
+    ("nav.help", "Help"),
+    ("titlebar.lock", "Lock"),
+    ("titlebar.language", "Language"),
+    ("titlebar.language_save_failed", "Failed to save the language"),
+    ("titlebar.theme", "Theme"),
+    ("titlebar.theme_save_failed", "Failed to save the theme"),
+    ("theme.light", "Light"),
+    ("theme.dark", "Dark"),
+    ("theme.system", "Follow system"),
+    ("toast.collapse", "Collapse"),
+    ("toast.details", "Details"),
+    ("toast.failure", "{0}: {1}"),
+    ("table.total", "{0} rows"),
+    ("table.total_range", "{0} rows, showing {1}-{2}"),
+    ("table.selected", ", {0} selected"),
+    ("table.columns", "Columns ▾"),
+    ("table.page_size", "Per page:"),
+    ("table.all", "All"),
+    ("table.size_option", "{0} rows"),
+    ("table.previous", "Previous"),
+    ("table.next", "Next"),
+    ("tray.show", "Show window"),
+    ("tray.hide", "Hide to tray"),
+    ("tray.quit", "Quit FactBot"),
+
+    // 首页、通知、时间线
+    ("home.tagline", "Airline fares, proxies and API settings in one place"),
+    ("home.intro", "FactBot gives you a single workspace for configuring proxies, maintaining tokens and querying airlines. Use the sidebar on the left to jump to the airline pages at any time."),
+    ("home.airlines_desc", "16 airlines supported"),
+    ("home.config_desc", "Manage proxies and cards together"),
+    ("home.secure", "Safe and reliable"),
+    ("home.secure_desc", "Settings are stored locally"),
+    ("home.open_airline", "Open airline fares →"),
+    ("notice.title", "📢 Announcements"),
+    ("notice.subtitle", "The latest updates and important notices"),
+    ("notice.welcome_title", "Welcome to the FactBot console"),
+    ("notice.welcome_content", "FactBot is a data operations assistant for airline fare queries, settings management and more. Start by setting up proxy groups and other settings on the Settings page."),
+    ("notice.import_title", "New: bulk import"),
+    ("notice.import_content", "Proxy settings now support bulk import in ip:port:username:password format, one proxy per line, which makes setup much faster."),
+    ("notice.airlines_title", "16 airlines supported"),
+    ("notice.airlines_content", "16 airlines are supported, including American Airlines, Belavia, Batik Air, Vietjet, Virgin Atlantic, Asiana Airlines, Copa Airlines, Ural Airlines, S7 Airlines, Iraqi Airways, Rossiya Airlines, Nordwind Airlines, Royal Brunei Airlines, Malaysia Airlines and Cebu Pacific."),
+    ("notice.important", "🔥 Important"),
+    ("notice.normal", "📌 Notice"),
+    ("logs.load_failed", "Failed to read the logs"),
+    ("timeline.title", "🕒 Task timeline"),
+    ("timeline.back", "← Back to logs"),
+    ("timeline.total_duration", "Duration"),
+    ("timeline.started", "Started"),
+    ("timeline.ended", "Ended"),
+    ("timeline.requests", "Requests"),
+    ("timeline.warnings", "Warnings"),
+    ("timeline.errors", "Errors"),
+    ("timeline.empty", "No logs found for this task"),
+    ("timeline.gap", "⋯ gap of {0}"),
+    ("timeline.unfinished", "Unfinished"),
+    ("timeline.summary", "{0} · {1} entries"),
+
+    // 帮助
+    ("help.title", "❓ Help center"),
+    ("help.subtitle", "Learn what FactBot can do and how to use it"),
+    ("help.airline", "✈️ Airline fare queries"),
+    ("help.config", "⚙️ Settings"),
+    ("help.storage", "💾 Data storage"),
+    ("help.captcha", "🔐 Captcha solving API"),
+    ("help.more", "Need more help?"),
+    ("help.contact", "For questions or suggestions, please contact the support team"),
+    ("help.proxy_group", "Choose a proxy group"),
+    ("help.proxy_group_desc", "Pick one of the proxy groups set up on the Settings page; a random proxy from the group is used"),
+    ("help.token", "Enter a token"),
+    ("help.token_desc", "Set the authentication token required by the airline API"),
+    ("help.choose_airline", "Choose an airline"),
+    ("help.choose_airline_desc", "16 airlines are supported, including American Airlines, Asiana Airlines and Copa Airlines"),
+    ("help.query", "Query now"),
+    ("help.query_desc", "Click the query button to send the request through a random proxy from the group"),
+    ("help.proxies", "Proxies"),
+    ("help.proxies_desc", "Proxies are organised in groups, each holding any number of proxies. Bulk import uses the format ip:port:username:password"),
+    ("help.otp", "OTP mailboxes"),
+    ("help.otp_desc", "Mailbox services that receive verification codes, in as many groups as needed"),
+    ("help.cards", "Payment cards"),
+    ("help.cards_desc", "Manage credit and gift cards, saved in groups"),
+    ("help.passengers", "Passengers"),
+    ("help.passengers_desc", "Frequent passengers with their name, email, phone, passport and more"),
+    ("help.location", "Location"),
+    ("help.location_desc", "All settings are stored in Documents/FactBot/config.db"),
+    ("help.autosave", "Saving"),
+    ("help.autosave_desc", "Data is written to the database when you click save"),
+    ("help.autoload", "Loading"),
+    ("help.autoload_desc", "All settings are loaded from the database on startup"),
+    ("help.security", "Privacy"),
+    ("help.security_desc", "A local SQLite database is used; data never leaves this computer"),
+    ("help.captcha_desc", "The captcha solving API comes with examples in several programming languages. Click the title to open the details page."),
+
+    // 验证码接口文档
+    ("captcha_help.title", "🔐 Captcha solving API reference"),
+    ("captcha_help.subtitle", "Two engines, ddddocr and AntiCAP, with examples in several programming languages"),
+    ("captcha_help.choose_language", "Choose a programming language"),
+    ("captcha_help.ddddocr_engine", "🔹 ddddocr engine"),
+    ("captcha_help.anticap_engine", "🔸 AntiCAP engine"),
+    ("captcha_help.text", "Text CAPTCHA"),
+    ("captcha_help.text_desc", "Recognises alphanumeric captchas, with standard, legacy and beta modes"),
+    ("captcha_help.mode_normal", "Standard - the default, suits most captchas"),
+    ("captcha_help.mode_old", "Legacy - uses the older OCR model"),
+    ("captcha_help.mode_beta", "Beta - probabilistic OCR that returns confidence scores"),
+    ("captcha_help.detection", "Object detection"),
+    ("captcha_help.detection_desc", "Finds objects in the image and returns their bounding boxes and centres"),
+    ("captcha_help.slider", "Slider CAPTCHA"),
+    ("captcha_help.slider_desc", "Locates the slider; needs the slider image and the background image"),
+    ("captcha_help.click", "Click CAPTCHA"),
+    ("captcha_help.click_desc", "Finds the targets to click and returns their coordinates"),
+    ("captcha_help.ocr", "General OCR"),
+    ("captcha_help.ocr_desc", "Recognises general text captchas"),
+    ("captcha_help.math", "Arithmetic captcha"),
+    ("captcha_help.math_desc", "Reads and evaluates the arithmetic expression"),
+    ("captcha_help.icon", "Icon detection"),
+    ("captcha_help.icon_desc", "Finds the positions of icons in the image"),
+    ("captcha_help.text_detection", "Text detection"),
+    ("captcha_help.text_detection_desc", "Finds the positions of text in the image"),
+    ("captcha_help.rotate", "Single image rotation"),
+    ("captcha_help.rotate_desc", "Finds the angle the image needs to be rotated by"),
+    ("captcha_help.gap_slider", "Gap slider"),
+    ("captcha_help.gap_slider_desc", "Locates the gap for the slider"),
+    ("captcha_help.shadow_slider", "Shadow slider"),
+    ("captcha_help.shadow_slider_desc", "Locates the shadow for the slider"),
+    ("captcha_help.double_rotate", "Double image rotation"),
+    ("captcha_help.double_rotate_desc", "Finds the rotation between the inner and outer circles"),
+    ("captcha_help.similarity", "Image similarity"),
+    ("captcha_help.similarity_desc", "Computes how similar two images are"),
+    ("captcha_help.modes", "📌 Modes:"),
+    ("captcha_help.hide_code", "Hide code"),
+    ("captcha_help.show_code", "Show code"),
+    ("common.copied", "✓ Copied"),
+    ("common.copy", "📋 Copy"),
+
+    // 验证码识别
+    ("captcha.title", "🔐 Captcha solver"),
+    ("captcha.subtitle", "Combines the ddddocr and AntiCAP engines to solve many kinds of captchas"),
+    ("captcha.choose_engine", "Choose an engine"),
+    ("captcha.ddddocr_engine", "ddddocr engine"),
+    ("captcha.ddddocr_features", "General + slider + click"),
+    ("captcha.anticap_engine", "AntiCAP engine"),
+    ("captcha.anticap_features", "OCR + arithmetic + detection + rotation"),
+    ("captcha.upload", "📸 Upload the captcha image"),
+    ("captcha.type_text", "Text captcha"),
+    ("captcha.type_detection", "Object detection"),
+    ("captcha.type_slider", "Slider captcha"),
+    ("captcha.type_click", "Click captcha"),
+    ("captcha.type_general", "General captcha"),
+    ("captcha.mode", "Mode"),
+    ("captcha.mode_normal", "🔹 Standard"),
+    ("captcha.mode_old", "🔸 Legacy"),
+    ("captcha.mode_beta", "⭐ Beta (high accuracy)"),
+    ("captcha.ddddocr_section", "Text captcha solving"),
+    ("captcha.anticap_section", "AntiCAP captcha solving"),
+    ("captcha.ddddocr_section_desc", "For common captchas, with standard, legacy and beta modes"),
+    ("captcha.background_image", "Background image"),
+    ("captcha.outer_image", "Outer circle image"),
+    ("captcha.compare_image", "Comparison image"),
+    ("captcha.second_image", "Second image"),
+    ("captcha.upload_main", "Click to upload the main image"),
+    ("captcha.remove", "🗑️ Remove"),
+    ("captcha.parse_failed", "❌ Could not parse the response"),
+    ("captcha.request_failed", "❌ Request failed: {0}"),
+    ("captcha.processing", "Solving..."),
+    ("captcha.start", "Solve"),
+    ("captcha.failed_short", "❌ Solving failed"),
+    ("captcha.detected", "📝 {0} targets detected:"),
+    ("captcha.unknown", "unknown"),
+    ("captcha.bbox", "  • {0} - position: [{1}, {2}, {3}, {4}]"),
+    ("captcha.result", "📝 Result: {0}"),
+    ("captcha.objects", "🎯 {0} objects detected:"),
+    ("captcha.object", "  • {0} - centre: ({1}, {2})"),
+    ("captcha.targets", "👆 {0} click targets:"),
+    ("captcha.target", "  • {0} - position: ({1}, {2})"),
+    ("captcha.distance", "🧩 Slide distance: {0} px"),
+    ("captcha.similarity", "📊 Similarity: {0}%"),
+    ("captcha.angle", "🔄 Rotation: {0}°"),
+    ("captcha.gap_position", "Gap: [{0},{1},{2},{3}]"),
+    ("captcha.result_label", "Result: {0}"),
+    ("captcha.computed", "Answer: {0}"),
+    ("captcha.similarity_label", "Similarity: {0}%"),
+    ("captcha.succeeded", "Solved"),
+    ("captcha.failed", "Failed"),
+    ("captcha.hide_raw", "Hide raw response"),
+    ("captcha.show_raw", "Show raw response"),
+    ("captcha.not_serializable", "Could not serialise"),
+
+    // 航司报价
+    ("airline.aa", "American Airlines (cash)"),
+    ("airline.b2", "Belavia"),
+    ("airline.od", "Batik Air Malaysia"),
+    ("airline.vj", "Vietjet"),
+    ("airline.vs", "Virgin Atlantic"),
+    ("airline.oz", "Asiana Airlines (award)"),
+    ("airline.cm_award", "Copa Airlines (award)"),
+    ("airline.cm_cash", "Copa Airlines (cash)"),
+    ("airline.u6", "Ural Airlines"),
+    ("airline.s7", "S7 Airlines"),
+    ("airline.ia", "Iraqi Airways"),
+    ("airline.fv", "Rossiya Airlines"),
+    ("airline.n4", "Nordwind Airlines"),
+    ("airline.bi", "Royal Brunei Airlines"),
+    ("airline.mh", "Malaysia Airlines"),
+    ("airline.5j", "Cebu Pacific"),
+    ("airline.load_groups_failed", "Failed to read the proxy groups"),
+    ("airline.not_tested", "Connection not tested yet"),
+    ("airline.title", "Airline fare console"),
+    ("airline.subtitle", "Manage proxies, tokens and airline codes, and quickly test each airline's fare API parameters."),
+    ("airline.settings", "Parameters"),
+    ("airline.settings_desc", "Choose a proxy group and set the credentials"),
+    ("airline.proxy_group", "Proxy group"),
+    ("airline.no_proxies", "⚠️ No proxies"),
+    ("airline.group_option", "{0} ({1} proxies)"),
+    ("airline.token", "Auth token"),
+    ("airline.token_placeholder", "Enter the access token"),
+    ("airline.check_token", "Check token"),
+    ("airline.fare_params", "Fare parameters"),
+    ("airline.fare_params_desc", "Choose an airline code; the parameter template switches immediately."),
+    ("airline.sending", "Sending the query..."),
+    ("airline.sent", "✓ Query sent\n\nRandomly chosen proxy: {0}:{1}\n\nFull request:\n{2}"),
+    ("airline.querying", "Querying..."),
+    ("airline.current", "Airline"),
+    ("airline.not_configured", "Not set"),
+    ("airline.configured", "Set"),
+    ("airline.not_selected", "None selected"),
+    ("airline.request_preview", "Full request (live)"),
+    ("airline.result", "Result"),
+    ("airline.result_desc", "The generated request below can be used for API calls as is"),
+    ("airline.backend_checking", "Checking"),
+    ("airline.backend_running", "Running"),
+    ("airline.backend_offline", "Offline"),
+    ("airline.port", "Port:"),
+
+    // 日志
+    ("logs.load_presets_failed", "Failed to read the filter presets"),
+    ("logs.bundle_saved", "Diagnostic bundle saved to {0} (settings redacted)"),
+    ("logs.save_query_failed", "Failed to save the query"),
+    ("logs.title", "📋 Log viewer"),
+    ("logs.subtitle", "Browse task logs and filter them by task name, UUID, level, keyword and time range; save frequent filters as presets"),
+    ("logs.filters", "🔍 Filters"),
+    ("logs.task_name", "Task name "),
+    ("logs.choose_task", "-- Choose a task --"),
+    ("logs.task_uuid", "Task UUID"),
+    ("logs.all_uuids", "-- All UUIDs --"),
+    ("logs.keyword", "Keyword"),
+    ("logs.keyword_placeholder", "Search log messages..."),
+    ("logs.levels", "Levels:"),
+    ("logs.all_levels", "All levels"),
+    ("logs.time_range", "Time range:"),
+    ("logs.since_hint", "Start time (in the display time zone)"),
+    ("logs.to", "to"),
+    ("logs.until_hint", "End time (in the display time zone); leave empty for now"),
+    ("logs.presets", "Presets:"),
+    ("logs.choose_preset", "-- Choose a preset --"),
+    ("logs.preset_deleted", "Preset deleted"),
+    ("logs.delete_preset_failed", "Failed to delete the preset"),
+    ("logs.delete_preset", "Delete preset"),
+    ("logs.preset_name", "Preset name"),
+    ("logs.save_preset_hint", "Save the current filters; quick time ranges are saved as relative ranges"),
+    ("logs.preset_saved", "Preset saved"),
+    ("logs.save_preset_failed", "Failed to save the preset"),
+    ("logs.save_preset", "💾 Save as preset"),
+    ("logs.sort", "Sort by time:"),
+    ("logs.newest_first", "⬇ Newest first"),
+    ("logs.oldest_first", "⬆ Oldest first"),
+    ("logs.display_zone", "Time zone:"),
+    ("logs.save_zone_failed", "Failed to save the time zone"),
+    ("logs.bundle_limit_hint", "How many recent log entries the diagnostic bundle includes"),
+    ("logs.bundle_recent", "Latest {0}"),
+    ("logs.bundle_hint", "Packs the logs of the selected task / UUID together with redacted settings, to attach when reporting a problem"),
+    ("logs.create_bundle", "🧰 Create diagnostic bundle"),
+    ("logs.query", "🔍 Search logs"),
+    ("logs.column_task", "Task ID"),
+    ("logs.column_level", "Level"),
+    ("logs.column_time", "Time"),
+    ("logs.column_message", "Message"),
+    ("logs.column_actions", "Actions"),
+    ("logs.selection_copied", "Selected logs copied"),
+    ("logs.copy_selected", "📋 Copy selected"),
+    ("logs.clear_selection", "Clear selection"),
+    ("logs.empty", "No logs"),
+    ("logs.empty_hint", "No log entries match the current filters"),
+    ("logs.no_task", "Choose a task"),
+    ("logs.no_task_hint", "A task name is required; choose one to start searching"),
+    ("logs.timeline_hint", "{0}\nClick to open the timeline of this run"),
+
+    // 配置管理
+    ("config.tab_proxy", "Proxies"),
+    ("config.tab_otp", "OTP mailboxes"),
+    ("config.tab_card", "Payment cards"),
+    ("config.tab_passenger", "Passengers"),
+    ("config.tab_intercept", "Request interception"),
+    ("config.tab_security", "Security & privacy"),
+    ("config.tab_storage", "Data storage"),
+    ("config.load_failed", "Failed to read the settings"),
+    ("config.load_failed_desc", "To avoid overwriting saved data, these settings cannot be edited or saved until they are read successfully"),
+    ("config.retry", "Retry"),
+    ("config.purge_failed", "Failed to remove expired records"),
+    ("config.purged", "Removed {0} expired records"),
+    ("config.default_group", "Default group"),
+    ("config.title", "Settings"),
+    ("config.subtitle", "Manage proxies, OTP mailboxes, payment cards and passengers (saved in groups)"),
+    ("config.quarantined", "⚠️ Some settings could not be parsed and were set aside"),
+    ("config.quarantined_desc", "The original data was not overwritten; the default groups are shown instead. The reason was logged (task Config). Delete the backup once you no longer need it."),
+    ("config.view", "View"),
+    ("config.backup_deleted", "Backup deleted"),
+    ("config.delete_backup_failed", "Failed to delete the backup"),
+    ("config.delete_backup", "Delete backup"),
+    ("config.proxy_groups", "Proxy groups"),
+    ("config.proxy_groups_desc", "Each group can hold any number of proxies"),
+    ("config.group_name_default", "Group {0}"),
+    ("config.new_group", "+ New group"),
+    ("config.group_name", "Group name"),
+    ("config.bulk_import", "📋 Bulk import"),
+    ("config.delete_group", "Delete group"),
+    ("config.column_ip", "IP address"),
+    ("config.column_port", "Port"),
+    ("config.column_username", "Username"),
+    ("config.column_password", "Password"),
+    ("config.delete_selected", "🗑 Delete selected"),
+    ("config.no_proxies", "No proxies yet; click “Bulk import” to add some"),
+    ("config.proxy_groups_saved", "Proxy groups saved"),
+    ("config.save_proxy_groups_failed", "Failed to save the proxy groups"),
+    ("config.save_groups", "💾 Save groups"),
+    ("config.optional", "Optional"),
+    ("config.delete", "Delete"),
+    ("config.import_proxies", "Bulk import proxies"),
+    ("config.import_format", "Format: ip:port:username:password or ip:port (one per line)"),
+    ("config.cancel", "Cancel"),
+    ("config.import", "Import"),
+    ("config.otp_groups", "OTP mailbox groups"),
+    ("config.otp_groups_desc", "Each group can hold any number of OTP mailboxes"),
+    ("config.otp_name_default", "Mailbox {0}"),
+    ("config.add_otp", "+ Add mailbox"),
+    ("config.column_name", "Name"),
+    ("config.column_email", "Email address"),
+    ("config.column_provider", "Provider"),
+    ("config.no_otp", "No OTP mailboxes yet; click “Add mailbox” to add one"),
+    ("config.otp_groups_saved", "OTP groups saved"),
+    ("config.save_otp_groups_failed", "Failed to save the OTP groups"),
+    ("config.card_groups", "Payment card groups"),
+    ("config.card_groups_desc", "Each group can hold any number of cards"),
+    ("config.add_card", "+ Add card"),
+    ("config.records_range", "{0} records, showing {1}-{2}"),
+    ("config.no_cards", "No cards yet; click “Add card” to add one"),
+    ("config.card_groups_saved", "Card groups saved"),
+    ("config.save_card_groups_failed", "Failed to save the card groups"),
+    ("config.card_title", "Card #{0}"),
+    ("config.card_type", "Card type"),
+    ("config.credit_card", "Credit card"),
+    ("config.gift_card", "Gift card"),
+    ("config.card_number", "Card number"),
+    ("config.card_expiry", "Expiry date"),
+    ("config.card_holder", "Cardholder name"),
+    ("config.record_expiry", "Record expiry date (deleted automatically afterwards)"),
+    ("config.passenger_groups", "Passenger groups"),
+    ("config.passenger_groups_desc", "Each group can hold any number of passengers"),
+    ("config.add_passenger", "+ Add passenger"),
+    ("config.no_passengers", "No passengers yet; click “Add passenger” to add one"),
+    ("config.passenger_groups_saved", "Passenger groups saved"),
+    ("config.save_passenger_groups_failed", "Failed to save the passenger groups"),
+    ("config.passenger_title", "Passenger #{0}"),
+    ("config.first_name", "First name"),
+    ("config.last_name", "Last name"),
+    ("config.email", "Email"),
+    ("config.phone", "Phone"),
+    ("config.passport", "Passport number"),
+    ("config.new_passphrase", "New passphrase"),
+    ("config.app_lock", "App lock"),
+    ("config.app_lock_enabled", "On: the passphrase is required on startup, and the app locks when idle or minimised"),
+    ("config.app_lock_disabled", "Off: once a passphrase is set, it is required on startup to access settings and logs"),
+    ("config.current_passphrase", "Current passphrase"),
+    ("config.passphrase_min", "At least 6 characters"),
+    ("config.confirm_passphrase", "Confirm passphrase"),
+    ("config.app_lock_disabled_done", "App lock turned off"),
+    ("config.disable_app_lock", "Turn off app lock"),
+    ("config.passphrase_mismatch", "The passphrases do not match"),
+    ("config.passphrase_saved", "Passphrase saved"),
+    ("config.change_passphrase", "Change passphrase"),
+    ("config.enable_app_lock", "Turn on app lock"),
+    ("config.idle_lock", "Lock when idle:"),
+    ("config.never", "Never"),
+    ("config.lock_on_minimize", "Lock when minimised or hidden to the tray"),
+    ("config.lock_settings_saved", "Lock settings saved"),
+    ("config.window", "Window"),
+    ("config.window_desc", "The window position, size and maximised state are saved on close and restored at the next launch"),
+    ("config.tray_enabled", "Show a tray icon and hide to the tray when the window is closed"),
+    ("config.tray_note", "Takes effect after restarting FactBot; once hidden, quit from the tray menu"),
+    ("config.tray_saved", "Tray setting saved; restart to apply"),
+    ("config.tray_save_failed", "Failed to save the tray setting"),
+    ("config.save_settings", "Save settings"),
+    ("config.passphrase_note", "The passphrase is stored in config.db as an Argon2 hash and must be at least {0} characters; if you forget it, delete the app_lock_hash record from config.db"),
+    ("config.load_rules_failed", "Failed to read the interceptor rules"),
+    ("config.intercept_desc", "Rules are checked from top to bottom and the first match decides whether the request is allowed or blocked; when nothing matches, the mode decides. Leave the methods empty to match every request method."),
+    ("config.mode", "Mode:"),
+    ("config.no_rules", "No rules"),
+    ("config.rule_enabled", "On"),
+    ("config.rule_action", "Action"),
+    ("config.rule_kind", "Match"),
+    ("config.rule_pattern", "Pattern"),
+    ("config.rule_methods", "Methods"),
+    ("config.add_rule", "+ Add rule"),
+    ("config.save_rules_failed", "Failed to save the interceptor rules"),
+    ("config.rules_saved", "Interceptor rules saved; they apply to new tasks"),
+    ("config.save_rules", "Save rules"),
+    ("config.test_label", "Test:"),
+    ("config.rule_hit", "Matched rule #{0}: {1}"),
+    ("config.no_rule_hit", "No rule matched, {0}"),
+    ("config.test", "Test"),
+    ("config.test_allowed", "✅ Allowed — {0}"),
+    ("config.test_blocked", "⛔ Blocked — {0}"),
+    ("config.pattern_help", "Domain and subdomains: example.com matches a.example.com but not notexample.com. URL wildcard matches the whole URL, with * for any characters and ? for a single one. URL regex matches any part of the URL."),
+    ("config.all_methods", "All"),
+    ("config.wipe", "Personal data removal"),
+    ("config.wipe_desc", "Deletes every passenger, payment card and OTP mailbox along with the automatic config backups, then compacts the database so nothing is left behind. Records with an expiry date are deleted automatically when they expire."),
+    ("config.wipe_button", "🗑 Remove all personal data"),
+    ("config.wipe_confirm_title", "Confirm personal data removal"),
+    ("config.wipe_confirm_desc", "This permanently deletes every passenger, payment card and OTP mailbox and cannot be undone. Type “{0}” to confirm."),
+    ("config.wiped", "Personal data removed"),
+    ("config.wipe_failed", "Removal failed: {0}"),
+    ("config.wipe_confirm", "Delete permanently"),
+    ("config.wipe_confirm_text", "DELETE"),
+    ("config.data_moved", "Data moved"),
+    ("config.move_failed", "Failed to move the data"),
+    ("config.no_data_dir", "Could not determine the data folder"),
+    ("config.data_dir", "Data folder"),
+    ("config.data_dir_desc", "Where the settings and log databases are stored (current source: {0})"),
+    ("config.log_db_fallback", "The program folder is not writable, so the log database is stored in the user data folder instead."),
+    ("config.data_dir_override", "The data folder is set by {0}; change it there and restart."),
+    ("config.new_data_dir", "New data folder"),
+    ("config.data_dir_example", "e.g. D:\\FactBotData"),
+    ("config.move_desc", "Moving copies config.db, logs.db and the settings backups to the new folder, verifies them and then deletes the originals; existing databases in the target folder are never overwritten."),
+    ("config.move_back", "Move back to the default location"),
+    ("config.move", "Move data"),
+    ("config.export_failed", "Failed to export the settings"),
+    ("config.exported", "Exported to {0} ({1})"),
+    ("config.read_backup_failed", "Failed to read the backup"),
+    ("config.imported", "Settings imported"),
+    ("config.import_failed", "Failed to import the settings"),
+    ("config.backup", "Backup & restore"),
+    ("config.backup_desc", "Exports the proxy, OTP, card and passenger groups plus the app settings to one encrypted file, for moving to a new computer or disaster recovery. The web API token and app lock passphrase are not exported; set them again on the new computer. The backup contains personal data, so keep the file and passphrase safe."),
+    ("config.export", "Export"),
+    ("config.save_to", "Save to"),
+    ("config.backup_path", "Backup file path"),
+    ("config.backup_passphrase", "Backup passphrase"),
+    ("config.backup_passphrase_min", "At least 6 characters"),
+    ("config.backup_passphrase_again", "Enter the backup passphrase again"),
+    ("config.export_backup", "📤 Export backup"),
+    ("config.backup_file", "Backup file"),
+    ("config.backup_file_example", "e.g. D:\\FactBot-settings-20240501.fbak"),
+    ("config.backup_passphrase_hint", "The passphrase set when exporting"),
+    ("config.preview_backup", "🔍 Read and preview"),
+    ("config.backup_contents", "Backup created at {0}, containing {1}. Choose how to import each part:"),
+    ("config.import_note", "The groups as they were before the import are kept in each group's history and can be restored at any time. Settings such as the app lock passphrase take effect after a restart."),
+    ("config.import_backup", "📥 Import"),
+    ("config.history_failed", "Failed to read the history: {0}"),
+    ("config.history_expanded", "▾ 📜 History"),
+    ("config.history_collapsed", "▸ 📜 History"),
+    ("config.refresh", "Refresh"),
+    ("config.no_history", "No history yet; the previous contents are recorded every time the groups are saved"),
+    ("config.restored", "Restored the selected version"),
+    ("config.restore_failed", "Restore failed: {0}"),
+    ("config.restore", "Restore"),
+    ("config.hide", "Hide"),
+    ("config.reveal", "Show for {0} s"),
+    ("config.copy_secret", "Copy (the clipboard is cleared after {0} s)"),
+    ("config.minutes_option", "{0} min"),
+
+    // 通用标签
+    ("time_range.all", "All time"),
+    ("time_range.15m", "Last 15 minutes"),
+    ("time_range.today", "Today"),
+    ("time_range.7d", "Last 7 days"),
+    ("time_range.custom", "Custom"),
+    ("zone.local", "Local time (UTC{0})"),
+    ("duration.seconds", "{0} s"),
+    ("duration.minutes", "{0} min {1} s"),
+    ("duration.hours", "{0} h {1} min"),
+    ("intercept.blocklist", "Blocklist (allow by default)"),
+    ("intercept.allowlist", "Allowlist (block by default)"),
+    ("intercept.block", "Block"),
+    ("intercept.allow", "Allow"),
+    ("intercept.host", "Host"),
+    ("intercept.domain_suffix", "Domain and subdomains"),
+    ("intercept.wildcard", "URL wildcard"),
+    ("intercept.regex", "URL regex"),
+    ("intercept.keyword", "URL keyword"),
+    ("archive.proxy_groups", "Proxy groups"),
+    ("archive.otp_groups", "OTP groups"),
+    ("archive.card_groups", "Card groups"),
+    ("archive.passenger_groups", "Passenger groups"),
+    ("archive.app_settings", "App settings"),
+    ("archive.skip", "Skip"),
+    ("archive.merge", "Merge"),
+    ("archive.replace", "Replace"),
+    ("archive.diff_unchanged", "Unchanged"),
+    ("archive.diff_added", "Add {0}"),
+    ("archive.diff_updated", "Overwrite {0}"),
+    ("archive.diff_removed", "Delete {0}"),
+    ("archive.diff_none", "No changes"),
+    ("archive.diff_item_separator", ", "),
+    ("archive.diff_part_separator", "; "),
+    ("db.config", "Settings database (config.db)"),
+    ("db.logs", "Log database (logs.db)"),
+    ("db.locked", "The database is in use by another program"),
+    ("db.read_only", "The database location is not writable"),
+    ("db.corrupt", "The database file is damaged: {0}"),
+    ("db.other", "Cannot open the database: {0}"),
+    ("db.remedy_locked", "Close any other running FactBot window and any database tool that may have the file open, then check again."),
+    ("db.remedy_read_only", "Check the write permissions of the file and its folder, or move the program to a writable folder and restart."),
+    ("db.remedy_corrupt_config", "You can restore the settings from the automatic backup made at the last start; the damaged file is kept for troubleshooting."),
+    ("db.remedy_corrupt_logs", "You can rebuild an empty log database; the damaged file is renamed and kept."),
+    ("db.remedy_other", "Make sure there is enough disk space and the path exists and is accessible, then check again."),
+    ("data_dir.cli_flag", "Command line option --data-dir"),
+    ("data_dir.env", "Environment variable FACTBOT_DATA_DIR"),
+    ("data_dir.setting", "Settings page"),
+    ("data_dir.default", "Default location"),
+    ("history.replaced", "Replaced entirely"),
+    ("history.unchanged", "No changes"),
+    ("history.groups_added", "{0} group(s) added"),
+    ("history.groups_removed", "{0} group(s) deleted"),
+    ("history.groups_renamed", "{0} group(s) renamed"),
+    ("history.records_added", "{0} record(s) added"),
+    ("history.records_removed", "{0} record(s) deleted"),
+    ("history.records_modified", "{0} record(s) changed"),
+    ("history.separator", ", "),
+
+    // 错误提示
+    ("error.blocked", "The request was blocked by an interceptor rule"),
+    ("error.no_proxy", "No proxy is available; add one in Settings first"),
+    ("error.request_failed", "The network request failed; check your network or proxy settings"),
+    ("error.invalid_config", "Invalid configuration: {0}"),
+    ("error.cookie", "The cookie format was not recognised"),
+    ("error.task_stopped", "The target server kept failing, so the task was stopped"),
+    ("error.database", "Database error: {0}"),
+    ("error.config_not_found", "Setting not found: {0}"),
+    ("error.config_version", "The settings were saved by a newer version of FactBot and cannot be read by this one"),
+    ("error.config_quarantined", "The settings could not be parsed; the original data was backed up to {0}"),
+    ("error.backup_decrypt", "Wrong passphrase, or the backup file is damaged"),
+    ("error.unsupported_backup", "Cannot read the backup file: {0}"),
+    ("error.log_write", "Failed to write the log"),
+    ("error.log_level", "Invalid log level: {0}"),
+    ("error.io_permission", "No permission to access the file"),
+    ("error.io_not_found", "The file or folder does not exist"),
+    ("error.io", "Failed to read or write the file"),
+    ("error.json", "The data could not be parsed"),
+    ("error.url", "The URL is not valid"),
+    ("error.db_busy", "The database is busy; please try again shortly"),
+    ("error.disk_full", "The disk is full; the data was not saved"),
+    ("error.db_permission", "No permission to write to the database"),
+    ("error.db_corrupt", "The database file is damaged"),
+    ("error.db", "The database operation failed"),
+    ("error.zip", "Failed to write the archive"),
+    ("error.unknown", "An unknown error occurred"),
+    ("error.preset_name_empty", "The preset name cannot be empty"),
+    ("error.passphrase_too_short", "The passphrase must be at least {0} characters"),
+    ("error.current_passphrase_wrong", "The current passphrase is incorrect"),
+    ("error.intercept_rule_empty", "The interception rule is empty: {0}"),
+    ("error.intercept_rule_invalid", "Invalid interception rule: {0} ({1})"),
+    ("error.rate_invalid", "Invalid requests per second: {0}"),
+    ("error.redaction_rule_invalid", "Invalid redaction rule `{0}`: {1}"),
+    ("error.proxy_url_invalid", "Invalid proxy address: {0}"),
+    ("error.request_method_invalid", "Invalid request method: {0}"),
+    ("error.fixture_version_newer", "Fixture file version {0} is newer than the supported version {1}"),
+    ("error.backup_kdf_unknown", "Unknown key derivation algorithm {0}"),
+    ("error.backup_kdf_too_large", "Key derivation parameters exceed the limit (memory {0} KiB, {1} iterations, parallelism {2})"),
+    ("error.backup_kdf_invalid", "Invalid key derivation parameters: {0}"),
+    ("error.backup_field_base64", "Field {0} is not valid Base64"),
+    ("error.backup_passphrase_too_short", "The backup passphrase must be at least {0} characters"),
+    ("error.backup_not_archive", "Not a FactBot settings backup file"),
+    ("error.backup_version_newer", "Backup format version {0} is newer than the supported version {1}"),
+    ("error.backup_nonce_length", "The nonce has the wrong length"),
+    ("error.no_config_backup", "No usable settings backup is available"),
+    ("error.proxy_port_invalid", "Invalid port for proxy {0}: {1}"),
+    ("error.history_version", "history version #{0}"),
+    ("error.no_data_dir", "Could not determine the application data directory"),
+    ("error.dir_not_writable", "The directory is not writable: {0}"),
+    ("error.target_db_exists", "A database already exists at the target location: {0}"),
+    ("error.moved_db_check_failed", "The moved database failed verification ({0}): {1}"),
+    ("error.data_dir_overridden", "The data directory is set by {0}; change it there and restart"),
+];
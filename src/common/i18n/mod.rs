@@ -0,0 +1,229 @@
+//! 界面文字翻译
+//!
+//! - 文案按 key 保存在各语言的消息目录中（`zh.rs`、`en.rs`），中文为默认语言
+//! - `t("logs.title")` 返回当前语言的文字，`t_args("table.total", &[&count])` 替换 `{0}`、`{1}` 等占位符
+//! - 某语言缺少翻译时回退到中文；测试会检查源码中用到的 key 在每个目录中都存在
+//! - 当前语言保存在 config.db，界面切换语言后重新渲染页面
+
+mod en;
+mod zh;
+
+use crate::common::Result;
+use crate::db::{load_config, save_config};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::OnceLock;
+
+/// 保存界面语言的配置 key
+const LANGUAGE_KEY: &str = "ui_language";
+
+static CURRENT: AtomicU8 = AtomicU8::new(Lang::Zh as u8);
+
+/// 界面语言
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Lang {
+    #[default]
+    Zh = 0,
+    En = 1,
+}
+
+impl Lang {
+    pub const ALL: [Lang; 2] = [Lang::Zh, Lang::En];
+
+    /// 保存到配置中的标识
+    pub fn id(&self) -> &'static str {
+        match self {
+            Lang::Zh => "zh",
+            Lang::En => "en",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|lang| lang.id() == id)
+    }
+
+    /// 语言自身的名称（不随界面语言变化）
+    pub fn native_name(&self) -> &'static str {
+        match self {
+            Lang::Zh => "中文",
+            Lang::En => "English",
+        }
+    }
+
+    /// 读取保存的语言，未设置或无法读取时为中文
+    pub fn load() -> Self {
+        load_config(LANGUAGE_KEY)
+            .ok()
+            .flatten()
+            .and_then(|id| Self::from_id(&id))
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) -> Result<()> {
+        save_config(LANGUAGE_KEY, self.id())
+    }
+
+    fn messages(&self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Lang::Zh => zh::MESSAGES,
+            Lang::En => en::MESSAGES,
+        }
+    }
+
+    fn catalogue(&self) -> &'static HashMap<&'static str, &'static str> {
+        static CATALOGUES: [OnceLock<HashMap<&str, &str>>; 2] = [OnceLock::new(), OnceLock::new()];
+        CATALOGUES[*self as usize].get_or_init(|| self.messages().iter().copied().collect())
+    }
+
+    /// 查找翻译：缺失时回退到中文，中文也没有时返回 key 本身
+    pub fn t(&self, key: &'static str) -> &'static str {
+        self.catalogue()
+            .get(key)
+            .or_else(|| Lang::Zh.catalogue().get(key))
+            .copied()
+            .unwrap_or(key)
+    }
+
+    /// 查找翻译并依次替换 `{0}`、`{1}` 等占位符
+    pub fn t_args(&self, key: &'static str, args: &[&dyn fmt::Display]) -> String {
+        args.iter()
+            .enumerate()
+            .fold(self.t(key).to_string(), |text, (index, arg)| text.replace(&format!("{{{}}}", index), &arg.to_string()))
+    }
+
+    /// 日期时间的显示格式（chrono 格式串）
+    pub fn datetime_format(&self) -> &'static str {
+        match self {
+            Lang::Zh => "%Y-%m-%d %H:%M:%S%.3f",
+            Lang::En => "%b %-d, %Y %H:%M:%S%.3f",
+        }
+    }
+
+    /// 数字按千位分组（两种语言的分隔符都是逗号）
+    pub fn format_number(&self, value: usize) -> String {
+        let digits = value.to_string();
+        let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+        for (index, digit) in digits.chars().enumerate() {
+            if index > 0 && (digits.len() - index).is_multiple_of(3) {
+                grouped.push(',');
+            }
+            grouped.push(digit);
+        }
+        grouped
+    }
+}
+
+/// 当前界面语言
+pub fn current() -> Lang {
+    match CURRENT.load(Ordering::Relaxed) {
+        1 => Lang::En,
+        _ => Lang::Zh,
+    }
+}
+
+pub fn set_current(lang: Lang) {
+    CURRENT.store(lang as u8, Ordering::Relaxed);
+}
+
+/// 当前语言的文字
+pub fn t(key: &'static str) -> &'static str {
+    current().t(key)
+}
+
+/// 当前语言的文字，替换占位符
+pub fn t_args(key: &'static str, args: &[&dyn fmt::Display]) -> String {
+    current().t_args(key, args)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use regex::Regex;
+    use std::collections::{BTreeSet, HashSet};
+    use std::path::{Path, PathBuf};
+
+    fn keys(lang: Lang) -> BTreeSet<&'static str> {
+        lang.messages().iter().map(|(key, _)| *key).collect()
+    }
+
+    fn placeholders(text: &str) -> BTreeSet<String> {
+        Regex::new(r"\{\d+\}").unwrap().find_iter(text).map(|m| m.as_str().to_string()).collect()
+    }
+
+    fn rust_files(dir: &Path, files: &mut Vec<PathBuf>) {
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                rust_files(&path, files);
+            } else if path.extension().is_some_and(|ext| ext == "rs") {
+                files.push(path);
+            }
+        }
+    }
+
+    #[test]
+    fn test_catalogues_complete() {
+        let reference = keys(Lang::Zh);
+        for lang in Lang::ALL {
+            let mut seen = HashSet::new();
+            for (key, _) in lang.messages() {
+                assert!(seen.insert(key), "{} 目录中 key 重复: {}", lang.id(), key);
+            }
+            let own = keys(lang);
+            let missing: Vec<_> = reference.difference(&own).collect();
+            let extra: Vec<_> = own.difference(&reference).collect();
+            assert!(missing.is_empty(), "{} 目录缺少: {:?}", lang.id(), missing);
+            assert!(extra.is_empty(), "{} 目录有多余的 key: {:?}", lang.id(), extra);
+
+            for (key, text) in lang.messages() {
+                assert_eq!(placeholders(text), placeholders(Lang::Zh.t(key)), "{} 的占位符与中文不一致: {}", lang.id(), key);
+            }
+        }
+    }
+
+    #[test]
+    fn test_used_keys_exist() {
+        // 文件路径相对于 crate 根目录（独立构建时可能已是绝对路径）
+        let this_file = Path::new(file!());
+        let this_file = if this_file.is_absolute() {
+            this_file.to_path_buf()
+        } else {
+            Path::new(env!("CARGO_MANIFEST_DIR")).join(this_file)
+        };
+        let src = this_file.ancestors().nth(3).unwrap();
+        let mut files = Vec::new();
+        rust_files(src, &mut files);
+
+        // `t(..)`、`t_args(..)`、`lang.t(..)` 以及错误原因 `Reason::new(..)` 形式的调用，key 需为字面量
+        let call = Regex::new(r#"\b(?:t|t_args|Reason::new)\(\s*"([^"]+)""#).unwrap();
+        let reference = keys(Lang::Zh);
+        let mut used = 0;
+        for file in files {
+            let source = std::fs::read_to_string(&file).unwrap();
+            for capture in call.captures_iter(&source) {
+                let key = capture.get(1).unwrap().as_str();
+                used += 1;
+                assert!(reference.contains(key), "{} 使用了目录中不存在的 key: {}", file.display(), key);
+            }
+        }
+        assert!(used > 0);
+    }
+
+    #[test]
+    fn test_lookup_and_format() {
+        assert_eq!(Lang::En.t("common.close"), "Close");
+        assert_eq!(Lang::Zh.t("common.close"), "关闭");
+        // 缺失的 key 原样返回（用变量传入，避免被 test_used_keys_exist 当作使用的 key）
+        let missing = "no.such.key";
+        assert_eq!(Lang::En.t(missing), missing);
+        assert_eq!(Lang::En.t_args("table.total", &[&12]), "12 rows");
+        assert_eq!(Lang::Zh.t_args("table.total", &[&12]), "共 12 条");
+
+        assert_eq!(Lang::En.format_number(0), "0");
+        assert_eq!(Lang::En.format_number(999), "999");
+        assert_eq!(Lang::Zh.format_number(1234567), "1,234,567");
+        assert_eq!(Lang::from_id("en"), Some(Lang::En));
+        assert_eq!(Lang::from_id("fr"), None);
+    }
+}
@@ -0,0 +1,625 @@
+//! 中文（默认语言，其他语言缺少的翻译回退到这里）
+
+pub(super) const MESSAGES: &[(&str, &str)] = &[
+    // 组件
+    ("common.close", "关闭"),
+    ("crash.title", "💥 FactBot 上次运行时崩溃了"),
+    ("crash.saved_to_logs", "崩溃信息已保存到日志（任务 System）。反馈问题时可以生成诊断包一并发送。"),
+    ("crash.occurred_at", "时间：{0}"),
+    ("crash.version", "版本：{0}"),
+    ("crash.hide_backtrace", "收起调用栈"),
+    ("crash.show_backtrace", "查看调用栈"),
+    ("crash.no_documents_dir", "无法确定文档目录"),
+    ("crash.bundle_failed", "生成诊断包失败"),
+    ("crash.bundle_saved", "诊断包已保存到 {0}"),
+    ("crash.create_bundle", "生成诊断包"),
+    ("crash.acknowledge_failed", "保存崩溃记录状态失败"),
+    ("lock.wrong_passphrase", "口令错误，请重试"),
+    ("lock.read_failed", "无法读取口令配置: {0}"),
+    ("lock.settings_failed", "无法读取应用锁设置，已锁定以保护数据: {0}"),
+    ("lock.title", "FactBot 已锁定"),
+    ("lock.prompt", "请输入应用锁口令以继续"),
+    ("lock.passphrase", "口令"),
+    ("lock.unlock", "解锁"),
+    ("startup.title", "⚠️ 启动自检未通过"),
+    ("startup.description", "FactBot 在启动时检查了本地数据库，发现以下问题。为避免数据丢失，处理完成前不会进入主界面。"),
+    ("startup.restored", "已从备份恢复：{0}"),
+    ("startup.restore_failed", "恢复失败：{0}"),
+    ("startup.rebuilt", "已重建日志数据库"),
+    ("startup.rebuild_failed", "重建失败：{0}"),
+    ("startup.ignore", "忽略并继续（日志不会保存）"),
+    ("startup.recheck", "重新检查"),
+    ("startup.unknown_path", "（路径未知）"),
+    ("startup.ok", "✅ 正常"),
+    ("startup.broken", "❌ 异常"),
+    ("startup.restore_latest", "从最近的自动备份恢复"),
+    ("startup.no_backups", "没有找到自动备份"),
+    ("startup.rebuild_logs", "重建空的日志数据库"),
+    ("startup.backup_failed", "自动备份配置失败"),
+    ("startup.db_init_failed", "数据库初始化失败"),
+    ("nav.home", "首页"),
+    ("nav.airline", "航司报价"),
+    ("nav.captcha", "验证码识别"),
+    ("nav.config", "配置管理"),
+    ("nav.logs", "日志查看"),
+    ("nav.notice", "通知"),
+    ("nav.help", "帮助"),
+    ("titlebar.lock", "锁定"),
+    ("titlebar.language", "界面语言"),
+    ("titlebar.language_save_failed", "保存界面语言失败"),
+    ("titlebar.theme", "主题"),
+    ("titlebar.theme_save_failed", "保存主题失败"),
+    ("theme.light", "浅色"),
+    ("theme.dark", "深色"),
+    ("theme.system", "跟随系统"),
+    ("toast.collapse", "收起"),
+    ("toast.details", "详情"),
+    ("toast.failure", "{0}：{1}"),
+    ("table.total", "共 {0} 条"),
+    ("table.total_range", "共 {0} 条，第 {1}-{2} 条"),
+    ("table.selected", "，已选 {0} 条"),
+    ("table.columns", "列 ▾"),
+    ("table.page_size", "每页显示："),
+    ("table.all", "全部"),
+    ("table.size_option", "{0} 条"),
+    ("table.previous", "上一页"),
+    ("table.next", "下一页"),
+    ("tray.show", "显示窗口"),
+    ("tray.hide", "隐藏到托盘"),
+    ("tray.quit", "退出 FactBot"),
+
+    // 首页、通知、时间线
+    ("home.tagline", "集中管理多家航司报价、代理与接口配置"),
+    ("home.intro", "FactBot 提供统一的工作台，帮助你在一个界面完成代理配置、Token 维护以及各航司查询。通过左侧侧边栏即可随时切换到航司功能页。"),
+    ("home.airlines_desc", "支持16家航司查询"),
+    ("home.config_desc", "统一管理代理和卡片"),
+    ("home.secure", "安全可靠"),
+    ("home.secure_desc", "本地存储配置信息"),
+    ("home.open_airline", "进入航司报价 →"),
+    ("notice.title", "📢 系统公告"),
+    ("notice.subtitle", "查看最新的系统更新和重要通知"),
+    ("notice.welcome_title", "欢迎使用 FactBot 控制台"),
+    ("notice.welcome_content", "FactBot 是一个强大的数据运维助手，提供航司报价查询、配置管理等功能。请先在配置管理页面设置代理分组和其他配置信息。"),
+    ("notice.import_title", "新增批量导入功能"),
+    ("notice.import_content", "代理配置现已支持批量导入功能，支持 ip:port:username:password 格式，每行一个代理，大大提升配置效率。"),
+    ("notice.airlines_title", "支持 16 家航司查询"),
+    ("notice.airlines_content", "目前已支持美国航空、白俄罗斯航空、飞狮航空、越捷航空、维珍航空、韩亚航空、巴拿马航空、乌拉尔航空、西伯利亚航空、伊拉克航空、俄罗斯国际航空、北风航空、皇雀航空、马来西亚国际航空、宿务航空等 16 家航司。"),
+    ("notice.important", "🔥 重要"),
+    ("notice.normal", "📌 通知"),
+    ("logs.load_failed", "读取日志失败"),
+    ("timeline.title", "🕒 任务时间线"),
+    ("timeline.back", "← 返回日志"),
+    ("timeline.total_duration", "总时长"),
+    ("timeline.started", "开始"),
+    ("timeline.ended", "结束"),
+    ("timeline.requests", "请求"),
+    ("timeline.warnings", "警告"),
+    ("timeline.errors", "错误"),
+    ("timeline.empty", "没有找到该任务的日志"),
+    ("timeline.gap", "⋯ 间隔 {0}"),
+    ("timeline.unfinished", "未完成"),
+    ("timeline.summary", "{0} · {1} 条"),
+
+    // 帮助
+    ("help.title", "❓ 帮助中心"),
+    ("help.subtitle", "了解 FactBot 的各项功能和使用方法"),
+    ("help.airline", "✈️ 航司报价查询"),
+    ("help.config", "⚙️ 配置管理"),
+    ("help.storage", "💾 数据存储"),
+    ("help.captcha", "🔐 验证码识别 API"),
+    ("help.more", "需要更多帮助？"),
+    ("help.contact", "如有问题或建议，请联系技术支持团队"),
+    ("help.proxy_group", "选择代理分组"),
+    ("help.proxy_group_desc", "从配置管理页面设置的代理分组中选择，系统会随机使用分组中的一个代理"),
+    ("help.token", "输入 Token"),
+    ("help.token_desc", "配置访问航司 API 所需的认证 Token"),
+    ("help.choose_airline", "选择航司"),
+    ("help.choose_airline_desc", "支持 16 家航司，包括美国航空、韩亚航空、巴拿马航空等"),
+    ("help.query", "立即查询"),
+    ("help.query_desc", "点击查询按钮发送请求，系统会随机选择分组中的一个代理使用"),
+    ("help.proxies", "代理配置"),
+    ("help.proxies_desc", "支持分组管理，每个分组可添加多个代理。支持批量导入，格式：ip:port:username:password"),
+    ("help.otp", "OTP 邮箱"),
+    ("help.otp_desc", "配置用于接收验证码的邮箱服务，支持多个配置分组"),
+    ("help.cards", "支付卡片"),
+    ("help.cards_desc", "管理信用卡和礼品卡信息，支持分组批量保存"),
+    ("help.passengers", "购票人信息"),
+    ("help.passengers_desc", "保存常用购票人信息，包括姓名、邮箱、电话、护照等"),
+    ("help.location", "存储位置"),
+    ("help.location_desc", "所有配置数据存储在：文档/FactBot/config.db"),
+    ("help.autosave", "自动保存"),
+    ("help.autosave_desc", "点击保存按钮后数据会持久化到数据库"),
+    ("help.autoload", "自动加载"),
+    ("help.autoload_desc", "应用启动时自动从数据库加载所有配置"),
+    ("help.security", "数据安全"),
+    ("help.security_desc", "使用 SQLite 本地数据库，数据仅存储在本地"),
+    ("help.captcha_desc", "验证码识别 API 提供了多种编程语言的调用示例，点击标题进入详细页面查看。"),
+
+    // 验证码接口文档
+    ("captcha_help.title", "🔐 验证码识别 API 文档"),
+    ("captcha_help.subtitle", "支持 ddddocr + AntiCAP 双引擎，提供多种编程语言调用示例"),
+    ("captcha_help.choose_language", "选择编程语言"),
+    ("captcha_help.ddddocr_engine", "🔹 ddddocr 引擎"),
+    ("captcha_help.anticap_engine", "🔸 AntiCAP 引擎"),
+    ("captcha_help.text", "普通验证码 (Text CAPTCHA)"),
+    ("captcha_help.text_desc", "识别英数字验证码，支持标准、旧版、Beta 三种模式"),
+    ("captcha_help.mode_normal", "标准模式 - 默认模式，适用于大多数验证码"),
+    ("captcha_help.mode_old", "旧版模式 - 使用旧版 OCR 模型"),
+    ("captcha_help.mode_beta", "Beta 模式 - 概率 OCR，返回置信度信息"),
+    ("captcha_help.detection", "目标检测 (Object Detection)"),
+    ("captcha_help.detection_desc", "检测图片中的目标物体，返回边界框和中心点坐标"),
+    ("captcha_help.slider", "滑块验证码 (Slider CAPTCHA)"),
+    ("captcha_help.slider_desc", "识别滑块位置，需要提供滑块图和背景图"),
+    ("captcha_help.click", "点选验证码 (Click CAPTCHA)"),
+    ("captcha_help.click_desc", "识别需要点击的目标位置，返回坐标列表"),
+    ("captcha_help.ocr", "通用 OCR"),
+    ("captcha_help.ocr_desc", "识别通用文字验证码"),
+    ("captcha_help.math", "算术验证码"),
+    ("captcha_help.math_desc", "识别并计算算术表达式"),
+    ("captcha_help.icon", "图标检测"),
+    ("captcha_help.icon_desc", "检测图片中的图标位置"),
+    ("captcha_help.text_detection", "文字检测"),
+    ("captcha_help.text_detection_desc", "检测图片中的文字位置"),
+    ("captcha_help.rotate", "单图旋转"),
+    ("captcha_help.rotate_desc", "识别图片需要旋转的角度"),
+    ("captcha_help.gap_slider", "缺口滑块"),
+    ("captcha_help.gap_slider_desc", "识别缺口滑块的位置"),
+    ("captcha_help.shadow_slider", "阴影滑块"),
+    ("captcha_help.shadow_slider_desc", "识别阴影滑块的位置"),
+    ("captcha_help.double_rotate", "双图旋转"),
+    ("captcha_help.double_rotate_desc", "识别内外圆图的旋转角度"),
+    ("captcha_help.similarity", "图片相似度"),
+    ("captcha_help.similarity_desc", "计算两张图片的相似度"),
+    ("captcha_help.modes", "📌 模式参数说明："),
+    ("captcha_help.hide_code", "隐藏代码"),
+    ("captcha_help.show_code", "查看代码"),
+    ("common.copied", "✓ 已复制"),
+    ("common.copy", "📋 复制"),
+
+    // 验证码识别
+    ("captcha.title", "🔐 验证码识别系统"),
+    ("captcha.subtitle", "集成 ddddocr + AntiCAP 双引擎，支持多种验证码类型识别"),
+    ("captcha.choose_engine", "选择识别引擎"),
+    ("captcha.ddddocr_engine", "ddddocr 引擎"),
+    ("captcha.ddddocr_features", "通用识别 + 滑块 + 点选"),
+    ("captcha.anticap_engine", "AntiCAP 引擎"),
+    ("captcha.anticap_features", "OCR + 算术 + 检测 + 旋转"),
+    ("captcha.upload", "📸 上传验证码图片"),
+    ("captcha.type_text", "普通验证码"),
+    ("captcha.type_detection", "目标检测"),
+    ("captcha.type_slider", "滑块验证码"),
+    ("captcha.type_click", "点选验证码"),
+    ("captcha.type_general", "通用验证码"),
+    ("captcha.mode", "识别模式"),
+    ("captcha.mode_normal", "🔹 标准模式"),
+    ("captcha.mode_old", "🔸 旧版模式"),
+    ("captcha.mode_beta", "⭐ Beta 高精度"),
+    ("captcha.ddddocr_section", "普通验证码识别"),
+    ("captcha.anticap_section", "AntiCAP 验证码识别"),
+    ("captcha.ddddocr_section_desc", "适用于各类常规验证码，支持标准、旧版、Beta 三种识别模式"),
+    ("captcha.background_image", "背景图"),
+    ("captcha.outer_image", "外圆图"),
+    ("captcha.compare_image", "对比图"),
+    ("captcha.second_image", "副图"),
+    ("captcha.upload_main", "点击上传主图"),
+    ("captcha.remove", "🗑️ 移除"),
+    ("captcha.parse_failed", "❌ 解析响应失败"),
+    ("captcha.request_failed", "❌ 请求失败: {0}"),
+    ("captcha.processing", "识别中..."),
+    ("captcha.start", "开始识别"),
+    ("captcha.failed_short", "❌ 识别失败"),
+    ("captcha.detected", "📝 检测到 {0} 个目标:"),
+    ("captcha.unknown", "未知"),
+    ("captcha.bbox", "  • {0} - 位置: [{1}, {2}, {3}, {4}]"),
+    ("captcha.result", "📝 识别结果: {0}"),
+    ("captcha.objects", "🎯 检测到 {0} 个目标:"),
+    ("captcha.object", "  • {0} - 中心点: ({1}, {2})"),
+    ("captcha.targets", "👆 可点击目标 {0} 个:"),
+    ("captcha.target", "  • {0} - 位置: ({1}, {2})"),
+    ("captcha.distance", "🧩 滑动距离: {0} 像素"),
+    ("captcha.similarity", "📊 相似度: {0}%"),
+    ("captcha.angle", "🔄 旋转角度: {0}°"),
+    ("captcha.gap_position", "缺口位置: [{0},{1},{2},{3}]"),
+    ("captcha.result_label", "识别结果: {0}"),
+    ("captcha.computed", "计算结果: {0}"),
+    ("captcha.similarity_label", "相似度: {0}%"),
+    ("captcha.succeeded", "识别成功"),
+    ("captcha.failed", "识别失败"),
+    ("captcha.hide_raw", "隐藏原始响应"),
+    ("captcha.show_raw", "查看原始响应"),
+    ("captcha.not_serializable", "无法序列化"),
+
+    // 航司报价
+    ("airline.aa", "美国航空（现金）"),
+    ("airline.b2", "白俄罗斯航空"),
+    ("airline.od", "飞狮航空"),
+    ("airline.vj", "越捷航空"),
+    ("airline.vs", "维珍航空"),
+    ("airline.oz", "韩亚航空（里程票）"),
+    ("airline.cm_award", "巴拿马航空（里程票）"),
+    ("airline.cm_cash", "巴拿马航空（现金票）"),
+    ("airline.u6", "乌拉尔航空"),
+    ("airline.s7", "西伯利亚航空"),
+    ("airline.ia", "伊拉克航空"),
+    ("airline.fv", "俄罗斯国际航空"),
+    ("airline.n4", "北风航空"),
+    ("airline.bi", "皇雀航空"),
+    ("airline.mh", "马来西亚国际航空"),
+    ("airline.5j", "宿务航空"),
+    ("airline.load_groups_failed", "读取代理分组失败"),
+    ("airline.not_tested", "尚未测试连接"),
+    ("airline.title", "航司报价查询控制台"),
+    ("airline.subtitle", "管理代理配置、Token 与航司二字码，快速测试各航司 API 报价参数。"),
+    ("airline.settings", "配置参数"),
+    ("airline.settings_desc", "选择代理分组和配置认证凭证"),
+    ("airline.proxy_group", "代理分组"),
+    ("airline.no_proxies", "⚠️ 暂无代理"),
+    ("airline.group_option", "{0} ({1} 个)"),
+    ("airline.token", "认证 Token"),
+    ("airline.token_placeholder", "请输入访问 Token"),
+    ("airline.check_token", "校验 Token"),
+    ("airline.fare_params", "航司报价参数"),
+    ("airline.fare_params_desc", "选择航司二字码，检查参数模版立即切换。"),
+    ("airline.sending", "正在发送查询请求..."),
+    ("airline.sent", "✓ 查询请求已发送\n\n随机选择的代理：{0}:{1}\n\n完整请求数据：\n{2}"),
+    ("airline.querying", "查询中..."),
+    ("airline.current", "当前航司"),
+    ("airline.not_configured", "未配置"),
+    ("airline.configured", "已配置"),
+    ("airline.not_selected", "未选择"),
+    ("airline.request_preview", "完整请求数据（实时更新）"),
+    ("airline.result", "查询结果"),
+    ("airline.result_desc", "以下是生成的完整请求数据，可直接用于 API 调用"),
+    ("airline.backend_checking", "检查中"),
+    ("airline.backend_running", "运行中"),
+    ("airline.backend_offline", "离线"),
+    ("airline.port", "端口:"),
+
+    // 日志
+    ("logs.load_presets_failed", "读取筛选预设失败"),
+    ("logs.bundle_saved", "诊断包已保存到 {0}（配置已脱敏）"),
+    ("logs.save_query_failed", "保存查询条件失败"),
+    ("logs.title", "📋 日志查看器"),
+    ("logs.subtitle", "查看和筛选任务执行日志，支持按任务名称、UUID、级别、关键词和时间范围过滤，常用条件可保存为预设"),
+    ("logs.filters", "🔍 筛选条件"),
+    ("logs.task_name", "任务名称 "),
+    ("logs.choose_task", "-- 请选择任务名称 --"),
+    ("logs.task_uuid", "任务 UUID"),
+    ("logs.all_uuids", "-- 全部 UUID --"),
+    ("logs.keyword", "关键词搜索"),
+    ("logs.keyword_placeholder", "搜索日志内容..."),
+    ("logs.levels", "日志级别："),
+    ("logs.all_levels", "全部级别"),
+    ("logs.time_range", "时间范围："),
+    ("logs.since_hint", "起始时间（按显示时区）"),
+    ("logs.to", "至"),
+    ("logs.until_hint", "截止时间（按显示时区），留空表示到现在"),
+    ("logs.presets", "筛选预设："),
+    ("logs.choose_preset", "-- 选择预设 --"),
+    ("logs.preset_deleted", "筛选预设已删除"),
+    ("logs.delete_preset_failed", "删除筛选预设失败"),
+    ("logs.delete_preset", "删除预设"),
+    ("logs.preset_name", "预设名称"),
+    ("logs.save_preset_hint", "保存当前筛选条件，快捷时间范围保存为相对范围"),
+    ("logs.preset_saved", "筛选预设已保存"),
+    ("logs.save_preset_failed", "保存筛选预设失败"),
+    ("logs.save_preset", "💾 保存为预设"),
+    ("logs.sort", "时间排序："),
+    ("logs.newest_first", "⬇ 最新在前"),
+    ("logs.oldest_first", "⬆ 最旧在前"),
+    ("logs.display_zone", "显示时区："),
+    ("logs.save_zone_failed", "保存显示时区失败"),
+    ("logs.bundle_limit_hint", "诊断包中包含的最近日志条数"),
+    ("logs.bundle_recent", "最近 {0} 条"),
+    ("logs.bundle_hint", "打包所选任务 / UUID 的日志和脱敏后的配置，反馈问题时附上"),
+    ("logs.create_bundle", "🧰 生成诊断包"),
+    ("logs.query", "🔍 查询日志"),
+    ("logs.column_task", "任务 ID"),
+    ("logs.column_level", "级别"),
+    ("logs.column_time", "时间"),
+    ("logs.column_message", "日志内容"),
+    ("logs.column_actions", "操作"),
+    ("logs.selection_copied", "已复制所选日志"),
+    ("logs.copy_selected", "📋 复制所选"),
+    ("logs.clear_selection", "取消选择"),
+    ("logs.empty", "暂无日志数据"),
+    ("logs.empty_hint", "当前筛选条件下没有找到日志记录"),
+    ("logs.no_task", "请选择任务名称"),
+    ("logs.no_task_hint", "任务名称是必填项，请先选择一个任务名称开始查询"),
+    ("logs.timeline_hint", "{0}\n点击查看该次运行的时间线"),
+
+    // 配置管理
+    ("config.tab_proxy", "代理配置"),
+    ("config.tab_otp", "OTP邮箱"),
+    ("config.tab_card", "支付卡片"),
+    ("config.tab_passenger", "购票人信息"),
+    ("config.tab_intercept", "请求拦截"),
+    ("config.tab_security", "安全与隐私"),
+    ("config.tab_storage", "数据存储"),
+    ("config.load_failed", "读取配置失败"),
+    ("config.load_failed_desc", "为避免覆盖已保存的数据，读取成功前不能编辑或保存这部分配置"),
+    ("config.retry", "重试"),
+    ("config.purge_failed", "清除过期记录失败"),
+    ("config.purged", "已自动清除 {0} 条过期记录"),
+    ("config.default_group", "默认分组"),
+    ("config.title", "配置管理中心"),
+    ("config.subtitle", "管理代理配置、OTP邮箱服务、支付卡片和购票人信息（支持分组批量保存）"),
+    ("config.quarantined", "⚠️ 部分配置无法解析，已隔离备份"),
+    ("config.quarantined_desc", "原数据未被覆盖，当前显示的是默认分组。失败原因已记录到日志（任务名 Config），确认不再需要后可删除备份。"),
+    ("config.view", "查看"),
+    ("config.backup_deleted", "备份已删除"),
+    ("config.delete_backup_failed", "删除备份失败"),
+    ("config.delete_backup", "删除备份"),
+    ("config.proxy_groups", "代理配置分组"),
+    ("config.proxy_groups_desc", "每个分组可以保存多个代理配置"),
+    ("config.group_name_default", "分组 {0}"),
+    ("config.new_group", "+ 新建分组"),
+    ("config.group_name", "分组名称"),
+    ("config.bulk_import", "📋 批量导入"),
+    ("config.delete_group", "删除分组"),
+    ("config.column_ip", "IP 地址"),
+    ("config.column_port", "端口"),
+    ("config.column_username", "用户名"),
+    ("config.column_password", "密码"),
+    ("config.delete_selected", "🗑 删除所选"),
+    ("config.no_proxies", "暂无代理配置，点击「批量导入」开始配置"),
+    ("config.proxy_groups_saved", "代理分组已保存"),
+    ("config.save_proxy_groups_failed", "保存代理分组失败"),
+    ("config.save_groups", "💾 保存分组配置"),
+    ("config.optional", "可选"),
+    ("config.delete", "删除"),
+    ("config.import_proxies", "批量导入代理"),
+    ("config.import_format", "支持格式：ip:port:username:password 或 ip:port（每行一个）"),
+    ("config.cancel", "取消"),
+    ("config.import", "导入"),
+    ("config.otp_groups", "OTP 邮箱服务分组"),
+    ("config.otp_groups_desc", "每个分组可以保存多个 OTP 邮箱配置"),
+    ("config.otp_name_default", "配置 {0}"),
+    ("config.add_otp", "+ 添加配置"),
+    ("config.column_name", "配置名称"),
+    ("config.column_email", "邮箱地址"),
+    ("config.column_provider", "服务商"),
+    ("config.no_otp", "暂无 OTP 配置，点击「添加配置」开始配置"),
+    ("config.otp_groups_saved", "OTP 分组已保存"),
+    ("config.save_otp_groups_failed", "保存OTP 分组失败"),
+    ("config.card_groups", "支付卡片分组"),
+    ("config.card_groups_desc", "每个分组可以保存多张卡片配置"),
+    ("config.add_card", "+ 添加卡片"),
+    ("config.records_range", "共 {0} 条记录，第 {1}-{2} 条"),
+    ("config.no_cards", "暂无卡片配置，点击「添加卡片」开始配置"),
+    ("config.card_groups_saved", "卡片分组已保存"),
+    ("config.save_card_groups_failed", "保存卡片分组失败"),
+    ("config.card_title", "卡片 #{0}"),
+    ("config.card_type", "卡片类型"),
+    ("config.credit_card", "信用卡"),
+    ("config.gift_card", "礼品卡"),
+    ("config.card_number", "卡号"),
+    ("config.card_expiry", "有效期"),
+    ("config.card_holder", "持卡人姓名"),
+    ("config.record_expiry", "记录过期日期（到期后自动删除）"),
+    ("config.passenger_groups", "购票人信息分组"),
+    ("config.passenger_groups_desc", "每个分组可以保存多个购票人信息"),
+    ("config.add_passenger", "+ 添加购票人"),
+    ("config.no_passengers", "暂无购票人信息，点击「添加购票人」开始配置"),
+    ("config.passenger_groups_saved", "购票人分组已保存"),
+    ("config.save_passenger_groups_failed", "保存购票人分组失败"),
+    ("config.passenger_title", "购票人 #{0}"),
+    ("config.first_name", "名（First Name）"),
+    ("config.last_name", "姓（Last Name）"),
+    ("config.email", "邮箱"),
+    ("config.phone", "电话"),
+    ("config.passport", "护照号码"),
+    ("config.new_passphrase", "新口令"),
+    ("config.app_lock", "应用锁"),
+    ("config.app_lock_enabled", "已启用：启动时需要输入口令，空闲或最小化后自动锁定"),
+    ("config.app_lock_disabled", "未启用：设置口令后，启动时需要输入口令才能访问配置和日志"),
+    ("config.current_passphrase", "当前口令"),
+    ("config.passphrase_min", "至少 6 位"),
+    ("config.confirm_passphrase", "确认口令"),
+    ("config.app_lock_disabled_done", "应用锁已关闭"),
+    ("config.disable_app_lock", "关闭应用锁"),
+    ("config.passphrase_mismatch", "两次输入的口令不一致"),
+    ("config.passphrase_saved", "口令已保存"),
+    ("config.change_passphrase", "修改口令"),
+    ("config.enable_app_lock", "启用应用锁"),
+    ("config.idle_lock", "空闲自动锁定："),
+    ("config.never", "从不"),
+    ("config.lock_on_minimize", "最小化或隐藏到托盘时锁定"),
+    ("config.lock_settings_saved", "锁定设置已保存"),
+    ("config.window", "窗口"),
+    ("config.window_desc", "窗口的位置、大小和最大化状态在关闭时自动保存，下次启动时恢复"),
+    ("config.tray_enabled", "显示托盘图标，关闭窗口时隐藏到托盘"),
+    ("config.tray_note", "重启 FactBot 后生效；隐藏后可以从托盘菜单退出"),
+    ("config.tray_saved", "托盘设置已保存，重启后生效"),
+    ("config.tray_save_failed", "保存托盘设置失败"),
+    ("config.save_settings", "保存设置"),
+    ("config.passphrase_note", "口令以 Argon2 哈希保存在 config.db 中，最短 {0} 位；忘记口令需删除 config.db 中的 app_lock_hash 记录"),
+    ("config.load_rules_failed", "读取拦截规则失败"),
+    ("config.intercept_desc", "规则从上到下检查，第一条命中的规则决定放行或拦截；都没有命中时按模式处理。方法留空表示所有请求方法。"),
+    ("config.mode", "模式："),
+    ("config.no_rules", "暂无规则"),
+    ("config.rule_enabled", "启用"),
+    ("config.rule_action", "动作"),
+    ("config.rule_kind", "匹配方式"),
+    ("config.rule_pattern", "模式"),
+    ("config.rule_methods", "方法"),
+    ("config.add_rule", "+ 添加规则"),
+    ("config.save_rules_failed", "保存拦截规则失败"),
+    ("config.rules_saved", "拦截规则已保存，新建的任务生效"),
+    ("config.save_rules", "保存规则"),
+    ("config.test_label", "测试："),
+    ("config.rule_hit", "命中规则 #{0}：{1}"),
+    ("config.no_rule_hit", "没有规则命中，{0}"),
+    ("config.test", "测试"),
+    ("config.test_allowed", "✅ 放行 — {0}"),
+    ("config.test_blocked", "⛔ 拦截 — {0}"),
+    ("config.pattern_help", "域名及子域名：example.com 匹配 a.example.com，不匹配 notexample.com；URL 通配符匹配完整 URL，* 为任意字符，? 为单个字符；URL 正则为部分匹配。"),
+    ("config.all_methods", "全部"),
+    ("config.wipe", "个人数据清除"),
+    ("config.wipe_desc", "删除全部购票人、支付卡片和 OTP 邮箱配置及配置数据库的自动备份，并整理数据库确保数据不会残留。设置了过期日期的记录会在到期后自动删除。"),
+    ("config.wipe_button", "🗑 清除全部个人数据"),
+    ("config.wipe_confirm_title", "确认清除个人数据"),
+    ("config.wipe_confirm_desc", "此操作会永久删除所有购票人、支付卡片和 OTP 邮箱配置，无法恢复。请输入「{0}」确认。"),
+    ("config.wiped", "个人数据已清除"),
+    ("config.wipe_failed", "清除失败: {0}"),
+    ("config.wipe_confirm", "永久删除"),
+    ("config.wipe_confirm_text", "确认删除"),
+    ("config.data_moved", "数据已迁移"),
+    ("config.move_failed", "迁移数据失败"),
+    ("config.no_data_dir", "无法确定数据目录"),
+    ("config.data_dir", "数据目录"),
+    ("config.data_dir_desc", "配置数据库和日志数据库的存放位置（当前来源：{0}）"),
+    ("config.log_db_fallback", "程序所在目录不可写，日志数据库已改为保存在用户数据目录。"),
+    ("config.data_dir_override", "数据目录由{0}指定，如需修改请调整后重新启动。"),
+    ("config.new_data_dir", "新的数据目录"),
+    ("config.data_dir_example", "例如 D:\\FactBotData"),
+    ("config.move_desc", "迁移会把 config.db、logs.db 和配置备份复制到新目录并校验，成功后删除原文件；目标目录中已有数据库时不会覆盖。"),
+    ("config.move_back", "迁回默认位置"),
+    ("config.move", "迁移数据"),
+    ("config.export_failed", "导出设置失败"),
+    ("config.exported", "已导出到 {0}（{1}）"),
+    ("config.read_backup_failed", "读取备份失败"),
+    ("config.imported", "设置已导入"),
+    ("config.import_failed", "导入设置失败"),
+    ("config.backup", "备份与恢复"),
+    ("config.backup_desc", "把代理、OTP、卡片、购票人分组和应用设置导出为一个加密文件，用于迁移到新电脑或灾难恢复。Web API 令牌和应用锁口令不会导出，导入后需在新电脑上重新设置。备份包含个人数据，请妥善保管文件和口令。"),
+    ("config.export", "导出"),
+    ("config.save_to", "保存到"),
+    ("config.backup_path", "备份文件路径"),
+    ("config.backup_passphrase", "备份口令"),
+    ("config.backup_passphrase_min", "至少 6 个字符"),
+    ("config.backup_passphrase_again", "再次输入备份口令"),
+    ("config.export_backup", "📤 导出备份"),
+    ("config.backup_file", "备份文件"),
+    ("config.backup_file_example", "例如 D:\\FactBot-settings-20240501.fbak"),
+    ("config.backup_passphrase_hint", "导出时设置的口令"),
+    ("config.preview_backup", "🔍 读取并预览"),
+    ("config.backup_contents", "备份创建于 {0}，包含 {1}。请为每部分选择导入方式："),
+    ("config.import_note", "导入前的分组会记录到各分组的历史版本中，可随时恢复。应用锁口令等设置在重启后生效。"),
+    ("config.import_backup", "📥 导入"),
+    ("config.history_failed", "读取历史失败: {0}"),
+    ("config.history_expanded", "▾ 📜 历史版本"),
+    ("config.history_collapsed", "▸ 📜 历史版本"),
+    ("config.refresh", "刷新"),
+    ("config.no_history", "暂无历史版本，每次保存分组配置时会自动记录修改前的内容"),
+    ("config.restored", "已恢复到所选版本"),
+    ("config.restore_failed", "恢复失败: {0}"),
+    ("config.restore", "恢复"),
+    ("config.hide", "隐藏"),
+    ("config.reveal", "显示 {0} 秒"),
+    ("config.copy_secret", "复制（{0} 秒后清空剪贴板）"),
+    ("config.minutes_option", "{0} 分钟"),
+
+    // 通用标签
+    ("time_range.all", "全部时间"),
+    ("time_range.15m", "最近 15 分钟"),
+    ("time_range.today", "今天"),
+    ("time_range.7d", "最近 7 天"),
+    ("time_range.custom", "自定义"),
+    ("zone.local", "本地时间 (UTC{0})"),
+    ("duration.seconds", "{0} 秒"),
+    ("duration.minutes", "{0} 分 {1} 秒"),
+    ("duration.hours", "{0} 小时 {1} 分"),
+    ("intercept.blocklist", "黑名单（默认放行）"),
+    ("intercept.allowlist", "白名单（默认拦截）"),
+    ("intercept.block", "拦截"),
+    ("intercept.allow", "放行"),
+    ("intercept.host", "主机名"),
+    ("intercept.domain_suffix", "域名及子域名"),
+    ("intercept.wildcard", "URL 通配符"),
+    ("intercept.regex", "URL 正则"),
+    ("intercept.keyword", "URL 关键词"),
+    ("archive.proxy_groups", "代理分组"),
+    ("archive.otp_groups", "OTP 分组"),
+    ("archive.card_groups", "卡片分组"),
+    ("archive.passenger_groups", "购票人分组"),
+    ("archive.app_settings", "应用设置"),
+    ("archive.skip", "跳过"),
+    ("archive.merge", "合并"),
+    ("archive.replace", "替换"),
+    ("archive.diff_unchanged", "保持不变"),
+    ("archive.diff_added", "新增 {0}"),
+    ("archive.diff_updated", "覆盖 {0}"),
+    ("archive.diff_removed", "删除 {0}"),
+    ("archive.diff_none", "无变化"),
+    ("archive.diff_item_separator", "、"),
+    ("archive.diff_part_separator", "；"),
+    ("db.config", "配置数据库 (config.db)"),
+    ("db.logs", "日志数据库 (logs.db)"),
+    ("db.locked", "数据库被其他程序占用"),
+    ("db.read_only", "数据库所在位置不可写"),
+    ("db.corrupt", "数据库文件已损坏：{0}"),
+    ("db.other", "无法打开数据库：{0}"),
+    ("db.remedy_locked", "请关闭其他正在运行的 FactBot 窗口，以及可能打开了该文件的数据库工具，然后重新检查。"),
+    ("db.remedy_read_only", "请检查文件和所在目录的写入权限，或把程序移动到可写的目录后重新启动。"),
+    ("db.remedy_corrupt_config", "可以从最近一次启动时的自动备份恢复配置，损坏的文件会被保留以便排查。"),
+    ("db.remedy_corrupt_logs", "可以重建一个空的日志数据库，损坏的文件会被重命名保留。"),
+    ("db.remedy_other", "请确认磁盘空间充足、路径存在且有访问权限后重新检查。"),
+    ("data_dir.cli_flag", "命令行参数 --data-dir"),
+    ("data_dir.env", "环境变量 FACTBOT_DATA_DIR"),
+    ("data_dir.setting", "配置页设置"),
+    ("data_dir.default", "默认位置"),
+    ("history.replaced", "整体替换"),
+    ("history.unchanged", "无变化"),
+    ("history.groups_added", "新增分组 {0}"),
+    ("history.groups_removed", "删除分组 {0}"),
+    ("history.groups_renamed", "重命名分组 {0}"),
+    ("history.records_added", "新增记录 {0}"),
+    ("history.records_removed", "删除记录 {0}"),
+    ("history.records_modified", "修改记录 {0}"),
+    ("history.separator", "，"),
+
+    // 错误提示
+    ("error.blocked", "请求被拦截规则阻止"),
+    ("error.no_proxy", "代理池中没有可用的代理，请先在配置管理中添加"),
+    ("error.request_failed", "网络请求失败，请检查网络或代理设置"),
+    ("error.invalid_config", "配置有误：{0}"),
+    ("error.cookie", "Cookie 格式无法识别"),
+    ("error.task_stopped", "目标服务器连续出错，任务已停止"),
+    ("error.database", "数据库出错：{0}"),
+    ("error.config_not_found", "找不到配置：{0}"),
+    ("error.config_version", "配置由更新版本的 FactBot 保存，当前版本无法读取"),
+    ("error.config_quarantined", "配置无法解析，原数据已备份到 {0}"),
+    ("error.backup_decrypt", "口令错误或备份文件已损坏"),
+    ("error.unsupported_backup", "无法读取备份文件：{0}"),
+    ("error.log_write", "日志写入失败"),
+    ("error.log_level", "无效的日志级别：{0}"),
+    ("error.io_permission", "没有访问文件的权限"),
+    ("error.io_not_found", "文件或目录不存在"),
+    ("error.io", "读写文件失败"),
+    ("error.json", "数据格式无法解析"),
+    ("error.url", "网址格式不正确"),
+    ("error.db_busy", "数据库正被占用，请稍后重试"),
+    ("error.disk_full", "磁盘空间不足，数据未能保存"),
+    ("error.db_permission", "没有写入数据库的权限"),
+    ("error.db_corrupt", "数据库文件已损坏"),
+    ("error.db", "数据库操作失败"),
+    ("error.zip", "压缩包写入失败"),
+    ("error.unknown", "发生未知错误"),
+    ("error.preset_name_empty", "预设名称不能为空"),
+    ("error.passphrase_too_short", "口令长度不能少于 {0} 位"),
+    ("error.current_passphrase_wrong", "当前口令错误"),
+    ("error.intercept_rule_empty", "拦截规则为空：{0}"),
+    ("error.intercept_rule_invalid", "拦截规则无效：{0}（{1}）"),
+    ("error.rate_invalid", "每秒请求数无效：{0}"),
+    ("error.redaction_rule_invalid", "脱敏规则 `{0}` 无效：{1}"),
+    ("error.proxy_url_invalid", "代理地址无效：{0}"),
+    ("error.request_method_invalid", "无效的请求方法：{0}"),
+    ("error.fixture_version_newer", "夹具文件版本 {0} 高于支持的版本 {1}"),
+    ("error.backup_kdf_unknown", "未知的密钥派生算法 {0}"),
+    ("error.backup_kdf_too_large", "密钥派生参数超出上限（内存 {0} KiB、迭代 {1} 次、并行度 {2}）"),
+    ("error.backup_kdf_invalid", "密钥派生参数无效：{0}"),
+    ("error.backup_field_base64", "字段 {0} 不是有效的 Base64"),
+    ("error.backup_passphrase_too_short", "备份口令至少 {0} 个字符"),
+    ("error.backup_not_archive", "不是 FactBot 设置备份文件"),
+    ("error.backup_version_newer", "备份格式版本 {0} 高于当前支持的版本 {1}"),
+    ("error.backup_nonce_length", "nonce 长度错误"),
+    ("error.no_config_backup", "没有可用的配置备份"),
+    ("error.proxy_port_invalid", "代理 {0} 的端口无效：{1}"),
+    ("error.history_version", "历史版本 #{0}"),
+    ("error.no_data_dir", "无法获取应用数据目录"),
+    ("error.dir_not_writable", "目录不可写：{0}"),
+    ("error.target_db_exists", "目标位置已存在数据库：{0}"),
+    ("error.moved_db_check_failed", "迁移后的数据库校验失败（{0}）：{1}"),
+    ("error.data_dir_overridden", "数据目录由{0}指定，请修改后重新启动"),
+];
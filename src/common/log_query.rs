@@ -0,0 +1,294 @@
+//! 日志页面的查询条件
+//!
+//! - `LogQuery` 编码在路由的查询字符串中（`/logs?task=Airline&level=WARN,ERROR&range=15m`），
+//!   同一链接可以原样重新打开
+//! - 快捷时间范围（最近 15 分钟、今天、最近 7 天）保存为相对范围，查询时按当前时间计算
+//! - 命名的筛选预设保存在 config.db 中
+
+use crate::common::i18n::t;
+use crate::common::timestamp::{self, DisplayZone};
+use crate::common::{config_store, AppError, Reason, Result};
+use crate::db::{self, LogFilter};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use url::form_urlencoded;
+
+/// 筛选预设
+pub const LOG_PRESETS_CONFIG_KEY: &str = "log_filter_presets";
+/// 上次查询的条件，打开日志页面时恢复
+const LAST_QUERY_KEY: &str = "log_last_query";
+
+/// 时间范围
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimeRange {
+    #[default]
+    All,
+    Last15Minutes,
+    Today,
+    Last7Days,
+    /// 使用 `LogQuery::since` / `until`
+    Custom,
+}
+
+impl TimeRange {
+    pub const ALL: [TimeRange; 5] = [
+        TimeRange::All,
+        TimeRange::Last15Minutes,
+        TimeRange::Today,
+        TimeRange::Last7Days,
+        TimeRange::Custom,
+    ];
+
+    /// 查询字符串中的标识
+    pub fn id(&self) -> &'static str {
+        match self {
+            TimeRange::All => "all",
+            TimeRange::Last15Minutes => "15m",
+            TimeRange::Today => "today",
+            TimeRange::Last7Days => "7d",
+            TimeRange::Custom => "custom",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|range| range.id() == id)
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            TimeRange::All => t("time_range.all"),
+            TimeRange::Last15Minutes => t("time_range.15m"),
+            TimeRange::Today => t("time_range.today"),
+            TimeRange::Last7Days => t("time_range.7d"),
+            TimeRange::Custom => t("time_range.custom"),
+        }
+    }
+}
+
+/// 日志页面的筛选条件，空字符串表示不限
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LogQuery {
+    pub task_name: String,
+    pub task_uuid: String,
+    /// 选中的级别，为空时不限
+    pub levels: Vec<String>,
+    pub keyword: String,
+    pub range: TimeRange,
+    /// 自定义范围的起止时间（存储格式，UTC），其他范围时忽略
+    pub since: Option<String>,
+    pub until: Option<String>,
+}
+
+impl LogQuery {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// 起止时间：相对范围按 `now` 计算，「今天」按显示时区的 0 点
+    pub fn bounds(&self, now: DateTime<Utc>, zone: DisplayZone) -> (Option<DateTime<Utc>>, Option<DateTime<Utc>>) {
+        match self.range {
+            TimeRange::All => (None, None),
+            TimeRange::Last15Minutes => (Some(now - Duration::minutes(15)), None),
+            TimeRange::Today => (Some(zone.start_of_day(now)), None),
+            TimeRange::Last7Days => (Some(now - Duration::days(7)), None),
+            TimeRange::Custom => (
+                self.since.as_deref().and_then(timestamp::parse),
+                self.until.as_deref().and_then(timestamp::parse),
+            ),
+        }
+    }
+
+    pub fn to_filter(&self, now: DateTime<Utc>, zone: DisplayZone, include_debug: bool) -> LogFilter {
+        let non_empty = |value: &str| Some(value.to_string()).filter(|value| !value.is_empty());
+        let (since, until) = self.bounds(now, zone);
+        LogFilter {
+            task_name: non_empty(&self.task_name),
+            task_uuid: non_empty(&self.task_uuid),
+            levels: self.levels.clone(),
+            keyword: non_empty(&self.keyword),
+            since: since.map(timestamp::to_storage),
+            until: until.map(timestamp::to_storage),
+            include_debug,
+            ascending: false,
+        }
+    }
+
+    /// 读取上次查询的条件
+    pub fn load_last() -> Self {
+        db::load_config(LAST_QUERY_KEY)
+            .ok()
+            .flatten()
+            .and_then(|raw| serde_json::from_str(&raw).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save_last(&self) -> Result<()> {
+        db::save_config(LAST_QUERY_KEY, &serde_json::to_string(self)?)
+    }
+}
+
+/// 编码为路由的查询字符串（不含 `?`），空条件为空字符串
+///
+/// 路由解析时会先对整个查询字符串做一次百分号解码再交给 `From<&str>`，写出时却不转义 `%`，
+/// 因此表单编码后的 `%` 需要再转义一次，否则值中的 `&`、`=` 会被当作分隔符
+impl fmt::Display for LogQuery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut query = form_urlencoded::Serializer::new(String::new());
+        let mut pair = |key: &str, value: &str| {
+            if !value.is_empty() {
+                query.append_pair(key, value);
+            }
+        };
+        pair("task", &self.task_name);
+        pair("uuid", &self.task_uuid);
+        pair("level", &self.levels.join(","));
+        pair("q", &self.keyword);
+        if self.range != TimeRange::All {
+            pair("range", self.range.id());
+        }
+        if self.range == TimeRange::Custom {
+            pair("since", self.since.as_deref().unwrap_or_default());
+            pair("until", self.until.as_deref().unwrap_or_default());
+        }
+        f.write_str(&query.finish().replace('%', "%25"))
+    }
+}
+
+/// 从路由解码后的查询字符串（表单编码）解析，无法识别的参数忽略
+impl From<&str> for LogQuery {
+    fn from(query: &str) -> Self {
+        let mut result = LogQuery::default();
+        for (key, value) in form_urlencoded::parse(query.trim_start_matches('?').as_bytes()) {
+            let value = value.into_owned();
+            match key.as_ref() {
+                "task" => result.task_name = value,
+                "uuid" => result.task_uuid = value,
+                "level" => {
+                    result.levels = value
+                        .split(',')
+                        .map(|level| level.trim().to_uppercase())
+                        .filter(|level| !level.is_empty())
+                        .collect()
+                }
+                "q" => result.keyword = value,
+                "range" => result.range = TimeRange::from_id(&value).unwrap_or_default(),
+                "since" => result.since = Some(value),
+                "until" => result.until = Some(value),
+                _ => {}
+            }
+        }
+        result
+    }
+}
+
+/// 命名的筛选预设
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LogFilterPreset {
+    pub name: String,
+    pub query: LogQuery,
+}
+
+pub fn load_presets() -> Result<Vec<LogFilterPreset>> {
+    Ok(config_store::load(LOG_PRESETS_CONFIG_KEY)?.unwrap_or_default())
+}
+
+pub fn save_presets(presets: &[LogFilterPreset]) -> Result<()> {
+    config_store::save(LOG_PRESETS_CONFIG_KEY, &presets)
+}
+
+/// 添加预设，同名时覆盖；名称不能为空
+pub fn upsert_preset(presets: &mut Vec<LogFilterPreset>, name: &str, query: LogQuery) -> Result<()> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(AppError::InvalidConfig(Reason::new("error.preset_name_empty", &[])));
+    }
+    match presets.iter_mut().find(|preset| preset.name == name) {
+        Some(preset) => preset.query = query,
+        None => presets.push(LogFilterPreset { name: name.to_string(), query }),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use chrono_tz::Tz;
+
+    #[test]
+    fn test_route_round_trip() {
+        let query = LogQuery {
+            task_name: "Airline 抢票".to_string(),
+            task_uuid: "abc-123".to_string(),
+            levels: vec!["WARN".to_string(), "ERROR".to_string()],
+            keyword: "a&b=c".to_string(),
+            range: TimeRange::Custom,
+            since: Some("2024-05-01T00:00:00.000Z".to_string()),
+            until: None,
+        };
+        let encoded = query.to_string();
+        assert!(encoded.contains("level=WARN%252CERROR"));
+        // 路由解析时先做一次百分号解码
+        let decoded = encoded.replace("%25", "%");
+        assert_eq!(LogQuery::from(decoded.as_str()), query);
+        assert_eq!(LogQuery::from(format!("?{}", decoded).as_str()), query);
+
+        assert_eq!(LogQuery::default().to_string(), "");
+        assert!(LogQuery::from("").is_empty());
+        // 相对范围不带起止时间，未知参数忽略
+        let relative = LogQuery::from("range=7d&since=2024-01-01T00:00:00.000Z&bogus=1&level=warn,");
+        assert_eq!(relative.range, TimeRange::Last7Days);
+        assert_eq!(relative.levels, vec!["WARN"]);
+        assert_eq!(relative.to_string(), "level=WARN&range=7d");
+    }
+
+    #[test]
+    fn test_time_bounds_and_filter() {
+        let now = Utc.with_ymd_and_hms(2024, 7, 1, 18, 30, 0).unwrap();
+        let zone = DisplayZone::Named(Tz::Asia__Shanghai);
+        let mut query = LogQuery { task_name: "Airline".to_string(), ..LogQuery::default() };
+
+        assert_eq!(query.bounds(now, zone), (None, None));
+        query.range = TimeRange::Last15Minutes;
+        assert_eq!(query.bounds(now, zone).0, Some(now - Duration::minutes(15)));
+        query.range = TimeRange::Today;
+        assert_eq!(query.bounds(now, zone).0, Some(Utc.with_ymd_and_hms(2024, 7, 1, 16, 0, 0).unwrap()));
+
+        query.range = TimeRange::Custom;
+        query.since = Some("2024-07-01T10:00:00.000Z".to_string());
+        query.until = Some("2024-07-01T11:00:00.000Z".to_string());
+        query.levels = vec!["ERROR".to_string(), "WARN".to_string()];
+        let filter = query.to_filter(now, zone, false);
+
+        let log = |level: &str, timestamp: &str| db::LogEntry {
+            id: None,
+            task_name: "Airline".to_string(),
+            log_level: level.to_string(),
+            task_uuid: "u1".to_string(),
+            timestamp: timestamp.to_string(),
+            message: "m".to_string(),
+        };
+        assert!(filter.matches(&log("ERROR", "2024-07-01T10:00:00.000Z")));
+        assert!(filter.matches(&log("WARN", "2024-07-01T10:59:59.999Z")));
+        assert!(!filter.matches(&log("INFO", "2024-07-01T10:30:00.000Z")));
+        assert!(!filter.matches(&log("ERROR", "2024-07-01T11:00:00.000Z")));
+        assert!(!filter.matches(&log("ERROR", "2024-07-01T09:59:59.999Z")));
+    }
+
+    #[test]
+    fn test_upsert_preset() {
+        let mut presets = Vec::new();
+        let errors = LogQuery { levels: vec!["ERROR".to_string()], ..LogQuery::default() };
+        upsert_preset(&mut presets, " 错误 ", errors.clone()).unwrap();
+        upsert_preset(&mut presets, "最近", LogQuery { range: TimeRange::Last15Minutes, ..LogQuery::default() }).unwrap();
+        upsert_preset(&mut presets, "错误", LogQuery::default()).unwrap();
+
+        assert_eq!(presets.len(), 2);
+        assert_eq!(presets[0].name, "错误");
+        assert!(presets[0].query.is_empty());
+        assert!(upsert_preset(&mut presets, "  ", errors).is_err());
+    }
+}
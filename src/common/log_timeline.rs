@@ -0,0 +1,290 @@
+//! 单个任务运行（task UUID）的日志时间线
+//!
+//! 日志按时间排列，连续的请求日志（拦截检查、请求详情、请求体、Cookie、重试、完成 / 失败）合并为一组，
+//! 相邻两组之间记录间隔，便于看出等待和卡顿的位置。
+
+use crate::common::i18n::t_args;
+use crate::common::timestamp;
+use crate::db::LogEntry;
+use chrono::{DateTime, Duration, Utc};
+
+/// 请求开始的日志前缀（DEBUG 级别，正式版不展示时分组只有结束行）
+const REQUEST_START_PREFIXES: &[&str] = &["Request passed interceptor check: ", "Request: "];
+
+/// 请求结束的日志前缀（会重试的连接失败记为 `连接失败，…后第 n 次重试`，不结束请求）
+const REQUEST_END_PREFIXES: &[&str] = &[
+    "请求完成: ",
+    "请求失败: ",
+    "Request blocked by interceptor: ",
+    "任务已停止，拒绝请求: ",
+];
+
+/// 时间线上的一项：一条普通日志或一组请求日志
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimelineItem {
+    pub entries: Vec<LogEntry>,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    /// 与上一项结束之间的间隔，第一项为 0
+    pub gap_before: Duration,
+    /// 请求的 `方法 URL`，普通日志为 None
+    pub request: Option<String>,
+    /// 请求结果：完成时为状态码，失败 / 拦截时为结束行的说明
+    pub outcome: Option<String>,
+}
+
+impl TimelineItem {
+    pub fn duration(&self) -> Duration {
+        self.end - self.start
+    }
+
+    /// 组内最严重的级别（WARN / ERROR 高亮）
+    pub fn level(&self) -> &str {
+        self.entries
+            .iter()
+            .map(|entry| entry.log_level.as_str())
+            .max_by_key(|level| severity(level))
+            .unwrap_or("INFO")
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Timeline {
+    pub items: Vec<TimelineItem>,
+    pub warnings: usize,
+    pub errors: usize,
+}
+
+impl Timeline {
+    /// 按时间顺序构建时间线，`logs` 的顺序不限；无法解析的时间戳沿用上一条的时间
+    pub fn build(mut logs: Vec<LogEntry>) -> Self {
+        logs.sort_by(|a, b| (&a.timestamp, a.id).cmp(&(&b.timestamp, b.id)));
+
+        let mut timeline = Timeline::default();
+        let mut open: Option<TimelineItem> = None;
+        let mut last_time: Option<DateTime<Utc>> = None;
+
+        for entry in logs {
+            match entry.log_level.as_str() {
+                "WARN" => timeline.warnings += 1,
+                "ERROR" => timeline.errors += 1,
+                _ => {}
+            }
+            let Some(time) = timestamp::parse(&entry.timestamp).or(last_time) else {
+                continue;
+            };
+            last_time = Some(time);
+
+            let target = request_target(&entry.message, REQUEST_START_PREFIXES);
+            let end_target = request_target(&entry.message, REQUEST_END_PREFIXES);
+
+            // 新的请求开始，或结束行属于另一个请求时，先结束当前分组
+            let same_request = |item: &TimelineItem, target: &Option<String>| target.is_none() || item.request == *target;
+            if let Some(item) = open.take_if(|item| !same_request(item, &target) || !same_request(item, &end_target)) {
+                timeline.push(item);
+            }
+
+            let outcome = end_target.as_ref().map(|_| request_outcome(&entry.message));
+            let mut item = match open.take() {
+                Some(mut item) => {
+                    item.end = time;
+                    item.entries.push(entry);
+                    item
+                }
+                None => TimelineItem {
+                    entries: vec![entry],
+                    start: time,
+                    end: time,
+                    gap_before: Duration::zero(),
+                    request: target.or(end_target),
+                    outcome: None,
+                },
+            };
+
+            // 普通日志和请求结束行立即结束当前项，请求中间的日志继续并入
+            if outcome.is_some() {
+                item.outcome = outcome;
+                timeline.push(item);
+            } else if item.request.is_none() {
+                timeline.push(item);
+            } else {
+                open = Some(item);
+            }
+        }
+        if let Some(item) = open {
+            timeline.push(item);
+        }
+        timeline
+    }
+
+    fn push(&mut self, mut item: TimelineItem) {
+        if let Some(previous) = self.items.last() {
+            item.gap_before = (item.start - previous.end).max(Duration::zero());
+        }
+        self.items.push(item);
+    }
+
+    pub fn start(&self) -> Option<DateTime<Utc>> {
+        self.items.first().map(|item| item.start)
+    }
+
+    pub fn end(&self) -> Option<DateTime<Utc>> {
+        self.items.last().map(|item| item.end)
+    }
+
+    /// 第一条到最后一条日志的总时长
+    pub fn duration(&self) -> Duration {
+        match (self.start(), self.end()) {
+            (Some(start), Some(end)) => end - start,
+            _ => Duration::zero(),
+        }
+    }
+
+    pub fn request_count(&self) -> usize {
+        self.items.iter().filter(|item| item.request.is_some()).count()
+    }
+}
+
+fn severity(level: &str) -> u8 {
+    match level {
+        "ERROR" => 4,
+        "WARN" => 3,
+        "SUCCESS" => 2,
+        "INFO" => 1,
+        _ => 0,
+    }
+}
+
+/// 匹配前缀时返回 `方法 URL`
+fn request_target(message: &str, prefixes: &[&str]) -> Option<String> {
+    let rest = prefixes.iter().find_map(|prefix| message.strip_prefix(prefix))?;
+    let mut parts = rest.split_whitespace();
+    let method = parts.next()?;
+    let url = parts.next()?;
+    Some(format!("{} {}", method, url))
+}
+
+/// 结束行 ` | ` 之后的部分（状态码或错误说明），没有时为整行前缀
+fn request_outcome(message: &str) -> String {
+    match message.split_once(" | ") {
+        Some((_, outcome)) => outcome.to_string(),
+        None => message.split(": ").next().unwrap_or(message).to_string(),
+    }
+}
+
+/// 简短的时长文字（按界面语言）：`850 ms`、`3.2 秒`、`2 分 5 秒`、`1 小时 3 分`
+pub fn format_duration(duration: Duration) -> String {
+    let millis = duration.num_milliseconds().max(0);
+    match millis {
+        0..=999 => format!("{} ms", millis),
+        1_000..=59_999 => t_args("duration.seconds", &[&format!("{:.1}", millis as f64 / 1000.0)]),
+        60_000..=3_599_999 => t_args("duration.minutes", &[&(millis / 60_000), &(millis % 60_000 / 1000)]),
+        _ => t_args("duration.hours", &[&(millis / 3_600_000), &(millis % 3_600_000 / 60_000)]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn log(id: i64, level: &str, timestamp: &str, message: &str) -> LogEntry {
+        LogEntry {
+            id: Some(id),
+            task_name: "Airline".to_string(),
+            log_level: level.to_string(),
+            task_uuid: "run-1".to_string(),
+            timestamp: timestamp.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_groups_request_lines() {
+        let logs = vec![
+            log(1, "INFO", "2024-05-01T10:00:00.000Z", "任务管理器初始化完成"),
+            log(2, "DEBUG", "2024-05-01T10:00:01.000Z", "Request passed interceptor check: GET https://a.com/x | 无规则命中"),
+            log(3, "DEBUG", "2024-05-01T10:00:01.010Z", "Request: GET https://a.com/x | No proxy"),
+            log(4, "WARN", "2024-05-01T10:00:01.500Z", "HTTP 503，1000 ms 后重试"),
+            log(5, "INFO", "2024-05-01T10:00:02.600Z", "请求完成: GET https://a.com/x | 200"),
+            // 正式版没有 DEBUG 行时，结束行单独成组
+            log(6, "INFO", "2024-05-01T10:00:12.600Z", "请求完成: POST https://a.com/y | 201"),
+            log(7, "WARN", "2024-05-01T10:00:13.000Z", "Request blocked by interceptor: GET https://ads.com/ | 规则 #1"),
+            log(8, "ERROR", "2024-05-01T10:00:14.000Z", "下单失败"),
+        ];
+        // 输入顺序不影响结果
+        let timeline = Timeline::build(logs.into_iter().rev().collect());
+
+        assert_eq!(timeline.items.len(), 5);
+        let request = &timeline.items[1];
+        assert_eq!(request.entries.len(), 4);
+        assert_eq!(request.request.as_deref(), Some("GET https://a.com/x"));
+        assert_eq!(request.outcome.as_deref(), Some("200"));
+        assert_eq!(request.level(), "WARN");
+        assert_eq!(request.duration(), Duration::milliseconds(1600));
+        assert_eq!(request.gap_before, Duration::seconds(1));
+
+        assert_eq!(timeline.items[2].request.as_deref(), Some("POST https://a.com/y"));
+        assert_eq!(timeline.items[2].gap_before, Duration::seconds(10));
+        assert_eq!(timeline.items[3].outcome.as_deref(), Some("规则 #1"));
+        assert_eq!(timeline.items[4].request, None);
+        assert_eq!(timeline.items[4].level(), "ERROR");
+
+        assert_eq!(timeline.request_count(), 3);
+        assert_eq!((timeline.warnings, timeline.errors), (2, 1));
+        assert_eq!(timeline.duration(), Duration::seconds(14));
+    }
+
+    #[test]
+    fn test_retried_request() {
+        let logs = vec![
+            log(1, "DEBUG", "2024-05-01T10:00:00.000Z", "Request: GET https://a.com/x | No proxy"),
+            log(2, "WARN", "2024-05-01T10:00:00.300Z", "连接失败，200 ms 后第 1 次重试: GET https://a.com/x | timeout"),
+            log(3, "INFO", "2024-05-01T10:00:00.800Z", "请求完成: GET https://a.com/x | 200"),
+            log(4, "DEBUG", "2024-05-01T10:00:01.000Z", "Request: GET https://a.com/y | No proxy"),
+            log(5, "WARN", "2024-05-01T10:00:01.300Z", "连接失败，200 ms 后第 1 次重试: GET https://a.com/y | timeout"),
+            log(6, "ERROR", "2024-05-01T10:00:01.800Z", "请求失败: GET https://a.com/y | timeout"),
+        ];
+        let timeline = Timeline::build(logs);
+
+        // 重试不结束请求，每个请求仍是一组
+        assert_eq!(timeline.items.len(), 2);
+        let retried = &timeline.items[0];
+        assert_eq!(retried.entries.len(), 3);
+        assert_eq!(retried.outcome.as_deref(), Some("200"));
+        assert_eq!(retried.level(), "WARN");
+        assert_eq!(retried.duration(), Duration::milliseconds(800));
+
+        let failed = &timeline.items[1];
+        assert_eq!(failed.entries.len(), 3);
+        assert_eq!(failed.outcome.as_deref(), Some("timeout"));
+        assert_eq!(failed.level(), "ERROR");
+        assert_eq!(timeline.request_count(), 2);
+        assert_eq!((timeline.warnings, timeline.errors), (2, 1));
+    }
+
+    #[test]
+    fn test_unfinished_request_and_bad_timestamps() {
+        let logs = vec![
+            log(1, "DEBUG", "2024-05-01T10:00:00.000Z", "Request: GET https://a.com/1 | No proxy"),
+            // 另一个请求开始时，未结束的请求单独成组
+            log(2, "DEBUG", "2024-05-01T10:00:00.000Z", "Request: GET https://a.com/2 | No proxy"),
+            log(3, "DEBUG", "garbage", "Request body: {}"),
+        ];
+        let timeline = Timeline::build(logs);
+
+        assert_eq!(timeline.items.len(), 2);
+        assert_eq!(timeline.items[0].outcome, None);
+        assert_eq!(timeline.items[1].entries.len(), 2);
+        assert_eq!(timeline.duration(), Duration::zero());
+        assert!(Timeline::build(Vec::new()).items.is_empty());
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::milliseconds(850)), "850 ms");
+        assert_eq!(format_duration(Duration::milliseconds(3_240)), "3.2 秒");
+        assert_eq!(format_duration(Duration::seconds(125)), "2 分 5 秒");
+        assert_eq!(format_duration(Duration::minutes(63)), "1 小时 3 分");
+        assert_eq!(format_duration(Duration::milliseconds(-5)), "0 ms");
+    }
+}
@@ -1,5 +1,20 @@
+pub mod api_token;
+pub mod app_lock;
+pub mod config_history;
+pub mod config_store;
+pub mod crash;
+pub mod diagnostics;
 pub mod error;
 pub mod http_task;
+pub mod i18n;
+pub mod log_query;
+pub mod log_timeline;
+pub mod privacy;
+pub mod settings_archive;
+pub mod single_instance;
+pub mod theme;
+pub mod timestamp;
+pub mod window_state;
 
 // 重新导出常用类型
-pub use error::{AppError, Result};
+pub use error::{AppError, Reason, Result};
@@ -0,0 +1,171 @@
+use crate::common::{config_store, timestamp, Result};
+use crate::db::health;
+use crate::db::repo::{self, CardGroup, ConfigGroup, PassengerGroup};
+use crate::db::{self, delete_config};
+use chrono::{Local, NaiveDate};
+use serde_json::Value;
+
+/// 含个人数据的配置项：(配置 key, 分组内记录数组字段)
+pub const PERSONAL_DATA_KEYS: &[(&str, &str)] = &[
+    ("passenger_groups", "passengers"),
+    ("card_groups", "cards"),
+    ("otp_groups", "configs"),
+];
+
+/// 记录过期日期字段（`YYYY-MM-DD`，当天仍有效，次日起清除）
+pub const EXPIRES_AT_FIELD: &str = "expires_at";
+
+const AUDIT_TASK_NAME: &str = "Privacy";
+const AUDIT_TASK_UUID: &str = "privacy-audit";
+
+/// 写入审计日志，失败不影响清理流程
+fn audit(level: &str, message: &str) {
+    let _ = db::save_log(AUDIT_TASK_NAME, level, AUDIT_TASK_UUID, &timestamp::now(), message);
+}
+
+/// 判断记录是否已过期，未设置或格式错误的日期视为永不过期
+fn is_expired(record: &Value, today: NaiveDate) -> bool {
+    record
+        .get(EXPIRES_AT_FIELD)
+        .and_then(Value::as_str)
+        .and_then(|date| NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d").ok())
+        .is_some_and(|expires_at| expires_at < today)
+}
+
+/// 从分组 JSON 中移除过期记录，返回新的 JSON 和移除数量
+///
+/// 保持原有存储版本；无法解析的 JSON 原样返回，不做任何修改
+pub fn purge_expired_in_json(json: &str, items_field: &str, today: NaiveDate) -> (String, usize) {
+    let Ok((version, mut data)) = config_store::parse_stored(json) else {
+        return (json.to_string(), 0);
+    };
+    let Some(groups) = data.as_array_mut() else {
+        return (json.to_string(), 0);
+    };
+
+    let mut removed = 0;
+    for group in groups.iter_mut() {
+        if let Some(Value::Array(items)) = group.get_mut(items_field) {
+            let before = items.len();
+            items.retain(|item| !is_expired(item, today));
+            removed += before - items.len();
+        }
+    }
+
+    if removed == 0 {
+        return (json.to_string(), 0);
+    }
+    match config_store::to_stored(version, &data) {
+        Ok(updated) => (updated, removed),
+        Err(_) => (json.to_string(), 0),
+    }
+}
+
+/// 清除某类分组中已过期的记录，返回清除数量
+fn purge_expired_groups<G: ConfigGroup>(items_field: &str, today: NaiveDate) -> Result<usize> {
+    let json = serde_json::to_string(&repo::load_groups::<G>()?)?;
+    let (updated, removed) = purge_expired_in_json(&json, items_field, today);
+    if removed > 0 {
+        repo::replace_groups(&serde_json::from_str::<Vec<G>>(&updated)?)?;
+        audit("INFO", &format!("已自动清除 {} 中 {} 条过期记录", G::CONFIG_KEY, removed));
+    }
+    Ok(removed)
+}
+
+/// 清除所有已过期的个人数据记录，返回清除数量
+pub fn purge_expired_records() -> Result<usize> {
+    let today = Local::now().date_naive();
+    // OTP 配置没有过期日期
+    let mut total = purge_expired_groups::<PassengerGroup>("passengers", today)?
+        + purge_expired_groups::<CardGroup>("cards", today)?;
+
+    // 历史版本中的过期记录同样清除
+    for (key, items_field) in PERSONAL_DATA_KEYS {
+        for entry in db::get_config_history(key)? {
+            let (updated, removed) = purge_expired_in_json(&entry.value, items_field, today);
+            if removed > 0 {
+                db::update_config_history_value(entry.id, &updated)?;
+                total += removed;
+            }
+        }
+    }
+
+    if total > 0 {
+        db::vacuum_config_db()?;
+    }
+    Ok(total)
+}
+
+/// 清除全部个人数据（购票人、卡片、OTP 配置及其历史版本、隔离备份），并 VACUUM 配置数据库
+///
+/// config.db 的自动备份是完整副本，同样含有个人数据，一并删除（下次启动时重新生成）
+pub fn wipe_personal_data() -> Result<()> {
+    for (key, _) in PERSONAL_DATA_KEYS {
+        repo::delete_kind(key)?;
+        // 尚未迁移的旧格式数据
+        delete_config(key)?;
+        db::delete_config_history(key)?;
+        for backup_key in config_store::quarantined_keys(key)? {
+            delete_config(&backup_key)?;
+        }
+    }
+    db::vacuum_config_db()?;
+    let removed_backups = health::delete_config_backups()?;
+
+    let keys = PERSONAL_DATA_KEYS
+        .iter()
+        .map(|(key, _)| *key)
+        .collect::<Vec<_>>()
+        .join(", ");
+    audit("WARN", &format!("用户执行了个人数据清除：{}，删除配置备份 {} 份", keys, removed_backups));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 6, 15).unwrap()
+    }
+
+    #[test]
+    fn test_purge_expired_records() {
+        let json = r#"[{"id":1,"name":"默认分组","passengers":[
+            {"id":1,"passport":"E1","expires_at":"2024-06-14"},
+            {"id":2,"passport":"E2","expires_at":"2024-06-15"},
+            {"id":3,"passport":"E3"}
+        ]}]"#;
+
+        let (updated, removed) = purge_expired_in_json(json, "passengers", today());
+        assert_eq!(removed, 1);
+
+        let groups: Vec<Value> = serde_json::from_str(&updated).unwrap();
+        let ids: Vec<i64> = groups[0]["passengers"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|p| p["id"].as_i64().unwrap())
+            .collect();
+        assert_eq!(ids, vec![2, 3]);
+    }
+
+    #[test]
+    fn test_purge_ignores_invalid_dates_and_json() {
+        let json = r#"[{"id":1,"cards":[{"id":1,"expires_at":"not-a-date"},{"id":2,"expires_at":""}]}]"#;
+        assert_eq!(purge_expired_in_json(json, "cards", today()), (json.to_string(), 0));
+
+        assert_eq!(purge_expired_in_json("{broken", "cards", today()), ("{broken".to_string(), 0));
+    }
+
+    #[test]
+    fn test_purge_keeps_envelope() {
+        let json = r#"{"version":2,"data":[{"id":1,"cards":[{"id":1,"expires_at":"2024-01-01"}]}]}"#;
+
+        let (updated, removed) = purge_expired_in_json(json, "cards", today());
+        assert_eq!(removed, 1);
+        let (version, data) = config_store::parse_stored(&updated).unwrap();
+        assert_eq!(version, 2);
+        assert_eq!(data[0]["cards"].as_array().unwrap().len(), 0);
+    }
+}
@@ -0,0 +1,714 @@
+//! 设置备份文件（导出 / 导入全部配置）
+//!
+//! 备份文件是一个 JSON 外壳，记录格式版本和密钥派生参数，正文为加密后的 `SettingsSnapshot`：
+//! 口令经 Argon2id 派生 256 位密钥，正文用 ChaCha20-Poly1305 加密，外壳的格式和版本作为附加数据参与认证。
+
+use crate::common::api_token::API_TOKEN_KEY;
+use crate::common::app_lock::{LOCK_HASH_KEY, MIN_PASSPHRASE_LEN};
+use crate::common::config_store::QUARANTINE_INFIX;
+use crate::common::i18n::{t, t_args};
+use crate::common::window_state::WINDOW_STATE_KEY;
+use crate::common::{timestamp, AppError, Reason, Result};
+use crate::db::repo::{self, CardGroup, ConfigGroup, OtpGroup, PassengerGroup, ProxyGroup};
+use crate::db::{self, load_config};
+use argon2::password_hash::rand_core::{OsRng, RngCore};
+use argon2::{Algorithm, Argon2, Params, Version};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine as Base64Engine;
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use chrono::Local;
+use rusqlite::Transaction;
+use directories::UserDirs;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// 备份文件格式标识
+pub const ARCHIVE_FORMAT: &str = "factbot-settings";
+/// 当前备份格式版本
+pub const ARCHIVE_VERSION: u32 = 1;
+/// 备份文件扩展名
+pub const ARCHIVE_EXTENSION: &str = "fbak";
+
+const KDF_ALGORITHM: &str = "argon2id";
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+const KEY_LEN: usize = 32;
+
+/// 密钥派生参数来自备份文件外壳（未经认证），超过上限的文件不解密，避免占用过多内存和时间
+const MAX_KDF_MEMORY_KIB: u32 = 256 * 1024;
+const MAX_KDF_ITERATIONS: u32 = 16;
+const MAX_KDF_PARALLELISM: u32 = 8;
+
+const AUDIT_TASK_NAME: &str = "Backup";
+const AUDIT_TASK_UUID: &str = "settings-backup";
+
+/// 分组配置保存在仓库表中，不属于「应用设置」
+const GROUP_CONFIG_KEYS: &[&str] = &[
+    ProxyGroup::CONFIG_KEY,
+    OtpGroup::CONFIG_KEY,
+    CardGroup::CONFIG_KEY,
+    PassengerGroup::CONFIG_KEY,
+];
+
+/// 写入审计日志，失败不影响导入导出
+fn audit(level: &str, message: &str) {
+    let _ = db::save_log(AUDIT_TASK_NAME, level, AUDIT_TASK_UUID, &timestamp::now(), message);
+}
+
+// ==================== 文件格式 ====================
+
+#[derive(Debug, Serialize, Deserialize)]
+struct KdfParams {
+    algorithm: String,
+    salt: String,
+    memory_kib: u32,
+    iterations: u32,
+    parallelism: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ArchiveFile {
+    format: String,
+    version: u32,
+    kdf: KdfParams,
+    nonce: String,
+    ciphertext: String,
+}
+
+/// 备份内容（解密后的正文）
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SettingsSnapshot {
+    pub created_at: String,
+    pub proxy_groups: Vec<ProxyGroup>,
+    pub otp_groups: Vec<OtpGroup>,
+    pub card_groups: Vec<CardGroup>,
+    pub passenger_groups: Vec<PassengerGroup>,
+    /// 其余应用设置（`config` 表中的 key / value）
+    pub settings: BTreeMap<String, String>,
+}
+
+impl SettingsSnapshot {
+    /// 读取当前全部配置
+    pub fn capture() -> Result<Self> {
+        let mut settings = BTreeMap::new();
+        for key in db::list_config_keys("")? {
+            if !is_app_setting(&key) {
+                continue;
+            }
+            if let Some(value) = load_config(&key)? {
+                settings.insert(key, value);
+            }
+        }
+
+        Ok(Self {
+            created_at: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+            proxy_groups: repo::load_groups()?,
+            otp_groups: repo::load_groups()?,
+            card_groups: repo::load_groups()?,
+            passenger_groups: repo::load_groups()?,
+            settings,
+        })
+    }
+
+    /// 导出前的内容概览，例如「代理分组 2 个、应用设置 3 项」
+    pub fn summary(&self) -> String {
+        ArchiveSection::ALL
+            .iter()
+            .map(|section| format!("{} {}", section.label(), section.entry_count(self)))
+            .collect::<Vec<_>>()
+            .join("、")
+    }
+}
+
+/// 只属于本机的凭据：导出时跳过，导入时忽略备份中的同名项，本机的值保持不变
+const MACHINE_SECRET_KEYS: [&str; 2] = [API_TOKEN_KEY, LOCK_HASH_KEY];
+
+/// 隔离备份、旧格式的分组 key、只对本机有意义的窗口位置和本机凭据不导出
+fn is_app_setting(key: &str) -> bool {
+    !key.contains(QUARANTINE_INFIX)
+        && !GROUP_CONFIG_KEYS.contains(&key)
+        && key != WINDOW_STATE_KEY
+        && !MACHINE_SECRET_KEYS.contains(&key)
+}
+
+fn derive_key(passphrase: &str, salt: &[u8], params: &KdfParams) -> Result<[u8; KEY_LEN]> {
+    if params.algorithm != KDF_ALGORITHM {
+        return Err(AppError::UnsupportedBackup(Reason::new("error.backup_kdf_unknown", &[&params.algorithm])));
+    }
+    if params.memory_kib > MAX_KDF_MEMORY_KIB
+        || params.iterations > MAX_KDF_ITERATIONS
+        || params.parallelism > MAX_KDF_PARALLELISM
+    {
+        return Err(AppError::UnsupportedBackup(Reason::new(
+            "error.backup_kdf_too_large",
+            &[&params.memory_kib, &params.iterations, &params.parallelism],
+        )));
+    }
+    let argon_params = Params::new(params.memory_kib, params.iterations, params.parallelism, Some(KEY_LEN))
+        .map_err(|e| AppError::UnsupportedBackup(Reason::new("error.backup_kdf_invalid", &[&e])))?;
+
+    let mut key = [0u8; KEY_LEN];
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, argon_params)
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|e| AppError::Unknown(format!("密钥派生失败: {}", e)))?;
+    Ok(key)
+}
+
+/// 外壳中的格式和版本参与认证，防止被篡改后按其他版本解析
+fn associated_data(version: u32) -> Vec<u8> {
+    format!("{}:{}", ARCHIVE_FORMAT, version).into_bytes()
+}
+
+fn decode_base64(field: &str, value: &str) -> Result<Vec<u8>> {
+    BASE64
+        .decode(value)
+        .map_err(|_| AppError::UnsupportedBackup(Reason::new("error.backup_field_base64", &[&field])))
+}
+
+/// 用口令加密备份内容，返回备份文件文本
+pub fn encrypt(snapshot: &SettingsSnapshot, passphrase: &str) -> Result<String> {
+    if passphrase.chars().count() < MIN_PASSPHRASE_LEN {
+        return Err(AppError::InvalidConfig(Reason::new("error.backup_passphrase_too_short", &[&MIN_PASSPHRASE_LEN])));
+    }
+
+    let mut salt = [0u8; SALT_LEN];
+    let mut nonce = [0u8; NONCE_LEN];
+    OsRng.fill_bytes(&mut salt);
+    OsRng.fill_bytes(&mut nonce);
+
+    let defaults = Params::default();
+    let kdf = KdfParams {
+        algorithm: KDF_ALGORITHM.to_string(),
+        salt: BASE64.encode(salt),
+        memory_kib: defaults.m_cost(),
+        iterations: defaults.t_cost(),
+        parallelism: defaults.p_cost(),
+    };
+    let key = derive_key(passphrase, &salt, &kdf)?;
+
+    let plaintext = serde_json::to_vec(snapshot)?;
+    let aad = associated_data(ARCHIVE_VERSION);
+    let ciphertext = ChaCha20Poly1305::new(Key::from_slice(&key))
+        .encrypt(Nonce::from_slice(&nonce), Payload { msg: &plaintext, aad: &aad })
+        .map_err(|_| AppError::Unknown("备份加密失败".to_string()))?;
+
+    let file = ArchiveFile {
+        format: ARCHIVE_FORMAT.to_string(),
+        version: ARCHIVE_VERSION,
+        kdf,
+        nonce: BASE64.encode(nonce),
+        ciphertext: BASE64.encode(ciphertext),
+    };
+    Ok(serde_json::to_string_pretty(&file)?)
+}
+
+/// 解密备份文件文本
+pub fn decrypt(contents: &str, passphrase: &str) -> Result<SettingsSnapshot> {
+    let file: ArchiveFile = serde_json::from_str(contents)
+        .map_err(|_| AppError::UnsupportedBackup(Reason::new("error.backup_not_archive", &[])))?;
+    if file.format != ARCHIVE_FORMAT {
+        return Err(AppError::UnsupportedBackup(Reason::new("error.backup_not_archive", &[])));
+    }
+    if file.version > ARCHIVE_VERSION {
+        return Err(AppError::UnsupportedBackup(Reason::new(
+            "error.backup_version_newer",
+            &[&file.version, &ARCHIVE_VERSION],
+        )));
+    }
+
+    let salt = decode_base64("kdf.salt", &file.kdf.salt)?;
+    let nonce = decode_base64("nonce", &file.nonce)?;
+    let ciphertext = decode_base64("ciphertext", &file.ciphertext)?;
+    if nonce.len() != NONCE_LEN {
+        return Err(AppError::UnsupportedBackup(Reason::new("error.backup_nonce_length", &[])));
+    }
+
+    let key = derive_key(passphrase, &salt, &file.kdf)?;
+    let aad = associated_data(file.version);
+    let plaintext = ChaCha20Poly1305::new(Key::from_slice(&key))
+        .decrypt(Nonce::from_slice(&nonce), Payload { msg: &ciphertext, aad: &aad })
+        .map_err(|_| AppError::BackupDecryptFailed)?;
+
+    // 旧版本导出的备份可能包含本机凭据，导入时不应覆盖本机的令牌和应用锁口令
+    let mut snapshot: SettingsSnapshot = serde_json::from_slice(&plaintext)?;
+    snapshot.settings.retain(|key, _| is_app_setting(key));
+    Ok(snapshot)
+}
+
+/// 导出当前全部配置到备份文件，返回导出的内容
+pub fn export_to_file(path: &Path, passphrase: &str) -> Result<SettingsSnapshot> {
+    let snapshot = SettingsSnapshot::capture()?;
+    let contents = encrypt(&snapshot, passphrase)?;
+    if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, contents)?;
+
+    audit("INFO", &format!("导出设置备份到 {}：{}", path.display(), snapshot.summary()));
+    Ok(snapshot)
+}
+
+/// 默认导出位置：「文档」目录（没有时为用户主目录）下按日期命名的文件
+pub fn default_export_path() -> Option<PathBuf> {
+    let dirs = UserDirs::new()?;
+    let dir = dirs.document_dir().unwrap_or(dirs.home_dir());
+    Some(dir.join(format!("FactBot-settings-{}.{}", Local::now().format("%Y%m%d"), ARCHIVE_EXTENSION)))
+}
+
+/// 读取并解密备份文件
+pub fn read_file(path: &Path, passphrase: &str) -> Result<SettingsSnapshot> {
+    decrypt(&fs::read_to_string(path)?, passphrase)
+}
+
+// ==================== 导入 ====================
+
+/// 备份中可单独选择导入方式的部分
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ArchiveSection {
+    ProxyGroups,
+    OtpGroups,
+    CardGroups,
+    PassengerGroups,
+    AppSettings,
+}
+
+impl ArchiveSection {
+    pub const ALL: [ArchiveSection; 5] = [
+        ArchiveSection::ProxyGroups,
+        ArchiveSection::OtpGroups,
+        ArchiveSection::CardGroups,
+        ArchiveSection::PassengerGroups,
+        ArchiveSection::AppSettings,
+    ];
+
+    /// 英文标识（命令行和 JSON 输出使用）
+    pub fn id(&self) -> &'static str {
+        match self {
+            ArchiveSection::ProxyGroups => ProxyGroup::CONFIG_KEY,
+            ArchiveSection::OtpGroups => OtpGroup::CONFIG_KEY,
+            ArchiveSection::CardGroups => CardGroup::CONFIG_KEY,
+            ArchiveSection::PassengerGroups => PassengerGroup::CONFIG_KEY,
+            ArchiveSection::AppSettings => "settings",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ArchiveSection::ProxyGroups => t("archive.proxy_groups"),
+            ArchiveSection::OtpGroups => t("archive.otp_groups"),
+            ArchiveSection::CardGroups => t("archive.card_groups"),
+            ArchiveSection::PassengerGroups => t("archive.passenger_groups"),
+            ArchiveSection::AppSettings => t("archive.app_settings"),
+        }
+    }
+
+    fn entry_count(&self, snapshot: &SettingsSnapshot) -> usize {
+        match self {
+            ArchiveSection::ProxyGroups => snapshot.proxy_groups.len(),
+            ArchiveSection::OtpGroups => snapshot.otp_groups.len(),
+            ArchiveSection::CardGroups => snapshot.card_groups.len(),
+            ArchiveSection::PassengerGroups => snapshot.passenger_groups.len(),
+            ArchiveSection::AppSettings => snapshot.settings.len(),
+        }
+    }
+}
+
+/// 每部分的导入方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportMode {
+    /// 不导入
+    Skip,
+    /// 备份中的分组（按 id）和设置（按 key）覆盖同名项，本地独有的保留
+    Merge,
+    /// 以备份内容整体替换，本地独有的删除
+    Replace,
+}
+
+impl ImportMode {
+    pub const ALL: [ImportMode; 3] = [ImportMode::Skip, ImportMode::Merge, ImportMode::Replace];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ImportMode::Skip => t("archive.skip"),
+            ImportMode::Merge => t("archive.merge"),
+            ImportMode::Replace => t("archive.replace"),
+        }
+    }
+
+    pub fn value(&self) -> &'static str {
+        match self {
+            ImportMode::Skip => "skip",
+            ImportMode::Merge => "merge",
+            ImportMode::Replace => "replace",
+        }
+    }
+
+    pub fn from_value(value: &str) -> Self {
+        Self::ALL
+            .into_iter()
+            .find(|mode| mode.value() == value)
+            .unwrap_or(ImportMode::Skip)
+    }
+}
+
+/// 备份与本地某部分的差异（记录分组名或设置 key）
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SectionDiff {
+    /// 仅备份中有
+    pub added: Vec<String>,
+    /// 两边都有但内容不同
+    pub updated: Vec<String>,
+    /// 两边相同
+    pub unchanged: usize,
+    /// 仅本地有（替换时删除，合并时保留）
+    pub local_only: Vec<String>,
+}
+
+impl SectionDiff {
+    /// 按导入方式描述将发生的变化
+    pub fn describe(&self, mode: ImportMode) -> String {
+        if mode == ImportMode::Skip {
+            return t("archive.diff_unchanged").to_string();
+        }
+
+        let separator = t("archive.diff_item_separator");
+        let mut parts = Vec::new();
+        if !self.added.is_empty() {
+            parts.push(t_args("archive.diff_added", &[&self.added.join(separator)]));
+        }
+        if !self.updated.is_empty() {
+            parts.push(t_args("archive.diff_updated", &[&self.updated.join(separator)]));
+        }
+        if mode == ImportMode::Replace && !self.local_only.is_empty() {
+            parts.push(t_args("archive.diff_removed", &[&self.local_only.join(separator)]));
+        }
+        if parts.is_empty() {
+            t("archive.diff_none").to_string()
+        } else {
+            parts.join(t("archive.diff_part_separator"))
+        }
+    }
+}
+
+fn diff_entries<T: PartialEq>(
+    local: &[T],
+    incoming: &[T],
+    key: impl Fn(&T) -> String,
+    label: impl Fn(&T) -> String,
+) -> SectionDiff {
+    let mut diff = SectionDiff::default();
+    for item in incoming {
+        match local.iter().find(|existing| key(existing) == key(item)) {
+            None => diff.added.push(label(item)),
+            Some(existing) if existing != item => diff.updated.push(label(item)),
+            Some(_) => diff.unchanged += 1,
+        }
+    }
+    diff.local_only = local
+        .iter()
+        .filter(|existing| !incoming.iter().any(|item| key(item) == key(existing)))
+        .map(label)
+        .collect();
+    diff
+}
+
+fn merge_entries<T: Clone>(local: &[T], incoming: &[T], key: impl Fn(&T) -> String, mode: ImportMode) -> Vec<T> {
+    match mode {
+        ImportMode::Skip => local.to_vec(),
+        ImportMode::Replace => incoming.to_vec(),
+        ImportMode::Merge => {
+            let mut merged = local.to_vec();
+            for item in incoming {
+                match merged.iter_mut().find(|existing| key(existing) == key(item)) {
+                    Some(existing) => *existing = item.clone(),
+                    None => merged.push(item.clone()),
+                }
+            }
+            merged
+        }
+    }
+}
+
+fn group_key<G: ConfigGroup>(group: &G) -> String {
+    group.id().to_string()
+}
+
+fn group_label<G: ConfigGroup>(group: &G) -> String {
+    group.name().to_string()
+}
+
+fn settings_entries(settings: &BTreeMap<String, String>) -> Vec<(String, String)> {
+    settings.iter().map(|(key, value)| (key.clone(), value.clone())).collect()
+}
+
+/// 比较备份和本地配置中的某一部分
+pub fn diff_section(section: ArchiveSection, local: &SettingsSnapshot, incoming: &SettingsSnapshot) -> SectionDiff {
+    match section {
+        ArchiveSection::ProxyGroups => diff_entries(&local.proxy_groups, &incoming.proxy_groups, group_key, group_label),
+        ArchiveSection::OtpGroups => diff_entries(&local.otp_groups, &incoming.otp_groups, group_key, group_label),
+        ArchiveSection::CardGroups => diff_entries(&local.card_groups, &incoming.card_groups, group_key, group_label),
+        ArchiveSection::PassengerGroups => {
+            diff_entries(&local.passenger_groups, &incoming.passenger_groups, group_key, group_label)
+        }
+        ArchiveSection::AppSettings => diff_entries(
+            &settings_entries(&local.settings),
+            &settings_entries(&incoming.settings),
+            |(key, _)| key.clone(),
+            |(key, _)| key.clone(),
+        ),
+    }
+}
+
+/// 按各部分的导入方式计算导入后的配置
+pub fn merge_snapshot(
+    local: &SettingsSnapshot,
+    incoming: &SettingsSnapshot,
+    modes: &BTreeMap<ArchiveSection, ImportMode>,
+) -> SettingsSnapshot {
+    let mode = |section| modes.get(&section).copied().unwrap_or(ImportMode::Skip);
+
+    SettingsSnapshot {
+        created_at: local.created_at.clone(),
+        proxy_groups: merge_entries(&local.proxy_groups, &incoming.proxy_groups, group_key, mode(ArchiveSection::ProxyGroups)),
+        otp_groups: merge_entries(&local.otp_groups, &incoming.otp_groups, group_key, mode(ArchiveSection::OtpGroups)),
+        card_groups: merge_entries(&local.card_groups, &incoming.card_groups, group_key, mode(ArchiveSection::CardGroups)),
+        passenger_groups: merge_entries(
+            &local.passenger_groups,
+            &incoming.passenger_groups,
+            group_key,
+            mode(ArchiveSection::PassengerGroups),
+        ),
+        settings: merge_entries(
+            &settings_entries(&local.settings),
+            &settings_entries(&incoming.settings),
+            |(key, _)| key.clone(),
+            mode(ArchiveSection::AppSettings),
+        )
+        .into_iter()
+        .collect(),
+    }
+}
+
+/// 导入备份，返回导入后的配置
+///
+/// 全部修改在 config.db 的同一个事务中写入，任一部分失败时整体回滚，不会只导入一半；
+/// 分组修改前的版本会进入配置历史，可以在各分组的历史面板中撤销
+pub fn import(incoming: &SettingsSnapshot, modes: &BTreeMap<ArchiveSection, ImportMode>) -> Result<SettingsSnapshot> {
+    let local = SettingsSnapshot::capture()?;
+    let merged = merge_snapshot(&local, incoming, modes);
+
+    let mut conn = db::init_config_db()?;
+    let tx = conn.transaction()?;
+    apply_snapshot(&tx, &local, &merged)?;
+    tx.commit()?;
+
+    let applied = ArchiveSection::ALL
+        .iter()
+        .filter_map(|section| {
+            let mode = modes.get(section).copied().unwrap_or(ImportMode::Skip);
+            (mode != ImportMode::Skip).then(|| format!("{}（{}）", section.label(), mode.label()))
+        })
+        .collect::<Vec<_>>();
+    audit(
+        "INFO",
+        &format!("导入设置备份（创建于 {}）：{}", incoming.created_at, applied.join("、")),
+    );
+    Ok(merged)
+}
+
+/// 在事务中写入与本地不同的部分
+fn apply_snapshot(tx: &Transaction, local: &SettingsSnapshot, merged: &SettingsSnapshot) -> Result<()> {
+    if merged.proxy_groups != local.proxy_groups {
+        repo::save_groups_in(tx, &merged.proxy_groups)?;
+    }
+    if merged.otp_groups != local.otp_groups {
+        repo::save_groups_in(tx, &merged.otp_groups)?;
+    }
+    if merged.card_groups != local.card_groups {
+        repo::save_groups_in(tx, &merged.card_groups)?;
+    }
+    if merged.passenger_groups != local.passenger_groups {
+        repo::save_groups_in(tx, &merged.passenger_groups)?;
+    }
+    for (key, value) in &merged.settings {
+        if local.settings.get(key) != Some(value) {
+            db::save_config_in(tx, key, value)?;
+        }
+    }
+    for key in local.settings.keys().filter(|key| !merged.settings.contains_key(*key)) {
+        db::delete_config_in(tx, key)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::repo::ProxyEntry;
+
+    fn proxy_group(id: usize, name: &str, ips: &[&str]) -> ProxyGroup {
+        ProxyGroup {
+            id,
+            name: name.to_string(),
+            proxies: ips
+                .iter()
+                .enumerate()
+                .map(|(index, ip)| ProxyEntry {
+                    id: index + 1,
+                    ip: ip.to_string(),
+                    port: "8080".to_string(),
+                    username: String::new(),
+                    password: String::new(),
+                })
+                .collect(),
+        }
+    }
+
+    fn snapshot(proxy_groups: Vec<ProxyGroup>, settings: &[(&str, &str)]) -> SettingsSnapshot {
+        SettingsSnapshot {
+            created_at: "2024-05-01 10:00:00".to_string(),
+            proxy_groups,
+            settings: settings.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_encrypt_roundtrip_and_wrong_passphrase() {
+        let original = snapshot(vec![proxy_group(1, "默认分组", &["1.1.1.1"])], &[("app_lock_idle_minutes", "5")]);
+        let contents = encrypt(&original, "correct horse").unwrap();
+
+        // 正文不以明文出现
+        assert!(!contents.contains("1.1.1.1"));
+        assert!(contents.contains(ARCHIVE_FORMAT));
+
+        assert_eq!(decrypt(&contents, "correct horse").unwrap(), original);
+        assert!(matches!(decrypt(&contents, "wrong horse"), Err(AppError::BackupDecryptFailed)));
+        assert!(matches!(encrypt(&original, "short"), Err(AppError::InvalidConfig(_))));
+    }
+
+    #[test]
+    fn test_machine_secrets_not_imported() {
+        assert!(is_app_setting("app_lock_idle_minutes"));
+        assert!(!is_app_setting(API_TOKEN_KEY));
+        assert!(!is_app_setting(LOCK_HASH_KEY));
+
+        // 旧版本导出的备份中带有令牌和口令哈希
+        let original = snapshot(
+            Vec::new(),
+            &[("app_lock_idle_minutes", "5"), (API_TOKEN_KEY, "token"), (LOCK_HASH_KEY, "$argon2id$...")],
+        );
+        let contents = encrypt(&original, "correct horse").unwrap();
+        let restored = decrypt(&contents, "correct horse").unwrap();
+        assert_eq!(restored.settings.keys().collect::<Vec<_>>(), vec!["app_lock_idle_minutes"]);
+    }
+
+    #[test]
+    fn test_rejects_tampered_or_newer_archive() {
+        let contents = encrypt(&SettingsSnapshot::default(), "correct horse").unwrap();
+
+        let mut file: serde_json::Value = serde_json::from_str(&contents).unwrap();
+        file["version"] = serde_json::json!(ARCHIVE_VERSION + 1);
+        assert!(matches!(
+            decrypt(&file.to_string(), "correct horse"),
+            Err(AppError::UnsupportedBackup(_))
+        ));
+
+        assert!(matches!(decrypt("{\"hello\":1}", "correct horse"), Err(AppError::UnsupportedBackup(_))));
+
+        // 篡改后的密钥派生参数在派生前就被拒绝
+        for (field, value) in [("memory_kib", u32::MAX), ("iterations", u32::MAX), ("parallelism", 1024)] {
+            let mut file: serde_json::Value = serde_json::from_str(&contents).unwrap();
+            file["kdf"][field] = serde_json::json!(value);
+            assert!(matches!(
+                decrypt(&file.to_string(), "correct horse"),
+                Err(AppError::UnsupportedBackup(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_apply_snapshot_is_atomic() {
+        let mut conn = rusqlite::Connection::open_in_memory().unwrap();
+        conn.pragma_update(None, "foreign_keys", "ON").unwrap();
+        db::create_config_tables(&conn).unwrap();
+        let local = snapshot(vec![proxy_group(1, "默认分组", &["1.1.1.1"])], &[("a", "1"), ("b", "2")]);
+        repo::replace_groups_in(&mut conn, &local.proxy_groups).unwrap();
+        for (key, value) in &local.settings {
+            db::save_config_in(&conn, key, value).unwrap();
+        }
+        let merged = snapshot(vec![proxy_group(1, "默认分组", &["9.9.9.9"])], &[("a", "1"), ("c", "3")]);
+        let settings = |conn: &rusqlite::Connection| -> Vec<(String, String)> {
+            conn.prepare("SELECT key, value FROM config ORDER BY key")
+                .unwrap()
+                .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+                .unwrap()
+                .collect::<rusqlite::Result<_>>()
+                .unwrap()
+        };
+
+        // 未提交的事务回滚后，分组和设置都保持原样
+        {
+            let tx = conn.transaction().unwrap();
+            apply_snapshot(&tx, &local, &merged).unwrap();
+        }
+        assert_eq!(repo::load_groups_from::<ProxyGroup>(&conn).unwrap(), local.proxy_groups);
+        assert_eq!(settings(&conn), settings_entries(&local.settings));
+
+        let tx = conn.transaction().unwrap();
+        apply_snapshot(&tx, &local, &merged).unwrap();
+        tx.commit().unwrap();
+        assert_eq!(repo::load_groups_from::<ProxyGroup>(&conn).unwrap(), merged.proxy_groups);
+        assert_eq!(settings(&conn), settings_entries(&merged.settings));
+        // 分组修改前的版本进入配置历史
+        let history: i64 = conn
+            .query_row("SELECT COUNT(*) FROM config_history WHERE key = ?1", [ProxyGroup::CONFIG_KEY], |row| row.get(0))
+            .unwrap();
+        assert_eq!(history, 1);
+    }
+
+    #[test]
+    fn test_diff_and_merge_modes() {
+        let local = snapshot(
+            vec![proxy_group(1, "默认分组", &["1.1.1.1"]), proxy_group(2, "本地", &["2.2.2.2"])],
+            &[("a", "1"), ("b", "2")],
+        );
+        let incoming = snapshot(
+            vec![proxy_group(1, "默认分组", &["9.9.9.9"]), proxy_group(3, "新分组", &[])],
+            &[("a", "1"), ("c", "3")],
+        );
+
+        let diff = diff_section(ArchiveSection::ProxyGroups, &local, &incoming);
+        assert_eq!(diff.added, vec!["新分组"]);
+        assert_eq!(diff.updated, vec!["默认分组"]);
+        assert_eq!(diff.local_only, vec!["本地"]);
+        // 按当前界面语言拼出预期文字，不依赖其他测试设置的语言
+        let added = t_args("archive.diff_added", &[&"新分组"]);
+        let updated = t_args("archive.diff_updated", &[&"默认分组"]);
+        let removed = t_args("archive.diff_removed", &[&"本地"]);
+        let separator = t("archive.diff_part_separator");
+        assert_eq!(diff.describe(ImportMode::Merge), [added.as_str(), &updated].join(separator));
+        assert_eq!(diff.describe(ImportMode::Replace), [added.as_str(), &updated, &removed].join(separator));
+        assert_eq!(diff.describe(ImportMode::Skip), t("archive.diff_unchanged"));
+
+        let settings_diff = diff_section(ArchiveSection::AppSettings, &local, &incoming);
+        assert_eq!(settings_diff.unchanged, 1);
+
+        let modes = BTreeMap::from([
+            (ArchiveSection::ProxyGroups, ImportMode::Merge),
+            (ArchiveSection::AppSettings, ImportMode::Replace),
+        ]);
+        let merged = merge_snapshot(&local, &incoming, &modes);
+        let ids: Vec<usize> = merged.proxy_groups.iter().map(|group| group.id).collect();
+        assert_eq!(ids, vec![1, 2, 3]);
+        assert_eq!(merged.proxy_groups[0].proxies[0].ip, "9.9.9.9");
+        assert_eq!(merged.settings, incoming.settings);
+
+        let skipped = merge_snapshot(&local, &incoming, &BTreeMap::new());
+        assert_eq!(skipped.proxy_groups, local.proxy_groups);
+        assert_eq!(skipped.settings, local.settings);
+    }
+}
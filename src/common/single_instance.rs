@@ -0,0 +1,115 @@
+//! 单实例：同一台电脑上只运行一个桌面窗口
+//!
+//! - 第一个实例监听本机端口 `INSTANCE_PORT`，作为「已在运行」的标记
+//! - 之后启动的实例连接该端口发送显示请求，已运行的实例把窗口显示到前台，新实例随即退出，
+//!   不会再启动 8080 端口的 API 服务或写入同一个数据库
+//! - 端口被其他程序占用时无法判断，只提示并照常启动
+
+use crate::common::{AppError, Result};
+use std::io::{BufRead, BufReader, ErrorKind, Write};
+use std::net::{Ipv4Addr, SocketAddr, TcpListener, TcpStream};
+use std::time::Duration;
+use tokio::sync::Notify;
+
+/// 单实例监听端口（仅 127.0.0.1）
+pub const INSTANCE_PORT: u16 = 47821;
+
+const SHOW_REQUEST: &str = "factbot:show";
+const ACKNOWLEDGE: &str = "ok";
+const REQUEST_TIMEOUT: Duration = Duration::from_millis(800);
+
+/// 收到其他实例的显示请求
+static SHOW_REQUESTED: Notify = Notify::const_new();
+
+pub enum Instance {
+    /// 当前是第一个实例，需调用 `listen` 接收之后实例的请求
+    Primary(TcpListener),
+    /// 已有实例在运行，并已通知它显示窗口
+    AlreadyRunning,
+}
+
+/// 占用单实例端口；端口已被 FactBot 占用时通知它显示窗口
+pub fn acquire(port: u16) -> Result<Instance> {
+    match TcpListener::bind(SocketAddr::from((Ipv4Addr::LOCALHOST, port))) {
+        Ok(listener) => Ok(Instance::Primary(listener)),
+        Err(e) if e.kind() == ErrorKind::AddrInUse => {
+            request_show(port)?;
+            Ok(Instance::AlreadyRunning)
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// 请求已运行的实例显示窗口，对方没有按约定回复时返回错误
+fn request_show(port: u16) -> Result<()> {
+    let address = SocketAddr::from((Ipv4Addr::LOCALHOST, port));
+    let mut stream = TcpStream::connect_timeout(&address, REQUEST_TIMEOUT)?;
+    stream.set_read_timeout(Some(REQUEST_TIMEOUT))?;
+    writeln!(stream, "{}", SHOW_REQUEST)?;
+
+    let mut reply = String::new();
+    BufReader::new(stream)
+        .read_line(&mut reply)
+        .map_err(|e| AppError::Unknown(format!("端口 {} 已被其他程序占用: {}", port, e)))?;
+    if reply.trim() != ACKNOWLEDGE {
+        return Err(AppError::Unknown(format!("端口 {} 已被其他程序占用", port)));
+    }
+    Ok(())
+}
+
+/// 在后台线程中接收之后启动的实例发来的请求，收到后唤醒 `show_requested`
+pub fn listen(listener: TcpListener) -> Result<()> {
+    std::thread::Builder::new()
+        .name("single-instance".to_string())
+        .spawn(move || {
+            for stream in listener.incoming().flatten() {
+                if handle_request(stream) {
+                    SHOW_REQUESTED.notify_one();
+                }
+            }
+        })?;
+    Ok(())
+}
+
+fn handle_request(stream: TcpStream) -> bool {
+    let _ = stream.set_read_timeout(Some(REQUEST_TIMEOUT));
+    let mut reader = BufReader::new(&stream);
+    let mut line = String::new();
+    if reader.read_line(&mut line).is_err() || line.trim() != SHOW_REQUEST {
+        return false;
+    }
+    writeln!(&stream, "{}", ACKNOWLEDGE).is_ok()
+}
+
+/// 等待下一次显示请求（请求在等待之前到达时立即返回）
+pub async fn show_requested() {
+    SHOW_REQUESTED.notified().await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_second_instance_notifies_first() {
+        let listener = match acquire(0).unwrap() {
+            Instance::Primary(listener) => listener,
+            Instance::AlreadyRunning => panic!("端口 0 不应被占用"),
+        };
+        let port = listener.local_addr().unwrap().port();
+        listen(listener).unwrap();
+
+        assert!(matches!(acquire(port).unwrap(), Instance::AlreadyRunning));
+        tokio::time::timeout(Duration::from_secs(5), show_requested())
+            .await
+            .expect("第一个实例应收到显示请求");
+    }
+
+    #[test]
+    fn test_port_taken_by_other_program() {
+        // 只监听、不回复的程序
+        let other = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+        let port = other.local_addr().unwrap().port();
+        assert!(acquire(port).is_err());
+    }
+}
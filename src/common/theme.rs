@@ -0,0 +1,96 @@
+//! 界面主题
+//!
+//! - 颜色、圆角、间距等设计变量以 CSS 变量定义在 `assets/styling/theme.css`，页面样式中用 `var(--fb-*)` 引用
+//! - 布局根元素的 `data-theme` 属性选择浅色 / 深色；跟随系统时由 `prefers-color-scheme` 决定
+//! - 用户的选择保存在 config.db
+
+use crate::common::i18n::t;
+use crate::common::Result;
+use crate::db::{load_config, save_config};
+
+/// 保存主题的配置 key
+const THEME_KEY: &str = "ui_theme";
+
+/// 界面主题
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Theme {
+    /// 浅色页面（标题栏和侧边栏保持深色）
+    #[default]
+    Light,
+    Dark,
+    /// 跟随系统的浅色 / 深色设置
+    System,
+}
+
+impl Theme {
+    pub const ALL: [Theme; 3] = [Theme::Light, Theme::Dark, Theme::System];
+
+    /// 保存到配置中的标识，同时作为 `data-theme` 属性的值
+    pub fn id(&self) -> &'static str {
+        match self {
+            Theme::Light => "light",
+            Theme::Dark => "dark",
+            Theme::System => "system",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|theme| theme.id() == id)
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Theme::Light => t("theme.light"),
+            Theme::Dark => t("theme.dark"),
+            Theme::System => t("theme.system"),
+        }
+    }
+
+    /// 标题栏按钮的图标
+    pub fn icon(&self) -> &'static str {
+        match self {
+            Theme::Light => "☀",
+            Theme::Dark => "☾",
+            Theme::System => "◐",
+        }
+    }
+
+    /// 标题栏按钮依次切换：浅色 → 深色 → 跟随系统
+    pub fn next(&self) -> Self {
+        match self {
+            Theme::Light => Theme::Dark,
+            Theme::Dark => Theme::System,
+            Theme::System => Theme::Light,
+        }
+    }
+
+    /// 读取保存的主题，未设置或无法读取时为浅色
+    pub fn load() -> Self {
+        load_config(THEME_KEY)
+            .ok()
+            .flatten()
+            .and_then(|id| Self::from_id(&id))
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) -> Result<()> {
+        save_config(THEME_KEY, self.id())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_theme_ids() {
+        for theme in Theme::ALL {
+            assert_eq!(Theme::from_id(theme.id()), Some(theme));
+        }
+        assert_eq!(Theme::from_id("sepia"), None);
+        // 依次切换会回到起点并经过每个主题
+        let cycle: Vec<_> = std::iter::successors(Some(Theme::Light), |theme| Some(theme.next())).take(3).collect();
+        assert_eq!(cycle, Theme::ALL);
+        assert_eq!(Theme::System.next(), Theme::Light);
+    }
+}
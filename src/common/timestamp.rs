@@ -0,0 +1,260 @@
+//! 日志时间戳
+//!
+//! 日志统一以 RFC 3339 UTC 格式存储，精确到毫秒（如 `2024-05-01T02:03:04.567Z`）。
+//! 格式固定长度，按字符串排序即按时间排序，不受写入机器时区和夏令时影响；
+//! 界面按用户选择的时区显示。早期版本以本地时间 `%Y-%m-%d %H:%M:%S` 存储，打开日志库时一次性迁移。
+
+use crate::common::i18n::{self, Lang};
+use crate::common::Result;
+use crate::db::{load_config, save_config};
+use chrono::{DateTime, Duration, Local, LocalResult, NaiveDate, NaiveDateTime, SecondsFormat, TimeZone, Utc};
+use chrono_tz::Tz;
+
+/// 旧版本的本地时间格式
+pub const LEGACY_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+/// 命令行和导出使用的显示格式，界面按语言使用 `Lang::datetime_format`
+pub const DISPLAY_FORMAT: &str = "%Y-%m-%d %H:%M:%S%.3f";
+/// 只显示时间时的格式
+const TIME_FORMAT: &str = "%H:%M:%S%.3f";
+/// 时间选择框（`datetime-local`）的格式
+pub const INPUT_FORMAT: &str = "%Y-%m-%dT%H:%M";
+
+/// 日志显示时区
+const DISPLAY_ZONE_KEY: &str = "log_display_timezone";
+
+/// 当前时间的存储格式
+pub fn now() -> String {
+    to_storage(Utc::now())
+}
+
+pub fn to_storage(time: DateTime<Utc>) -> String {
+    time.to_rfc3339_opts(SecondsFormat::Millis, true)
+}
+
+/// 解析存储的时间戳，兼容旧版本的本地时间格式
+pub fn parse(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value.trim())
+        .map(|time| time.with_timezone(&Utc))
+        .ok()
+        .or_else(|| parse_local(value))
+}
+
+/// 旧格式时间戳转为存储格式；已是新格式或无法识别时返回 None
+pub fn legacy_to_storage(value: &str) -> Option<String> {
+    legacy_in(value, &Local).map(to_storage)
+}
+
+fn legacy_in<Z: TimeZone>(value: &str, zone: &Z) -> Option<DateTime<Utc>> {
+    let naive = NaiveDateTime::parse_from_str(value.trim(), LEGACY_FORMAT).ok()?;
+    local_to_utc(naive, zone)
+}
+
+/// 本地时间转 UTC：夏令时回拨产生的重复时刻取较早的一个，跳过的时刻按跳变前的偏移计算
+fn local_to_utc<Z: TimeZone>(naive: NaiveDateTime, zone: &Z) -> Option<DateTime<Utc>> {
+    match zone.from_local_datetime(&naive) {
+        LocalResult::Single(time) | LocalResult::Ambiguous(time, _) => Some(time.with_timezone(&Utc)),
+        LocalResult::None => zone
+            .from_local_datetime(&(naive - Duration::hours(1)))
+            .earliest()
+            .map(|time| time.with_timezone(&Utc) + Duration::hours(1)),
+    }
+}
+
+/// 解析用户输入的本地时间（`YYYY-MM-DD` 表示当天 0 点，或 `YYYY-MM-DD HH:MM:SS`）
+pub fn parse_local(value: &str) -> Option<DateTime<Utc>> {
+    let value = value.trim();
+    let naive = NaiveDateTime::parse_from_str(value, LEGACY_FORMAT)
+        .ok()
+        .or_else(|| NaiveDate::parse_from_str(value, "%Y-%m-%d").ok()?.and_hms_opt(0, 0, 0))?;
+    local_to_utc(naive, &Local)
+}
+
+/// 日志显示时区
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DisplayZone {
+    /// 本机时区
+    Local,
+    Named(Tz),
+}
+
+impl DisplayZone {
+    /// 界面中可选择的时区
+    pub const CHOICES: &'static [Tz] = &[
+        Tz::UTC,
+        Tz::Asia__Shanghai,
+        Tz::Asia__Hong_Kong,
+        Tz::Asia__Taipei,
+        Tz::Asia__Tokyo,
+        Tz::Asia__Seoul,
+        Tz::Asia__Singapore,
+        Tz::Asia__Bangkok,
+        Tz::Asia__Dubai,
+        Tz::Europe__London,
+        Tz::Europe__Paris,
+        Tz::Europe__Berlin,
+        Tz::Europe__Moscow,
+        Tz::America__New_York,
+        Tz::America__Chicago,
+        Tz::America__Denver,
+        Tz::America__Los_Angeles,
+        Tz::Australia__Sydney,
+    ];
+
+    /// 保存到配置中的标识（本机时区为 `local`，其余为 IANA 名称）
+    pub fn id(&self) -> String {
+        match self {
+            DisplayZone::Local => "local".to_string(),
+            DisplayZone::Named(tz) => tz.name().to_string(),
+        }
+    }
+
+    pub fn from_id(id: &str) -> Self {
+        id.parse::<Tz>().map(DisplayZone::Named).unwrap_or(DisplayZone::Local)
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            DisplayZone::Local => i18n::t_args("zone.local", &[&Local::now().format("%:z")]),
+            DisplayZone::Named(tz) => tz.name().to_string(),
+        }
+    }
+
+    /// 读取用户选择的时区，未设置时为本机时区
+    pub fn load() -> Self {
+        load_config(DISPLAY_ZONE_KEY)
+            .ok()
+            .flatten()
+            .map(|id| Self::from_id(&id))
+            .unwrap_or(DisplayZone::Local)
+    }
+
+    pub fn save(&self) -> Result<()> {
+        save_config(DISPLAY_ZONE_KEY, &self.id())
+    }
+
+    /// 把存储的时间戳转为该时区的显示格式，无法解析时原样返回
+    pub fn format(&self, stored: &str) -> String {
+        match parse(stored) {
+            Some(time) => self.format_with(time, DISPLAY_FORMAT),
+            None => stored.to_string(),
+        }
+    }
+
+    /// 按界面语言的日期格式显示
+    pub fn format_in(&self, stored: &str, lang: Lang) -> String {
+        match parse(stored) {
+            Some(time) => self.format_with(time, lang.datetime_format()),
+            None => stored.to_string(),
+        }
+    }
+
+    /// 只显示时间部分（`HH:MM:SS.mmm`），无法解析时原样返回
+    pub fn format_time(&self, stored: &str) -> String {
+        match parse(stored) {
+            Some(time) => self.format_with(time, TIME_FORMAT),
+            None => stored.to_string(),
+        }
+    }
+
+    fn format_with(&self, time: DateTime<Utc>, format: &str) -> String {
+        match self {
+            DisplayZone::Local => time.with_timezone(&Local).format(format).to_string(),
+            DisplayZone::Named(tz) => time.with_timezone(tz).format(format).to_string(),
+        }
+    }
+
+    /// 转为时间选择框（`datetime-local`）的值，按该时区显示
+    pub fn to_input(&self, time: DateTime<Utc>) -> String {
+        self.format_with(time, INPUT_FORMAT)
+    }
+
+    /// 解析时间选择框的值（`YYYY-MM-DDTHH:MM[:SS]`，也接受空格分隔），按该时区理解
+    pub fn parse_input(&self, value: &str) -> Option<DateTime<Utc>> {
+        let value = value.trim().replacen(' ', "T", 1);
+        let naive = NaiveDateTime::parse_from_str(&value, "%Y-%m-%dT%H:%M:%S")
+            .or_else(|_| NaiveDateTime::parse_from_str(&value, INPUT_FORMAT))
+            .ok()?;
+        self.utc_of(naive)
+    }
+
+    /// `time` 所在日期在该时区的 0 点
+    pub fn start_of_day(&self, time: DateTime<Utc>) -> DateTime<Utc> {
+        let midnight = |date: NaiveDate| date.and_hms_opt(0, 0, 0).unwrap_or_default();
+        let naive = match self {
+            DisplayZone::Local => midnight(time.with_timezone(&Local).date_naive()),
+            DisplayZone::Named(tz) => midnight(time.with_timezone(tz).date_naive()),
+        };
+        self.utc_of(naive).unwrap_or(time)
+    }
+
+    /// 该时区的本地时间转 UTC
+    fn utc_of(&self, naive: NaiveDateTime) -> Option<DateTime<Utc>> {
+        match self {
+            DisplayZone::Local => local_to_utc(naive, &Local),
+            DisplayZone::Named(tz) => local_to_utc(naive, tz),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono_tz::America::New_York;
+
+    #[test]
+    fn test_storage_format_sorts_chronologically() {
+        let earlier = Utc.with_ymd_and_hms(2024, 5, 1, 9, 59, 59).unwrap() + Duration::milliseconds(999);
+        let later = Utc.with_ymd_and_hms(2024, 5, 1, 10, 0, 0).unwrap();
+        let (a, b) = (to_storage(earlier), to_storage(later));
+
+        assert_eq!(a, "2024-05-01T09:59:59.999Z");
+        assert!(a < b);
+        assert_eq!(parse(&b), Some(later));
+    }
+
+    #[test]
+    fn test_legacy_conversion_across_dst() {
+        // 夏令时回拨：01:30 出现两次，取较早的（EDT，UTC-4）
+        let ambiguous = legacy_in("2024-11-03 01:30:00", &New_York).unwrap();
+        assert_eq!(to_storage(ambiguous), "2024-11-03T05:30:00.000Z");
+
+        // 夏令时开始：02:30 不存在，按跳变前的偏移（EST，UTC-5）计算
+        let skipped = legacy_in("2024-03-10 02:30:00", &New_York).unwrap();
+        assert_eq!(to_storage(skipped), "2024-03-10T07:30:00.000Z");
+
+        // 已是新格式或无法识别的不转换
+        assert!(legacy_to_storage("2024-05-01T10:00:00.000Z").is_none());
+        assert!(legacy_to_storage("yesterday").is_none());
+    }
+
+    #[test]
+    fn test_display_zone() {
+        let stored = "2024-07-01T12:00:00.250Z";
+        assert_eq!(DisplayZone::Named(Tz::UTC).format(stored), "2024-07-01 12:00:00.250");
+        assert_eq!(DisplayZone::Named(Tz::Asia__Shanghai).format(stored), "2024-07-01 20:00:00.250");
+        assert_eq!(DisplayZone::Named(New_York).format(stored), "2024-07-01 08:00:00.250");
+        assert_eq!(DisplayZone::Local.format("not a time"), "not a time");
+        assert_eq!(DisplayZone::Named(Tz::UTC).format_in(stored, Lang::Zh), "2024-07-01 12:00:00.250");
+        assert_eq!(DisplayZone::Named(Tz::UTC).format_in(stored, Lang::En), "Jul 1, 2024 12:00:00.250");
+        assert_eq!(DisplayZone::Named(Tz::Asia__Shanghai).format_time(stored), "20:00:00.250");
+
+        assert_eq!(DisplayZone::from_id("Asia/Tokyo"), DisplayZone::Named(Tz::Asia__Tokyo));
+        assert_eq!(DisplayZone::from_id("local"), DisplayZone::Local);
+        assert_eq!(DisplayZone::Named(Tz::Asia__Tokyo).id(), "Asia/Tokyo");
+    }
+
+    #[test]
+    fn test_display_zone_inputs() {
+        let shanghai = DisplayZone::Named(Tz::Asia__Shanghai);
+        let time = Utc.with_ymd_and_hms(2024, 7, 1, 18, 30, 0).unwrap();
+
+        assert_eq!(shanghai.to_input(time), "2024-07-02T02:30");
+        assert_eq!(shanghai.parse_input("2024-07-02T02:30"), Some(time));
+        assert_eq!(shanghai.parse_input("2024-07-02 02:30:00"), Some(time));
+        assert_eq!(shanghai.parse_input("tomorrow"), None);
+
+        // 上海已是 7 月 2 日，当天 0 点为 UTC 7 月 1 日 16:00
+        assert_eq!(shanghai.start_of_day(time), Utc.with_ymd_and_hms(2024, 7, 1, 16, 0, 0).unwrap());
+        assert_eq!(DisplayZone::Named(Tz::UTC).start_of_day(time), Utc.with_ymd_and_hms(2024, 7, 1, 0, 0, 0).unwrap());
+    }
+}
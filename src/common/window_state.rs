@@ -0,0 +1,109 @@
+//! 主窗口的位置、大小和托盘设置
+//!
+//! - 关闭窗口（或从托盘退出）时保存位置、大小和是否最大化，下次启动时恢复
+//! - 最大化时只更新最大化标记，保留之前的普通窗口位置和大小，取消最大化后回到原处
+//! - 窗口状态与具体电脑的屏幕有关，不包含在设置备份中
+
+use crate::common::Result;
+use crate::db::{load_config, save_config};
+use serde::{Deserialize, Serialize};
+
+/// 窗口位置和大小（JSON）
+pub const WINDOW_STATE_KEY: &str = "window_state";
+/// 是否启用托盘图标
+const TRAY_ENABLED_KEY: &str = "tray_enabled";
+
+/// 默认窗口大小（逻辑像素）
+pub const DEFAULT_SIZE: (f64, f64) = (1260.0, 900.0);
+/// 最小窗口大小（逻辑像素）
+pub const MIN_SIZE: (f64, f64) = (1120.0, 780.0);
+
+/// 超出该范围的坐标视为无效（多显示器拼接后也不会这么远）
+const MAX_COORDINATE: f64 = 32_000.0;
+
+/// 窗口状态，位置和大小均为逻辑像素
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct WindowState {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+    #[serde(default)]
+    pub maximized: bool,
+}
+
+impl WindowState {
+    /// 读取保存的窗口状态，未保存或内容无效时为 None（使用默认大小并居中）
+    pub fn load() -> Option<Self> {
+        load_config(WINDOW_STATE_KEY)
+            .ok()
+            .flatten()
+            .and_then(|raw| serde_json::from_str::<Self>(&raw).ok())
+            .and_then(Self::sanitized)
+    }
+
+    pub fn save(&self) -> Result<()> {
+        save_config(WINDOW_STATE_KEY, &serde_json::to_string(self)?)
+    }
+
+    /// 最大化时保存：保留已保存的普通窗口位置和大小，只更新最大化标记
+    pub fn save_maximized() -> Result<()> {
+        let state = Self::load().unwrap_or(Self {
+            x: 0.0,
+            y: 0.0,
+            width: DEFAULT_SIZE.0,
+            height: DEFAULT_SIZE.1,
+            maximized: true,
+        });
+        Self { maximized: true, ..state }.save()
+    }
+
+    /// 大小不小于最小值；坐标不是有限值或离得太远时整体视为无效
+    pub fn sanitized(self) -> Option<Self> {
+        let values = [self.x, self.y, self.width, self.height];
+        if values.iter().any(|value| !value.is_finite()) || self.x.abs() > MAX_COORDINATE || self.y.abs() > MAX_COORDINATE {
+            return None;
+        }
+        Some(Self {
+            width: self.width.clamp(MIN_SIZE.0, MAX_COORDINATE),
+            height: self.height.clamp(MIN_SIZE.1, MAX_COORDINATE),
+            ..self
+        })
+    }
+}
+
+/// 托盘图标设置：启用后关闭窗口时隐藏到托盘，重启后生效
+pub fn tray_enabled() -> bool {
+    load_config(TRAY_ENABLED_KEY).ok().flatten().is_some_and(|value| value == "true")
+}
+
+pub fn set_tray_enabled(enabled: bool) -> Result<()> {
+    save_config(TRAY_ENABLED_KEY, &enabled.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitized() {
+        let state = WindowState { x: 120.0, y: -8.0, width: 1400.0, height: 600.0, maximized: false };
+        let fixed = state.sanitized().unwrap();
+        assert_eq!((fixed.x, fixed.y, fixed.width), (120.0, -8.0, 1400.0));
+        // 小于最小值时放大
+        assert_eq!(fixed.height, MIN_SIZE.1);
+
+        assert_eq!(WindowState { x: f64::NAN, ..state }.sanitized(), None);
+        assert_eq!(WindowState { y: -50_000.0, ..state }.sanitized(), None);
+        assert_eq!(WindowState { width: f64::INFINITY, ..state }.sanitized(), None);
+    }
+
+    #[test]
+    fn test_state_json() {
+        // 旧数据没有 maximized 字段
+        let state: WindowState = serde_json::from_str(r#"{"x":10,"y":20,"width":1300,"height":900}"#).unwrap();
+        assert!(!state.maximized);
+        let round_trip: WindowState = serde_json::from_str(&serde_json::to_string(&state).unwrap()).unwrap();
+        assert_eq!(round_trip, state);
+    }
+}
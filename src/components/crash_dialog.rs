@@ -0,0 +1,83 @@
+use crate::common::diagnostics::{self, BundleOptions};
+use crate::common::timestamp::DisplayZone;
+use crate::common::i18n::{t, t_args};
+use crate::common::{crash, AppError};
+use crate::components::use_notifier;
+use dioxus::prelude::*;
+
+/// 上次运行崩溃时的提示对话框
+///
+/// 显示最近一次未确认的崩溃，关闭后标记为已确认，下次启动不再提示
+#[component]
+pub fn CrashReportDialog() -> Element {
+    let mut notifier = use_notifier();
+    let mut pending = use_signal(crash::pending_crash);
+    let mut show_backtrace = use_signal(|| false);
+
+    let Some((_, report)) = pending() else {
+        return rsx! {};
+    };
+    let occurred_at = DisplayZone::load().format(&report.occurred_at);
+
+    rsx! {
+        div {
+            style: "position:fixed; inset:0; z-index:1500; background:rgba(2,6,23,0.7); display:flex; align-items:center; justify-content:center; padding:24px;",
+
+            div {
+                style: "width:100%; max-width:640px; max-height:80vh; display:flex; flex-direction:column; gap:14px; background:#0f172a; border:1px solid rgba(248,113,113,0.35); border-radius:16px; padding:24px; box-shadow:0 20px 50px rgba(2,6,23,0.6);",
+
+                h2 {
+                    style: "margin:0; font-size:18px; font-weight:700; color:#f8fafc;",
+                    {t("crash.title")}
+                }
+                p {
+                    style: "margin:0; font-size:13px; color:#94a3b8; line-height:1.6;",
+                    {t("crash.saved_to_logs")}
+                }
+
+                div {
+                    style: "display:flex; flex-direction:column; gap:6px; font-size:13px; color:#cbd5e1;",
+                    span { {t_args("crash.occurred_at", &[&occurred_at])} }
+                    span { {t_args("crash.version", &[&report.app_version])} }
+                    span { style: "word-break:break-all;", "{report.summary()}" }
+                }
+
+                button {
+                    style: "align-self:flex-start; padding:0; border:none; background:transparent; color:#818cf8; cursor:pointer; font-size:13px;",
+                    onclick: move |_| show_backtrace.toggle(),
+                    if show_backtrace() { {t("crash.hide_backtrace")} } else { {t("crash.show_backtrace")} }
+                }
+                if show_backtrace() {
+                    pre {
+                        style: "margin:0; padding:10px 12px; background:rgba(2,6,23,0.8); border-radius:10px; font-size:11px; color:#cbd5e1; white-space:pre-wrap; word-break:break-all; overflow:auto; flex:1 1 auto; min-height:0;",
+                        "{report.backtrace}"
+                    }
+                }
+
+                div {
+                    style: "display:flex; gap:12px; justify-content:flex-end;",
+                    button {
+                        style: "padding:10px 20px; border-radius:10px; border:1px solid rgba(148,163,184,0.35); background:transparent; color:#cbd5e1; font-weight:500; cursor:pointer;",
+                        onclick: move |_| {
+                            let result = diagnostics::default_bundle_path()
+                                .ok_or_else(|| AppError::Unknown(t("crash.no_documents_dir").to_string()))
+                                .and_then(|path| diagnostics::create_bundle(&path, &BundleOptions::default()).map(|_| path));
+                            if let Some(path) = notifier.report_error(result, t("crash.bundle_failed")) {
+                                notifier.success(t_args("crash.bundle_saved", &[&path.display()]));
+                            }
+                        },
+                        {t("crash.create_bundle")}
+                    }
+                    button {
+                        style: "padding:10px 20px; border-radius:10px; border:none; background:linear-gradient(120deg,#4f46e5,#7c3aed); color:white; font-weight:600; cursor:pointer;",
+                        onclick: move |_| {
+                            notifier.report_error(crash::acknowledge_crashes(), t("crash.acknowledge_failed"));
+                            pending.set(None);
+                        },
+                        {t("common.close")}
+                    }
+                }
+            }
+        }
+    }
+}
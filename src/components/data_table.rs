@@ -0,0 +1,602 @@
+//! 通用数据表格
+//!
+//! - 虚拟滚动：行高固定，只渲染可视区域附近的行
+//! - 点击表头排序，拖动表头右边缘调整列宽，在「列」菜单中隐藏列
+//! - 分页按钮只显示首尾页和当前页附近的页码
+//! - 勾选行：选中的行号保存在 `TableState::selected`
+//!
+//! 单元格由 `render_cell((行号, 列 id))` 渲染，行号是调用方数据中的下标，不受排序和分页影响；
+//! 需要 hook 的单元格应封装为组件。
+
+use crate::common::i18n::{self, t, t_args};
+use dioxus::prelude::*;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::ops::Range;
+
+/// 可视区域上下额外渲染的行数，快速滚动时不出现空白
+const OVERSCAN_ROWS: usize = 6;
+/// 分页按钮中当前页两侧显示的页码数
+const PAGE_WINDOW_RADIUS: usize = 2;
+/// 拖动调整列宽时的最小宽度（px）
+const MIN_COLUMN_WIDTH: u32 = 60;
+/// 占用剩余宽度的列的最小宽度（px）
+const FLEX_COLUMN_MIN_WIDTH: u32 = 160;
+/// 勾选列宽度（px）
+const SELECT_COLUMN_WIDTH: u32 = 44;
+
+/// 列定义
+#[derive(Clone, Debug, PartialEq)]
+pub struct Column {
+    pub id: &'static str,
+    pub label: &'static str,
+    /// 初始宽度（px），0 表示占用剩余宽度（不能拖动调整）
+    pub width: u32,
+    pub sortable: bool,
+    pub hideable: bool,
+    pub centered: bool,
+}
+
+impl Column {
+    pub fn new(id: &'static str, label: &'static str, width: u32) -> Self {
+        Self { id, label, width, sortable: false, hideable: true, centered: false }
+    }
+
+    pub fn sortable(mut self) -> Self {
+        self.sortable = true;
+        self
+    }
+
+    /// 不出现在「列」菜单中，始终显示
+    pub fn always_visible(mut self) -> Self {
+        self.hideable = false;
+        self
+    }
+
+    pub fn centered(mut self) -> Self {
+        self.centered = true;
+        self
+    }
+}
+
+/// 排序用的值，数字排在文本之前
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SortKey {
+    Number(i64),
+    Text(String),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SortState {
+    pub column: &'static str,
+    pub ascending: bool,
+}
+
+/// 表格状态，由调用方持有（`use_table_state`），便于读取选中行或从外部改变排序
+#[derive(Clone, Debug, PartialEq)]
+pub struct TableState {
+    pub sort: Option<SortState>,
+    /// 选中的行号；数据增删后需要调用方清空
+    pub selected: BTreeSet<usize>,
+    /// 当前页，从 1 开始
+    pub page: usize,
+    /// 每页条数，0 表示不分页（全部行虚拟滚动）
+    pub page_size: usize,
+    widths: HashMap<&'static str, u32>,
+    hidden: HashSet<&'static str>,
+}
+
+impl TableState {
+    pub fn new(page_size: usize) -> Self {
+        Self {
+            sort: None,
+            selected: BTreeSet::new(),
+            page: 1,
+            page_size,
+            widths: HashMap::new(),
+            hidden: HashSet::new(),
+        }
+    }
+
+    pub fn sorted_by(mut self, column: &'static str, ascending: bool) -> Self {
+        self.sort = Some(SortState { column, ascending });
+        self
+    }
+
+    /// 数据重新加载后回到第一页并清空选择
+    pub fn reset(&mut self) {
+        self.page = 1;
+        self.selected.clear();
+    }
+
+    /// 点击表头：同一列切换升降序，其他列从升序开始
+    pub fn toggle_sort(&mut self, column: &'static str) {
+        self.sort = Some(match self.sort {
+            Some(sort) if sort.column == column => SortState { column, ascending: !sort.ascending },
+            _ => SortState { column, ascending: true },
+        });
+    }
+
+    fn width_of(&self, column: &Column) -> u32 {
+        self.widths.get(column.id).copied().unwrap_or(column.width)
+    }
+}
+
+pub fn use_table_state(init: impl FnOnce() -> TableState) -> Signal<TableState> {
+    use_signal(init)
+}
+
+/// 排序后的行号（稳定排序，未排序时为原顺序）
+pub fn sorted_rows(
+    row_count: usize,
+    sort: Option<SortState>,
+    sort_key: impl Fn(usize, &'static str) -> SortKey,
+) -> Vec<usize> {
+    let mut rows: Vec<usize> = (0..row_count).collect();
+    if let Some(sort) = sort {
+        let mut keyed: Vec<(SortKey, usize)> = rows.iter().map(|&row| (sort_key(row, sort.column), row)).collect();
+        keyed.sort_by(|a, b| if sort.ascending { a.0.cmp(&b.0) } else { b.0.cmp(&a.0) });
+        rows = keyed.into_iter().map(|(_, row)| row).collect();
+    }
+    rows
+}
+
+/// 总页数，至少为 1
+pub fn page_count(row_count: usize, page_size: usize) -> usize {
+    if page_size == 0 {
+        1
+    } else {
+        row_count.div_ceil(page_size).max(1)
+    }
+}
+
+/// 需要渲染的行范围（含上下额外的 `OVERSCAN_ROWS` 行）
+pub fn visible_range(scroll_top: f64, viewport_height: u32, row_height: u32, row_count: usize) -> Range<usize> {
+    let row_height = row_height.max(1) as f64;
+    let first = (scroll_top.max(0.0) / row_height).floor() as usize;
+    let visible = (viewport_height as f64 / row_height).ceil() as usize;
+    let start = first.saturating_sub(OVERSCAN_ROWS).min(row_count);
+    let end = (first + visible + OVERSCAN_ROWS).min(row_count);
+    start..end
+}
+
+/// 分页按钮：首页、末页和当前页两侧的页码，`None` 表示省略号（只省略一页时直接显示该页）
+pub fn page_window(current: usize, total: usize) -> Vec<Option<usize>> {
+    let low = current.saturating_sub(PAGE_WINDOW_RADIUS).max(1);
+    let high = (current + PAGE_WINDOW_RADIUS).min(total);
+    let mut pages = Vec::new();
+    if low > 1 {
+        pages.push(Some(1));
+        match low {
+            2 => {}
+            3 => pages.push(Some(2)),
+            _ => pages.push(None),
+        }
+    }
+    pages.extend((low..=high).map(Some));
+    if high < total {
+        match total - high {
+            1 => {}
+            2 => pages.push(Some(total - 1)),
+            _ => pages.push(None),
+        }
+        pages.push(Some(total));
+    }
+    pages
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct DataTableProps {
+    pub columns: Vec<Column>,
+    pub row_count: usize,
+    pub state: Signal<TableState>,
+    /// 渲染单元格：(行号, 列 id)
+    pub render_cell: Callback<(usize, &'static str), Element>,
+    /// 排序值：(行号, 列 id)，未提供时可排序列按行号排序
+    #[props(optional)]
+    pub sort_key: Option<Callback<(usize, &'static str), SortKey>>,
+    /// 显示勾选列
+    #[props(default)]
+    pub selectable: bool,
+    /// 行高（px），内容超出时被截断
+    #[props(default = 48)]
+    pub row_height: u32,
+    /// 表体最大高度（px），超出时滚动
+    #[props(default = 520)]
+    pub viewport_height: u32,
+    /// 每页条数选项（0 表示全部），为空时不显示选择框
+    #[props(default)]
+    pub page_sizes: Vec<usize>,
+    /// 工具栏右侧的额外内容，如批量操作按钮
+    #[props(default)]
+    pub children: Element,
+}
+
+#[component]
+pub fn DataTable(props: DataTableProps) -> Element {
+    let mut state = props.state;
+    let mut scroll_top = use_signal(|| 0.0f64);
+    // (列 id, 开始拖动时鼠标的 x 坐标, 开始时的列宽)
+    let mut resizing = use_signal(|| Option::<(&'static str, f64, u32)>::None);
+    let mut show_column_menu = use_signal(|| false);
+
+    let snapshot = state.read().clone();
+    let columns: Vec<Column> = props
+        .columns
+        .iter()
+        .filter(|column| !snapshot.hidden.contains(column.id))
+        .cloned()
+        .collect();
+
+    let sort_key = props.sort_key;
+    let order = sorted_rows(props.row_count, snapshot.sort, |row, column| match sort_key {
+        Some(sort_key) => sort_key.call((row, column)),
+        None => SortKey::Number(row as i64),
+    });
+    let total_pages = page_count(order.len(), snapshot.page_size);
+    let page = snapshot.page.clamp(1, total_pages);
+    let page_rows: Vec<usize> = if snapshot.page_size == 0 {
+        order.clone()
+    } else {
+        order.iter().copied().skip((page - 1) * snapshot.page_size).take(snapshot.page_size).collect()
+    };
+    let first_index = if page_rows.is_empty() { 0 } else { (page - 1) * snapshot.page_size + 1 };
+    let last_index = (first_index + page_rows.len()).saturating_sub(1);
+
+    let row_height = props.row_height;
+    let range = visible_range(scroll_top(), props.viewport_height, row_height, page_rows.len());
+    let visible_rows: Vec<(usize, usize)> = page_rows[range.clone()]
+        .iter()
+        .enumerate()
+        .map(|(offset, &row)| (range.start + offset, row))
+        .collect();
+    let body_height = page_rows.len() as u32 * row_height;
+
+    let mut template: Vec<String> = Vec::new();
+    let mut min_width = 0;
+    if props.selectable {
+        template.push(format!("{}px", SELECT_COLUMN_WIDTH));
+        min_width += SELECT_COLUMN_WIDTH;
+    }
+    for column in &columns {
+        match snapshot.width_of(column) {
+            0 => {
+                template.push(format!("minmax({}px, 1fr)", FLEX_COLUMN_MIN_WIDTH));
+                min_width += FLEX_COLUMN_MIN_WIDTH;
+            }
+            width => {
+                template.push(format!("{}px", width));
+                min_width += width;
+            }
+        }
+    }
+    let template = template.join(" ");
+
+    let all_selected = !order.is_empty() && order.iter().all(|row| snapshot.selected.contains(row));
+    let selected_count = snapshot.selected.len();
+    let total = i18n::current().format_number(order.len());
+    let hideable: Vec<Column> = props.columns.iter().filter(|column| column.hideable).cloned().collect();
+    let render_cell = props.render_cell;
+
+    rsx! {
+        div {
+            style: "display:flex; flex-direction:column; gap:12px;",
+
+            // 工具栏
+            div {
+                style: "display:flex; justify-content:space-between; align-items:center; gap:12px; flex-wrap:wrap; padding:0 4px;",
+                div {
+                    style: "font-size:13px; color:var(--fb-text-muted);",
+                    if snapshot.page_size == 0 || page_rows.is_empty() {
+                        {t_args("table.total", &[&total])}
+                    } else {
+                        {t_args("table.total_range", &[&total, &first_index, &last_index])}
+                    }
+                    if selected_count > 0 {
+                        {t_args("table.selected", &[&selected_count])}
+                    }
+                }
+                div {
+                    style: "display:flex; align-items:center; gap:12px;",
+                    {props.children}
+
+                    if !hideable.is_empty() {
+                        div {
+                            style: "position:relative;",
+                            button {
+                                style: "padding:6px 12px; border-radius:6px; border:1px solid var(--fb-border-strong); background:var(--fb-surface); color:var(--fb-text-secondary); font-size:13px; cursor:pointer;",
+                                onclick: move |_| show_column_menu.toggle(),
+                                {t("table.columns")}
+                            }
+                            if show_column_menu() {
+                                div {
+                                    style: "position:absolute; right:0; top:100%; margin-top:6px; z-index:20; background:var(--fb-surface); border:1px solid var(--fb-border); border-radius:10px; padding:8px 12px; box-shadow:0 10px 30px rgba(15,23,42,0.12); display:flex; flex-direction:column; gap:6px; min-width:140px;",
+                                    onmouseleave: move |_| show_column_menu.set(false),
+                                    for Column { id, label, .. } in hideable {
+                                        label {
+                                            key: "{id}",
+                                            style: "display:flex; align-items:center; gap:8px; font-size:13px; color:var(--fb-text-secondary); cursor:pointer; white-space:nowrap;",
+                                            input {
+                                                r#type: "checkbox",
+                                                checked: !snapshot.hidden.contains(id),
+                                                onchange: move |evt| {
+                                                    let mut state = state.write();
+                                                    if evt.checked() {
+                                                        state.hidden.remove(id);
+                                                    } else {
+                                                        state.hidden.insert(id);
+                                                    }
+                                                },
+                                            }
+                                            "{label}"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    if !props.page_sizes.is_empty() {
+                        span { style: "font-size:13px; color:var(--fb-text-muted);", {t("table.page_size")} }
+                        select {
+                            value: snapshot.page_size.to_string(),
+                            onchange: move |evt| {
+                                if let Ok(size) = evt.value().parse::<usize>() {
+                                    let mut state = state.write();
+                                    state.page_size = size;
+                                    state.page = 1;
+                                    scroll_top.set(0.0);
+                                }
+                            },
+                            style: "padding:6px 10px; border-radius:6px; border:1px solid var(--fb-border-strong); font-size:13px; background:var(--fb-surface); cursor:pointer;",
+                            for size in props.page_sizes.clone() {
+                                option {
+                                    value: "{size}",
+                                    if size == 0 { {t("table.all")} } else { {t_args("table.size_option", &[&size])} }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            div {
+                style: "background:var(--fb-surface); border-radius:12px; border:1px solid var(--fb-border); overflow-x:auto;",
+                onmousemove: move |evt| {
+                    if let Some((column, start_x, start_width)) = resizing() {
+                        let width = (start_width as f64 + evt.client_coordinates().x - start_x).max(MIN_COLUMN_WIDTH as f64);
+                        state.write().widths.insert(column, width as u32);
+                    }
+                },
+                onmouseup: move |_| resizing.set(None),
+                onmouseleave: move |_| resizing.set(None),
+
+                div {
+                    style: "min-width:{min_width}px;",
+
+                    // 表头
+                    div {
+                        style: "display:grid; grid-template-columns:{template}; background:var(--fb-surface-muted); border-bottom:2px solid var(--fb-border); user-select:none;",
+                        if props.selectable {
+                            div {
+                                style: "display:flex; align-items:center; justify-content:center; padding:12px 0;",
+                                input {
+                                    r#type: "checkbox",
+                                    checked: all_selected,
+                                    onchange: {
+                                        let order = order.clone();
+                                        move |evt: FormEvent| {
+                                            let mut state = state.write();
+                                            if evt.checked() {
+                                                state.selected = order.iter().copied().collect();
+                                            } else {
+                                                state.selected.clear();
+                                            }
+                                        }
+                                    },
+                                }
+                            }
+                        }
+                        for column in columns.clone() {
+                            {
+                                let indicator = match snapshot.sort {
+                                    Some(sort) if sort.column == column.id => if sort.ascending { " ▲" } else { " ▼" },
+                                    _ => "",
+                                };
+                                let width = snapshot.width_of(&column);
+                                let (id, sortable) = (column.id, column.sortable);
+                                rsx! {
+                                    div {
+                                        key: "{id}",
+                                        style: if column.centered {
+                                            "position:relative; padding:12px 16px; font-size:13px; font-weight:600; color:var(--fb-text-secondary); text-align:center; white-space:nowrap; overflow:hidden;"
+                                        } else {
+                                            "position:relative; padding:12px 16px; font-size:13px; font-weight:600; color:var(--fb-text-secondary); text-align:left; white-space:nowrap; overflow:hidden;"
+                                        },
+                                        span {
+                                            style: if sortable { "cursor:pointer;" } else { "" },
+                                            onclick: move |_| {
+                                                if sortable {
+                                                    state.write().toggle_sort(id);
+                                                }
+                                            },
+                                            "{column.label}{indicator}"
+                                        }
+                                        if width > 0 {
+                                            div {
+                                                style: "position:absolute; right:0; top:0; bottom:0; width:6px; cursor:col-resize;",
+                                                onmousedown: move |evt| {
+                                                    evt.stop_propagation();
+                                                    resizing.set(Some((id, evt.client_coordinates().x, width)));
+                                                },
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    // 表体：只渲染可视区域附近的行；换页时重新挂载以回到顶部
+                    div {
+                        key: "{page}-{snapshot.page_size}",
+                        style: "max-height:{props.viewport_height}px; overflow-y:auto;",
+                        onscroll: move |evt| scroll_top.set(evt.scroll_top()),
+                        div {
+                            style: "position:relative; height:{body_height}px;",
+                            for (position, row) in visible_rows {
+                                {
+                                    let selected = snapshot.selected.contains(&row);
+                                    let top = position as u32 * row_height;
+                                    rsx! {
+                                        div {
+                                            key: "{row}",
+                                            style: if selected {
+                                                "position:absolute; left:0; right:0; top:{top}px; height:{row_height}px; display:grid; grid-template-columns:{template}; align-items:center; border-bottom:1px solid var(--fb-border); background:var(--fb-primary-soft);"
+                                            } else {
+                                                "position:absolute; left:0; right:0; top:{top}px; height:{row_height}px; display:grid; grid-template-columns:{template}; align-items:center; border-bottom:1px solid var(--fb-border);"
+                                            },
+                                            if props.selectable {
+                                                div {
+                                                    style: "display:flex; align-items:center; justify-content:center;",
+                                                    input {
+                                                        r#type: "checkbox",
+                                                        checked: selected,
+                                                        onchange: move |evt| {
+                                                            let mut state = state.write();
+                                                            if evt.checked() {
+                                                                state.selected.insert(row);
+                                                            } else {
+                                                                state.selected.remove(&row);
+                                                            }
+                                                        },
+                                                    }
+                                                }
+                                            }
+                                            for column in columns.iter() {
+                                                div {
+                                                    key: "{column.id}",
+                                                    style: if column.centered {
+                                                        "padding:0 16px; min-width:0; overflow:hidden; text-align:center;"
+                                                    } else {
+                                                        "padding:0 16px; min-width:0; overflow:hidden;"
+                                                    },
+                                                    {render_cell.call((row, column.id))}
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            if snapshot.page_size > 0 && total_pages > 1 {
+                Pagination {
+                    current_page: page,
+                    total_pages,
+                    on_change: move |page| {
+                        state.write().page = page;
+                        scroll_top.set(0.0);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 分页按钮，页数很多时只显示首尾页和当前页附近的页码
+#[component]
+pub fn Pagination(current_page: usize, total_pages: usize, on_change: EventHandler<usize>) -> Element {
+    rsx! {
+        div {
+            style: "display:flex; justify-content:center; align-items:center; gap:8px; margin-top:4px;",
+            button {
+                style: "padding:8px 14px; border-radius:8px; border:1px solid var(--fb-border-strong); background:var(--fb-surface); color:var(--fb-text-muted); font-weight:500; cursor:pointer; font-size:13px;",
+                disabled: current_page <= 1,
+                onclick: move |_| on_change.call(current_page.saturating_sub(1).max(1)),
+                {t("table.previous")}
+            }
+            for (index, page) in page_window(current_page, total_pages).into_iter().enumerate() {
+                match page {
+                    Some(page) => rsx! {
+                        button {
+                            key: "{index}",
+                            style: if page == current_page {
+                                "padding:8px 12px; border-radius:8px; background:#4f46e5; color:white; font-weight:600; cursor:pointer; border:none; font-size:13px;"
+                            } else {
+                                "padding:8px 12px; border-radius:8px; background:var(--fb-surface); color:var(--fb-text-muted); font-weight:500; cursor:pointer; border:1px solid var(--fb-border-strong); font-size:13px;"
+                            },
+                            onclick: move |_| on_change.call(page),
+                            "{page}"
+                        }
+                    },
+                    None => rsx! {
+                        span { key: "{index}", style: "padding:0 4px; color:var(--fb-text-subtle);", "…" }
+                    },
+                }
+            }
+            button {
+                style: "padding:8px 14px; border-radius:8px; border:1px solid var(--fb-border-strong); background:var(--fb-surface); color:var(--fb-text-muted); font-weight:500; cursor:pointer; font-size:13px;",
+                disabled: current_page >= total_pages,
+                onclick: move |_| on_change.call((current_page + 1).min(total_pages)),
+                {t("table.next")}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_page_window() {
+        let pages = |current, total| {
+            page_window(current, total)
+                .into_iter()
+                .map(|page| page.map_or("…".to_string(), |page| page.to_string()))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        assert_eq!(pages(1, 1), "1");
+        assert_eq!(pages(1, 5), "1 2 3 4 5");
+        assert_eq!(pages(1, 1000), "1 2 3 … 1000");
+        assert_eq!(pages(500, 1000), "1 … 498 499 500 501 502 … 1000");
+        assert_eq!(pages(4, 1000), "1 2 3 4 5 6 … 1000");
+        assert_eq!(pages(5, 1000), "1 2 3 4 5 6 7 … 1000");
+        assert_eq!(pages(6, 1000), "1 … 4 5 6 7 8 … 1000");
+        assert_eq!(pages(1000, 1000), "1 … 998 999 1000");
+    }
+
+    #[test]
+    fn test_visible_range() {
+        assert_eq!(visible_range(0.0, 480, 48, 10_000), 0..16);
+        assert_eq!(visible_range(48.0 * 100.0, 480, 48, 10_000), 94..116);
+        assert_eq!(visible_range(48.0 * 9_999.0, 480, 48, 10_000), 9_993..10_000);
+        assert_eq!(visible_range(0.0, 480, 48, 3), 0..3);
+        assert_eq!(visible_range(10_000.0, 480, 48, 0), 0..0);
+        assert_eq!(page_count(0, 50), 1);
+        assert_eq!(page_count(101, 50), 3);
+        assert_eq!(page_count(101, 0), 1);
+    }
+
+    #[test]
+    fn test_sorted_rows_and_toggle() {
+        let names = ["b", "a", "c", "a"];
+        let key = |row: usize, _: &'static str| SortKey::Text(names[row].to_string());
+        assert_eq!(sorted_rows(4, None, key), [0, 1, 2, 3]);
+
+        let mut state = TableState::new(20);
+        state.toggle_sort("name");
+        assert_eq!(sorted_rows(4, state.sort, key), [1, 3, 0, 2]);
+        state.toggle_sort("name");
+        assert_eq!(sorted_rows(4, state.sort, key), [2, 0, 1, 3]);
+        state.toggle_sort("other");
+        assert_eq!(state.sort, Some(SortState { column: "other", ascending: true }));
+
+        assert!(SortKey::Number(10) > SortKey::Number(9));
+        assert!(SortKey::Number(i64::MAX) < SortKey::Text(String::new()));
+    }
+}
@@ -0,0 +1,74 @@
+use crate::common::i18n::{self, t, Lang};
+use crate::common::Result;
+use crate::components::Notifier;
+use dioxus::prelude::*;
+
+/// 界面语言（由 `App` 通过 context 提供，启动自检界面也可切换）
+///
+/// `t()` 读取的是全局的当前语言，不会订阅信号；布局按语言给主体区域加 key，
+/// 切换后页面组件重新挂载并用新语言渲染
+#[derive(Clone, Copy)]
+pub struct LanguageState {
+    pub lang: Signal<Lang>,
+}
+
+impl LanguageState {
+    /// 切换语言并保存到 config.db；保存失败时本次运行仍使用新语言
+    pub fn switch(&mut self, lang: Lang) -> Result<()> {
+        i18n::set_current(lang);
+        self.lang.set(lang);
+        lang.save()
+    }
+}
+
+/// 创建语言状态，`init` 返回启动时的语言
+pub fn use_language_provider(init: impl FnOnce() -> Lang) -> LanguageState {
+    use_context_provider(|| {
+        let lang = init();
+        i18n::set_current(lang);
+        LanguageState { lang: Signal::new(lang) }
+    })
+}
+
+/// 当前界面语言，切换时重新渲染调用的组件
+pub fn use_language() -> Lang {
+    (use_context::<LanguageState>().lang)()
+}
+
+/// 标题栏上的语言切换按钮：在中文和英文之间切换
+#[component]
+pub fn LanguageSwitch(style: &'static str) -> Element {
+    let mut language = use_context::<LanguageState>();
+    let notifier = try_use_context::<Notifier>();
+    let next = match (language.lang)() {
+        Lang::Zh => Lang::En,
+        Lang::En => Lang::Zh,
+    };
+    let title = format!("{} · {}", t("titlebar.language"), next.native_name());
+    let label = match next {
+        Lang::Zh => "中",
+        Lang::En => "EN",
+    };
+
+    rsx! {
+        button {
+            style,
+            title,
+            onpointerdown: move |evt| evt.stop_propagation(),
+            onclick: move |_| {
+                let result = language.switch(next);
+                match notifier {
+                    Some(mut notifier) => {
+                        notifier.report_error(result, t("titlebar.language_save_failed"));
+                    }
+                    None => {
+                        if let Err(e) = result {
+                            eprintln!("{}: {}", t("titlebar.language_save_failed"), e);
+                        }
+                    }
+                }
+            },
+            "{label}"
+        }
+    }
+}
@@ -0,0 +1,183 @@
+use crate::common::app_lock::{self, AppLockSettings};
+use crate::common::i18n::{t, t_args};
+use dioxus::prelude::*;
+use std::time::{Duration, Instant};
+
+/// 空闲检测的轮询间隔
+const IDLE_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+/// 应用锁状态（由 `Sidebar` 通过 context 提供）
+///
+/// 锁定时布局不再渲染 `Outlet`，页面组件连同其中已加载的配置一起被卸载，
+/// 解锁后重新从数据库读取
+#[derive(Clone, Copy)]
+pub struct AppLockState {
+    pub locked: Signal<bool>,
+    pub settings: Signal<AppLockSettings>,
+    /// 读取设置失败的原因，显示在锁定界面上
+    load_error: Signal<Option<String>>,
+    // 只在空闲检测中 peek，写入不会触发重新渲染
+    last_activity: Signal<Instant>,
+}
+
+/// 读取设置；失败时按已启用处理（fail closed），返回失败原因
+fn load_settings() -> (AppLockSettings, Option<String>) {
+    match AppLockSettings::load() {
+        Ok(settings) => (settings, None),
+        Err(e) => (AppLockSettings::fail_closed(), Some(t_args("lock.settings_failed", &[&e]))),
+    }
+}
+
+impl AppLockState {
+    fn new() -> Self {
+        let (settings, load_error) = load_settings();
+        Self {
+            // 已设置口令（或无法确认是否设置）时，启动即进入锁定状态
+            locked: Signal::new(settings.enabled),
+            settings: Signal::new(settings),
+            load_error: Signal::new(load_error),
+            last_activity: Signal::new(Instant::now()),
+        }
+    }
+
+    /// 记录一次用户操作，重置空闲计时
+    pub fn touch(&mut self) {
+        self.last_activity.set(Instant::now());
+    }
+
+    /// 锁定应用（未设置口令时无效）
+    pub fn lock(&mut self) {
+        if self.settings.peek().enabled && !*self.locked.peek() {
+            self.locked.set(true);
+        }
+    }
+
+    /// 最小化窗口时按设置决定是否锁定
+    pub fn lock_on_minimize(&mut self) {
+        if self.settings.peek().lock_on_minimize {
+            self.lock();
+        }
+    }
+
+    pub fn unlock(&mut self) {
+        self.touch();
+        self.locked.set(false);
+    }
+
+    /// 重新读取设置（在配置页修改后调用）
+    pub fn reload_settings(&mut self) {
+        let (settings, load_error) = load_settings();
+        self.settings.set(settings);
+        self.load_error.set(load_error);
+    }
+
+    fn is_idle(&self) -> bool {
+        let idle_minutes = self.settings.peek().idle_minutes;
+        idle_minutes > 0
+            && self.last_activity.peek().elapsed() >= Duration::from_secs(u64::from(idle_minutes) * 60)
+    }
+}
+
+/// 创建应用锁状态并启动空闲 / 最小化检测
+pub fn use_app_lock_provider() -> AppLockState {
+    let lock = use_context_provider(AppLockState::new);
+
+    use_future(move || {
+        let mut watcher = lock;
+        async move {
+            loop {
+                tokio::time::sleep(IDLE_CHECK_INTERVAL).await;
+                if *watcher.locked.peek() {
+                    continue;
+                }
+                if watcher.is_idle() {
+                    watcher.lock();
+                    continue;
+                }
+                #[cfg(feature = "desktop")]
+                {
+                    // 最小化或隐藏到托盘
+                    let window = dioxus_desktop::window();
+                    if window.is_minimized() || !window.is_visible() {
+                        watcher.lock_on_minimize();
+                    }
+                }
+            }
+        }
+    });
+
+    lock
+}
+
+/// 获取应用锁状态
+pub fn use_app_lock() -> AppLockState {
+    use_context::<AppLockState>()
+}
+
+/// 锁定界面：输入口令解锁
+#[component]
+pub fn LockScreen() -> Element {
+    let mut lock = use_app_lock();
+    let mut passphrase = use_signal(String::new);
+    // 读取设置失败时先显示原因；数据库恢复后输入口令即可解锁（未设置口令时任意输入均可）
+    let mut error = use_signal(|| lock.load_error.peek().clone());
+
+    let mut try_unlock = move || {
+        match app_lock::verify_passphrase(&passphrase()) {
+            Ok(true) => {
+                passphrase.set(String::new());
+                error.set(None);
+                lock.unlock();
+            }
+            Ok(false) => {
+                passphrase.set(String::new());
+                error.set(Some(t("lock.wrong_passphrase").to_string()));
+            }
+            Err(e) => error.set(Some(t_args("lock.read_failed", &[&e]))),
+        }
+    };
+
+    rsx! {
+        div {
+            style: "flex:1 1 0; display:flex; align-items:center; justify-content:center; background:rgba(2,6,23,0.85);",
+
+            div {
+                style: "width:380px; background:linear-gradient(195deg,rgba(15,23,42,0.95),rgba(2,6,23,0.9)); border:1px solid rgba(148,163,184,0.2); border-radius:20px; padding:36px 32px; box-shadow:0 25px 50px rgba(0,0,0,0.5); display:flex; flex-direction:column; gap:16px; text-align:center;",
+
+                div { style: "font-size:44px;", "🔒" }
+                h2 {
+                    style: "margin:0; font-size:20px; font-weight:700; color:#f8fafc;",
+                    {t("lock.title")}
+                }
+                p {
+                    style: "margin:0; font-size:13px; color:#94a3b8;",
+                    {t("lock.prompt")}
+                }
+                input {
+                    r#type: "password",
+                    autofocus: true,
+                    value: passphrase(),
+                    placeholder: t("lock.passphrase"),
+                    oninput: move |evt| passphrase.set(evt.value()),
+                    onkeydown: move |evt| {
+                        if evt.key() == Key::Enter {
+                            try_unlock();
+                        }
+                    },
+                    style: "padding:12px 14px; border-radius:10px; border:1px solid rgba(148,163,184,0.35); background:rgba(15,23,42,0.8); color:#f8fafc; font-size:14px; outline:none;",
+                }
+                if let Some(message) = error() {
+                    div {
+                        style: "font-size:13px; color:#f87171;",
+                        "{message}"
+                    }
+                }
+                button {
+                    style: "padding:12px; border-radius:10px; border:none; background:linear-gradient(120deg,#4f46e5,#7c3aed); color:white; font-weight:600; cursor:pointer; box-shadow:0 4px 12px rgba(79, 70, 229, 0.3);",
+                    onclick: move |_| try_unlock(),
+                    {t("lock.unlock")}
+                }
+            }
+        }
+    }
+}
@@ -1,3 +1,32 @@
+mod lock_screen;
+pub use lock_screen::{use_app_lock, LockScreen};
+
+mod data_table;
+pub use data_table::{use_table_state, Column, DataTable, Pagination, SortKey, SortState, TableState};
+
+mod toast;
+pub use toast::{use_notifier, Notifier, StartupToasts, Toast, ToastHost};
+
+mod language;
+pub use language::{use_language, use_language_provider};
+
+mod theme;
+pub use theme::use_theme_provider;
+
+mod ui;
+pub use ui::{Button, ButtonVariant, Card, Input, Modal};
+
+#[cfg(feature = "desktop")]
+mod window_shell;
+#[cfg(feature = "desktop")]
+pub use window_shell::{use_close_window, use_window_shell, WindowShellConfig};
+
 mod sidebar;
-pub use sidebar::Sidebar;
+pub use sidebar::{Sidebar, TitleBar};
+
+mod startup_check;
+pub use startup_check::StartupCheckScreen;
+
 
+mod crash_dialog;
+pub use crash_dialog::CrashReportDialog;
@@ -1,61 +1,109 @@
+use crate::common::i18n::t;
+use crate::common::log_query::LogQuery;
+use crate::components::language::{use_language, LanguageSwitch};
+use crate::components::lock_screen::{use_app_lock_provider, AppLockState};
+use crate::components::theme::ThemeSwitch;
+use crate::components::toast::use_notifier_provider;
+#[cfg(feature = "desktop")]
+use crate::components::use_close_window;
+use crate::components::{CrashReportDialog, LockScreen, ToastHost};
 use crate::Route;
 use dioxus::prelude::*;
 
 /// 布局组件：顶部自定义标题栏 + 左侧窄竖条图标栏 + 右侧内容区域
 #[component]
 pub fn Sidebar() -> Element {
+    // 应用锁：锁定时用锁屏替换主体区域（页面组件被卸载）
+    let mut lock = use_app_lock_provider();
+    let locked = lock.locked;
+
+    // 全局通知：页面通过 use_notifier() 报告保存结果和错误
+    use_notifier_provider();
+
+    let language = use_language();
+
     rsx! {
         div {
             style: "display:flex; flex-direction:column; height:100%; min-height:100vh; width:100%; background:transparent; color:#e2e8f0; font-family:-apple-system,BlinkMacSystemFont,'Segoe UI',sans-serif; box-sizing:border-box;",
+            onpointermove: move |_| lock.touch(),
+            onkeydown: move |_| lock.touch(),
 
-            // 顶部标题栏（含最小化/最大化/关闭）
+            // 顶部标题栏（含锁定/主题/语言/最小化/最大化/关闭）
             TitleBar {}
 
-            // 主体区域：左侧侧边栏 + 右侧内容
-            div {
-                style: "display:flex; flex:1 1 0; min-height:0; background:rgba(2,6,23,0.85); border-top:1px solid rgba(15,23,42,0.9); overflow:hidden;",
-
-                // 左侧窄侧边栏（仅图标）
+            // 切换语言后按语言重新挂载主体区域，页面用新语言渲染
+            for lang in [language] {
                 div {
-                    style: "width:80px; min-width:80px; max-width:80px; background:linear-gradient(195deg,rgba(15,23,42,0.95),rgba(2,6,23,0.8)); color:#f8fafc; display:flex; flex-direction:column; align-items:center; padding:16px 0; border-right:1px solid rgba(148,163,184,0.12); gap:12px; flex-shrink:0;",
+                    key: "{lang.id()}",
+                    style: "display:contents;",
 
-                    // 顶部 Logo - 用户头像
-                    UserAvatar { size: 48 }
+                    if locked() {
+                        LockScreen {}
+                    } else {
+                        // 主体区域：左侧侧边栏 + 右侧内容
+                        div {
+                            style: "display:flex; flex:1 1 0; min-height:0; background:rgba(2,6,23,0.85); border-top:1px solid rgba(15,23,42,0.9); overflow:hidden;",
 
-                    // 中间主导航图标
-                    nav {
-                        style: "display:flex; flex-direction:column; align-items:center; gap:10px; margin-top:12px; flex:1; width:100%; padding-inline:8px;",
+                            // 左侧窄侧边栏（仅图标）
+                            div {
+                                style: "width:80px; min-width:80px; max-width:80px; background:linear-gradient(195deg,rgba(15,23,42,0.95),rgba(2,6,23,0.8)); color:#f8fafc; display:flex; flex-direction:column; align-items:center; padding:16px 0; border-right:1px solid rgba(148,163,184,0.12); gap:12px; flex-shrink:0;",
 
-                        SidebarIcon { icon: "🏠", label: "首页", to: Some(Route::Home {}) }
-                        SidebarIcon { icon: "✈️", label: "航司报价", to: Some(Route::Airline {}) }
-                        SidebarIcon { icon: "🔤", label: "验证码识别", to: Some(Route::Captcha {}) }
-                        SidebarIcon { icon: "⚙️", label: "配置管理", to: Some(Route::ConfigView {}) }
-                        SidebarIcon { icon: "📋", label: "日志查看", to: Some(Route::Logs {}) }
-                    }
+                                // 顶部 Logo - 用户头像
+                                UserAvatar { size: 48 }
 
-                    // 底部辅助图标（帮助、通知等）
-                    div {
-                        style: "display:flex; flex-direction:column; align-items:center; gap:10px; padding-top:12px; border-top:1px solid rgba(30,41,59,0.65); width:100%; padding-inline:8px;",
-                        SidebarIcon { icon: "🔔", label: "通知", to: Some(Route::Notice {}) }
-                        SidebarIcon { icon: "❓", label: "帮助", to: Some(Route::Help {}) }
+                                // 中间主导航图标
+                                nav {
+                                    style: "display:flex; flex-direction:column; align-items:center; gap:10px; margin-top:12px; flex:1; width:100%; padding-inline:8px;",
+
+                                    SidebarIcon { icon: "🏠", label: t("nav.home"), to: Some(Route::Home {}) }
+                                    SidebarIcon { icon: "✈️", label: t("nav.airline"), to: Some(Route::Airline {}) }
+                                    SidebarIcon { icon: "🔤", label: t("nav.captcha"), to: Some(Route::Captcha {}) }
+                                    SidebarIcon { icon: "⚙️", label: t("nav.config"), to: Some(Route::ConfigView {}) }
+                                    SidebarIcon { icon: "📋", label: t("nav.logs"), to: Some(Route::Logs { query: LogQuery::default() }) }
+                                }
+
+                                // 底部辅助图标（帮助、通知等）
+                                div {
+                                    style: "display:flex; flex-direction:column; align-items:center; gap:10px; padding-top:12px; border-top:1px solid rgba(30,41,59,0.65); width:100%; padding-inline:8px;",
+                                    SidebarIcon { icon: "🔔", label: t("nav.notice"), to: Some(Route::Notice {}) }
+                                    SidebarIcon { icon: "❓", label: t("nav.help"), to: Some(Route::Help {}) }
+                                }
+                            }
+
+                            // 右侧内容区域，通过 Outlet 渲染当前路由页面
+                            div {
+                                style: "flex:1 1 0; min-width:0; width:0; padding:32px 40px; overflow:hidden; background:rgba(15,23,42,0.4); color:#e2e8f0; backdrop-filter:blur(30px); border-left:1px solid rgba(148,163,184,0.12); box-sizing:border-box;",
+                                Outlet::<Route> {}
+                            }
+                        }
                     }
                 }
+            }
 
-                // 右侧内容区域，通过 Outlet 渲染当前路由页面
-                div {
-                    style: "flex:1 1 0; min-width:0; width:0; padding:32px 40px; overflow:hidden; background:rgba(15,23,42,0.4); color:#e2e8f0; backdrop-filter:blur(30px); border-left:1px solid rgba(148,163,184,0.12); box-sizing:border-box;",
-                    Outlet::<Route> {}
-                }
+            // 上次崩溃的提示在解锁后显示（调用栈可能含有敏感信息）
+            if !locked() {
+                CrashReportDialog {}
             }
+
+            ToastHost {}
         }
     }
 }
 
-/// 自定义标题栏，带最小化 / 最大化 / 关闭按钮
+/// 自定义标题栏，带锁定 / 主题 / 语言切换 / 最小化 / 最大化 / 关闭按钮
+///
+/// 启动自检界面没有应用锁，此时不显示锁定按钮
 #[component]
-fn TitleBar() -> Element {
+pub fn TitleBar() -> Element {
     const VERSION: &str = env!("CARGO_PKG_VERSION");
-    
+    let lock = try_use_context::<AppLockState>();
+    let lock_enabled = lock.is_some_and(|lock| lock.settings.read().enabled);
+    let locked = lock.is_some_and(|lock| (lock.locked)());
+    // 切换语言时更新按钮提示
+    use_language();
+    #[cfg(feature = "desktop")]
+    let close_window = use_close_window();
+
     rsx! {
         div {
             style: "height:42px; display:flex; align-items:center; justify-content:space-between; padding:0 18px; background:linear-gradient(120deg,#020617,#020617,#0b1120); color:#e5e7eb; border-bottom:1px solid rgba(15,23,42,0.9); user-select:none; cursor:grab;",
@@ -90,11 +138,32 @@ fn TitleBar() -> Element {
             div {
                 style: "display:flex; align-items:center; gap:6px; font-size:11px;",
 
+                // 锁定（仅在设置了应用锁口令时显示）
+                if lock_enabled && !locked {
+                    button {
+                        style: TITLE_BTN_STYLE,
+                        title: t("titlebar.lock"),
+                        onpointerdown: move |evt| evt.stop_propagation(),
+                        onclick: move |_| {
+                            if let Some(mut lock) = lock {
+                                lock.lock();
+                            }
+                        },
+                        "🔒"
+                    }
+                }
+
+                ThemeSwitch { style: TITLE_BTN_STYLE }
+                LanguageSwitch { style: TITLE_BTN_STYLE }
+
                 // 最小化
                 button {
                     style: TITLE_BTN_STYLE,
                     onpointerdown: move |evt| evt.stop_propagation(),
                     onclick: move |_| {
+                        if let Some(mut lock) = lock {
+                            lock.lock_on_minimize();
+                        }
                         #[cfg(feature = "desktop")]
                         {
                             let window = dioxus_desktop::window();
@@ -124,11 +193,9 @@ fn TitleBar() -> Element {
                     style: TITLE_BTN_CLOSE_STYLE,
                     onpointerdown: move |evt| evt.stop_propagation(),
                     onclick: move |_| {
+                        // 启用托盘时隐藏到托盘
                         #[cfg(feature = "desktop")]
-                        {
-                            let window = dioxus_desktop::window();
-                            window.close();
-                        }
+                        close_window.call(());
                     },
                     "✕"
                 }
@@ -0,0 +1,171 @@
+use crate::common::i18n::{t, t_args};
+use crate::components::{use_language, TitleBar};
+use crate::db::health::{self, DbKind, DbProblem, DbStatus, StartupReport};
+use dioxus::prelude::*;
+
+/// 启动自检失败界面：说明问题和处理方式，提供恢复 / 重建 / 重新检查
+///
+/// 只有日志数据库有问题时允许忽略并继续（日志无法保存，但配置可正常使用）
+#[component]
+pub fn StartupCheckScreen(report: Signal<StartupReport>, on_continue: EventHandler<()>) -> Element {
+    // (是否成功, 提示信息)
+    let mut status = use_signal(|| Option::<(bool, String)>::None);
+    let config_backups = use_signal(|| health::list_config_backups().unwrap_or_default());
+    // 标题栏切换语言时重新渲染
+    use_language();
+
+    let logs_only = report
+        .read()
+        .databases
+        .iter()
+        .all(|db| db.problem.is_none() || db.kind == DbKind::Logs);
+
+    rsx! {
+        div {
+            style: "display:flex; flex-direction:column; height:100%; min-height:100vh; width:100%; background:rgba(2,6,23,0.95); color:#e2e8f0; font-family:-apple-system,BlinkMacSystemFont,'Segoe UI',sans-serif;",
+
+            TitleBar {}
+
+            div {
+                style: "flex:1 1 0; overflow-y:auto; display:flex; justify-content:center; padding:40px 24px;",
+
+                div {
+                    style: "width:100%; max-width:720px; display:flex; flex-direction:column; gap:18px;",
+
+                    div {
+                        h1 {
+                            style: "margin:0 0 8px 0; font-size:22px; font-weight:700; color:#f8fafc;",
+                            {t("startup.title")}
+                        }
+                        p {
+                            style: "margin:0; font-size:14px; color:#94a3b8; line-height:1.6;",
+                            {t("startup.description")}
+                        }
+                    }
+
+                    for db in report().databases {
+                        DatabaseStatusCard {
+                            status: db,
+                            has_backups: !config_backups().is_empty(),
+                            on_restore: move |_| match health::restore_latest_config_backup() {
+                                Ok(backup) => {
+                                    status.set(Some((true, t_args("startup.restored", &[&backup.display()]))));
+                                    report.set(health::run_startup_checks());
+                                }
+                                Err(e) => status.set(Some((false, t_args("startup.restore_failed", &[&e.user_message()])))),
+                            },
+                            on_rebuild: move |_| match health::rebuild_log_db() {
+                                Ok(()) => {
+                                    status.set(Some((true, t("startup.rebuilt").to_string())));
+                                    report.set(health::run_startup_checks());
+                                }
+                                Err(e) => status.set(Some((false, t_args("startup.rebuild_failed", &[&e.user_message()])))),
+                            },
+                        }
+                    }
+
+                    if let Some((ok, message)) = status() {
+                        div {
+                            style: if ok { "font-size:13px; color:#34d399;" } else { "font-size:13px; color:#f87171;" },
+                            "{message}"
+                        }
+                    }
+
+                    div {
+                        style: "display:flex; gap:12px; justify-content:flex-end;",
+
+                        if logs_only {
+                            button {
+                                style: "padding:10px 20px; border-radius:10px; border:1px solid rgba(148,163,184,0.35); background:transparent; color:#cbd5e1; font-weight:500; cursor:pointer;",
+                                onclick: move |_| on_continue.call(()),
+                                {t("startup.ignore")}
+                            }
+                        }
+                        button {
+                            style: "padding:10px 20px; border-radius:10px; border:none; background:linear-gradient(120deg,#4f46e5,#7c3aed); color:white; font-weight:600; cursor:pointer; box-shadow:0 4px 12px rgba(79, 70, 229, 0.3);",
+                            onclick: move |_| {
+                                status.set(None);
+                                report.set(health::run_startup_checks());
+                            },
+                            {t("startup.recheck")}
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn DatabaseStatusCard(
+    status: DbStatus,
+    has_backups: bool,
+    on_restore: EventHandler<()>,
+    on_rebuild: EventHandler<()>,
+) -> Element {
+    let path = status
+        .path
+        .as_ref()
+        .map(|path| path.display().to_string())
+        .unwrap_or_else(|| t("startup.unknown_path").to_string());
+
+    rsx! {
+        div {
+            style: "background:rgba(15,23,42,0.8); border:1px solid rgba(148,163,184,0.2); border-radius:14px; padding:18px 20px; display:flex; flex-direction:column; gap:8px;",
+
+            div {
+                style: "display:flex; align-items:center; justify-content:space-between; gap:12px;",
+                span {
+                    style: "font-size:15px; font-weight:600; color:#f8fafc;",
+                    "{status.kind.label()}"
+                }
+                if status.problem.is_none() {
+                    span { style: "font-size:13px; color:#34d399;", {t("startup.ok")} }
+                } else {
+                    span { style: "font-size:13px; color:#f87171;", {t("startup.broken")} }
+                }
+            }
+            div {
+                style: "font-size:12px; color:#64748b; font-family:monospace; word-break:break-all;",
+                "{path}"
+            }
+
+            if let Some(problem) = status.problem.clone() {
+                div {
+                    style: "font-size:13px; color:#fca5a5; word-break:break-all;",
+                    "{problem.description()}"
+                }
+                div {
+                    style: "font-size:13px; color:#cbd5e1; line-height:1.6;",
+                    "{problem.remedy(status.kind)}"
+                }
+
+                if matches!(problem, DbProblem::Corrupt(_)) {
+                    div {
+                        style: "display:flex; gap:10px; margin-top:4px;",
+                        if status.kind == DbKind::Config {
+                            if has_backups {
+                                button {
+                                    style: "padding:8px 16px; border-radius:8px; border:none; background:#4f46e5; color:white; font-weight:500; cursor:pointer; font-size:13px;",
+                                    onclick: move |_| on_restore.call(()),
+                                    {t("startup.restore_latest")}
+                                }
+                            } else {
+                                span {
+                                    style: "font-size:12px; color:#94a3b8;",
+                                    {t("startup.no_backups")}
+                                }
+                            }
+                        } else {
+                            button {
+                                style: "padding:8px 16px; border-radius:8px; border:none; background:#4f46e5; color:white; font-weight:500; cursor:pointer; font-size:13px;",
+                                onclick: move |_| on_rebuild.call(()),
+                                {t("startup.rebuild_logs")}
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
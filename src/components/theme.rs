@@ -0,0 +1,55 @@
+use crate::common::i18n::t;
+use crate::common::theme::Theme;
+use crate::common::Result;
+use crate::components::Notifier;
+use dioxus::prelude::*;
+
+/// 界面主题（由 `App` 通过 context 提供，根元素的 `data-theme` 随之变化）
+#[derive(Clone, Copy)]
+pub struct ThemeState {
+    pub theme: Signal<Theme>,
+}
+
+impl ThemeState {
+    /// 切换主题并保存到 config.db；保存失败时本次运行仍使用新主题
+    pub fn switch(&mut self, theme: Theme) -> Result<()> {
+        self.theme.set(theme);
+        theme.save()
+    }
+}
+
+/// 创建主题状态，`init` 返回启动时的主题
+pub fn use_theme_provider(init: impl FnOnce() -> Theme) -> ThemeState {
+    use_context_provider(|| ThemeState { theme: Signal::new(init()) })
+}
+
+/// 标题栏上的主题按钮：依次切换浅色、深色、跟随系统
+#[component]
+pub fn ThemeSwitch(style: &'static str) -> Element {
+    let mut state = use_context::<ThemeState>();
+    let notifier = try_use_context::<Notifier>();
+    let theme = (state.theme)();
+    let title = format!("{}: {}", t("titlebar.theme"), theme.label());
+
+    rsx! {
+        button {
+            style,
+            title,
+            onpointerdown: move |evt| evt.stop_propagation(),
+            onclick: move |_| {
+                let result = state.switch(theme.next());
+                match notifier {
+                    Some(mut notifier) => {
+                        notifier.report_error(result, t("titlebar.theme_save_failed"));
+                    }
+                    None => {
+                        if let Err(e) = result {
+                            eprintln!("{}: {}", t("titlebar.theme_save_failed"), e);
+                        }
+                    }
+                }
+            },
+            "{theme.icon()}"
+        }
+    }
+}
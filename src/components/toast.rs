@@ -0,0 +1,218 @@
+use crate::common::i18n::{t, t_args};
+use crate::common::{AppError, Result};
+use dioxus::core::spawn_forever;
+use dioxus::prelude::*;
+use std::time::Duration;
+
+/// 成功 / 提示类通知的显示时长
+const SHORT_TOAST_DURATION: Duration = Duration::from_secs(4);
+/// 错误类通知的显示时长（展开详情后不会自动关闭）
+const LONG_TOAST_DURATION: Duration = Duration::from_secs(10);
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum ToastKind {
+    Success,
+    Info,
+    Error,
+}
+
+/// 一条通知内容
+#[derive(Clone, PartialEq)]
+pub struct Toast {
+    pub kind: ToastKind,
+    pub message: String,
+    /// 技术细节，在「详情」中展开
+    pub details: Option<String>,
+}
+
+impl Toast {
+    pub fn success(message: impl Into<String>) -> Self {
+        Self { kind: ToastKind::Success, message: message.into(), details: None }
+    }
+
+    pub fn info(message: impl Into<String>) -> Self {
+        Self { kind: ToastKind::Info, message: message.into(), details: None }
+    }
+
+    /// 由 `AppError` 生成错误通知：`<操作>：<用户可读说明>`，原始错误放入详情
+    pub fn error(action: &str, error: &AppError) -> Self {
+        Self {
+            kind: ToastKind::Error,
+            message: t_args("toast.failure", &[&action, &error.user_message()]),
+            details: Some(error.to_string()),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq)]
+struct ToastEntry {
+    id: u64,
+    toast: Toast,
+    expanded: bool,
+}
+
+/// 启动阶段（布局挂载前）产生的通知，由 `App` 通过 context 交给 `Sidebar` 显示
+#[derive(Clone)]
+pub struct StartupToasts(pub Vec<Toast>);
+
+/// 全局通知（由 `Sidebar` 通过 context 提供）
+#[derive(Clone, Copy)]
+pub struct Notifier {
+    toasts: Signal<Vec<ToastEntry>>,
+    next_id: Signal<u64>,
+}
+
+impl Notifier {
+    fn new() -> Self {
+        Self {
+            toasts: Signal::new(Vec::new()),
+            next_id: Signal::new(0),
+        }
+    }
+
+    pub fn notify(&mut self, toast: Toast) {
+        let id = *self.next_id.peek();
+        self.next_id.set(id + 1);
+
+        let duration = match toast.kind {
+            ToastKind::Error => LONG_TOAST_DURATION,
+            _ => SHORT_TOAST_DURATION,
+        };
+        self.toasts.write().push(ToastEntry { id, toast, expanded: false });
+
+        // 在根作用域计时，发出通知的页面被卸载后仍能按时关闭
+        let mut toasts = self.toasts;
+        spawn_forever(async move {
+            tokio::time::sleep(duration).await;
+            // 正在查看详情的通知保留，等用户手动关闭
+            toasts.write().retain(|toast| toast.id != id || toast.expanded);
+        });
+    }
+
+    pub fn success(&mut self, message: impl Into<String>) {
+        self.notify(Toast::success(message));
+    }
+
+    pub fn info(&mut self, message: impl Into<String>) {
+        self.notify(Toast::info(message));
+    }
+
+    pub fn error(&mut self, action: &str, error: &AppError) {
+        self.notify(Toast::error(action, error));
+    }
+
+    /// 根据操作结果发出成功或失败通知，返回成功时的值
+    pub fn report<T>(&mut self, result: Result<T>, success: &str, action: &str) -> Option<T> {
+        match result {
+            Ok(value) => {
+                self.success(success);
+                Some(value)
+            }
+            Err(e) => {
+                self.error(action, &e);
+                None
+            }
+        }
+    }
+
+    /// 只在失败时通知（用于加载等不需要成功提示的操作）
+    pub fn report_error<T>(&mut self, result: Result<T>, action: &str) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(e) => {
+                self.error(action, &e);
+                None
+            }
+        }
+    }
+
+    fn dismiss(&mut self, id: u64) {
+        self.toasts.write().retain(|toast| toast.id != id);
+    }
+
+    fn toggle_details(&mut self, id: u64) {
+        if let Some(toast) = self.toasts.write().iter_mut().find(|toast| toast.id == id) {
+            toast.expanded = !toast.expanded;
+        }
+    }
+}
+
+/// 创建全局通知，并显示启动阶段留下的通知
+pub fn use_notifier_provider() -> Notifier {
+    let mut notifier = use_context_provider(Notifier::new);
+    use_hook(move || {
+        if let Some(StartupToasts(toasts)) = try_consume_context::<StartupToasts>() {
+            for toast in toasts {
+                notifier.notify(toast);
+            }
+        }
+    });
+    notifier
+}
+
+/// 获取全局通知
+pub fn use_notifier() -> Notifier {
+    use_context::<Notifier>()
+}
+
+/// 通知列表（固定在窗口右下角）
+#[component]
+pub fn ToastHost() -> Element {
+    let mut notifier = use_notifier();
+    let toasts = notifier.toasts;
+
+    rsx! {
+        div {
+            style: "position:fixed; right:24px; bottom:24px; z-index:2000; display:flex; flex-direction:column; gap:10px; width:360px; pointer-events:none;",
+
+            for toast in toasts() {
+                div {
+                    key: "{toast.id}",
+                    style: match toast.toast.kind {
+                        ToastKind::Success => "pointer-events:auto; background:#ecfdf5; border:1px solid #6ee7b7; border-radius:12px; padding:12px 14px; box-shadow:0 10px 25px rgba(2,6,23,0.35); color:#065f46;",
+                        ToastKind::Info => "pointer-events:auto; background:#eff6ff; border:1px solid #93c5fd; border-radius:12px; padding:12px 14px; box-shadow:0 10px 25px rgba(2,6,23,0.35); color:#1e3a8a;",
+                        ToastKind::Error => "pointer-events:auto; background:#fef2f2; border:1px solid #fca5a5; border-radius:12px; padding:12px 14px; box-shadow:0 10px 25px rgba(2,6,23,0.35); color:#991b1b;",
+                    },
+
+                    div {
+                        style: "display:flex; align-items:flex-start; gap:10px;",
+                        span {
+                            style: "font-size:16px; line-height:20px;",
+                            match toast.toast.kind {
+                                ToastKind::Success => "✅",
+                                ToastKind::Info => "ℹ️",
+                                ToastKind::Error => "⚠️",
+                            }
+                        }
+                        span {
+                            style: "flex:1; font-size:13px; line-height:20px; word-break:break-all;",
+                            "{toast.toast.message}"
+                        }
+                        if toast.toast.details.is_some() {
+                            button {
+                                style: "padding:0 4px; border:none; background:transparent; color:inherit; cursor:pointer; font-size:12px; text-decoration:underline; line-height:20px;",
+                                onclick: move |_| notifier.toggle_details(toast.id),
+                                if toast.expanded { {t("toast.collapse")} } else { {t("toast.details")} }
+                            }
+                        }
+                        button {
+                            style: "padding:0 4px; border:none; background:transparent; color:inherit; cursor:pointer; font-size:14px; line-height:20px;",
+                            title: t("common.close"),
+                            onclick: move |_| notifier.dismiss(toast.id),
+                            "✕"
+                        }
+                    }
+
+                    if toast.expanded {
+                        if let Some(details) = toast.toast.details.clone() {
+                            pre {
+                                style: "margin:8px 0 0 0; padding:8px 10px; background:rgba(255,255,255,0.7); border-radius:8px; font-size:11px; white-space:pre-wrap; word-break:break-all; max-height:160px; overflow:auto;",
+                                "{details}"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,111 @@
+//! 共享的基础组件：卡片、按钮、输入框、弹窗
+//!
+//! 样式在 `assets/styling/theme.css` 中以 `fb-*` 类定义并引用主题变量，切换主题时自动生效；
+//! `style` 属性只用来补充布局（宽度、外边距等），颜色请使用 `var(--fb-*)`
+
+use dioxus::prelude::*;
+
+/// 按钮样式
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum ButtonVariant {
+    /// 主要操作（保存、导入等）
+    #[default]
+    Primary,
+    /// 次要操作（取消、刷新等）
+    Secondary,
+    Success,
+    /// 不可恢复的操作（删除、清除）
+    Danger,
+}
+
+impl ButtonVariant {
+    fn class(&self) -> &'static str {
+        match self {
+            ButtonVariant::Primary => "fb-button fb-button-primary",
+            ButtonVariant::Secondary => "fb-button fb-button-secondary",
+            ButtonVariant::Success => "fb-button fb-button-success",
+            ButtonVariant::Danger => "fb-button fb-button-danger",
+        }
+    }
+}
+
+/// 页面中的内容区块
+#[component]
+pub fn Card(#[props(default, into)] style: String, children: Element) -> Element {
+    rsx! {
+        section { class: "fb-card", style, {children} }
+    }
+}
+
+#[component]
+pub fn Button(
+    #[props(default)] variant: ButtonVariant,
+    #[props(default)] disabled: bool,
+    #[props(default, into)] title: String,
+    #[props(default, into)] style: String,
+    onclick: EventHandler<MouseEvent>,
+    children: Element,
+) -> Element {
+    rsx! {
+        button {
+            class: variant.class(),
+            disabled,
+            title,
+            style,
+            onclick: move |evt| onclick.call(evt),
+            {children}
+        }
+    }
+}
+
+/// 单行输入框，`oninput` 收到输入后的完整文字
+#[component]
+pub fn Input(
+    value: String,
+    oninput: EventHandler<String>,
+    #[props(default, into)] placeholder: String,
+    #[props(default = "text")] input_type: &'static str,
+    #[props(default)] disabled: bool,
+    #[props(default, into)] style: String,
+) -> Element {
+    rsx! {
+        input {
+            class: "fb-input",
+            r#type: input_type,
+            value,
+            placeholder,
+            disabled,
+            style,
+            oninput: move |evt| oninput.call(evt.value()),
+        }
+    }
+}
+
+/// 居中弹窗，点击遮罩关闭；底部按钮放在 `class: "fb-modal-actions"` 的容器中
+#[component]
+pub fn Modal(
+    #[props(into)] title: String,
+    on_close: EventHandler<()>,
+    #[props(default = 520)] max_width: u32,
+    /// 标题使用警告色（确认删除等）
+    #[props(default)] danger: bool,
+    children: Element,
+) -> Element {
+    let title_color = if danger { "color:var(--fb-danger);" } else { "" };
+
+    rsx! {
+        div {
+            class: "fb-modal-overlay",
+            onclick: move |_| on_close.call(()),
+
+            div {
+                class: "fb-modal",
+                style: "max-width:{max_width}px;",
+                onclick: move |evt| evt.stop_propagation(),
+
+                h3 { class: "fb-modal-title", style: "{title_color}", "{title}" }
+                {children}
+            }
+        }
+    }
+}
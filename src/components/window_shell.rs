@@ -0,0 +1,213 @@
+//! 桌面窗口外壳：保存窗口位置、响应之后启动的实例、托盘图标
+//!
+//! 窗口的初始位置、大小和关闭行为在 `main` 创建窗口时根据保存的设置决定；
+//! 这里负责运行期间的部分，只在桌面端编译。
+
+use crate::common::i18n::t;
+use crate::common::single_instance;
+use crate::common::window_state::WindowState;
+use crate::components::use_language;
+use dioxus::prelude::*;
+use dioxus_desktop::tao::event::Event;
+use dioxus_desktop::trayicon::menu::{Menu, MenuEvent, MenuItem, PredefinedMenuItem};
+use dioxus_desktop::trayicon::{Icon, TrayIcon, TrayIconBuilder};
+use dioxus_desktop::{use_tray_menu_event_handler, use_wry_event_handler, window, WindowCloseBehaviour, WindowEvent};
+
+/// 启动时决定的窗口设置，由 `main` 通过 context 传入
+#[derive(Clone)]
+pub struct WindowShellConfig {
+    /// 启用托盘时为托盘图标的 RGBA 像素和边长，未启用时为 None
+    pub tray_icon: Option<(Vec<u8>, u32)>,
+}
+
+#[derive(Clone, Copy)]
+struct WindowShell {
+    tray_enabled: bool,
+    /// 最近一次普通状态（非最大化、非最小化）的位置和大小，最大化时保存这一组
+    normal_bounds: Signal<Option<WindowState>>,
+}
+
+impl WindowShell {
+    /// 保存窗口位置、大小和是否最大化，失败时只输出到控制台（窗口正在关闭）
+    fn save_state(&self) {
+        let maximized = window().is_maximized();
+        let result = match current_bounds().or(*self.normal_bounds.peek()) {
+            Some(bounds) => WindowState { maximized, ..bounds }.save(),
+            None if maximized => WindowState::save_maximized(),
+            None => Ok(()),
+        };
+        if let Err(e) = result {
+            eprintln!("保存窗口位置失败: {}", e);
+        }
+    }
+
+    /// 隐藏到托盘
+    fn hide(&self) {
+        self.save_state();
+        window().set_visible(false);
+    }
+
+    /// 退出程序：关闭最后一个窗口后事件循环结束，不受关闭行为设置影响
+    fn quit(&self) {
+        self.save_state();
+        window().close();
+    }
+}
+
+/// 托盘图标和菜单，图标在最后一个引用释放时移除
+#[derive(Clone)]
+struct Tray {
+    _icon: TrayIcon,
+    show: MenuItem,
+    hide: MenuItem,
+    quit: MenuItem,
+}
+
+impl Tray {
+    fn new(rgba: Vec<u8>, size: u32) -> Result<Self, String> {
+        let show = MenuItem::new(t("tray.show"), true, None);
+        let hide = MenuItem::new(t("tray.hide"), true, None);
+        let quit = MenuItem::new(t("tray.quit"), true, None);
+        let menu = Menu::new();
+        menu.append_items(&[&show, &hide, &PredefinedMenuItem::separator(), &quit])
+            .map_err(|e| e.to_string())?;
+
+        let icon = TrayIconBuilder::new()
+            .with_menu(Box::new(menu))
+            .with_menu_on_left_click(false)
+            .with_tooltip("FactBot")
+            .with_icon(Icon::from_rgba(rgba, size, size).map_err(|e| e.to_string())?)
+            .build()
+            .map_err(|e| e.to_string())?;
+
+        Ok(Self { _icon: icon, show, hide, quit })
+    }
+
+    /// 切换界面语言后更新菜单文字
+    fn relabel(&self) {
+        self.show.set_text(t("tray.show"));
+        self.hide.set_text(t("tray.hide"));
+        self.quit.set_text(t("tray.quit"));
+    }
+}
+
+/// 当前的位置和大小（逻辑像素），最大化或最小化时为 None
+fn current_bounds() -> Option<WindowState> {
+    let window = window();
+    if window.is_maximized() || window.is_minimized() {
+        return None;
+    }
+    let scale = window.scale_factor();
+    let position = window.outer_position().ok()?.to_logical::<f64>(scale);
+    let size = window.inner_size().to_logical::<f64>(scale);
+    Some(WindowState {
+        x: position.x,
+        y: position.y,
+        width: size.width,
+        height: size.height,
+        maximized: false,
+    })
+}
+
+/// 保存的位置所在的显示器已断开时，把窗口移到主显示器
+fn ensure_on_screen() {
+    let window = window();
+    let Ok(position) = window.outer_position() else {
+        return;
+    };
+    let size = window.outer_size();
+    let (center_x, center_y) = (position.x + size.width as i32 / 2, position.y + size.height as i32 / 2);
+    let visible = window.available_monitors().any(|monitor| {
+        let (origin, extent) = (monitor.position(), monitor.size());
+        (origin.x..origin.x + extent.width as i32).contains(&center_x)
+            && (origin.y..origin.y + extent.height as i32).contains(&center_y)
+    });
+    if !visible {
+        if let Some(monitor) = window.primary_monitor() {
+            window.set_outer_position(monitor.position());
+        }
+    }
+}
+
+/// 显示主窗口并放到前台
+fn show_main_window() {
+    let window = window();
+    window.set_visible(true);
+    window.set_minimized(false);
+    window.set_focus();
+}
+
+/// 在 `App` 中调用：记录窗口位置、关闭时保存，处理之后启动的实例和托盘菜单
+pub fn use_window_shell() {
+    use_hook(ensure_on_screen);
+    let tray = use_hook(|| {
+        let (rgba, size) = try_consume_context::<WindowShellConfig>()?.tray_icon?;
+        match Tray::new(rgba, size) {
+            Ok(tray) => Some(tray),
+            Err(e) => {
+                // 没有托盘时隐藏的窗口无法找回，改为关闭即退出
+                eprintln!("无法创建托盘图标: {}", e);
+                window().set_close_behavior(WindowCloseBehaviour::WindowCloses);
+                None
+            }
+        }
+    });
+    let shell = use_context_provider(|| WindowShell {
+        tray_enabled: tray.is_some(),
+        normal_bounds: Signal::new(WindowState::load()),
+    });
+
+    // 切换语言时更新托盘菜单
+    use_language();
+    if let Some(tray) = &tray {
+        tray.relabel();
+    }
+
+    // 之后启动的实例请求显示窗口
+    use_future(|| async {
+        loop {
+            single_instance::show_requested().await;
+            show_main_window();
+        }
+    });
+
+    use_wry_event_handler(move |event, _| {
+        let Event::WindowEvent { event, .. } = event else {
+            return;
+        };
+        let mut normal_bounds = shell.normal_bounds;
+        match event {
+            WindowEvent::Moved(_) | WindowEvent::Resized(_) => {
+                if let Some(bounds) = current_bounds() {
+                    normal_bounds.set(Some(bounds));
+                }
+            }
+            // 系统的关闭操作（Alt+F4 等），启用托盘时窗口随后被隐藏
+            WindowEvent::CloseRequested => shell.save_state(),
+            _ => {}
+        }
+    });
+
+    use_tray_menu_event_handler(move |event: &MenuEvent| {
+        let Some(tray) = &tray else {
+            return;
+        };
+        if event.id() == tray.show.id() {
+            show_main_window();
+        } else if event.id() == tray.hide.id() {
+            shell.hide();
+        } else if event.id() == tray.quit.id() {
+            shell.quit();
+        }
+    });
+}
+
+/// 标题栏关闭按钮：启用托盘时隐藏到托盘，否则保存窗口位置后退出
+pub fn use_close_window() -> Callback<()> {
+    let shell = try_use_context::<WindowShell>();
+    use_callback(move |_| match shell {
+        Some(shell) if shell.tray_enabled => shell.hide(),
+        Some(shell) => shell.quit(),
+        None => window().close(),
+    })
+}
@@ -1,37 +1,39 @@
-use crate::common::Result;
-use directories::ProjectDirs;
+use crate::common::{timestamp, Result};
 use rusqlite::{Connection, params};
-use std::env;
+use serde::Serialize;
 use std::fs;
 use std::path::PathBuf;
 
-/// 获取配置数据库路径（用户数据目录）
+pub mod health;
+pub mod location;
+pub mod repo;
+
+/// 获取配置数据库路径（默认为用户数据目录，可通过数据目录设置修改）
 /// - Windows: C:\Users\<用户>\AppData\Roaming\FactBot\config.db
 /// - macOS: ~/Library/Application Support/FactBot/config.db
 /// - Linux: ~/.local/share/FactBot/config.db
 pub fn get_config_db_path() -> Result<PathBuf> {
-    let project_dirs = ProjectDirs::from("", "", "FactBot")
-        .ok_or_else(|| crate::common::AppError::DatabaseError("无法获取应用数据目录".to_string()))?;
-    
-    let data_dir = project_dirs.data_dir();
+    let data_dir = location::current()?.config_dir;
     
     // 创建数据目录
     if !data_dir.exists() {
-        fs::create_dir_all(data_dir)
+        fs::create_dir_all(&data_dir)
             .map_err(crate::common::AppError::IoError)?;
     }
     
-    Ok(data_dir.join("config.db"))
+    Ok(data_dir.join(location::CONFIG_DB_FILE))
 }
 
-/// 获取日志数据库路径（软件运行目录）
+/// 获取日志数据库路径（默认为软件运行目录，不可写时回退到用户数据目录）
 pub fn get_log_db_path() -> Result<PathBuf> {
-    let exe_path = env::current_exe()
-        .map_err(crate::common::AppError::IoError)?;
-    let exe_dir = exe_path.parent()
-        .ok_or_else(|| crate::common::AppError::DatabaseError("无法获取可执行文件目录".to_string()))?;
+    let log_dir = location::current()?.log_dir;
+    
+    if !log_dir.exists() {
+        fs::create_dir_all(&log_dir)
+            .map_err(crate::common::AppError::IoError)?;
+    }
     
-    Ok(exe_dir.join("logs.db"))
+    Ok(log_dir.join(location::LOG_DB_FILE))
 }
 
 /// 初始化配置数据库
@@ -39,6 +41,18 @@ pub fn init_config_db() -> Result<Connection> {
     let db_path = get_config_db_path()?;
     let conn = Connection::open(&db_path)?;
     
+    // 删除时用 0 覆盖旧数据，避免个人数据残留在空闲页中
+    conn.pragma_update(None, "secure_delete", "ON")?;
+    // 分组明细表依赖外键级联删除
+    conn.pragma_update(None, "foreign_keys", "ON")?;
+    
+    create_config_tables(&conn)?;
+    
+    Ok(conn)
+}
+
+/// 创建配置数据库的全部表（`init_config_db` 及测试使用指定的连接）
+pub(crate) fn create_config_tables(conn: &Connection) -> Result<()> {
     // 创建配置表
     conn.execute(
         "CREATE TABLE IF NOT EXISTS config (
@@ -50,13 +64,45 @@ pub fn init_config_db() -> Result<Connection> {
         [],
     )?;
     
-    Ok(conn)
+    // 创建配置历史表（保存每次修改前的版本）
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS config_history (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            key TEXT NOT NULL,
+            value TEXT NOT NULL,
+            summary TEXT NOT NULL,
+            created_at TEXT NOT NULL
+        )",
+        [],
+    )?;
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_config_history_key ON config_history(key)",
+        [],
+    )?;
+    
+    // 创建分组配置表
+    repo::create_tables(conn)?;
+    
+    Ok(())
 }
 
+/// logs.db 结构版本（保存在 `PRAGMA user_version` 中）
+/// - 1：timestamp 由本地时间字符串改为 RFC 3339 UTC
+const LOG_SCHEMA_VERSION: i32 = 1;
+
 /// 初始化日志数据库
 pub fn init_log_db() -> Result<Connection> {
+    Ok(open_log_db()?.0)
+}
+
+/// 初始化日志数据库并执行结构迁移，返回迁移的记录数
+pub fn migrate_log_db() -> Result<usize> {
+    Ok(open_log_db()?.1)
+}
+
+fn open_log_db() -> Result<(Connection, usize)> {
     let db_path = get_log_db_path()?;
-    let conn = Connection::open(&db_path)?;
+    let mut conn = Connection::open(&db_path)?;
     
     // 创建日志表
     conn.execute(
@@ -86,14 +132,62 @@ pub fn init_log_db() -> Result<Connection> {
         [],
     )?;
     
-    Ok(conn)
+    let migrated = migrate_log_schema(&mut conn)?;
+    Ok((conn, migrated))
+}
+
+/// 磁盘上 logs.db 的结构版本和当前程序支持的版本（只读打开，不触发迁移）
+pub fn log_schema_versions() -> Result<(i32, i32)> {
+    let conn = Connection::open_with_flags(get_log_db_path()?, rusqlite::OpenFlags::SQLITE_OPEN_READ_ONLY)?;
+    Ok((log_schema_version(&conn)?, LOG_SCHEMA_VERSION))
+}
+
+fn log_schema_version(conn: &Connection) -> rusqlite::Result<i32> {
+    conn.query_row("PRAGMA user_version", [], |row| row.get(0))
+}
+
+/// 把旧版本的本地时间戳转换为 RFC 3339 UTC，返回转换的记录数
+///
+/// 每次打开日志库都会调用，已是最新版本时只读取一次 `user_version`
+fn migrate_log_schema(conn: &mut Connection) -> Result<usize> {
+    if log_schema_version(conn)? >= LOG_SCHEMA_VERSION {
+        return Ok(0);
+    }
+
+    // 立即加写锁，多个连接同时打开时只有一个执行迁移
+    let tx = conn.transaction_with_behavior(rusqlite::TransactionBehavior::Immediate)?;
+    if log_schema_version(&tx)? >= LOG_SCHEMA_VERSION {
+        return Ok(0);
+    }
+
+    let rows: Vec<(i64, String)> = tx
+        .prepare("SELECT id, timestamp FROM logs")?
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+        .collect::<rusqlite::Result<_>>()?;
+
+    let mut migrated = 0;
+    for (id, value) in rows {
+        // 无法识别的时间戳保持原样
+        if let Some(converted) = timestamp::legacy_to_storage(&value) {
+            tx.execute("UPDATE logs SET timestamp = ?1 WHERE id = ?2", params![converted, id])?;
+            migrated += 1;
+        }
+    }
+
+    tx.pragma_update(None, "user_version", LOG_SCHEMA_VERSION)?;
+    tx.commit()?;
+    Ok(migrated)
 }
 
 // ==================== 配置数据库操作 ====================
 
 /// 保存配置
 pub fn save_config(key: &str, value: &str) -> Result<()> {
-    let conn = init_config_db()?;
+    save_config_in(&init_config_db()?, key, value)
+}
+
+/// 在指定连接（或事务）中保存配置
+pub(crate) fn save_config_in(conn: &Connection, key: &str, value: &str) -> Result<()> {
     conn.execute(
         "INSERT OR REPLACE INTO config (key, value, updated_at) VALUES (?1, ?2, CURRENT_TIMESTAMP)",
         [key, value],
@@ -114,6 +208,131 @@ pub fn load_config(key: &str) -> Result<Option<String>> {
     }
 }
 
+/// 删除配置
+pub fn delete_config(key: &str) -> Result<()> {
+    delete_config_in(&init_config_db()?, key)
+}
+
+/// 在指定连接（或事务）中删除配置
+pub(crate) fn delete_config_in(conn: &Connection, key: &str) -> Result<()> {
+    conn.execute("DELETE FROM config WHERE key = ?1", [key])?;
+    Ok(())
+}
+
+/// 获取以 `prefix` 开头的全部配置 key
+pub fn list_config_keys(prefix: &str) -> Result<Vec<String>> {
+    let conn = init_config_db()?;
+    let mut stmt = conn.prepare(
+        "SELECT key FROM config WHERE substr(key, 1, length(?1)) = ?1 ORDER BY key"
+    )?;
+    
+    let keys = stmt.query_map([prefix], |row| row.get(0))?;
+    
+    let mut result = Vec::new();
+    for key in keys {
+        result.push(key?);
+    }
+    
+    Ok(result)
+}
+
+// ==================== 配置历史操作 ====================
+
+/// 配置历史版本（`value` 为修改前的内容）
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigHistoryEntry {
+    pub id: i64,
+    pub key: String,
+    pub value: String,
+    pub summary: String,
+    pub created_at: String,
+}
+
+/// 保存一条配置历史，并只保留最近 `keep` 条
+pub fn save_config_history(key: &str, value: &str, summary: &str, keep: usize) -> Result<()> {
+    save_config_history_in(&init_config_db()?, key, value, summary, keep)
+}
+
+/// 在指定连接（或事务）中保存一条配置历史
+pub(crate) fn save_config_history_in(conn: &Connection, key: &str, value: &str, summary: &str, keep: usize) -> Result<()> {
+    use chrono::Local;
+    let created_at = Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+    conn.execute(
+        "INSERT INTO config_history (key, value, summary, created_at) VALUES (?1, ?2, ?3, ?4)",
+        params![key, value, summary, created_at],
+    )?;
+    conn.execute(
+        "DELETE FROM config_history WHERE key = ?1 AND id NOT IN (
+            SELECT id FROM config_history WHERE key = ?1 ORDER BY id DESC LIMIT ?2
+        )",
+        params![key, keep as i64],
+    )?;
+    Ok(())
+}
+
+fn map_config_history_row(row: &rusqlite::Row) -> rusqlite::Result<ConfigHistoryEntry> {
+    Ok(ConfigHistoryEntry {
+        id: row.get(0)?,
+        key: row.get(1)?,
+        value: row.get(2)?,
+        summary: row.get(3)?,
+        created_at: row.get(4)?,
+    })
+}
+
+/// 获取指定配置的历史版本（最新在前）
+pub fn get_config_history(key: &str) -> Result<Vec<ConfigHistoryEntry>> {
+    let conn = init_config_db()?;
+    let mut stmt = conn.prepare(
+        "SELECT id, key, value, summary, created_at FROM config_history WHERE key = ?1 ORDER BY id DESC"
+    )?;
+    
+    let entries = stmt.query_map([key], map_config_history_row)?;
+    
+    let mut result = Vec::new();
+    for entry in entries {
+        result.push(entry?);
+    }
+    
+    Ok(result)
+}
+
+/// 获取单条配置历史
+pub fn get_config_history_entry(id: i64) -> Result<Option<ConfigHistoryEntry>> {
+    let conn = init_config_db()?;
+    let mut stmt = conn.prepare(
+        "SELECT id, key, value, summary, created_at FROM config_history WHERE id = ?1"
+    )?;
+    let mut rows = stmt.query_map([id], map_config_history_row)?;
+    
+    Ok(rows.next().transpose()?)
+}
+
+/// 更新配置历史内容（用于清除历史中的过期记录）
+pub fn update_config_history_value(id: i64, value: &str) -> Result<()> {
+    let conn = init_config_db()?;
+    conn.execute(
+        "UPDATE config_history SET value = ?1 WHERE id = ?2",
+        params![value, id],
+    )?;
+    Ok(())
+}
+
+/// 删除指定配置的全部历史
+pub fn delete_config_history(key: &str) -> Result<()> {
+    let conn = init_config_db()?;
+    conn.execute("DELETE FROM config_history WHERE key = ?1", [key])?;
+    Ok(())
+}
+
+/// 整理配置数据库，回收已删除数据占用的页
+pub fn vacuum_config_db() -> Result<()> {
+    let conn = init_config_db()?;
+    conn.execute_batch("VACUUM")?;
+    Ok(())
+}
+
 /// 初始化所有数据库
 pub fn initialize_databases() -> Result<()> {
     // 初始化配置数据库
@@ -122,15 +341,16 @@ pub fn initialize_databases() -> Result<()> {
     // 初始化日志数据库
     let _log_conn = init_log_db()?;
     
+    // 清除已过期的个人数据记录
+    let _ = crate::common::privacy::purge_expired_records();
+    
     // 检查并标记首次启动
     if is_first_launch() {
-        use chrono::Local;
-        let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
         let _ = save_log(
             "System",
             "INFO",
             "system-init",
-            &timestamp,
+            &timestamp::now(),
             "FactBot 首次启动，数据库初始化完成"
         );
     }
@@ -152,7 +372,7 @@ pub fn is_first_launch() -> bool {
 // ==================== 日志数据库操作 ====================
 
 /// 日志条目结构
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct LogEntry {
     pub id: Option<i64>,
     pub task_name: String,
@@ -203,3 +423,103 @@ pub fn get_all_logs() -> Result<Vec<LogEntry>> {
     
     Ok(result)
 }
+
+/// 日志筛选条件（日志页面和命令行共用）
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LogFilter {
+    /// 任务名称（完全匹配）
+    pub task_name: Option<String>,
+    /// 任务 UUID（包含即可）
+    pub task_uuid: Option<String>,
+    /// 日志级别（完全匹配其中之一），为空时不限
+    pub levels: Vec<String>,
+    /// 消息关键词
+    pub keyword: Option<String>,
+    /// 起始时间（存储格式，包含）
+    pub since: Option<String>,
+    /// 截止时间（存储格式，不包含）
+    pub until: Option<String>,
+    /// 是否包含 DEBUG 日志（正式版界面不展示）
+    pub include_debug: bool,
+    /// 按时间升序，默认降序
+    pub ascending: bool,
+}
+
+impl LogFilter {
+    pub fn matches(&self, log: &LogEntry) -> bool {
+        (self.include_debug || log.log_level != "DEBUG")
+            && self.task_name.as_ref().is_none_or(|name| &log.task_name == name)
+            && self.task_uuid.as_ref().is_none_or(|uuid| log.task_uuid.contains(uuid.as_str()))
+            && (self.levels.is_empty() || self.levels.contains(&log.log_level))
+            && self.keyword.as_ref().is_none_or(|keyword| log.message.contains(keyword.as_str()))
+            // 存储格式固定长度，按字符串比较即按时间比较
+            && self.since.as_ref().is_none_or(|since| &log.timestamp >= since)
+            && self.until.as_ref().is_none_or(|until| &log.timestamp < until)
+    }
+
+    /// 筛选并按时间排序
+    pub fn apply(&self, mut logs: Vec<LogEntry>) -> Vec<LogEntry> {
+        logs.retain(|log| self.matches(log));
+        // 时间戳为固定长度的 UTC 字符串，按字符串比较即按时间排序；同一毫秒内按写入顺序
+        logs.sort_by(|a, b| (&a.timestamp, a.id).cmp(&(&b.timestamp, b.id)));
+        if !self.ascending {
+            logs.reverse();
+        }
+        logs
+    }
+}
+
+/// 按条件查询日志
+pub fn query_logs(filter: &LogFilter) -> Result<Vec<LogEntry>> {
+    Ok(filter.apply(get_all_logs()?))
+}
+
+/// 统计早于 `before`（存储格式的时间戳）的日志条数
+pub fn count_logs_before(before: &str) -> Result<usize> {
+    let conn = init_log_db()?;
+    let count: i64 = conn.query_row(
+        "SELECT COUNT(*) FROM logs WHERE timestamp < ?1",
+        [before],
+        |row| row.get(0),
+    )?;
+    Ok(count as usize)
+}
+
+/// 删除早于 `before`（存储格式的时间戳）的日志，返回删除条数
+pub fn prune_logs(before: &str) -> Result<usize> {
+    let conn = init_log_db()?;
+    let removed = conn.execute("DELETE FROM logs WHERE timestamp < ?1", [before])?;
+    Ok(removed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_migrate_log_timestamps() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE logs (id INTEGER PRIMARY KEY AUTOINCREMENT, timestamp TEXT NOT NULL);
+             INSERT INTO logs (timestamp) VALUES ('2024-05-01 10:00:00'), ('2024-05-01T02:00:00.000Z'), ('garbage');",
+        )
+        .unwrap();
+
+        assert_eq!(migrate_log_schema(&mut conn).unwrap(), 1);
+        assert_eq!(log_schema_version(&conn).unwrap(), LOG_SCHEMA_VERSION);
+
+        let stamps: Vec<String> = conn
+            .prepare("SELECT timestamp FROM logs ORDER BY id")
+            .unwrap()
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .collect::<rusqlite::Result<_>>()
+            .unwrap();
+        assert_eq!(stamps[0], timestamp::legacy_to_storage("2024-05-01 10:00:00").unwrap());
+        assert_eq!(stamps[1], "2024-05-01T02:00:00.000Z");
+        assert_eq!(stamps[2], "garbage");
+
+        // 已是最新版本时不再处理
+        assert_eq!(migrate_log_schema(&mut conn).unwrap(), 0);
+    }
+}
@@ -0,0 +1,379 @@
+//! 启动自检与数据库修复
+//!
+//! 启动时对 config.db 和 logs.db 做完整性检查和写入测试，发现问题时由界面展示修复方案；
+//! 每次启动成功后为 config.db 生成一份轮换备份，用于损坏时恢复。
+
+use super::{get_config_db_path, get_log_db_path, init_log_db};
+use crate::common::i18n::{t, t_args};
+use crate::common::{AppError, Reason, Result};
+use chrono::Local;
+use rusqlite::{Connection, ErrorCode, OpenFlags};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// config.db 自动备份保留份数
+pub const MAX_CONFIG_BACKUPS: usize = 5;
+
+pub(super) const BACKUP_DIR_NAME: &str = "backups";
+const BACKUP_PREFIX: &str = "config-";
+const BACKUP_EXTENSION: &str = "db";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DbKind {
+    Config,
+    Logs,
+}
+
+impl DbKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            DbKind::Config => t("db.config"),
+            DbKind::Logs => t("db.logs"),
+        }
+    }
+
+    fn path(&self) -> Result<PathBuf> {
+        match self {
+            DbKind::Config => get_config_db_path(),
+            DbKind::Logs => get_log_db_path(),
+        }
+    }
+}
+
+/// 数据库检查发现的问题
+#[derive(Debug, Clone, PartialEq)]
+pub enum DbProblem {
+    /// 被其他进程占用（如另一个 FactBot 实例）
+    Locked,
+    /// 文件或目录不可写
+    ReadOnly,
+    /// 完整性检查失败或不是有效的数据库文件
+    Corrupt(String),
+    /// 其他错误（如无法创建数据目录）
+    Other(String),
+}
+
+impl DbProblem {
+    pub fn description(&self) -> String {
+        match self {
+            DbProblem::Locked => t("db.locked").to_string(),
+            DbProblem::ReadOnly => t("db.read_only").to_string(),
+            DbProblem::Corrupt(detail) => t_args("db.corrupt", &[detail]),
+            DbProblem::Other(detail) => t_args("db.other", &[detail]),
+        }
+    }
+
+    /// 建议的处理方式
+    pub fn remedy(&self, kind: DbKind) -> &'static str {
+        match (self, kind) {
+            (DbProblem::Locked, _) => t("db.remedy_locked"),
+            (DbProblem::ReadOnly, _) => t("db.remedy_read_only"),
+            (DbProblem::Corrupt(_), DbKind::Config) => t("db.remedy_corrupt_config"),
+            (DbProblem::Corrupt(_), DbKind::Logs) => t("db.remedy_corrupt_logs"),
+            (DbProblem::Other(_), _) => t("db.remedy_other"),
+        }
+    }
+}
+
+fn classify(error: &rusqlite::Error) -> DbProblem {
+    match error.sqlite_error_code() {
+        Some(ErrorCode::DatabaseBusy) | Some(ErrorCode::DatabaseLocked) => DbProblem::Locked,
+        Some(ErrorCode::ReadOnly) | Some(ErrorCode::PermissionDenied) | Some(ErrorCode::CannotOpen) => {
+            DbProblem::ReadOnly
+        }
+        Some(ErrorCode::DatabaseCorrupt) | Some(ErrorCode::NotADatabase) => {
+            DbProblem::Corrupt(error.to_string())
+        }
+        _ => DbProblem::Other(error.to_string()),
+    }
+}
+
+/// 检查单个数据库文件：完整性检查 + 写入测试（在事务中回滚，不留下数据）
+pub fn check_database(path: &Path) -> Option<DbProblem> {
+    let run = || -> rusqlite::Result<Option<DbProblem>> {
+        let mut conn = Connection::open(path)?;
+
+        let result: String = conn.query_row("PRAGMA integrity_check", [], |row| row.get(0))?;
+        if result != "ok" {
+            return Ok(Some(DbProblem::Corrupt(result)));
+        }
+
+        let tx = conn.transaction_with_behavior(rusqlite::TransactionBehavior::Immediate)?;
+        tx.execute_batch(
+            "CREATE TABLE IF NOT EXISTS __startup_write_test (id INTEGER);
+             INSERT INTO __startup_write_test DEFAULT VALUES;",
+        )?;
+        tx.rollback()?;
+        Ok(None)
+    };
+
+    match run() {
+        Ok(problem) => problem,
+        Err(e) => Some(classify(&e)),
+    }
+}
+
+/// 单个数据库的检查结果
+#[derive(Debug, Clone, PartialEq)]
+pub struct DbStatus {
+    pub kind: DbKind,
+    pub path: Option<PathBuf>,
+    pub problem: Option<DbProblem>,
+}
+
+impl DbStatus {
+    fn check(kind: DbKind) -> Self {
+        match kind.path() {
+            Ok(path) => Self {
+                kind,
+                problem: check_database(&path),
+                path: Some(path),
+            },
+            Err(e) => Self {
+                kind,
+                path: None,
+                problem: Some(DbProblem::Other(e.to_string())),
+            },
+        }
+    }
+}
+
+/// 启动自检结果
+#[derive(Debug, Clone, PartialEq)]
+pub struct StartupReport {
+    pub databases: Vec<DbStatus>,
+}
+
+impl StartupReport {
+    pub fn is_healthy(&self) -> bool {
+        self.databases.iter().all(|status| status.problem.is_none())
+    }
+}
+
+/// 检查全部数据库
+pub fn run_startup_checks() -> StartupReport {
+    StartupReport {
+        databases: vec![DbStatus::check(DbKind::Config), DbStatus::check(DbKind::Logs)],
+    }
+}
+
+// ==================== 备份与修复 ====================
+
+fn backup_dir_for(db_path: &Path) -> PathBuf {
+    db_path
+        .parent()
+        .map(|dir| dir.join(BACKUP_DIR_NAME))
+        .unwrap_or_else(|| PathBuf::from(BACKUP_DIR_NAME))
+}
+
+fn timestamp() -> String {
+    Local::now().format("%Y%m%d-%H%M%S").to_string()
+}
+
+/// 列出备份目录中的备份（最新在前）
+fn list_backups_in(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut backups: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            let name = path.file_name().and_then(|name| name.to_str()).unwrap_or_default();
+            name.starts_with(BACKUP_PREFIX)
+                && path.extension().and_then(|ext| ext.to_str()) == Some(BACKUP_EXTENSION)
+        })
+        .collect();
+    // 文件名中的时间戳可直接按字典序排序
+    backups.sort();
+    backups.reverse();
+    backups
+}
+
+/// 用 `VACUUM INTO` 生成一致的备份，并只保留最近 `keep` 份
+fn backup_to(db_path: &Path, dir: &Path, keep: usize) -> Result<PathBuf> {
+    fs::create_dir_all(dir)?;
+    let target = dir.join(format!("{}{}.{}", BACKUP_PREFIX, timestamp(), BACKUP_EXTENSION));
+    if target.exists() {
+        fs::remove_file(&target)?;
+    }
+
+    let conn = Connection::open_with_flags(db_path, OpenFlags::SQLITE_OPEN_READ_ONLY)?;
+    conn.execute("VACUUM INTO ?1", [target.to_string_lossy()])?;
+
+    for old in list_backups_in(dir).into_iter().skip(keep) {
+        let _ = fs::remove_file(old);
+    }
+    Ok(target)
+}
+
+/// 为 config.db 生成一份轮换备份（启动自检通过后调用）
+pub fn backup_config_db() -> Result<PathBuf> {
+    let db_path = get_config_db_path()?;
+    backup_to(&db_path, &backup_dir_for(&db_path), MAX_CONFIG_BACKUPS)
+}
+
+/// config.db 的全部自动备份（最新在前）
+pub fn list_config_backups() -> Result<Vec<PathBuf>> {
+    let db_path = get_config_db_path()?;
+    Ok(list_backups_in(&backup_dir_for(&db_path)))
+}
+
+/// 删除数据库的全部自动备份和重命名保留的损坏副本，返回删除的文件数
+fn delete_backups_for(db_path: &Path) -> Result<usize> {
+    let mut removed = 0;
+    for backup in list_backups_in(&backup_dir_for(db_path)) {
+        fs::remove_file(backup)?;
+        removed += 1;
+    }
+
+    let broken_prefix = format!("{}.broken-", file_name_of(db_path));
+    let Some(Ok(entries)) = db_path.parent().map(fs::read_dir) else {
+        return Ok(removed);
+    };
+    for path in entries.filter_map(|entry| entry.ok().map(|entry| entry.path())) {
+        if file_name_of(&path).starts_with(&broken_prefix) {
+            fs::remove_file(path)?;
+            removed += 1;
+        }
+    }
+    Ok(removed)
+}
+
+/// 删除 config.db 的全部备份（备份是完整副本，含个人数据；清除个人数据时调用）
+pub fn delete_config_backups() -> Result<usize> {
+    delete_backups_for(&get_config_db_path()?)
+}
+
+/// 把有问题的数据库文件重命名保留，返回新路径（文件不存在时返回 None）
+fn set_aside(db_path: &Path) -> Result<Option<PathBuf>> {
+    if !db_path.exists() {
+        return Ok(None);
+    }
+    let file_name = db_path.file_name().and_then(|name| name.to_str()).unwrap_or("database");
+    let target = db_path.with_file_name(format!("{}.broken-{}", file_name, timestamp()));
+    fs::rename(db_path, &target)?;
+
+    // 日志模式残留的辅助文件与主文件不再匹配，一并移走
+    for suffix in ["-journal", "-wal", "-shm"] {
+        let side = db_path.with_file_name(format!("{}{}", file_name, suffix));
+        if side.exists() {
+            let _ = fs::rename(&side, target.with_file_name(format!("{}{}", file_name_of(&target), suffix)));
+        }
+    }
+    Ok(Some(target))
+}
+
+fn file_name_of(path: &Path) -> String {
+    path.file_name().and_then(|name| name.to_str()).unwrap_or_default().to_string()
+}
+
+fn restore_from(db_path: &Path, backups: &[PathBuf]) -> Result<PathBuf> {
+    let backup = backups
+        .iter()
+        .find(|backup| check_database(backup).is_none())
+        .ok_or_else(|| AppError::DatabaseError(Reason::new("error.no_config_backup", &[])))?;
+
+    set_aside(db_path)?;
+    fs::copy(backup, db_path)?;
+    Ok(backup.clone())
+}
+
+/// 用最近一份完好的自动备份替换 config.db，返回使用的备份路径
+pub fn restore_latest_config_backup() -> Result<PathBuf> {
+    let db_path = get_config_db_path()?;
+    restore_from(&db_path, &list_backups_in(&backup_dir_for(&db_path)))
+}
+
+/// 重建空的 logs.db，原文件重命名保留
+pub fn rebuild_log_db() -> Result<()> {
+    set_aside(&get_log_db_path()?)?;
+    init_log_db()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir() -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("factbot-health-{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn create_db(path: &Path, value: &str) {
+        let conn = Connection::open(path).unwrap();
+        conn.execute_batch("CREATE TABLE IF NOT EXISTS config (key TEXT PRIMARY KEY, value TEXT)").unwrap();
+        conn.execute("INSERT OR REPLACE INTO config VALUES ('k', ?1)", [value]).unwrap();
+    }
+
+    fn read_value(path: &Path) -> String {
+        let conn = Connection::open(path).unwrap();
+        conn.query_row("SELECT value FROM config WHERE key = 'k'", [], |row| row.get(0)).unwrap()
+    }
+
+    #[test]
+    fn test_check_database() {
+        let dir = temp_dir();
+        let healthy = dir.join("healthy.db");
+        create_db(&healthy, "v");
+        assert_eq!(check_database(&healthy), None);
+
+        let corrupt = dir.join("corrupt.db");
+        fs::write(&corrupt, b"this is definitely not a sqlite database, just some bytes").unwrap();
+        assert!(matches!(check_database(&corrupt), Some(DbProblem::Corrupt(_))));
+
+        // 另一个连接持有写锁时视为被占用
+        let holder = Connection::open(&healthy).unwrap();
+        holder.execute_batch("BEGIN EXCLUSIVE").unwrap();
+        assert_eq!(check_database(&healthy), Some(DbProblem::Locked));
+        holder.execute_batch("ROLLBACK").unwrap();
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_backup_rotation_and_restore() {
+        let dir = temp_dir();
+        let db_path = dir.join("config.db");
+        let backup_dir = backup_dir_for(&db_path);
+
+        create_db(&db_path, "good");
+        // 预置旧备份，验证轮换只保留最近的几份
+        fs::create_dir_all(&backup_dir).unwrap();
+        for day in 1..=3 {
+            fs::copy(&db_path, backup_dir.join(format!("config-2024010{}-000000.db", day))).unwrap();
+        }
+        let latest = backup_to(&db_path, &backup_dir, 2).unwrap();
+        let backups = list_backups_in(&backup_dir);
+        assert_eq!(backups.len(), 2);
+        assert_eq!(backups[0], latest);
+
+        // 最新备份损坏时回退到上一份完好的备份
+        fs::write(&latest, b"broken backup").unwrap();
+        fs::write(&db_path, b"broken database").unwrap();
+        let used = restore_from(&db_path, &list_backups_in(&backup_dir)).unwrap();
+        assert_eq!(used, backups[1]);
+        assert_eq!(read_value(&db_path), "good");
+
+        // 损坏的文件被保留
+        let kept = fs::read_dir(&dir)
+            .unwrap()
+            .filter_map(|entry| entry.ok())
+            .any(|entry| entry.file_name().to_string_lossy().starts_with("config.db.broken-"));
+        assert!(kept);
+
+        // 清除个人数据时备份和损坏副本一并删除，数据库本身保留
+        assert_eq!(delete_backups_for(&db_path).unwrap(), 3);
+        assert!(list_backups_in(&backup_dir).is_empty());
+        let remaining: Vec<_> = fs::read_dir(&dir)
+            .unwrap()
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path().is_file())
+            .map(|entry| entry.file_name().to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(remaining, ["config.db"]);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
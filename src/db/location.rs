@@ -0,0 +1,380 @@
+//! 数据目录
+//!
+//! 默认情况下 config.db 位于系统用户数据目录，logs.db 位于程序所在目录；
+//! 程序目录不可写（如 `/usr/bin`、`Program Files`）时 logs.db 回退到用户数据目录。
+//! 指定数据目录后两个数据库都放在该目录中，优先级：
+//! 命令行 `--data-dir` > 环境变量 `FACTBOT_DATA_DIR` > 配置页保存的设置。
+
+use super::health::{self, BACKUP_DIR_NAME};
+use crate::common::i18n::t;
+use crate::common::{AppError, Reason, Result};
+use directories::ProjectDirs;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+
+pub const DATA_DIR_ENV: &str = "FACTBOT_DATA_DIR";
+pub const DATA_DIR_FLAG: &str = "--data-dir";
+
+pub const CONFIG_DB_FILE: &str = "config.db";
+pub const LOG_DB_FILE: &str = "logs.db";
+
+/// 保存数据目录设置的文件（位于系统配置目录，不能放在 config.db 中）
+const SETTING_FILE: &str = "data_dir";
+
+/// 数据目录的来源
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DataDirSource {
+    CliFlag,
+    Env,
+    Setting,
+    Default,
+}
+
+impl DataDirSource {
+    pub fn label(&self) -> &'static str {
+        match self {
+            DataDirSource::CliFlag => t("data_dir.cli_flag"),
+            DataDirSource::Env => t("data_dir.env"),
+            DataDirSource::Setting => t("data_dir.setting"),
+            DataDirSource::Default => t("data_dir.default"),
+        }
+    }
+
+    /// 命令行和环境变量的优先级高于设置，此时在界面中修改不会生效
+    pub fn is_override(&self) -> bool {
+        matches!(self, DataDirSource::CliFlag | DataDirSource::Env)
+    }
+}
+
+/// 两个数据库所在的目录
+#[derive(Debug, Clone, PartialEq)]
+pub struct DataLocation {
+    pub config_dir: PathBuf,
+    pub log_dir: PathBuf,
+    pub source: DataDirSource,
+    /// 默认位置下程序目录不可写，logs.db 已回退到用户数据目录
+    pub log_fallback: bool,
+}
+
+impl DataLocation {
+    pub fn config_db_path(&self) -> PathBuf {
+        self.config_dir.join(CONFIG_DB_FILE)
+    }
+
+    pub fn log_db_path(&self) -> PathBuf {
+        self.log_dir.join(LOG_DB_FILE)
+    }
+
+    fn custom(dir: PathBuf, source: DataDirSource) -> Self {
+        Self {
+            config_dir: dir.clone(),
+            log_dir: dir,
+            source,
+            log_fallback: false,
+        }
+    }
+}
+
+static LOCATION: RwLock<Option<DataLocation>> = RwLock::new(None);
+
+fn project_dirs() -> Result<ProjectDirs> {
+    ProjectDirs::from("", "", "FactBot")
+        .ok_or_else(|| AppError::DatabaseError(Reason::new("error.no_data_dir", &[])))
+}
+
+fn setting_path() -> Result<PathBuf> {
+    Ok(project_dirs()?.config_dir().join(SETTING_FILE))
+}
+
+/// 检查目录是否可写（目录不存在时尝试创建）
+pub fn is_writable(dir: &Path) -> bool {
+    if fs::create_dir_all(dir).is_err() {
+        return false;
+    }
+    let probe = dir.join(format!(".factbot-write-test-{}", uuid::Uuid::new_v4()));
+    match fs::write(&probe, b"") {
+        Ok(()) => {
+            let _ = fs::remove_file(probe);
+            true
+        }
+        Err(_) => false,
+    }
+}
+
+/// 从命令行参数中读取 `--data-dir <路径>` 或 `--data-dir=<路径>`
+fn dir_from_args(args: impl IntoIterator<Item = String>) -> Option<PathBuf> {
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        if arg == DATA_DIR_FLAG {
+            return args.next().map(PathBuf::from);
+        }
+        if let Some(value) = arg.strip_prefix(DATA_DIR_FLAG).and_then(|rest| rest.strip_prefix('=')) {
+            return Some(PathBuf::from(value));
+        }
+    }
+    None
+}
+
+fn non_empty(value: Option<String>) -> Option<PathBuf> {
+    value
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+}
+
+/// 按优先级确定数据目录
+fn resolve_with(
+    cli: Option<PathBuf>,
+    env_dir: Option<PathBuf>,
+    setting: Option<PathBuf>,
+    default_data_dir: PathBuf,
+    exe_dir: Option<PathBuf>,
+) -> DataLocation {
+    if let Some(dir) = cli {
+        return DataLocation::custom(dir, DataDirSource::CliFlag);
+    }
+    if let Some(dir) = env_dir {
+        return DataLocation::custom(dir, DataDirSource::Env);
+    }
+    if let Some(dir) = setting {
+        return DataLocation::custom(dir, DataDirSource::Setting);
+    }
+
+    let (log_dir, log_fallback) = match exe_dir {
+        Some(dir) if is_writable(&dir) => (dir, false),
+        _ => (default_data_dir.clone(), true),
+    };
+    DataLocation {
+        config_dir: default_data_dir,
+        log_dir,
+        source: DataDirSource::Default,
+        log_fallback,
+    }
+}
+
+fn default_location() -> Result<DataLocation> {
+    let exe_dir = env::current_exe()
+        .ok()
+        .and_then(|exe| exe.parent().map(Path::to_path_buf));
+    Ok(resolve_with(None, None, None, project_dirs()?.data_dir().to_path_buf(), exe_dir))
+}
+
+fn resolve() -> Result<DataLocation> {
+    let setting = setting_path()
+        .ok()
+        .and_then(|path| fs::read_to_string(path).ok());
+    let exe_dir = env::current_exe()
+        .ok()
+        .and_then(|exe| exe.parent().map(Path::to_path_buf));
+
+    Ok(resolve_with(
+        dir_from_args(env::args().skip(1)),
+        non_empty(env::var(DATA_DIR_ENV).ok()),
+        non_empty(setting),
+        project_dirs()?.data_dir().to_path_buf(),
+        exe_dir,
+    ))
+}
+
+/// 当前数据目录（首次调用时确定，之后只在迁移数据时改变）
+pub fn current() -> Result<DataLocation> {
+    if let Some(location) = LOCATION.read().ok().and_then(|guard| guard.clone()) {
+        return Ok(location);
+    }
+    let location = resolve()?;
+    set_current(location.clone());
+    Ok(location)
+}
+
+/// 更新当前数据目录（界面和 API 服务共用这一份缓存）
+pub fn set_current(location: DataLocation) {
+    if let Ok(mut guard) = LOCATION.write() {
+        *guard = Some(location);
+    }
+}
+
+/// 把数据库文件（及配置备份）从一个位置迁移到另一个位置
+///
+/// 先复制并校验，全部成功后才删除原文件；目标已有数据库时拒绝覆盖
+fn move_between(from: &DataLocation, to: &DataLocation) -> Result<()> {
+    let pairs = [
+        (from.config_db_path(), to.config_db_path()),
+        (from.log_db_path(), to.log_db_path()),
+    ];
+
+    for dir in [&to.config_dir, &to.log_dir] {
+        if !is_writable(dir) {
+            return Err(AppError::InvalidConfig(Reason::new("error.dir_not_writable", &[&dir.display()])));
+        }
+    }
+    for (source, target) in &pairs {
+        if source != target && source.exists() && target.exists() {
+            return Err(AppError::InvalidConfig(Reason::new("error.target_db_exists", &[&target.display()])));
+        }
+    }
+
+    let mut copied = Vec::new();
+    for (source, target) in &pairs {
+        if source == target || !source.exists() {
+            continue;
+        }
+        fs::copy(source, target)?;
+        if let Some(problem) = health::check_database(target) {
+            for path in copied.iter().chain([target]) {
+                let _ = fs::remove_file(path);
+            }
+            return Err(AppError::DatabaseError(Reason::new(
+                "error.moved_db_check_failed",
+                &[&target.display(), &problem.description()],
+            )));
+        }
+        copied.push(target.clone());
+    }
+
+    // 配置备份随 config.db 一起迁移，失败不影响主流程
+    let (from_backups, to_backups) = (from.config_dir.join(BACKUP_DIR_NAME), to.config_dir.join(BACKUP_DIR_NAME));
+    if from_backups != to_backups && from_backups.is_dir() {
+        if let Ok(entries) = fs::read_dir(&from_backups) {
+            let _ = fs::create_dir_all(&to_backups);
+            for entry in entries.flatten() {
+                let target = to_backups.join(entry.file_name());
+                if !target.exists() && fs::copy(entry.path(), &target).is_ok() {
+                    let _ = fs::remove_file(entry.path());
+                }
+            }
+            let _ = fs::remove_dir(&from_backups);
+        }
+    }
+
+    for (source, target) in &pairs {
+        if source != target && source.exists() && copied.contains(target) {
+            fs::remove_file(source)?;
+        }
+    }
+    Ok(())
+}
+
+/// 迁移数据到新目录，`None` 表示恢复默认位置；返回迁移后的位置
+///
+/// 命令行或环境变量指定了数据目录时无法在运行中修改
+pub fn move_data_to(dir: Option<&Path>) -> Result<DataLocation> {
+    let from = current()?;
+    if from.source.is_override() {
+        return Err(AppError::InvalidConfig(Reason::new(
+            "error.data_dir_overridden",
+            &[&from.source.label()],
+        )));
+    }
+
+    let to = match dir {
+        Some(dir) => DataLocation::custom(dir.to_path_buf(), DataDirSource::Setting),
+        None => default_location()?,
+    };
+    move_between(&from, &to)?;
+
+    let setting = setting_path()?;
+    match dir {
+        Some(dir) => {
+            if let Some(parent) = setting.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(&setting, dir.to_string_lossy().as_bytes())?;
+        }
+        None => {
+            if setting.exists() {
+                fs::remove_file(&setting)?;
+            }
+        }
+    }
+
+    set_current(to.clone());
+    Ok(to)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rusqlite::Connection;
+
+    fn temp_dir() -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("factbot-location-{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_resolve_priority_and_fallback() {
+        let root = temp_dir();
+        let default_dir = root.join("default");
+        let pick = |cli: Option<&str>, env_dir: Option<&str>, setting: Option<&str>| {
+            resolve_with(
+                cli.map(PathBuf::from),
+                env_dir.map(PathBuf::from),
+                setting.map(PathBuf::from),
+                default_dir.clone(),
+                Some(root.clone()),
+            )
+        };
+
+        assert_eq!(pick(Some("/cli"), Some("/env"), Some("/set")).source, DataDirSource::CliFlag);
+        assert_eq!(pick(None, Some("/env"), Some("/set")).config_dir, PathBuf::from("/env"));
+        assert_eq!(pick(None, None, Some("/set")).log_dir, PathBuf::from("/set"));
+
+        let default = pick(None, None, None);
+        assert_eq!(default.config_dir, default_dir);
+        assert_eq!(default.log_dir, root);
+        assert!(!default.log_fallback);
+
+        // 程序目录不可写（这里用一个普通文件充当目录）时 logs.db 回退到用户数据目录
+        let not_a_dir = root.join("file");
+        fs::write(&not_a_dir, b"").unwrap();
+        let fallback = resolve_with(None, None, None, default_dir.clone(), Some(not_a_dir.join("bin")));
+        assert_eq!(fallback.log_dir, default_dir);
+        assert!(fallback.log_fallback);
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_dir_from_args() {
+        let args = |list: &[&str]| list.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+
+        assert_eq!(dir_from_args(args(&["--data-dir", "/data"])), Some(PathBuf::from("/data")));
+        assert_eq!(dir_from_args(args(&["--x", "--data-dir=/d"])), Some(PathBuf::from("/d")));
+        assert_eq!(dir_from_args(args(&["--data-directory", "/d"])), None);
+        assert_eq!(dir_from_args(args(&["--data-dir"])), None);
+    }
+
+    #[test]
+    fn test_move_between() {
+        let root = temp_dir();
+        let from = DataLocation::custom(root.join("old"), DataDirSource::Setting);
+        let to = DataLocation::custom(root.join("new"), DataDirSource::Setting);
+
+        fs::create_dir_all(&from.config_dir).unwrap();
+        let conn = Connection::open(from.config_db_path()).unwrap();
+        conn.execute_batch("CREATE TABLE config (key TEXT); INSERT INTO config VALUES ('k');").unwrap();
+        drop(conn);
+        fs::create_dir_all(from.config_dir.join(BACKUP_DIR_NAME)).unwrap();
+        fs::write(from.config_dir.join(BACKUP_DIR_NAME).join("config-1.db"), b"").unwrap();
+
+        move_between(&from, &to).unwrap();
+        assert!(!from.config_db_path().exists());
+        assert!(to.config_db_path().exists());
+        assert!(to.config_dir.join(BACKUP_DIR_NAME).join("config-1.db").exists());
+        let conn = Connection::open(to.config_db_path()).unwrap();
+        let key: String = conn.query_row("SELECT key FROM config", [], |row| row.get(0)).unwrap();
+        assert_eq!(key, "k");
+        drop(conn);
+
+        // 目标已有数据库时拒绝覆盖
+        fs::create_dir_all(&from.config_dir).unwrap();
+        fs::copy(to.config_db_path(), from.config_db_path()).unwrap();
+        assert!(move_between(&from, &to).is_err());
+        assert!(from.config_db_path().exists());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -1,12 +1,17 @@
 use dioxus::prelude::*;
 
-use views::{Airline, Blog, Home, Config as ConfigView, Notice, Help, Logs, Captcha, CaptchaHelp};
-use components::Sidebar;
+use views::{Airline, Blog, Home, Config as ConfigView, Notice, Help, Logs, TaskTimeline, Captcha, CaptchaHelp};
+use components::{use_language_provider, use_theme_provider, Sidebar, StartupCheckScreen, StartupToasts, Toast};
+use common::i18n::{t, Lang};
+use common::theme::Theme;
+use common::log_query::LogQuery;
 
-pub mod common;
+// 数据库和公共模块只使用库中的一份（API 服务也使用库），否则两份 `db::location` 缓存会各自为政
+use fact_bot::{common, db};
+
+mod cli;
 mod components;
 mod views;
-mod db;
 
 #[derive(Debug, Clone, Routable, PartialEq)]
 #[rustfmt::skip]
@@ -22,8 +27,10 @@ enum Route {
         CaptchaHelp {},
         #[route("/config")]
         ConfigView {},
-        #[route("/logs")]
-        Logs {},
+        #[route("/logs?:..query")]
+        Logs { query: LogQuery },
+        #[route("/logs/timeline/:uuid")]
+        TaskTimeline { uuid: String },
         #[route("/notice")]
         Notice {},
         #[route("/help")]
@@ -34,6 +41,7 @@ enum Route {
 
 const FAVICON: Asset = asset!("/assets/favicon.svg");
 const MAIN_CSS: Asset = asset!("/assets/styling/main.css");
+const THEME_CSS: Asset = asset!("/assets/styling/theme.css");
 const TAILWIND_CSS: Asset = asset!("/assets/tailwind.css");
 const HEAD_STYLE: &str = r#"
     <style>
@@ -58,11 +66,37 @@ const HEAD_STYLE: &str = r#"
 /// 桌面端：使用 dioxus_desktop::launch::launch + Config 做无边框窗口
 #[cfg(feature = "desktop")]
 fn main() {
-    use dioxus_desktop::{Config, WindowBuilder, LogicalSize};
+    // panic 写入日志和崩溃文件，下次启动时提示
+    common::crash::install_panic_hook();
+
+    // 带子命令时作为命令行工具运行，不打开窗口
+    if let Some(code) = cli::run_from_args() {
+        std::process::exit(code);
+    }
+
+    use dioxus_desktop::{Config, WindowBuilder, WindowCloseBehaviour, LogicalPosition, LogicalSize};
     use dioxus_desktop::launch::launch as desktop_launch;
     use dioxus_desktop::tao::window::Icon;
+    use common::single_instance::{self, Instance, INSTANCE_PORT};
+    use common::window_state::{self, WindowState, DEFAULT_SIZE, MIN_SIZE};
+    use components::WindowShellConfig;
+    use std::any::Any;
     use std::fs;
 
+    // 只运行一个窗口：已有实例在运行时把它的窗口显示到前台，本次启动直接退出
+    match single_instance::acquire(INSTANCE_PORT) {
+        Ok(Instance::Primary(listener)) => {
+            if let Err(e) = single_instance::listen(listener) {
+                eprintln!("警告: 无法接收其他实例的请求: {}", e);
+            }
+        }
+        Ok(Instance::AlreadyRunning) => {
+            println!("FactBot 已在运行，已切换到现有窗口");
+            return;
+        }
+        Err(e) => eprintln!("警告: 无法确认是否已有实例在运行: {}", e),
+    }
+
     // 清除 WebView 缓存，确保每次启动都是干净状态
     let webview_cache = std::env::temp_dir().join("factbot_webview");
     if webview_cache.exists() {
@@ -70,20 +104,7 @@ fn main() {
     }
 
     // 启动 Web API 服务器（后台线程）
-    std::thread::spawn(|| {
-        let rt = match tokio::runtime::Runtime::new() {
-            Ok(runtime) => runtime,
-            Err(e) => {
-                eprintln!("无法创建 Tokio 运行时: {}", e);
-                return;
-            }
-        };
-        rt.block_on(async {
-            if let Err(e) = fact_bot::api::start_server().await {
-                eprintln!("Web API 服务器启动失败: {}", e);
-            }
-        });
-    });
+    spawn_api_server();
 
     // 加载图标
     let icon_bytes = include_bytes!("../assets/favicon.ico");
@@ -95,6 +116,11 @@ fn main() {
             return;
         }
     };
+    // 启用托盘时关闭窗口只隐藏，设置在重启后生效
+    let tray_enabled = window_state::tray_enabled();
+    let shell_config = WindowShellConfig {
+        tray_icon: tray_enabled.then(|| (icon_rgba.clone(), 256)),
+    };
     let icon = match Icon::from_rgba(icon_rgba, 256, 256) {
         Ok(icon) => icon,
         Err(e) => {
@@ -103,22 +129,66 @@ fn main() {
         }
     };
 
-    let window = WindowBuilder::new()
+    // 恢复上次关闭时的位置、大小和最大化状态，没有保存过时使用默认大小
+    let saved_window = WindowState::load();
+    let (width, height) = saved_window.map_or(DEFAULT_SIZE, |state| (state.width, state.height));
+    let mut window = WindowBuilder::new()
         .with_title("FactBot 面板")
         .with_window_icon(Some(icon))
         .with_decorations(false) // 关闭系统边框和菜单栏
         .with_transparent(false) // 允许自定义背景覆盖整个窗口
-        .with_inner_size(LogicalSize::new(1260.0, 900.0))
-        .with_min_inner_size(LogicalSize::new(1120.0, 780.0));
+        .with_inner_size(LogicalSize::new(width, height))
+        .with_min_inner_size(LogicalSize::new(MIN_SIZE.0, MIN_SIZE.1));
+    if let Some(state) = saved_window {
+        window = window
+            .with_position(LogicalPosition::new(state.x, state.y))
+            .with_maximized(state.maximized);
+    }
 
+    let close_behaviour = if tray_enabled {
+        WindowCloseBehaviour::WindowHides
+    } else {
+        WindowCloseBehaviour::WindowCloses
+    };
     let cfg = Config::new()
         .with_window(window)
+        .with_close_behaviour(close_behaviour)
         .with_custom_head(HEAD_STYLE.to_string())
         .with_background_color((2, 6, 23, 255)) // 统一 WebView 背景色，与 UI 深色主题一致
         .with_data_directory(std::env::temp_dir().join("factbot_webview")); // 使用临时目录，避免历史记录持久化
 
-    // contexts 使用默认空向量即可
-    desktop_launch(App, Vec::new(), vec![Box::new(cfg)]);
+    let contexts: Vec<Box<dyn Fn() -> Box<dyn Any> + Send + Sync>> =
+        vec![Box::new(move || Box::new(shell_config.clone()))];
+    desktop_launch(App, contexts, vec![Box::new(cfg)]);
+}
+
+/// 在名为 `api-server` 的线程中运行 Web API 服务器
+///
+/// 启动失败（如端口被占用）时除打印外还写入日志（`System` / ERROR），panic 由全局 hook 记录
+#[cfg(feature = "desktop")]
+fn spawn_api_server() {
+    let spawned = std::thread::Builder::new()
+        .name("api-server".to_string())
+        .spawn(|| {
+            let result = match tokio::runtime::Runtime::new() {
+                Ok(runtime) => runtime
+                    .block_on(fact_bot::api::start_server())
+                    .map_err(|e| format!("Web API 服务器启动失败: {}", e)),
+                Err(e) => Err(format!("无法创建 Tokio 运行时: {}", e)),
+            };
+            if let Err(message) = result {
+                report_api_server_failure(&message);
+            }
+        });
+    if let Err(e) = spawned {
+        report_api_server_failure(&format!("无法创建 Web API 服务器线程: {}", e));
+    }
+}
+
+#[cfg(feature = "desktop")]
+fn report_api_server_failure(message: &str) {
+    eprintln!("{}", message);
+    let _ = db::save_log("System", "ERROR", "api-server", &common::timestamp::now(), message);
 }
 
 #[cfg(feature = "desktop")]
@@ -138,20 +208,71 @@ fn load_icon_rgba(icon_bytes: &[u8]) -> Result<Vec<u8>, String> {
 /// 非桌面平台：保持原有启动方式
 #[cfg(not(feature = "desktop"))]
 fn main() {
+    common::crash::install_panic_hook();
+
+    if let Some(code) = cli::run_from_args() {
+        std::process::exit(code);
+    }
+
     dioxus::launch(App);
 }
 
 #[component]
 fn App() -> Element {
-    // 初始化数据库（首次启动会自动标记）
-    // 这会同时初始化配置数据库和日志数据库
-    let _ = db::initialize_databases();
-    
+    // 启动自检：数据库被占用、损坏或不可写时先显示自检界面，不进入主界面
+    let report = use_signal(db::health::run_startup_checks);
+    let mut ignore_problems = use_signal(|| false);
+    // 自检通过时读取保存的界面语言和主题，数据库有问题时先用默认值
+    use_language_provider(|| if report.peek().is_healthy() { Lang::load() } else { Lang::default() });
+    let theme = use_theme_provider(|| if report.peek().is_healthy() { Theme::load() } else { Theme::default() }).theme;
+    // 记录窗口位置、托盘菜单、响应之后启动的实例
+    #[cfg(feature = "desktop")]
+    components::use_window_shell();
+    let ready = report.read().is_healthy() || ignore_problems();
+
     rsx! {
         document::Link { rel: "icon", href: FAVICON }
         document::Link { rel: "stylesheet", href: MAIN_CSS }
+        document::Link { rel: "stylesheet", href: THEME_CSS }
         document::Link { rel: "stylesheet", href: TAILWIND_CSS }
 
+        // 主题变量定义在 data-theme 元素上，页面中的 var(--fb-*) 随主题变化
+        div {
+            "data-theme": theme().id(),
+            style: "display:contents;",
+
+            if ready {
+                MainApp {}
+            } else {
+                StartupCheckScreen {
+                    report,
+                    on_continue: move |_| ignore_problems.set(true),
+                }
+            }
+        }
+    }
+}
+
+/// 自检通过后的主界面
+#[component]
+fn MainApp() -> Element {
+    // 初始化数据库（首次启动会自动标记）
+    // 这会同时初始化配置数据库和日志数据库，失败时交给布局中的通知显示
+    use_context_provider(|| {
+        let mut toasts = Vec::new();
+        match db::initialize_databases() {
+            // 启动成功后为 config.db 生成轮换备份
+            Ok(()) => {
+                if let Err(e) = db::health::backup_config_db() {
+                    toasts.push(Toast::error(t("startup.backup_failed"), &e));
+                }
+            }
+            Err(e) => toasts.push(Toast::error(t("startup.db_init_failed"), &e)),
+        }
+        StartupToasts(toasts)
+    });
+
+    rsx! {
         Router::<Route> {}
     }
 }
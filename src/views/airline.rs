@@ -1,27 +1,12 @@
 use dioxus::prelude::*;
-use serde::{Deserialize, Serialize};
-use crate::db::load_config;
-
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
-struct ProxyConfig {
-    id: usize,
-    ip: String,
-    port: String,
-    username: String,
-    password: String,
-}
-
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
-struct ProxyGroup {
-    id: usize,
-    name: String,
-    proxies: Vec<ProxyConfig>,
-}
+use crate::common::i18n::{t, t_args};
+use crate::components::{use_notifier, Card};
+use crate::db::repo::{self, ProxyGroup};
 
 const AIRLINE_OPTIONS: &[AirlineOption] = &[
     AirlineOption {
         code: "AA",
-        name: "美国航空（现金）",
+        name_key: "airline.aa",
         request_preview: r#"{
     "TRIP_TYPE": "O",
     "B_LOCATION_1": "TYO",
@@ -31,7 +16,7 @@ const AIRLINE_OPTIONS: &[AirlineOption] = &[
     },
     AirlineOption {
         code: "B2",
-        name: "白俄罗斯航空",
+        name_key: "airline.b2",
         request_preview: r#"{
     "currency": "BYN",
     "segments": [{"departure": {"iata": "MSQ"}, "arrival": {"iata": "NOJ"}}],
@@ -40,7 +25,7 @@ const AIRLINE_OPTIONS: &[AirlineOption] = &[
     },
     AirlineOption {
         code: "FS",
-        name: "飞狮航空",
+        name_key: "airline.od",
         request_preview: r#"{
     "tripType": "ONE_WAY",
     "depPort": "ALA",
@@ -51,7 +36,7 @@ const AIRLINE_OPTIONS: &[AirlineOption] = &[
     },
     AirlineOption {
         code: "VJ",
-        name: "越捷航空",
+        name_key: "airline.vj",
         request_preview: r#"{
     "currency": "AUD",
     "departurePlace": "HAN",
@@ -62,7 +47,7 @@ const AIRLINE_OPTIONS: &[AirlineOption] = &[
     },
     AirlineOption {
         code: "VA",
-        name: "维珍航空",
+        name_key: "airline.vs",
         request_preview: r#"{
     "cabinClass": "First",
     "awardBooking": true,
@@ -72,7 +57,7 @@ const AIRLINE_OPTIONS: &[AirlineOption] = &[
     },
     AirlineOption {
         code: "OZ",
-        name: "韩亚航空（里程票）",
+        name_key: "airline.oz",
         request_preview: r#"{
     "bizType": "RED",
     "tripType": "OW",
@@ -83,7 +68,7 @@ const AIRLINE_OPTIONS: &[AirlineOption] = &[
     },
     AirlineOption {
         code: "CM",
-        name: "巴拿马航空（里程票）",
+        name_key: "airline.cm_award",
         request_preview: r#"{
     "adults": 1,
     "departureAirport1": "LAX",
@@ -93,7 +78,7 @@ const AIRLINE_OPTIONS: &[AirlineOption] = &[
     },
     AirlineOption {
         code: "CM_CASH",
-        name: "巴拿马航空（现金票）",
+        name_key: "airline.cm_cash",
         request_preview: r#"{
     "departureAirport1": "ASU",
     "arrivalAirport1": "PTY",
@@ -103,7 +88,7 @@ const AIRLINE_OPTIONS: &[AirlineOption] = &[
     },
     AirlineOption {
         code: "U6",
-        name: "乌拉尔航空",
+        name_key: "airline.u6",
         request_preview: r#"{
     "orig": "BJS",
     "dest": "SVX",
@@ -113,7 +98,7 @@ const AIRLINE_OPTIONS: &[AirlineOption] = &[
     },
     AirlineOption {
         code: "S7",
-        name: "西伯利亚航空",
+        name_key: "airline.s7",
         request_preview: r#"{
     "currency": "RUB",
     "origin": "MOW",
@@ -123,7 +108,7 @@ const AIRLINE_OPTIONS: &[AirlineOption] = &[
     },
     AirlineOption {
         code: "IA",
-        name: "伊拉克航空",
+        name_key: "airline.ia",
         request_preview: r#"{
     "originLocationCode": "EBL",
     "destinationLocationCode": "BGW",
@@ -132,7 +117,7 @@ const AIRLINE_OPTIONS: &[AirlineOption] = &[
     },
     AirlineOption {
         code: "SU",
-        name: "俄罗斯国际航空",
+        name_key: "airline.fv",
         request_preview: r#"{
     "origin": "ABA",
     "destination": "MOW",
@@ -142,7 +127,7 @@ const AIRLINE_OPTIONS: &[AirlineOption] = &[
     },
     AirlineOption {
         code: "N4",
-        name: "北风航空",
+        name_key: "airline.n4",
         request_preview: r#"{
     "departure": {"iata": "KZN"},
     "arrival": {"iata": "MOW"},
@@ -151,7 +136,7 @@ const AIRLINE_OPTIONS: &[AirlineOption] = &[
     },
     AirlineOption {
         code: "DD",
-        name: "皇雀航空",
+        name_key: "airline.bi",
         request_preview: r#"{
     "fromAirport": "DMK",
     "toAirport": "CNX",
@@ -161,7 +146,7 @@ const AIRLINE_OPTIONS: &[AirlineOption] = &[
     },
     AirlineOption {
         code: "MH",
-        name: "马来西亚国际航空",
+        name_key: "airline.mh",
         request_preview: r#"{
     "originLocationCode": "PKX",
     "destinationLocationCode": "JED",
@@ -170,7 +155,7 @@ const AIRLINE_OPTIONS: &[AirlineOption] = &[
     },
     AirlineOption {
         code: "5J",
-        name: "宿务航空",
+        name_key: "airline.5j",
         request_preview: r#"{
     "origin": "ICN",
     "destination": "KUL",
@@ -182,25 +167,26 @@ const AIRLINE_OPTIONS: &[AirlineOption] = &[
 
 struct AirlineOption {
     code: &'static str,
-    name: &'static str,
+    /// 名称的翻译 key
+    name_key: &'static str,
     request_preview: &'static str,
 }
 
 #[component]
 pub fn Airline() -> Element {
+    let mut notifier = use_notifier();
+
     // 加载代理分组
     let proxy_groups = use_signal(|| {
-        load_config("proxy_groups")
-            .ok()
-            .flatten()
-            .and_then(|json| serde_json::from_str::<Vec<ProxyGroup>>(&json).ok())
+        notifier
+            .report_error(repo::load_groups::<ProxyGroup>(), t("airline.load_groups_failed"))
             .unwrap_or_default()
     });
 
     let mut selected_group_index = use_signal(|| 0usize);
     let mut token = use_signal(|| "".to_string());
     let mut selected_code = use_signal(|| AIRLINE_OPTIONS[0].code.to_string());
-    let test_message = use_signal(|| "尚未测试连接".to_string());
+    let test_message = use_signal(|| t("airline.not_tested").to_string());
     let mut query_result = use_signal(|| "".to_string());
     let mut is_querying = use_signal(|| false);
 
@@ -308,25 +294,25 @@ pub fn Airline() -> Element {
                     
                     h1 {
                         style: "font-size:26px; font-weight:700; margin:0 0 10px 0; color:#312e81; letter-spacing:-0.02em;",
-                        "航司报价查询控制台"
+                        {t("airline.title")}
                     }
                     p {
                         style: "color:#4338ca; font-size:15px; margin:0; line-height:1.6;",
-                        "管理代理配置、Token 与航司二字码，快速测试各航司 API 报价参数。"
+                        {t("airline.subtitle")}
                     }
                 }
 
                 // 配置表单区 - 统一卡片
                 section {
-                    style: "background:white; border-radius:18px; padding:28px 32px; border:1px solid #e5e7eb; box-shadow:0 6px 24px rgba(15,23,42,0.06);",
+                    style: "background:var(--fb-surface); border-radius:18px; padding:28px 32px; border:1px solid var(--fb-border); box-shadow:0 6px 24px rgba(15,23,42,0.06);",
                     
                     h2 {
-                        style: "margin:0 0 8px 0; font-size:20px; font-weight:700; color:#111827; letter-spacing:-0.01em;",
-                        "配置参数"
+                        style: "margin:0 0 8px 0; font-size:20px; font-weight:700; color:var(--fb-text); letter-spacing:-0.01em;",
+                        {t("airline.settings")}
                     }
                     p {
-                        style: "margin:0 0 24px 0; font-size:14px; color:#6b7280; line-height:1.5;",
-                        "选择代理分组和配置认证凭证"
+                        style: "margin:0 0 24px 0; font-size:14px; color:var(--fb-text-muted); line-height:1.5;",
+                        {t("airline.settings_desc")}
                     }
 
                     // 代理分组和 Token 配置（一行）
@@ -336,17 +322,17 @@ pub fn Airline() -> Element {
                         // 代理分组选择
                         div {
                             label {
-                                style: "display:block; font-size:13px; font-weight:600; color:#374151; margin-bottom:8px;",
-                                "代理分组"
+                                style: "display:block; font-size:13px; font-weight:600; color:var(--fb-text-secondary); margin-bottom:8px;",
+                                {t("airline.proxy_group")}
                             }
                             if proxy_groups().is_empty() {
                                 div {
                                     style: "padding:11px 14px; border-radius:10px; background:#fef3c7; border:1px solid #fbbf24; color:#92400e; font-size:13px;",
-                                    "⚠️ 暂无代理"
+                                    {t("airline.no_proxies")}
                                 }
                             } else {
                                 select {
-                                    style: "width:100%; padding:11px 14px; border-radius:10px; border:1px solid #d1d5db; font-size:14px; background:white;",
+                                    style: "width:100%; padding:11px 14px; border-radius:10px; border:1px solid var(--fb-border-strong); font-size:14px; background:var(--fb-surface);",
                                     value: selected_group_index().to_string(),
                                     onchange: move |evt| {
                                         if let Ok(index) = evt.value().parse::<usize>() {
@@ -356,7 +342,7 @@ pub fn Airline() -> Element {
                                     for (index, group) in proxy_groups().iter().enumerate() {
                                         option {
                                             value: "{index}",
-                                            "{group.name} ({group.proxies.len()} 个)"
+                                            {t_args("airline.group_option", &[&group.name, &group.proxies.len()])}
                                         }
                                     }
                                 }
@@ -365,46 +351,45 @@ pub fn Airline() -> Element {
                         
                         // Token 配置
                         FormField {
-                            label: "认证 Token",
+                            label: t("airline.token"),
                             value: token(),
-                            placeholder: "请输入访问 Token",
+                            placeholder: t("airline.token_placeholder"),
                             onchange: move |value| token.set(value),
                         }
                     }
 
                     // 操作按钮行
                     div {
-                        style: "display:flex; gap:12px; align-items:center; padding-top:8px; border-top:1px solid #f3f4f6;",
+                        style: "display:flex; gap:12px; align-items:center; padding-top:8px; border-top:1px solid var(--fb-border);",
                         button {
                             style: BUTTON_SECONDARY_STYLE,
-                            "校验 Token"
+                            {t("airline.check_token")}
                         }
                         span {
-                            style: "font-size:13px; color:#6b7280; margin-left:8px;",
+                            style: "font-size:13px; color:var(--fb-text-muted); margin-left:8px;",
                             "{test_message}"
                         }
                     }
                 }
 
                 // 航司选择与请求参数展示
-                section {
-                    style: "background:white; border-radius:20px; padding:28px 32px; border:1px solid #e5e7eb; box-shadow:0 8px 30px rgba(15,23,42,0.08);",
+                Card {
                     
                     header {
                         style: "display:flex; flex-wrap:wrap; align-items:center; gap:16px; margin-bottom:24px;",
                         div {
                             style: "flex:1; min-width:200px;",
                             h2 {
-                                style: "font-size:22px; font-weight:700; margin:0 0 6px 0; color:#111827; letter-spacing:-0.02em;",
-                                "航司报价参数"
+                                style: "font-size:22px; font-weight:700; margin:0 0 6px 0; color:var(--fb-text); letter-spacing:-0.02em;",
+                                {t("airline.fare_params")}
                             }
                             p {
-                                style: "margin:0; color:#6b7280; font-size:14px; line-height:1.5;",
-                                "选择航司二字码，检查参数模版立即切换。"
+                                style: "margin:0; color:var(--fb-text-muted); font-size:14px; line-height:1.5;",
+                                {t("airline.fare_params_desc")}
                             }
                         }
                         select {
-                            style: "padding:11px 16px; border-radius:12px; border:1px solid #d1d5db; font-size:15px; font-weight:500; background:white; cursor:pointer; transition:all 0.2s ease;",
+                            style: "padding:11px 16px; border-radius:12px; border:1px solid var(--fb-border-strong); font-size:15px; font-weight:500; background:var(--fb-surface); cursor:pointer; transition:all 0.2s ease;",
                             value: selected_code(),
                             onchange: move |event| {
                                 selected_code.set(event.value());
@@ -412,7 +397,7 @@ pub fn Airline() -> Element {
                             for option in AIRLINE_OPTIONS {
                                 option {
                                     value: "{option.code}",
-                                    "{option.code} · {option.name}"
+                                    "{option.code} · {t(option.name_key)}"
                                 }
                             }
                         }
@@ -421,7 +406,7 @@ pub fn Airline() -> Element {
                             disabled: is_querying(),
                             onclick: move |_| {
                                 is_querying.set(true);
-                                query_result.set("正在发送查询请求...".to_string());
+                                query_result.set(t("airline.sending").to_string());
                                 
                                 // 从当前分组中随机选择一个代理
                                 let (proxy_ip, proxy_port, proxy_user, proxy_pass) = if let Some(group) = proxy_groups().get(selected_group_index()) {
@@ -483,29 +468,29 @@ pub fn Airline() -> Element {
                                 );
                                 
                                 // 模拟API调用
-                                query_result.set(format!("✓ 查询请求已发送\n\n随机选择的代理：{}:{}\n\n完整请求数据：\n{}", proxy_ip, proxy_port, request_data));
+                                query_result.set(t_args("airline.sent", &[&proxy_ip, &proxy_port, &request_data]));
                                 is_querying.set(false);
                             },
-                            if is_querying() { "查询中..." } else { "立即查询" }
+                            if is_querying() { {t("airline.querying")} } else { {t("help.query")} }
                         }
                     }
 
                     div {
                         style: "display:grid; grid-template-columns:repeat(auto-fit,minmax(220px,1fr)); gap:18px;",
                         InfoTile {
-                            label: "当前航司",
-                            value: format!("{} · {}", current_airline.code, current_airline.name),
+                            label: t("airline.current"),
+                            value: format!("{} · {}", current_airline.code, t(current_airline.name_key)),
                         }
                         InfoTile {
                             label: "Token",
-                            value: if token().is_empty() { "未配置".to_string() } else { "已配置".to_string() },
+                            value: if token().is_empty() { t("airline.not_configured").to_string() } else { t("airline.configured").to_string() },
                         }
                         InfoTile {
-                            label: "代理分组",
+                            label: t("airline.proxy_group"),
                             value: if let Some(group) = current_group() {
-                                format!("{} ({} 个)", group.name, group.proxies.len())
+                                t_args("airline.group_option", &[&group.name, &group.proxies.len()])
                             } else {
-                                "未选择".to_string()
+                                t("airline.not_selected").to_string()
                             },
                         }
                     }
@@ -514,7 +499,7 @@ pub fn Airline() -> Element {
                         style: "margin-top:24px;",
                         label {
                             style: "display:block; font-size:14px; font-weight:600; color:#4b5563; margin-bottom:10px;",
-                            "完整请求数据（实时更新）"
+                            {t("airline.request_preview")}
                         }
                         pre {
                             style: "background:linear-gradient(135deg, #0f172a 0%, #1e293b 100%); color:#e0e7ff; border-radius:14px; padding:18px; overflow:auto; font-size:12px; line-height:1.6; max-height:400px; border:1px solid rgba(148, 163, 184, 0.1); box-shadow:inset 0 2px 8px rgba(0,0,0,0.3); white-space:pre-wrap; word-wrap:break-word;",
@@ -529,11 +514,11 @@ pub fn Airline() -> Element {
                         style: "background:linear-gradient(135deg, #f0fdf4 0%, #dcfce7 100%); border-radius:20px; padding:28px 32px; border:1px solid #86efac; box-shadow:0 4px 20px rgba(34, 197, 94, 0.1);",
                         h2 {
                             style: "margin:0 0 8px 0; font-size:20px; font-weight:700; color:#166534; letter-spacing:-0.01em;",
-                            "查询结果"
+                            {t("airline.result")}
                         }
                         p {
                             style: "margin:0 0 16px 0; font-size:14px; color:#15803d; line-height:1.5;",
-                            "以下是生成的完整请求数据，可直接用于 API 调用"
+                            {t("airline.result_desc")}
                         }
                         pre {
                             style: "background:linear-gradient(135deg, #0f172a 0%, #1e293b 100%); color:#a5f3fc; border-radius:14px; padding:18px; overflow:auto; font-size:12px; line-height:1.6; max-height:400px; border:1px solid rgba(148, 163, 184, 0.1); box-shadow:inset 0 2px 8px rgba(0,0,0,0.3); white-space:pre-wrap; word-wrap:break-word;",
@@ -546,20 +531,20 @@ pub fn Airline() -> Element {
     }
 }
 
-const BUTTON_PRIMARY_STYLE: &str = "padding:11px 20px; border-radius:12px; border:none; background:linear-gradient(120deg,#4f46e5,#7c3aed); color:white; font-weight:600; cursor:pointer; box-shadow:0 4px 12px rgba(79, 70, 229, 0.3); transition:all 0.2s ease;";
-const BUTTON_SECONDARY_STYLE: &str = "border-radius:12px; border:1px solid #c7d2fe; background:#eef2ff; padding:11px 20px; color:#4338ca; font-weight:600; cursor:pointer; transition:all 0.2s ease;";
+const BUTTON_PRIMARY_STYLE: &str = "padding:11px 20px; border-radius:12px; border:none; background:var(--fb-primary-gradient); color:white; font-weight:600; cursor:pointer; box-shadow:var(--fb-primary-shadow); transition:all 0.2s ease;";
+const BUTTON_SECONDARY_STYLE: &str = "border-radius:12px; border:1px solid #c7d2fe; background:var(--fb-primary-soft); padding:11px 20px; color:#4338ca; font-weight:600; cursor:pointer; transition:all 0.2s ease;";
 
 #[component]
 fn FormCard(title: &'static str, subtitle: &'static str, content: Element) -> Element {
     rsx! {
         section {
-            style: "background:white; border-radius:18px; padding:24px; border:1px solid #e5e7eb; box-shadow:0 6px 24px rgba(15,23,42,0.06); transition:box-shadow 0.3s ease;",
+            style: "background:var(--fb-surface); border-radius:18px; padding:24px; border:1px solid var(--fb-border); box-shadow:0 6px 24px rgba(15,23,42,0.06); transition:box-shadow 0.3s ease;",
             h3 {
-                style: "margin:0 0 6px 0; font-size:19px; font-weight:700; color:#111827; letter-spacing:-0.01em;",
+                style: "margin:0 0 6px 0; font-size:19px; font-weight:700; color:var(--fb-text); letter-spacing:-0.01em;",
                 "{title}"
             }
             p {
-                style: "margin:0 0 18px 0; font-size:13px; color:#6b7280; line-height:1.5;",
+                style: "margin:0 0 18px 0; font-size:13px; color:var(--fb-text-muted); line-height:1.5;",
                 "{subtitle}"
             }
             {content}
@@ -581,14 +566,14 @@ struct FormFieldProps {
 fn FormField(props: FormFieldProps) -> Element {
     rsx! {
         label {
-            style: "display:flex; flex-direction:column; gap:6px; font-size:13px; font-weight:600; color:#374151; margin-bottom:12px;",
+            style: "display:flex; flex-direction:column; gap:6px; font-size:13px; font-weight:600; color:var(--fb-text-secondary); margin-bottom:12px;",
             span { "{props.label}" }
             input {
                 r#type: props.input_type,
                 value: props.value.clone(),
                 placeholder: props.placeholder,
                 oninput: move |event| props.onchange.call(event.value()),
-                style: "padding:11px 14px; border-radius:10px; border:1px solid #d1d5db; font-size:14px; transition:border-color 0.2s ease, box-shadow 0.2s ease; background:white;",
+                style: "padding:11px 14px; border-radius:10px; border:1px solid var(--fb-border-strong); font-size:14px; transition:border-color 0.2s ease, box-shadow 0.2s ease; background:var(--fb-surface);",
             }
         }
     }
@@ -604,13 +589,13 @@ struct InfoTileProps {
 fn InfoTile(props: InfoTileProps) -> Element {
     rsx! {
         div {
-            style: "background:linear-gradient(135deg, #f9fafb 0%, #f3f4f6 100%); border-radius:14px; padding:14px 18px; border:1px solid #e5e7eb;",
+            style: "background:linear-gradient(135deg, #f9fafb 0%, #f3f4f6 100%); border-radius:14px; padding:14px 18px; border:1px solid var(--fb-border);",
             span {
-                style: "display:block; font-size:12px; color:#6b7280; text-transform:uppercase; letter-spacing:0.08em;",
+                style: "display:block; font-size:12px; color:var(--fb-text-muted); text-transform:uppercase; letter-spacing:0.08em;",
                 "{props.label}"
             }
             strong {
-                style: "display:block; margin-top:6px; font-size:16px; color:#111827;",
+                style: "display:block; margin-top:6px; font-size:16px; color:var(--fb-text);",
                 "{props.value}"
             }
         }
@@ -629,18 +614,17 @@ fn BackendStatus() -> Element {
     
     use_effect(move || {
         spawn(async move {
-            use chrono::Local;
+            use crate::common::timestamp;
             use uuid::Uuid;
             
             let task_uuid = Uuid::new_v4().to_string();
             
             if first_check() {
-                let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
                 let _ = crate::db::save_log(
                     "Backend Health Check",
                     "INFO",
                     &task_uuid,
-                    &timestamp,
+                    &timestamp::now(),
                     "开始检查 Web 后台健康状态"
                 );
                 first_check.set(false);
@@ -666,7 +650,6 @@ fn BackendStatus() -> Element {
                 };
                 
                 if status_changed {
-                    let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
                     let (status_text, log_level) = if current_healthy {
                         ("运行中", "SUCCESS")
                     } else {
@@ -678,7 +661,7 @@ fn BackendStatus() -> Element {
                         "Backend Health Check",
                         log_level,
                         &task_uuid,
-                        &timestamp,
+                        &timestamp::now(),
                         &message
                     );
                     
@@ -691,11 +674,11 @@ fn BackendStatus() -> Element {
     });
     
     let (status_color, status_text, status_bg) = if is_checking() {
-        ("#94a3b8", "检查中", "rgba(148, 163, 184, 0.1)")
+        ("#94a3b8", t("airline.backend_checking"), "rgba(148, 163, 184, 0.1)")
     } else if is_healthy() {
-        ("#10b981", "运行中", "rgba(16, 185, 129, 0.1)")
+        ("#10b981", t("airline.backend_running"), "rgba(16, 185, 129, 0.1)")
     } else {
-        ("#ef4444", "离线", "rgba(239, 68, 68, 0.1)")
+        ("#ef4444", t("airline.backend_offline"), "rgba(239, 68, 68, 0.1)")
     };
     
     rsx! {
@@ -721,7 +704,7 @@ fn BackendStatus() -> Element {
                 style: "display:flex; align-items:center; gap:6px;",
                 span {
                     style: "font-size:13px; font-weight:600; color:#4338ca;",
-                    "端口:"
+                    {t("airline.port")}
                 }
                 span {
                     style: "font-size:15px; font-weight:700; color:#4f46e5; font-family:monospace;",
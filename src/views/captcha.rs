@@ -1,3 +1,5 @@
+use crate::common::i18n::{t, t_args};
+use crate::components::Card;
 use dioxus::prelude::*;
 use serde::{Deserialize, Serialize};
 use base64::Engine as Base64Engine;
@@ -92,11 +94,11 @@ pub fn Captcha() -> Element {
                     style: "background:linear-gradient(135deg, #fef3c7 0%, #fde68a 100%); border-radius:20px; padding:28px 32px; border:1px solid #fbbf24; box-shadow:0 4px 20px rgba(251, 191, 36, 0.1);",
                     h1 {
                         style: "font-size:26px; font-weight:700; margin:0 0 10px 0; color:#78350f; letter-spacing:-0.02em;",
-                        "🔐 验证码识别系统"
+                        {t("captcha.title")}
                     }
                     p {
                         style: "color:#92400e; font-size:15px; margin:0; line-height:1.6;",
-                        "集成 ddddocr + AntiCAP 双引擎，支持多种验证码类型识别"
+                        {t("captcha.subtitle")}
                     }
                 }
 
@@ -111,12 +113,12 @@ pub fn Captcha() -> Element {
 #[component]
 fn EngineSelector(selected_engine: Signal<CaptchaEngine>, selected_type: Signal<CaptchaType>, mut result_text: Signal<String>, mut api_response: Signal<Option<ApiResponse>>, mut image_base64: Signal<String>, mut image2_base64: Signal<String>) -> Element {
     rsx! {
-        section {
-            style: "background:white; border-radius:20px; padding:24px 32px; border:1px solid #e5e7eb; box-shadow:0 8px 30px rgba(15,23,42,0.08);",
+        Card {
+            style: "padding:24px 32px;",
 
             h2 {
-                style: "font-size:20px; font-weight:700; margin:0 0 16px 0; color:#111827;",
-                "选择识别引擎"
+                style: "font-size:20px; font-weight:700; margin:0 0 16px 0; color:var(--fb-text);",
+                {t("captcha.choose_engine")}
             }
 
             div {
@@ -126,7 +128,7 @@ fn EngineSelector(selected_engine: Signal<CaptchaEngine>, selected_type: Signal<
                     style: if matches!(selected_engine(), CaptchaEngine::Ddddocr) {
                         "padding:20px; border-radius:12px; background:linear-gradient(135deg, #fbbf24 0%, #f59e0b 100%); color:white; font-weight:600; cursor:pointer; border:none; box-shadow:0 4px 12px rgba(251, 191, 36, 0.4); transition:all 0.3s;"
                     } else {
-                        "padding:20px; border-radius:12px; background:#f9fafb; color:#6b7280; font-weight:500; cursor:pointer; border:1px solid #e5e7eb; transition:all 0.3s;"
+                        "padding:20px; border-radius:12px; background:var(--fb-surface-muted); color:var(--fb-text-muted); font-weight:500; cursor:pointer; border:1px solid var(--fb-border); transition:all 0.3s;"
                     },
                     onclick: move |_| {
                         selected_engine.set(CaptchaEngine::Ddddocr);
@@ -138,15 +140,15 @@ fn EngineSelector(selected_engine: Signal<CaptchaEngine>, selected_type: Signal<
                     },
                     div {
                         style: "font-size:18px; font-weight:700; margin-bottom:6px;",
-                        "ddddocr 引擎"
+                        {t("captcha.ddddocr_engine")}
                     }
                     div {
                         style: if matches!(selected_engine(), CaptchaEngine::Ddddocr) {
                             "font-size:13px; opacity:0.9;"
                         } else {
-                            "font-size:13px; color:#9ca3af;"
+                            "font-size:13px; color:var(--fb-text-subtle);"
                         },
-                        "通用识别 + 滑块 + 点选"
+                        {t("captcha.ddddocr_features")}
                     }
                 }
 
@@ -154,7 +156,7 @@ fn EngineSelector(selected_engine: Signal<CaptchaEngine>, selected_type: Signal<
                     style: if matches!(selected_engine(), CaptchaEngine::AntiCAP) {
                         "padding:20px; border-radius:12px; background:linear-gradient(135deg, #fbbf24 0%, #f59e0b 100%); color:white; font-weight:600; cursor:pointer; border:none; box-shadow:0 4px 12px rgba(251, 191, 36, 0.4); transition:all 0.3s;"
                     } else {
-                        "padding:20px; border-radius:12px; background:#f9fafb; color:#6b7280; font-weight:500; cursor:pointer; border:1px solid #e5e7eb; transition:all 0.3s;"
+                        "padding:20px; border-radius:12px; background:var(--fb-surface-muted); color:var(--fb-text-muted); font-weight:500; cursor:pointer; border:1px solid var(--fb-border); transition:all 0.3s;"
                     },
                     onclick: move |_| {
                         selected_engine.set(CaptchaEngine::AntiCAP);
@@ -166,15 +168,15 @@ fn EngineSelector(selected_engine: Signal<CaptchaEngine>, selected_type: Signal<
                     },
                     div {
                         style: "font-size:18px; font-weight:700; margin-bottom:6px;",
-                        "AntiCAP 引擎"
+                        {t("captcha.anticap_engine")}
                     }
                     div {
                         style: if matches!(selected_engine(), CaptchaEngine::AntiCAP) {
                             "font-size:13px; opacity:0.9;"
                         } else {
-                            "font-size:13px; color:#9ca3af;"
+                            "font-size:13px; color:var(--fb-text-subtle);"
                         },
-                        "OCR + 算术 + 检测 + 旋转"
+                        {t("captcha.anticap_features")}
                     }
                 }
             }
@@ -187,12 +189,12 @@ fn RecognitionPanel(selected_engine: Signal<CaptchaEngine>, selected_type: Signa
     let is_processing = use_signal(|| false);
 
     rsx! {
-        section {
-            style: "background:white; border-radius:20px; padding:24px 32px; border:1px solid #e5e7eb; box-shadow:0 8px 30px rgba(15,23,42,0.08);",
+        Card {
+            style: "padding:24px 32px;",
 
             h3 {
-                style: "margin:0 0 16px 0; font-size:18px; font-weight:700; color:#111827;",
-                "📸 上传验证码图片"
+                style: "margin:0 0 16px 0; font-size:18px; font-weight:700; color:var(--fb-text);",
+                {t("captcha.upload")}
             }
 
             ImageUploader {
@@ -226,16 +228,16 @@ fn RecognitionPanel(selected_engine: Signal<CaptchaEngine>, selected_type: Signa
 #[component]
 fn TypeSelector(selected_engine: Signal<CaptchaEngine>, selected_type: Signal<CaptchaType>, ocr_mode: Signal<String>, mut result_text: Signal<String>, mut api_response: Signal<Option<ApiResponse>>, mut image_base64: Signal<String>, mut image2_base64: Signal<String>) -> Element {
     rsx! {
-        section {
-            style: "background:white; border-radius:20px; padding:24px 32px; border:1px solid #e5e7eb; box-shadow:0 8px 30px rgba(15,23,42,0.08);",
+        Card {
+            style: "padding:24px 32px;",
             
             h3 {
-                style: "margin:0 0 16px 0; font-size:18px; font-weight:700; color:#111827;",
+                style: "margin:0 0 16px 0; font-size:18px; font-weight:700; color:var(--fb-text);",
                 "{get_type_section_title(selected_engine())}"
             }
 
             p {
-                style: "margin:0 0 16px 0; font-size:14px; color:#6b7280;",
+                style: "margin:0 0 16px 0; font-size:14px; color:var(--fb-text-muted);",
                 "{get_type_section_desc(selected_engine())}"
             }
 
@@ -243,30 +245,30 @@ fn TypeSelector(selected_engine: Signal<CaptchaEngine>, selected_type: Signal<Ca
                 style: "display:grid; grid-template-columns:repeat(auto-fill, minmax(180px, 1fr)); gap:12px;",
 
                 if matches!(selected_engine(), CaptchaEngine::Ddddocr) {
-                    TypeButton { selected_type, value: CaptchaType::DdddocrOcr, label: "普通验证码", icon: "ddddocr", result_text, api_response, image_base64, image2_base64 }
-                    TypeButton { selected_type, value: CaptchaType::DdddocrDet, label: "目标检测", icon: "", result_text, api_response, image_base64, image2_base64 }
-                    TypeButton { selected_type, value: CaptchaType::DdddocrSlide, label: "滑块验证码", icon: "", result_text, api_response, image_base64, image2_base64 }
-                    TypeButton { selected_type, value: CaptchaType::DdddocrClick, label: "点选验证码", icon: "", result_text, api_response, image_base64, image2_base64 }
+                    TypeButton { selected_type, value: CaptchaType::DdddocrOcr, label: t("captcha.type_text"), icon: "ddddocr", result_text, api_response, image_base64, image2_base64 }
+                    TypeButton { selected_type, value: CaptchaType::DdddocrDet, label: t("captcha.type_detection"), icon: "", result_text, api_response, image_base64, image2_base64 }
+                    TypeButton { selected_type, value: CaptchaType::DdddocrSlide, label: t("captcha.type_slider"), icon: "", result_text, api_response, image_base64, image2_base64 }
+                    TypeButton { selected_type, value: CaptchaType::DdddocrClick, label: t("captcha.type_click"), icon: "", result_text, api_response, image_base64, image2_base64 }
                 } else {
-                    TypeButton { selected_type, value: CaptchaType::AnticapOcr, label: "通用验证码", icon: "", result_text, api_response, image_base64, image2_base64 }
-                    TypeButton { selected_type, value: CaptchaType::AnticapMath, label: "算术验证码", icon: "", result_text, api_response, image_base64, image2_base64 }
-                    TypeButton { selected_type, value: CaptchaType::AnticapDetIcon, label: "图标检测", icon: "", result_text, api_response, image_base64, image2_base64 }
-                    TypeButton { selected_type, value: CaptchaType::AnticapDetText, label: "文字检测", icon: "", result_text, api_response, image_base64, image2_base64 }
-                    TypeButton { selected_type, value: CaptchaType::AnticapRotate, label: "单图旋转", icon: "", result_text, api_response, image_base64, image2_base64 }
-                    TypeButton { selected_type, value: CaptchaType::AnticapSlideMatch, label: "缺口滑块", icon: "", result_text, api_response, image_base64, image2_base64 }
-                    TypeButton { selected_type, value: CaptchaType::AnticapSlideComp, label: "阴影滑块", icon: "", result_text, api_response, image_base64, image2_base64 }
-                    TypeButton { selected_type, value: CaptchaType::AnticapRotateDouble, label: "双图旋转", icon: "", result_text, api_response, image_base64, image2_base64 }
-                    TypeButton { selected_type, value: CaptchaType::AnticapCompare, label: "图片相似度", icon: "", result_text, api_response, image_base64, image2_base64 }
+                    TypeButton { selected_type, value: CaptchaType::AnticapOcr, label: t("captcha.type_general"), icon: "", result_text, api_response, image_base64, image2_base64 }
+                    TypeButton { selected_type, value: CaptchaType::AnticapMath, label: t("captcha_help.math"), icon: "", result_text, api_response, image_base64, image2_base64 }
+                    TypeButton { selected_type, value: CaptchaType::AnticapDetIcon, label: t("captcha_help.icon"), icon: "", result_text, api_response, image_base64, image2_base64 }
+                    TypeButton { selected_type, value: CaptchaType::AnticapDetText, label: t("captcha_help.text_detection"), icon: "", result_text, api_response, image_base64, image2_base64 }
+                    TypeButton { selected_type, value: CaptchaType::AnticapRotate, label: t("captcha_help.rotate"), icon: "", result_text, api_response, image_base64, image2_base64 }
+                    TypeButton { selected_type, value: CaptchaType::AnticapSlideMatch, label: t("captcha_help.gap_slider"), icon: "", result_text, api_response, image_base64, image2_base64 }
+                    TypeButton { selected_type, value: CaptchaType::AnticapSlideComp, label: t("captcha_help.shadow_slider"), icon: "", result_text, api_response, image_base64, image2_base64 }
+                    TypeButton { selected_type, value: CaptchaType::AnticapRotateDouble, label: t("captcha_help.double_rotate"), icon: "", result_text, api_response, image_base64, image2_base64 }
+                    TypeButton { selected_type, value: CaptchaType::AnticapCompare, label: t("captcha_help.similarity"), icon: "", result_text, api_response, image_base64, image2_base64 }
                 }
             }
 
             // OCR 模式选择（仅 ddddocr 普通验证码显示）
             if matches!(selected_engine(), CaptchaEngine::Ddddocr) && matches!(selected_type(), CaptchaType::DdddocrOcr) {
                 div {
-                    style: "margin-top:20px; padding-top:20px; border-top:1px solid #e5e7eb;",
+                    style: "margin-top:20px; padding-top:20px; border-top:1px solid var(--fb-border);",
                     p {
-                        style: "margin:0 0 12px 0; font-size:14px; font-weight:600; color:#374151;",
-                        "识别模式"
+                        style: "margin:0 0 12px 0; font-size:14px; font-weight:600; color:var(--fb-text-secondary);",
+                        {t("captcha.mode")}
                     }
                     div {
                         style: "display:flex; gap:12px;",
@@ -275,30 +277,30 @@ fn TypeSelector(selected_engine: Signal<CaptchaEngine>, selected_type: Signal<Ca
                             style: if ocr_mode() == "normal" {
                                 "padding:10px 16px; border-radius:8px; background:#fbbf24; color:white; font-weight:600; cursor:pointer; border:none; font-size:13px;"
                             } else {
-                                "padding:10px 16px; border-radius:8px; background:#f9fafb; color:#6b7280; font-weight:500; cursor:pointer; border:1px solid #e5e7eb; font-size:13px;"
+                                "padding:10px 16px; border-radius:8px; background:var(--fb-surface-muted); color:var(--fb-text-muted); font-weight:500; cursor:pointer; border:1px solid var(--fb-border); font-size:13px;"
                             },
                             onclick: move |_| ocr_mode.set("normal".to_string()),
-                            "🔹 标准模式"
+                            {t("captcha.mode_normal")}
                         }
                         
                         button {
                             style: if ocr_mode() == "old" {
                                 "padding:10px 16px; border-radius:8px; background:#fbbf24; color:white; font-weight:600; cursor:pointer; border:none; font-size:13px;"
                             } else {
-                                "padding:10px 16px; border-radius:8px; background:#f9fafb; color:#6b7280; font-weight:500; cursor:pointer; border:1px solid #e5e7eb; font-size:13px;"
+                                "padding:10px 16px; border-radius:8px; background:var(--fb-surface-muted); color:var(--fb-text-muted); font-weight:500; cursor:pointer; border:1px solid var(--fb-border); font-size:13px;"
                             },
                             onclick: move |_| ocr_mode.set("old".to_string()),
-                            "🔸 旧版模式"
+                            {t("captcha.mode_old")}
                         }
                         
                         button {
                             style: if ocr_mode() == "beta" {
                                 "padding:10px 16px; border-radius:8px; background:#fbbf24; color:white; font-weight:600; cursor:pointer; border:none; font-size:13px;"
                             } else {
-                                "padding:10px 16px; border-radius:8px; background:#f9fafb; color:#6b7280; font-weight:500; cursor:pointer; border:1px solid #e5e7eb; font-size:13px;"
+                                "padding:10px 16px; border-radius:8px; background:var(--fb-surface-muted); color:var(--fb-text-muted); font-weight:500; cursor:pointer; border:1px solid var(--fb-border); font-size:13px;"
                             },
                             onclick: move |_| ocr_mode.set("beta".to_string()),
-                            "⭐ Beta 高精度"
+                            {t("captcha.mode_beta")}
                         }
                     }
                 }
@@ -309,15 +311,15 @@ fn TypeSelector(selected_engine: Signal<CaptchaEngine>, selected_type: Signal<Ca
 
 fn get_type_section_title(engine: CaptchaEngine) -> &'static str {
     match engine {
-        CaptchaEngine::Ddddocr => "普通验证码识别",
-        CaptchaEngine::AntiCAP => "AntiCAP 验证码识别",
+        CaptchaEngine::Ddddocr => t("captcha.ddddocr_section"),
+        CaptchaEngine::AntiCAP => t("captcha.anticap_section"),
     }
 }
 
 fn get_type_section_desc(engine: CaptchaEngine) -> &'static str {
     match engine {
-        CaptchaEngine::Ddddocr => "适用于各类常规验证码，支持标准、旧版、Beta 三种识别模式",
-        CaptchaEngine::AntiCAP => "OCR + 算术 + 检测 + 旋转",
+        CaptchaEngine::Ddddocr => t("captcha.ddddocr_section_desc"),
+        CaptchaEngine::AntiCAP => t("captcha.anticap_features"),
     }
 }
 
@@ -330,7 +332,7 @@ fn TypeButton(selected_type: Signal<CaptchaType>, value: CaptchaType, label: &'s
             style: if is_selected {
                 "padding:12px 16px; border-radius:10px; background:#fbbf24; color:white; font-weight:600; cursor:pointer; border:none; box-shadow:0 2px 8px rgba(251, 191, 36, 0.3); transition:all 0.2s; text-align:center; font-size:14px;"
             } else {
-                "padding:12px 16px; border-radius:10px; background:white; color:#374151; font-weight:500; cursor:pointer; border:1px solid #e5e7eb; transition:all 0.2s; text-align:center; font-size:14px;"
+                "padding:12px 16px; border-radius:10px; background:var(--fb-surface); color:var(--fb-text-secondary); font-weight:500; cursor:pointer; border:1px solid var(--fb-border); transition:all 0.2s; text-align:center; font-size:14px;"
             },
             onclick: move |_| {
                 selected_type.set(value.clone());
@@ -363,10 +365,10 @@ fn needs_second_image(captcha_type: CaptchaType) -> bool {
 
 fn get_second_image_label(captcha_type: CaptchaType) -> &'static str {
     match captcha_type {
-        CaptchaType::DdddocrSlide | CaptchaType::AnticapSlideMatch | CaptchaType::AnticapSlideComp => "背景图",
-        CaptchaType::AnticapRotateDouble => "外圆图",
-        CaptchaType::AnticapCompare => "对比图",
-        _ => "副图",
+        CaptchaType::DdddocrSlide | CaptchaType::AnticapSlideMatch | CaptchaType::AnticapSlideComp => t("captcha.background_image"),
+        CaptchaType::AnticapRotateDouble => t("captcha.outer_image"),
+        CaptchaType::AnticapCompare => t("captcha.compare_image"),
+        _ => t("captcha.second_image"),
     }
 }
 
@@ -409,9 +411,9 @@ fn ImageUploader(image_base64: Signal<String>, image2_base64: Signal<String>, se
                 if image_base64().is_empty() {
                     label {
                         r#for: "upload-main",
-                        style: "display:block; padding:40px; text-align:center; cursor:pointer; border-radius:8px; background:white; border:2px dashed #d1d5db; transition:all 0.2s;",
+                        style: "display:block; padding:40px; text-align:center; cursor:pointer; border-radius:8px; background:var(--fb-surface); border:2px dashed var(--fb-border-strong); transition:all 0.2s;",
                         p { style: "margin:0 0 8px 0; font-size:48px;", "📷" }
-                        p { style: "margin:0; font-size:14px; color:#6b7280;", "点击上传主图" }
+                        p { style: "margin:0; font-size:14px; color:var(--fb-text-muted);", {t("captcha.upload_main")} }
                     }
                     input {
                         r#type: "file",
@@ -422,7 +424,7 @@ fn ImageUploader(image_base64: Signal<String>, image2_base64: Signal<String>, se
                     }
                 } else {
                     div {
-                        style: "position:relative; background:white; border-radius:8px; padding:12px; border:1px solid #e5e7eb;",
+                        style: "position:relative; background:var(--fb-surface); border-radius:8px; padding:12px; border:1px solid var(--fb-border);",
                         div {
                             style: "position:relative; display:inline-block;",
                             img {
@@ -439,8 +441,8 @@ fn ImageUploader(image_base64: Signal<String>, image2_base64: Signal<String>, se
                         }
                         button {
                             onclick: move |_| image_base64.set(String::new()),
-                            style: "margin-top:8px; width:100%; padding:8px; border-radius:6px; border:1px solid #d1d5db; background:white; color:#374151; font-size:13px; cursor:pointer;",
-                            "🗑️ 移除"
+                            style: "margin-top:8px; width:100%; padding:8px; border-radius:6px; border:1px solid var(--fb-border-strong); background:var(--fb-surface); color:var(--fb-text-secondary); font-size:13px; cursor:pointer;",
+                            {t("captcha.remove")}
                         }
                     }
                 }
@@ -452,9 +454,9 @@ fn ImageUploader(image_base64: Signal<String>, image2_base64: Signal<String>, se
                     if image2_base64().is_empty() {
                         label {
                             r#for: "upload-second",
-                            style: "display:block; padding:40px; text-align:center; cursor:pointer; border-radius:8px; background:white; border:2px dashed #d1d5db; transition:all 0.2s;",
+                            style: "display:block; padding:40px; text-align:center; cursor:pointer; border-radius:8px; background:var(--fb-surface); border:2px dashed var(--fb-border-strong); transition:all 0.2s;",
                             p { style: "margin:0 0 8px 0; font-size:48px;", "🖼️" }
-                            p { style: "margin:0; font-size:14px; color:#6b7280;", "{get_second_image_label(selected_type())}" }
+                            p { style: "margin:0; font-size:14px; color:var(--fb-text-muted);", "{get_second_image_label(selected_type())}" }
                         }
                         input {
                             r#type: "file",
@@ -467,7 +469,7 @@ fn ImageUploader(image_base64: Signal<String>, image2_base64: Signal<String>, se
                         // 双图旋转验证码特殊处理：叠加显示
                         if matches!(selected_type(), CaptchaType::AnticapRotateDouble) {
                             div {
-                                style: "position:relative; background:white; border-radius:8px; padding:12px; border:1px solid #e5e7eb;",
+                                style: "position:relative; background:var(--fb-surface); border-radius:8px; padding:12px; border:1px solid var(--fb-border);",
                                 div {
                                     style: "position:relative; display:inline-block;",
                                     // 外圆图（背景）
@@ -499,14 +501,14 @@ fn ImageUploader(image_base64: Signal<String>, image2_base64: Signal<String>, se
                                 }
                                 button {
                                     onclick: move |_| image2_base64.set(String::new()),
-                                    style: "margin-top:8px; width:100%; padding:8px; border-radius:6px; border:1px solid #d1d5db; background:white; color:#374151; font-size:13px; cursor:pointer;",
-                                    "🗑️ 移除"
+                                    style: "margin-top:8px; width:100%; padding:8px; border-radius:6px; border:1px solid var(--fb-border-strong); background:var(--fb-surface); color:var(--fb-text-secondary); font-size:13px; cursor:pointer;",
+                                    {t("captcha.remove")}
                                 }
                             }
                         } else {
                             // 其他类型：正常显示副图
                             div {
-                                style: "position:relative; background:white; border-radius:8px; padding:12px; border:1px solid #e5e7eb;",
+                                style: "position:relative; background:var(--fb-surface); border-radius:8px; padding:12px; border:1px solid var(--fb-border);",
                                 div {
                                     style: "position:relative; display:inline-block;",
                                     img {
@@ -521,8 +523,8 @@ fn ImageUploader(image_base64: Signal<String>, image2_base64: Signal<String>, se
                                 }
                                 button {
                                     onclick: move |_| image2_base64.set(String::new()),
-                                    style: "margin-top:8px; width:100%; padding:8px; border-radius:6px; border:1px solid #d1d5db; background:white; color:#374151; font-size:13px; cursor:pointer;",
-                                    "🗑️ 移除"
+                                    style: "margin-top:8px; width:100%; padding:8px; border-radius:6px; border:1px solid var(--fb-border-strong); background:var(--fb-surface); color:var(--fb-text-secondary); font-size:13px; cursor:pointer;",
+                                    {t("captcha.remove")}
                                 }
                             }
                         }
@@ -577,7 +579,7 @@ fn RecognizeButton(
                                 #[cfg(debug_assertions)]
                                 {
                                     println!("=== API 原始响应 ===");
-                                    println!("{}", serde_json::to_string_pretty(&api_resp).unwrap_or_else(|_| "无法序列化".to_string()));
+                                    println!("{}", serde_json::to_string_pretty(&api_resp).unwrap_or_else(|_| t("captcha.not_serializable").to_string()));
                                     println!("==================");
                                     
                                     if let Some(ref targets) = api_resp.targets {
@@ -600,11 +602,11 @@ fn RecognizeButton(
                                 result_text.set(format_result(api_resp.clone()));
                                 api_response.set(Some(api_resp));
                             } else {
-                                result_text.set("❌ 解析响应失败".to_string());
+                                result_text.set(t("captcha.parse_failed").to_string());
                             }
                         }
                         Err(e) => {
-                            result_text.set(format!("❌ 请求失败: {}", e));
+                            result_text.set(t_args("captcha.request_failed", &[&e]));
                         }
                     }
 
@@ -614,10 +616,10 @@ fn RecognizeButton(
             style: if can_submit {
                 "width:100%; padding:16px 24px; border-radius:12px; border:none; background:linear-gradient(135deg, #fbbf24 0%, #f59e0b 100%); color:white; font-weight:700; cursor:pointer; box-shadow:0 4px 16px rgba(251, 191, 36, 0.4); font-size:16px; transition:all 0.3s; display:flex; align-items:center; justify-content:center; gap:8px;"
             } else {
-                "width:100%; padding:16px 24px; border-radius:12px; border:none; background:#e5e7eb; color:#9ca3af; font-weight:700; cursor:not-allowed; font-size:16px; display:flex; align-items:center; justify-content:center; gap:8px;"
+                "width:100%; padding:16px 24px; border-radius:12px; border:none; background:#e5e7eb; color:var(--fb-text-subtle); font-weight:700; cursor:not-allowed; font-size:16px; display:flex; align-items:center; justify-content:center; gap:8px;"
             },
             span { style: "font-size:20px;", if is_processing() { "⏳" } else { "🚀" } }
-            span { if is_processing() { "识别中..." } else { "开始识别" } }
+            span { if is_processing() { {t("captcha.processing")} } else { {t("captcha.start")} } }
         }
     }
 }
@@ -691,7 +693,7 @@ fn format_result(resp: ApiResponse) -> String {
     }
 
     if !resp.success {
-        return "❌ 识别失败".to_string();
+        return t("captcha.failed_short").to_string();
     }
 
     let mut output = String::new();
@@ -699,7 +701,7 @@ fn format_result(resp: ApiResponse) -> String {
     if let Some(result) = resp.result {
         if let Some(result_array) = result.as_array() {
             // 如果是数组（图标检测、文字检测的结果）
-            output.push_str(&format!("📝 检测到 {} 个目标:\n", result_array.len()));
+            output.push_str(&format!("{}\n", t_args("captcha.detected", &[&result_array.len()])));
             for item in result_array.iter().take(5) {
                 if let Some(item_obj) = item.as_object() {
                     let bbox_opt = item_obj.get("bbox")
@@ -710,58 +712,58 @@ fn format_result(resp: ApiResponse) -> String {
                         .or_else(|| item_obj.get("text"))
                         .or_else(|| item_obj.get("name"))
                         .and_then(|v| v.as_str())
-                        .unwrap_or("未知");
+                        .unwrap_or(t("captcha.unknown"));
                     
                     if let Some(bbox) = bbox_opt {
                         if bbox.len() >= 4 {
-                            output.push_str(&format!("  • {} - 位置: [{}, {}, {}, {}]\n", 
-                                label,
-                                bbox[0],
-                                bbox[1],
-                                bbox[2],
-                                bbox[3]
-                            ));
+                            output.push_str(&format!("{}\n", t_args("captcha.bbox", &[
+                                &label,
+                                &bbox[0],
+                                &bbox[1],
+                                &bbox[2],
+                                &bbox[3],
+                            ])));
                         }
                     }
                 }
             }
         } else if let Some(s) = result.as_str() {
-            output.push_str(&format!("📝 识别结果: {}\n", s));
+            output.push_str(&format!("{}\n", t_args("captcha.result", &[&s])));
         } else if let Some(n) = result.as_i64() {
-            output.push_str(&format!("📝 识别结果: {}\n", n));
+            output.push_str(&format!("{}\n", t_args("captcha.result", &[&n])));
         } else if let Some(f) = result.as_f64() {
-            output.push_str(&format!("📝 识别结果: {:.2}\n", f));
+            output.push_str(&format!("{}\n", t_args("captcha.result", &[&format!("{:.2}", f)])));
         } else if let Some(b) = result.as_bool() {
-            output.push_str(&format!("📝 识别结果: {}\n", b));
+            output.push_str(&format!("{}\n", t_args("captcha.result", &[&b])));
         } else {
-            output.push_str(&format!("📝 识别结果: {}\n", result));
+            output.push_str(&format!("{}\n", t_args("captcha.result", &[&result])));
         }
     }
 
     if let Some(objects) = resp.objects {
-        output.push_str(&format!("\n🎯 检测到 {} 个目标:\n", objects.len()));
+        output.push_str(&format!("\n{}\n", t_args("captcha.objects", &[&objects.len()])));
         for obj in objects.iter().take(5) {
-            output.push_str(&format!("  • {} - 中心点: ({}, {})\n", obj.label, obj.center[0], obj.center[1]));
+            output.push_str(&format!("{}\n", t_args("captcha.object", &[&obj.label, &obj.center[0], &obj.center[1]])));
         }
     }
 
     if let Some(targets) = resp.targets {
-        output.push_str(&format!("\n👆 可点击目标 {} 个:\n", targets.len()));
+        output.push_str(&format!("\n{}\n", t_args("captcha.targets", &[&targets.len()])));
         for target in targets.iter().take(5) {
-            output.push_str(&format!("  • {} - 位置: ({}, {})\n", target.label, target.position.x, target.position.y));
+            output.push_str(&format!("{}\n", t_args("captcha.target", &[&target.label, &target.position.x, &target.position.y])));
         }
     }
 
     if let Some(distance) = resp.distance {
-        output.push_str(&format!("\n🧩 滑动距离: {} 像素\n", distance));
+        output.push_str(&format!("\n{}\n", t_args("captcha.distance", &[&distance])));
     }
 
     if let Some(similarity) = resp.similarity {
-        output.push_str(&format!("\n📊 相似度: {:.2}%\n", similarity * 100.0));
+        output.push_str(&format!("\n{}\n", t_args("captcha.similarity", &[&format!("{:.2}", similarity * 100.0)])));
     }
 
     if let Some(angle) = resp.inner_angle {
-        output.push_str(&format!("\n🔄 旋转角度: {:.1}°\n", angle));
+        output.push_str(&format!("\n{}\n", t_args("captcha.angle", &[&format!("{:.1}", angle)])));
     }
 
     if let Some(desc) = resp.description {
@@ -1165,7 +1167,7 @@ fn AnnotationLayer(response: ApiResponse, image_id: String, is_background: bool)
                                         fill: "white",
                                         font_size: "14",
                                         font_weight: "bold",
-                                        "缺口位置: [{x1_i32},{y1_i32},{x2_i32},{y2_i32}]"
+                                        {t_args("captcha.gap_position", &[&x1_i32, &y1_i32, &x2_i32, &y2_i32])}
                                     }
                                 }
                             }
@@ -1236,7 +1238,7 @@ fn AnnotationLayer(response: ApiResponse, image_id: String, is_background: bool)
                     if let Some(result_str) = result.as_str() {
                         if !result_str.is_empty() {
                             {
-                                let label_text = format!("识别结果: {}", result_str);
+                                let label_text = t_args("captcha.result_label", &[&result_str]);
                                 rsx! {
                                     g {
                                         // 结果文本背景
@@ -1264,7 +1266,7 @@ fn AnnotationLayer(response: ApiResponse, image_id: String, is_background: bool)
                     } else if let Some(result_num) = result.as_f64() {
                         // 算术验证码结果（数字）
                         {
-                            let result_text = format!("计算结果: {:.2}", result_num);
+                            let result_text = t_args("captcha.computed", &[&format!("{:.2}", result_num)]);
                             rsx! {
                                 g {
                                     // 结果文本背景
@@ -1296,7 +1298,7 @@ fn AnnotationLayer(response: ApiResponse, image_id: String, is_background: bool)
             if let Some(similarity) = response.similarity {
                 if response.distance.is_none() && response.objects.is_none() && response.targets.is_none() && response.inner_angle.is_none() && response.result.is_none() {
                     {
-                        let similarity_text = format!("相似度: {:.1}%", similarity * 100.0);
+                        let similarity_text = t_args("captcha.similarity_label", &[&format!("{:.1}", similarity * 100.0)]);
                         rsx! {
                             g {
                                 // 相似度文本背景
@@ -1353,21 +1355,21 @@ fn ResultDisplay(result_text: String, api_response: Option<ApiResponse>) -> Elem
                         } else {
                             "margin:0; font-size:16px; font-weight:700; color:#991b1b;"
                         },
-                        if is_success { "识别成功" } else { "识别失败" }
+                        if is_success { {t("captcha.succeeded")} } else { {t("captcha.failed")} }
                     }
                 }
                 button {
                     onclick: move |_| show_raw.set(!show_raw()),
-                    style: "padding:6px 12px; border-radius:6px; border:1px solid #d1d5db; background:white; color:#374151; font-size:12px; cursor:pointer;",
-                    if show_raw() { "隐藏原始响应" } else { "查看原始响应" }
+                    style: "padding:6px 12px; border-radius:6px; border:1px solid var(--fb-border-strong); background:var(--fb-surface); color:var(--fb-text-secondary); font-size:12px; cursor:pointer;",
+                    if show_raw() { {t("captcha.hide_raw")} } else { {t("captcha.show_raw")} }
                 }
             }
 
             if show_raw() {
                 if let Some(ref resp) = api_response {
                     pre {
-                        style: "margin:0 0 12px 0; padding:12px; background:#f9fafb; border-radius:6px; font-size:12px; color:#374151; font-family:monospace; white-space:pre-wrap; word-break:break-word; line-height:1.4; overflow-x:auto;",
-                        "{serde_json::to_string_pretty(resp).unwrap_or_else(|_| \"无法序列化\".to_string())}"
+                        style: "margin:0 0 12px 0; padding:12px; background:var(--fb-surface-muted); border-radius:6px; font-size:12px; color:var(--fb-text-secondary); font-family:monospace; white-space:pre-wrap; word-break:break-word; line-height:1.4; overflow-x:auto;",
+                        {serde_json::to_string_pretty(resp).unwrap_or_else(|_| t("captcha.not_serializable").to_string())}
                     }
                 }
             }
@@ -1,3 +1,4 @@
+use crate::common::i18n::t;
 use dioxus::prelude::*;
 
 #[derive(Clone, Copy, PartialEq)]
@@ -77,20 +78,20 @@ pub fn CaptchaHelp() -> Element {
                     style: "background:linear-gradient(135deg, #fef3c7 0%, #fde68a 100%); border-radius:20px; padding:28px 32px; border:1px solid #fbbf24;",
                     h1 {
                         style: "font-size:26px; font-weight:700; margin:0 0 10px 0; color:#78350f;",
-                        "🔐 验证码识别 API 文档"
+                        {t("captcha_help.title")}
                     }
                     p {
                         style: "color:#92400e; font-size:15px; margin:0;",
-                        "支持 ddddocr + AntiCAP 双引擎，提供多种编程语言调用示例"
+                        {t("captcha_help.subtitle")}
                     }
                 }
 
                 // 编程语言选择
                 section {
-                    style: "background:white; border-radius:18px; padding:24px 32px; border:1px solid #e5e7eb; box-shadow:0 6px 20px rgba(15,23,42,0.08);",
+                    style: "background:var(--fb-surface); border-radius:18px; padding:24px 32px; border:1px solid var(--fb-border); box-shadow:0 6px 20px rgba(15,23,42,0.08);",
                     h2 {
-                        style: "font-size:20px; font-weight:700; margin:0 0 16px 0; color:#111827;",
-                        "选择编程语言"
+                        style: "font-size:20px; font-weight:700; margin:0 0 16px 0; color:var(--fb-text);",
+                        {t("captcha_help.choose_language")}
                     }
                     div {
                         style: "display:flex; gap:12px; flex-wrap:wrap;",
@@ -129,7 +130,7 @@ fn LanguageButton(mut selected_lang: Signal<CodeLanguage>, lang: CodeLanguage, l
             style: if is_selected {
                 "padding:10px 20px; border-radius:8px; background:#3b82f6; color:white; font-weight:600; border:none; cursor:pointer; font-size:14px;"
             } else {
-                "padding:10px 20px; border-radius:8px; background:#f9fafb; color:#374151; font-weight:500; border:1px solid #e5e7eb; cursor:pointer; font-size:14px;"
+                "padding:10px 20px; border-radius:8px; background:var(--fb-surface-muted); color:var(--fb-text-secondary); font-weight:500; border:1px solid var(--fb-border); cursor:pointer; font-size:14px;"
             },
             "{label}"
         }
@@ -142,11 +143,11 @@ fn EngineSection(code_lang: CodeLanguage, engine: &'static str) -> Element {
     
     rsx! {
         section {
-            style: "background:white; border-radius:18px; padding:28px 32px; border:1px solid #e5e7eb; box-shadow:0 6px 20px rgba(15,23,42,0.08);",
+            style: "background:var(--fb-surface); border-radius:18px; padding:28px 32px; border:1px solid var(--fb-border); box-shadow:0 6px 20px rgba(15,23,42,0.08);",
             
             h2 {
                 style: "font-size:22px; font-weight:700; margin:0 0 20px 0; color:#3b82f6;",
-                {if is_ddddocr { "🔹 ddddocr 引擎" } else { "🔸 AntiCAP 引擎" }}
+                {if is_ddddocr { t("captcha_help.ddddocr_engine") } else { t("captcha_help.anticap_engine") }}
             }
 
             div {
@@ -155,36 +156,36 @@ fn EngineSection(code_lang: CodeLanguage, engine: &'static str) -> Element {
                 if is_ddddocr {
                     CaptchaTypeCard {
                         code_lang,
-                        title: "普通验证码 (Text CAPTCHA)",
-                        desc: "识别英数字验证码，支持标准、旧版、Beta 三种模式",
+                        title: t("captcha_help.text"),
+                        desc: t("captcha_help.text_desc"),
                         endpoint: "/api/captcha/ddddocr/ocr",
                         json_body: r#"{{"image": "data:image/png;base64,iVBORw0KG...", "type": "normal"}}"#,
                         mode_params: Some(vec![
-                            ("normal", "标准模式 - 默认模式，适用于大多数验证码"),
-                            ("old", "旧版模式 - 使用旧版 OCR 模型"),
-                            ("beta", "Beta 模式 - 概率 OCR，返回置信度信息"),
+                            ("normal", t("captcha_help.mode_normal")),
+                            ("old", t("captcha_help.mode_old")),
+                            ("beta", t("captcha_help.mode_beta")),
                         ]),
                     }
                     CaptchaTypeCard {
                         code_lang,
-                        title: "目标检测 (Object Detection)",
-                        desc: "检测图片中的目标物体，返回边界框和中心点坐标",
+                        title: t("captcha_help.detection"),
+                        desc: t("captcha_help.detection_desc"),
                         endpoint: "/api/captcha/ddddocr/det",
                         json_body: r#"{{"image": "data:image/png;base64,iVBORw0KG..."}}"#,
                         mode_params: None,
                     }
                     CaptchaTypeCard {
                         code_lang,
-                        title: "滑块验证码 (Slider CAPTCHA)",
-                        desc: "识别滑块位置，需要提供滑块图和背景图",
+                        title: t("captcha_help.slider"),
+                        desc: t("captcha_help.slider_desc"),
                         endpoint: "/api/captcha/ddddocr/slide",
                         json_body: r#"{{"target": "data:image/png;base64,...", "background": "data:image/png;base64,..."}}"#,
                         mode_params: None,
                     }
                     CaptchaTypeCard {
                         code_lang,
-                        title: "点选验证码 (Click CAPTCHA)",
-                        desc: "识别需要点击的目标位置，返回坐标列表",
+                        title: t("captcha_help.click"),
+                        desc: t("captcha_help.click_desc"),
                         endpoint: "/api/captcha/ddddocr/click",
                         json_body: r#"{{"image": "data:image/png;base64,..."}}"#,
                         mode_params: None,
@@ -192,72 +193,72 @@ fn EngineSection(code_lang: CodeLanguage, engine: &'static str) -> Element {
                 } else {
                     CaptchaTypeCard {
                         code_lang,
-                        title: "通用 OCR",
-                        desc: "识别通用文字验证码",
+                        title: t("captcha_help.ocr"),
+                        desc: t("captcha_help.ocr_desc"),
                         endpoint: "/api/captcha/anticap/ocr",
                         json_body: r#"{{"image": "data:image/png;base64,...", "type": "ocr"}}"#,
                         mode_params: None,
                     }
                     CaptchaTypeCard {
                         code_lang,
-                        title: "算术验证码",
-                        desc: "识别并计算算术表达式",
+                        title: t("captcha_help.math"),
+                        desc: t("captcha_help.math_desc"),
                         endpoint: "/api/captcha/anticap/ocr",
                         json_body: r#"{{"image": "data:image/png;base64,...", "type": "math"}}"#,
                         mode_params: None,
                     }
                     CaptchaTypeCard {
                         code_lang,
-                        title: "图标检测",
-                        desc: "检测图片中的图标位置",
+                        title: t("captcha_help.icon"),
+                        desc: t("captcha_help.icon_desc"),
                         endpoint: "/api/captcha/anticap/ocr",
                         json_body: r#"{{"image": "data:image/png;base64,...", "type": "detection_icon"}}"#,
                         mode_params: None,
                     }
                     CaptchaTypeCard {
                         code_lang,
-                        title: "文字检测",
-                        desc: "检测图片中的文字位置",
+                        title: t("captcha_help.text_detection"),
+                        desc: t("captcha_help.text_detection_desc"),
                         endpoint: "/api/captcha/anticap/ocr",
                         json_body: r#"{{"image": "data:image/png;base64,...", "type": "detection_text"}}"#,
                         mode_params: None,
                     }
                     CaptchaTypeCard {
                         code_lang,
-                        title: "单图旋转",
-                        desc: "识别图片需要旋转的角度",
+                        title: t("captcha_help.rotate"),
+                        desc: t("captcha_help.rotate_desc"),
                         endpoint: "/api/captcha/anticap/ocr",
                         json_body: r#"{{"image": "data:image/png;base64,...", "type": "single_rotate"}}"#,
                         mode_params: None,
                     }
                     CaptchaTypeCard {
                         code_lang,
-                        title: "缺口滑块",
-                        desc: "识别缺口滑块的位置",
+                        title: t("captcha_help.gap_slider"),
+                        desc: t("captcha_help.gap_slider_desc"),
                         endpoint: "/api/captcha/anticap/slide",
                         json_body: r#"{{"target": "data:image/png;base64,...", "background": "data:image/png;base64,...", "mode": "match"}}"#,
                         mode_params: None,
                     }
                     CaptchaTypeCard {
                         code_lang,
-                        title: "阴影滑块",
-                        desc: "识别阴影滑块的位置",
+                        title: t("captcha_help.shadow_slider"),
+                        desc: t("captcha_help.shadow_slider_desc"),
                         endpoint: "/api/captcha/anticap/slide",
                         json_body: r#"{{"target": "data:image/png;base64,...", "background": "data:image/png;base64,...", "mode": "comparison"}}"#,
                         mode_params: None,
                     }
                     CaptchaTypeCard {
                         code_lang,
-                        title: "双图旋转",
-                        desc: "识别内外圆图的旋转角度",
+                        title: t("captcha_help.double_rotate"),
+                        desc: t("captcha_help.double_rotate_desc"),
                         endpoint: "/api/captcha/anticap/rotate",
                         json_body: r#"{{"inside": "data:image/png;base64,...", "outside": "data:image/png;base64,..."}}"#,
                         mode_params: None,
                     }
                     CaptchaTypeCard {
                         code_lang,
-                        title: "图片相似度",
-                        desc: "计算两张图片的相似度",
+                        title: t("captcha_help.similarity"),
+                        desc: t("captcha_help.similarity_desc"),
                         endpoint: "/api/captcha/anticap/compare",
                         json_body: r#"{{"image1": "data:image/png;base64,...", "image2": "data:image/png;base64,..."}}"#,
                         mode_params: None,
@@ -282,15 +283,15 @@ fn CaptchaTypeCard(
     
     rsx! {
         div {
-            style: "background:#f9fafb; border-radius:12px; padding:20px; border:1px solid #e5e7eb;",
+            style: "background:var(--fb-surface-muted); border-radius:12px; padding:20px; border:1px solid var(--fb-border);",
             
             h3 {
-                style: "font-size:16px; font-weight:700; margin:0 0 8px 0; color:#111827;",
+                style: "font-size:16px; font-weight:700; margin:0 0 8px 0; color:var(--fb-text);",
                 "{title}"
             }
             
             p {
-                style: "font-size:13px; color:#6b7280; margin:0 0 12px 0; line-height:1.5;",
+                style: "font-size:13px; color:var(--fb-text-muted); margin:0 0 12px 0; line-height:1.5;",
                 "{desc}"
             }
             
@@ -300,7 +301,7 @@ fn CaptchaTypeCard(
                     style: "background:#eff6ff; border-left:3px solid #3b82f6; border-radius:4px; padding:12px; margin-bottom:12px;",
                     div {
                         style: "font-size:12px; font-weight:600; color:#1e40af; margin-bottom:8px;",
-                        "📌 模式参数说明："
+                        {t("captcha_help.modes")}
                     }
                     div {
                         style: "display:flex; flex-direction:column; gap:6px;",
@@ -336,7 +337,7 @@ fn CaptchaTypeCard(
                     style: "flex:1; padding:8px; border-radius:6px; background:#3b82f6; color:white; font-size:13px; font-weight:600; border:none; cursor:pointer; transition:background 0.2s;",
                     onmouseenter: move |_| {},
                     onmouseleave: move |_| {},
-                    {if show_code() { "隐藏代码" } else { "查看代码" }}
+                    {if show_code() { t("captcha_help.hide_code") } else { t("captcha_help.show_code") }}
                 }
             }
             
@@ -366,7 +367,7 @@ fn CaptchaTypeCard(
                                 style: "position:absolute; top:8px; right:8px; padding:6px 12px; border-radius:6px; background:rgba(59,130,246,0.9); color:white; font-size:12px; font-weight:600; border:none; cursor:pointer; transition:all 0.2s; backdrop-filter:blur(4px);",
                                 onmouseenter: move |_| {},
                                 onmouseleave: move |_| {},
-                                {if copy_feedback() { "✓ 已复制" } else { "📋 复制" }}
+                                {if copy_feedback() { t("common.copied") } else { t("common.copy") }}
                             }
                         }
                     }
@@ -1,132 +1,116 @@
 use dioxus::prelude::*;
-use serde::{Deserialize, Serialize};
-use crate::db::{save_config, load_config};
-
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
-struct ProxyConfig {
-    id: usize,
-    ip: String,
-    port: String,
-    username: String,
-    password: String,
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use crate::common::{AppError, Reason};
+use crate::common::i18n::{t, t_args};
+use crate::common::app_lock::{self, AppLockSettings, MIN_PASSPHRASE_LEN};
+use crate::common::{config_history, config_store};
+use crate::common::http_task::{
+    InterceptMode, InterceptRule, Interceptor, InterceptorConfig, PatternKind, Redactor, RuleAction,
+};
+use crate::common::privacy;
+use crate::common::settings_archive::{self, ArchiveSection, ImportMode, SettingsSnapshot};
+use crate::common::window_state;
+use crate::components::{
+    use_app_lock, use_notifier, use_table_state, Button, ButtonVariant, Card, Column, DataTable, Input, Modal, Notifier,
+    Pagination, SortKey, TableState,
+};
+use crate::db::repo::{
+    self, CardConfig, CardGroup, ConfigGroup, OtpConfig, OtpGroup, PassengerGroup, PassengerInfo,
+    ProxyEntry, ProxyGroup,
+};
+use crate::db::location::{self, DataLocation};
+use crate::db::{self, delete_config, load_config, ConfigHistoryEntry};
+
+/// 分组配置 key 及其界面名称的翻译 key
+const GROUP_CONFIG_KEYS: &[(&str, &str)] = &[
+    ("proxy_groups", "config.tab_proxy"),
+    ("otp_groups", "config.tab_otp"),
+    ("card_groups", "config.tab_card"),
+    ("passenger_groups", "config.tab_passenger"),
+];
+
+/// 某类分组的默认内容：一个空的默认分组
+fn default_groups<G: ConfigGroup>() -> Vec<G> {
+    vec![G::new(1, t("config.default_group").to_string(), vec![])]
 }
 
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
-struct ProxyGroup {
-    id: usize,
-    name: String,
-    proxies: Vec<ProxyConfig>,
-}
-
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
-struct CardConfig {
-    id: usize,
-    card_type: String,
-    card_number: String,
-    cvv: String,
-    expiry: String,
-    holder_name: String,
-}
-
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
-struct CardGroup {
-    id: usize,
-    name: String,
-    cards: Vec<CardConfig>,
-}
-
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
-struct PassengerInfo {
-    id: usize,
-    first_name: String,
-    last_name: String,
-    email: String,
-    phone: String,
-    passport: String,
+/// 加载分组配置，没有分组时使用默认分组
+///
+/// - 旧格式数据无法解析时会在迁移中被隔离备份，这时使用默认分组不会覆盖原数据
+/// - 其他读取失败返回 `None`，界面显示重试，不能用默认分组顶替，否则保存时会覆盖数据库中的分组
+fn load_groups<G: ConfigGroup>(mut notifier: Notifier) -> Option<Vec<G>> {
+    match repo::load_groups::<G>() {
+        Ok(groups) if groups.is_empty() => Some(default_groups()),
+        Ok(groups) => Some(groups),
+        Err(e) => {
+            notifier.error(t("config.load_failed"), &e);
+            matches!(e, AppError::ConfigQuarantined { .. }).then(default_groups)
+        }
+    }
 }
 
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
-struct PassengerGroup {
-    id: usize,
-    name: String,
-    passengers: Vec<PassengerInfo>,
+/// 分组配置及其是否读取失败
+fn use_groups<G: ConfigGroup + 'static>(notifier: Notifier) -> (Signal<Vec<G>>, Signal<bool>) {
+    use_hook(|| {
+        let loaded = load_groups::<G>(notifier);
+        (Signal::new(loaded.clone().unwrap_or_default()), Signal::new(loaded.is_none()))
+    })
 }
 
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
-struct OtpConfig {
-    id: usize,
-    name: String,
-    email: String,
-    api_key: String,
-    service_provider: String,
+/// 重新读取失败的分组配置
+fn reload_groups<G: ConfigGroup + 'static>(notifier: Notifier, mut groups: Signal<Vec<G>>, mut failed: Signal<bool>) {
+    if !failed() {
+        return;
+    }
+    if let Some(loaded) = load_groups::<G>(notifier) {
+        groups.set(loaded);
+        failed.set(false);
+    }
 }
 
-#[derive(Clone, PartialEq, Serialize, Deserialize)]
-struct OtpGroup {
-    id: usize,
-    name: String,
-    configs: Vec<OtpConfig>,
+/// 列出所有分组配置的隔离备份：(界面名称, 备份 key)
+fn list_quarantined() -> Vec<(&'static str, String)> {
+    GROUP_CONFIG_KEYS
+        .iter()
+        .flat_map(|(key, label)| {
+            config_store::quarantined_keys(key)
+                .unwrap_or_default()
+                .into_iter()
+                .map(move |backup_key| (t(label), backup_key))
+        })
+        .collect()
 }
 
 #[component]
 pub fn Config() -> Element {
-    // 从数据库加载数据
-    let proxy_groups = use_signal(|| {
-        load_config("proxy_groups")
-            .ok()
-            .flatten()
-            .and_then(|json| serde_json::from_str::<Vec<ProxyGroup>>(&json).ok())
-            .unwrap_or_else(|| vec![
-                ProxyGroup {
-                    id: 1,
-                    name: "默认分组".to_string(),
-                    proxies: vec![],
-                }
-            ])
-    });
-    
-    let card_groups = use_signal(|| {
-        load_config("card_groups")
-            .ok()
-            .flatten()
-            .and_then(|json| serde_json::from_str::<Vec<CardGroup>>(&json).ok())
-            .unwrap_or_else(|| vec![
-                CardGroup {
-                    id: 1,
-                    name: "默认分组".to_string(),
-                    cards: vec![],
-                }
-            ])
-    });
-    
-    let passenger_groups = use_signal(|| {
-        load_config("passenger_groups")
-            .ok()
-            .flatten()
-            .and_then(|json| serde_json::from_str::<Vec<PassengerGroup>>(&json).ok())
-            .unwrap_or_else(|| vec![
-                PassengerGroup {
-                    id: 1,
-                    name: "默认分组".to_string(),
-                    passengers: vec![],
-                }
-            ])
-    });
-    
-    let otp_groups = use_signal(|| {
-        load_config("otp_groups")
-            .ok()
-            .flatten()
-            .and_then(|json| serde_json::from_str::<Vec<OtpGroup>>(&json).ok())
-            .unwrap_or_else(|| vec![
-                OtpGroup {
-                    id: 1,
-                    name: "默认分组".to_string(),
-                    configs: vec![],
-                }
-            ])
+    let mut notifier = use_notifier();
+
+    // 加载前先清除已过期的个人数据记录
+    use_hook(move || {
+        match notifier.report_error(privacy::purge_expired_records(), t("config.purge_failed")) {
+            Some(removed) if removed > 0 => notifier.info(t_args("config.purged", &[&removed])),
+            _ => {}
+        }
     });
 
+    // 从数据库加载数据
+    let (proxy_groups, proxy_failed) = use_groups::<ProxyGroup>(notifier);
+    let (card_groups, card_failed) = use_groups::<CardGroup>(notifier);
+    let (passenger_groups, passenger_failed) = use_groups::<PassengerGroup>(notifier);
+    let (otp_groups, otp_failed) = use_groups::<OtpGroup>(notifier);
+    let retry_load = move |_| {
+        reload_groups(notifier, proxy_groups, proxy_failed);
+        reload_groups(notifier, card_groups, card_failed);
+        reload_groups(notifier, passenger_groups, passenger_failed);
+        reload_groups(notifier, otp_groups, otp_failed);
+    };
+    // 读取失败的分组不能编辑或保存，涉及多类分组的功能需要全部读取成功
+    let any_failed = proxy_failed() || card_failed() || passenger_failed() || otp_failed();
+
+    // 放在分组加载之后，才能看到本次加载时隔离的数据
+    let quarantined = use_signal(list_quarantined);
+
     let active_tab = use_signal(|| "proxy".to_string());
 
     rsx! {
@@ -165,36 +149,75 @@ pub fn Config() -> Element {
                     style: "background:linear-gradient(135deg, #f0f9ff 0%, #e0f2fe 100%); border-radius:20px; padding:28px 32px; border:1px solid #7dd3fc; box-shadow:0 4px 20px rgba(14, 165, 233, 0.1);",
                     h1 {
                         style: "font-size:26px; font-weight:700; margin:0 0 10px 0; color:#0c4a6e; letter-spacing:-0.02em;",
-                        "配置管理中心"
+                        {t("config.title")}
                     }
                     p {
                         style: "color:#0369a1; font-size:15px; margin:0; line-height:1.6;",
-                        "管理代理配置、OTP邮箱服务、支付卡片和购票人信息（支持分组批量保存）"
+                        {t("config.subtitle")}
                     }
                 }
 
+                if !quarantined().is_empty() {
+                    QuarantineNotice { quarantined }
+                }
+
                 div {
-                    style: "background:white; border-radius:18px; padding:8px; border:1px solid #e5e7eb; box-shadow:0 2px 8px rgba(15,23,42,0.04); display:flex; gap:8px; flex-wrap:wrap;",
+                    style: "background:var(--fb-surface); border-radius:18px; padding:8px; border:1px solid var(--fb-border); box-shadow:0 2px 8px rgba(15,23,42,0.04); display:flex; gap:8px; flex-wrap:wrap;",
                     
-                    TabButton { label: "代理配置", value: "proxy", active_tab, icon: "🌐" }
-                    TabButton { label: "OTP邮箱", value: "otp", active_tab, icon: "📧" }
-                    TabButton { label: "支付卡片", value: "card", active_tab, icon: "💳" }
-                    TabButton { label: "购票人信息", value: "passenger", active_tab, icon: "👤" }
+                    TabButton { label: t("config.tab_proxy"), value: "proxy", active_tab, icon: "🌐" }
+                    TabButton { label: t("config.tab_otp"), value: "otp", active_tab, icon: "📧" }
+                    TabButton { label: t("config.tab_card"), value: "card", active_tab, icon: "💳" }
+                    TabButton { label: t("config.tab_passenger"), value: "passenger", active_tab, icon: "👤" }
+                    TabButton { label: t("config.tab_intercept"), value: "intercept", active_tab, icon: "🛡" }
+                    TabButton { label: t("config.tab_security"), value: "security", active_tab, icon: "🔒" }
+                    TabButton { label: t("config.tab_storage"), value: "storage", active_tab, icon: "💾" }
                 }
 
                 match active_tab().as_str() {
+                    "proxy" if proxy_failed() => rsx! {
+                        LoadFailedNotice { on_retry: retry_load }
+                    },
                     "proxy" => rsx! {
                         ProxyGroupSection { proxy_groups }
                     },
+                    "otp" if otp_failed() => rsx! {
+                        LoadFailedNotice { on_retry: retry_load }
+                    },
                     "otp" => rsx! {
                         OtpGroupSection { otp_groups }
                     },
+                    "card" if card_failed() => rsx! {
+                        LoadFailedNotice { on_retry: retry_load }
+                    },
                     "card" => rsx! {
                         CardGroupSection { card_groups }
                     },
+                    "passenger" if passenger_failed() => rsx! {
+                        LoadFailedNotice { on_retry: retry_load }
+                    },
                     "passenger" => rsx! {
                         PassengerGroupSection { passenger_groups }
                     },
+                    "intercept" => rsx! {
+                        InterceptorSection {}
+                    },
+                    "security" => rsx! {
+                        AppLockSection {}
+                        WindowSection {}
+                        if any_failed {
+                            LoadFailedNotice { on_retry: retry_load }
+                        } else {
+                            PrivacySection { card_groups, passenger_groups, otp_groups }
+                        }
+                    },
+                    "storage" => rsx! {
+                        DataDirectorySection {}
+                        if any_failed {
+                            LoadFailedNotice { on_retry: retry_load }
+                        } else {
+                            SettingsBackupSection { proxy_groups, otp_groups, card_groups, passenger_groups }
+                        }
+                    },
                     _ => rsx! { div {} }
                 }
             }
@@ -202,13 +225,109 @@ pub fn Config() -> Element {
     }
 }
 
+/// 配置解析失败提示：列出隔离备份，可查看或删除
+#[component]
+fn QuarantineNotice(quarantined: Signal<Vec<(&'static str, String)>>) -> Element {
+    let mut notifier = use_notifier();
+    let mut inspected = use_signal(|| Option::<String>::None);
+
+    rsx! {
+        section {
+            style: "background:#fef2f2; border-radius:16px; padding:20px 24px; border:1px solid var(--fb-danger-border); display:flex; flex-direction:column; gap:10px;",
+            div {
+                style: "font-size:15px; font-weight:600; color:#991b1b;",
+                {t("config.quarantined")}
+            }
+            div {
+                style: "font-size:13px; color:#b91c1c; line-height:1.6;",
+                {t("config.quarantined_desc")}
+            }
+            for (label, backup_key) in quarantined() {
+                div {
+                    key: "{backup_key}",
+                    style: "background:var(--fb-surface); border-radius:10px; border:1px solid #fecaca; padding:10px 14px;",
+                    div {
+                        style: "display:flex; align-items:center; gap:12px;",
+                        span {
+                            style: "font-size:13px; font-weight:600; color:var(--fb-text); white-space:nowrap;",
+                            "{label}"
+                        }
+                        span {
+                            style: "flex:1; font-size:12px; color:var(--fb-text-muted); font-family:monospace; word-break:break-all;",
+                            "{backup_key}"
+                        }
+                        button {
+                            style: "padding:4px 10px; border-radius:6px; border:1px solid var(--fb-border-strong); background:var(--fb-surface); color:var(--fb-text-muted); cursor:pointer; font-size:12px;",
+                            onclick: {
+                                let backup_key = backup_key.clone();
+                                move |_| {
+                                    if inspected().as_deref() == Some(backup_key.as_str()) {
+                                        inspected.set(None);
+                                    } else {
+                                        inspected.set(Some(backup_key.clone()));
+                                    }
+                                }
+                            },
+                            {t("config.view")}
+                        }
+                        button {
+                            style: "padding:4px 10px; border-radius:6px; border:1px solid var(--fb-danger-border); background:var(--fb-surface); color:var(--fb-danger); cursor:pointer; font-size:12px;",
+                            onclick: {
+                                let backup_key = backup_key.clone();
+                                move |_| {
+                                    if notifier.report(delete_config(&backup_key), t("config.backup_deleted"), t("config.delete_backup_failed")).is_some() {
+                                        quarantined.set(list_quarantined());
+                                    }
+                                }
+                            },
+                            {t("config.delete_backup")}
+                        }
+                    }
+                    if inspected().as_deref() == Some(backup_key.as_str()) {
+                        pre {
+                            style: "margin:10px 0 0 0; padding:12px; background:#0f172a; color:#e2e8f0; border-radius:8px; font-size:12px; max-height:240px; overflow:auto; white-space:pre-wrap; word-break:break-all;",
+                            {history_preview(&load_config(&backup_key).ok().flatten().unwrap_or_default())}
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 配置读取失败提示：不显示编辑界面，避免保存时覆盖数据库中的原数据
+#[component]
+fn LoadFailedNotice(on_retry: EventHandler<()>) -> Element {
+    rsx! {
+        section {
+            style: "background:#fef2f2; border-radius:16px; padding:20px 24px; border:1px solid var(--fb-danger-border); display:flex; align-items:center; gap:16px;",
+            div {
+                style: "flex:1; display:flex; flex-direction:column; gap:6px;",
+                div {
+                    style: "font-size:15px; font-weight:600; color:#991b1b;",
+                    {t("config.load_failed")}
+                }
+                div {
+                    style: "font-size:13px; color:#b91c1c; line-height:1.6;",
+                    {t("config.load_failed_desc")}
+                }
+            }
+            button {
+                style: "padding:8px 18px; border-radius:8px; border:1px solid var(--fb-danger-border); background:var(--fb-surface); color:var(--fb-danger); cursor:pointer; font-size:13px; font-weight:600;",
+                onclick: move |_| on_retry.call(()),
+                {t("config.retry")}
+            }
+        }
+    }
+}
+
 #[component]
 fn TabButton(label: &'static str, value: &'static str, active_tab: Signal<String>, icon: &'static str) -> Element {
     let is_active = active_tab() == value;
     let style = if is_active {
-        "padding:12px 24px; border-radius:12px; background:linear-gradient(120deg,#4f46e5,#7c3aed); color:white; font-weight:600; cursor:pointer; border:none; transition:all 0.2s ease; box-shadow:0 4px 12px rgba(79, 70, 229, 0.3);"
+        "padding:12px 24px; border-radius:12px; background:var(--fb-primary-gradient); color:white; font-weight:600; cursor:pointer; border:none; transition:all 0.2s ease; box-shadow:var(--fb-primary-shadow);"
     } else {
-        "padding:12px 24px; border-radius:12px; background:transparent; color:#6b7280; font-weight:500; cursor:pointer; border:none; transition:all 0.2s ease;"
+        "padding:12px 24px; border-radius:12px; background:transparent; color:var(--fb-text-muted); font-weight:500; cursor:pointer; border:none; transition:all 0.2s ease;"
     };
 
     rsx! {
@@ -225,34 +344,34 @@ fn ProxyGroupSection(proxy_groups: Signal<Vec<ProxyGroup>>) -> Element {
     let mut selected_group = use_signal(|| 0usize);
 
     rsx! {
-        section {
-            style: "background:white; border-radius:20px; padding:28px 32px; border:1px solid #e5e7eb; box-shadow:0 8px 30px rgba(15,23,42,0.08);",
+        Card {
             
             div {
                 style: "display:flex; justify-content:space-between; align-items:center; margin-bottom:24px;",
                 div {
                     h2 {
-                        style: "font-size:22px; font-weight:700; margin:0 0 6px 0; color:#111827; letter-spacing:-0.02em;",
-                        "代理配置分组"
+                        style: "font-size:22px; font-weight:700; margin:0 0 6px 0; color:var(--fb-text); letter-spacing:-0.02em;",
+                        {t("config.proxy_groups")}
                     }
                     p {
-                        style: "margin:0; color:#6b7280; font-size:14px;",
-                        "每个分组可以保存多个代理配置"
+                        style: "margin:0; color:var(--fb-text-muted); font-size:14px;",
+                        {t("config.proxy_groups_desc")}
                     }
                 }
-                button {
-                    style: "padding:11px 20px; border-radius:12px; border:none; background:linear-gradient(120deg,#10b981,#059669); color:white; font-weight:600; cursor:pointer; box-shadow:0 4px 12px rgba(16, 185, 129, 0.3);",
+                Button {
+                    variant: ButtonVariant::Success,
+                    style: "padding:11px 20px; border-radius:12px; box-shadow:0 4px 12px rgba(16, 185, 129, 0.3);",
                     onclick: move |_| {
                         let mut groups = proxy_groups();
                         let new_id = groups.iter().map(|g| g.id).max().unwrap_or(0) + 1;
                         groups.push(ProxyGroup {
                             id: new_id,
-                            name: format!("分组 {}", new_id),
+                            name: t_args("config.group_name_default", &[&new_id]),
                             proxies: vec![],
                         });
                         proxy_groups.set(groups);
                     },
-                    "+ 新建分组"
+                    {t("config.new_group")}
                 }
             }
 
@@ -264,7 +383,7 @@ fn ProxyGroupSection(proxy_groups: Signal<Vec<ProxyGroup>>) -> Element {
                         let btn_style = if is_selected {
                             "padding:10px 18px; border-radius:10px; background:#4f46e5; color:white; font-weight:600; cursor:pointer; border:1px solid #4f46e5;"
                         } else {
-                            "padding:10px 18px; border-radius:10px; background:white; color:#6b7280; font-weight:500; cursor:pointer; border:1px solid #d1d5db;"
+                            "padding:10px 18px; border-radius:10px; background:var(--fb-surface); color:var(--fb-text-muted); font-weight:500; cursor:pointer; border:1px solid var(--fb-border-strong);"
                         };
                         rsx! {
                             button {
@@ -285,21 +404,31 @@ fn ProxyGroupSection(proxy_groups: Signal<Vec<ProxyGroup>>) -> Element {
                     proxy_groups
                 }
             }
+
+            ConfigHistoryPanel {
+                config_key: "proxy_groups",
+                on_restore: move |history_id: i64| {
+                    let groups = repo::restore_history::<ProxyGroup>(history_id).map_err(|e| e.to_string())?;
+                    selected_group.set(0);
+                    proxy_groups.set(groups);
+                    Ok(())
+                }
+            }
         }
     }
 }
 
 #[component]
 fn ProxyGroupEditor(group: ProxyGroup, group_index: usize, proxy_groups: Signal<Vec<ProxyGroup>>) -> Element {
+    let mut notifier = use_notifier();
     let mut show_batch_modal = use_signal(|| false);
     let batch_input = use_signal(|| "".to_string());
-    let mut page_size = use_signal(|| 20usize);
-    let mut current_page = use_signal(|| 1usize);
-    
-    let total_items = group.proxies.len();
-    let total_pages = total_items.div_ceil(page_size());
-    let start_index = (current_page() - 1) * page_size();
-    let end_index = (start_index + page_size()).min(total_items);
+    let mut table = use_table_state(|| TableState::new(20));
+    // 切换分组后回到第一页并清空选择
+    use_effect(use_reactive!(|group_index| {
+        let _ = group_index;
+        table.write().reset();
+    }));
 
     rsx! {
         div {
@@ -314,106 +443,89 @@ fn ProxyGroupEditor(group: ProxyGroup, group_index: usize, proxy_groups: Signal<
                         groups[group_index].name = evt.value();
                         proxy_groups.set(groups);
                     },
-                    style: "flex:1; max-width:300px; padding:10px 14px; border-radius:10px; border:1px solid #86efac; font-size:16px; font-weight:600; background:white;",
-                    placeholder: "分组名称"
+                    style: "flex:1; max-width:300px; padding:10px 14px; border-radius:10px; border:1px solid #86efac; font-size:16px; font-weight:600; background:var(--fb-surface);",
+                    placeholder: t("config.group_name")
                 }
                 div {
                     style: "display:flex; gap:12px;",
                     button {
                         style: "padding:10px 18px; border-radius:10px; border:none; background:linear-gradient(120deg,#8b5cf6,#6366f1); color:white; font-weight:600; cursor:pointer;",
                         onclick: move |_| show_batch_modal.set(true),
-                        "📋 批量导入"
+                        {t("config.bulk_import")}
                     }
                     button {
-                        style: "padding:10px 18px; border-radius:10px; border:1px solid #fca5a5; background:#fee2e2; color:#dc2626; font-weight:600; cursor:pointer;",
+                        style: "padding:10px 18px; border-radius:10px; border:1px solid var(--fb-danger-border); background:var(--fb-danger-bg); color:var(--fb-danger); font-weight:600; cursor:pointer;",
                         onclick: move |_| {
                             let mut groups = proxy_groups();
                             groups.remove(group_index);
                             proxy_groups.set(groups);
                         },
-                        "删除分组"
+                        {t("config.delete_group")}
                     }
                 }
             }
 
             if !group.proxies.is_empty() {
-                div {
-                    style: "display:flex; justify-content:space-between; align-items:center; margin-bottom:12px; padding:0 4px;",
-                    div {
-                        style: "font-size:13px; color:#6b7280;",
-                        "共 {total_items} 条记录，第 {start_index + 1}-{end_index} 条"
-                    }
-                    div {
-                        style: "display:flex; align-items:center; gap:12px;",
-                        span {
-                            style: "font-size:13px; color:#6b7280;",
-                            "每页显示："
-                        }
-                        select {
-                            value: page_size().to_string(),
-                            onchange: move |evt| {
-                                if let Ok(size) = evt.value().parse::<usize>() {
-                                    page_size.set(size);
-                                    current_page.set(1);
-                                }
-                            },
-                            style: "padding:6px 10px; border-radius:6px; border:1px solid #d1d5db; font-size:13px; background:white; cursor:pointer;",
-                            option { value: "20", "20 条" }
-                            option { value: "50", "50 条" }
-                            option { value: "100", "100 条" }
+                DataTable {
+                    columns: vec![
+                        Column::new("id", "#", 70).sortable(),
+                        Column::new("ip", t("config.column_ip"), 0).sortable().always_visible(),
+                        Column::new("port", t("config.column_port"), 120).sortable(),
+                        Column::new("username", t("config.column_username"), 180).sortable(),
+                        Column::new("password", t("config.column_password"), 200),
+                        Column::new("actions", t("logs.column_actions"), 100).centered(),
+                    ],
+                    row_count: group.proxies.len(),
+                    state: table,
+                    selectable: true,
+                    row_height: 56,
+                    page_sizes: vec![20, 50, 100],
+                    sort_key: move |(row, column): (usize, &'static str)| {
+                        let groups = proxy_groups.read();
+                        let proxy = &groups[group_index].proxies[row];
+                        match column {
+                            "ip" => SortKey::Text(proxy.ip.clone()),
+                            "port" => proxy.port.parse().map(SortKey::Number).unwrap_or_else(|_| SortKey::Text(proxy.port.clone())),
+                            "username" => SortKey::Text(proxy.username.clone()),
+                            _ => SortKey::Number(proxy.id as i64),
                         }
-                    }
-                }
-                
-                div {
-                    style: "background:white; border-radius:12px; overflow:hidden; border:1px solid #e5e7eb;",
-                    table {
-                        style: "width:100%; border-collapse:collapse;",
-                        thead {
-                            tr {
-                                style: "background:#f9fafb;",
-                                th { style: "padding:12px 16px; text-align:left; font-size:13px; font-weight:600; color:#374151; border-bottom:2px solid #e5e7eb;", "#" }
-                                th { style: "padding:12px 16px; text-align:left; font-size:13px; font-weight:600; color:#374151; border-bottom:2px solid #e5e7eb;", "IP 地址" }
-                                th { style: "padding:12px 16px; text-align:left; font-size:13px; font-weight:600; color:#374151; border-bottom:2px solid #e5e7eb;", "端口" }
-                                th { style: "padding:12px 16px; text-align:left; font-size:13px; font-weight:600; color:#374151; border-bottom:2px solid #e5e7eb;", "用户名" }
-                                th { style: "padding:12px 16px; text-align:left; font-size:13px; font-weight:600; color:#374151; border-bottom:2px solid #e5e7eb;", "密码" }
-                                th { style: "padding:12px 16px; text-align:center; font-size:13px; font-weight:600; color:#374151; border-bottom:2px solid #e5e7eb;", "操作" }
-                            }
+                    },
+                    render_cell: move |(row, column): (usize, &'static str)| {
+                        match proxy_groups.read()[group_index].proxies.get(row).cloned() {
+                            Some(proxy) => rsx! { ProxyCell { proxy, column, proxy_index: row, group_index, proxy_groups, table } },
+                            None => rsx! {},
                         }
-                        tbody {
-                            for (proxy_index, proxy) in group.proxies.iter().enumerate().skip(start_index).take(page_size()) {
-                                ProxyTableRow {
-                                    proxy: proxy.clone(),
-                                    proxy_index,
-                                    group_index,
-                                    proxy_groups
+                    },
+                    if !table.read().selected.is_empty() {
+                        button {
+                            style: "padding:6px 12px; border-radius:8px; border:1px solid var(--fb-danger-border); background:var(--fb-danger-bg); color:var(--fb-danger); font-weight:500; cursor:pointer; font-size:13px;",
+                            onclick: move |_| {
+                                let selected = std::mem::take(&mut table.write().selected);
+                                let mut groups = proxy_groups();
+                                for index in selected.into_iter().rev() {
+                                    groups[group_index].proxies.remove(index);
                                 }
-                            }
+                                proxy_groups.set(groups);
+                            },
+                            {t("config.delete_selected")}
                         }
                     }
                 }
-                
-                if total_pages > 1 {
-                    Pagination { current_page, total_pages }
-                }
             } else {
                 div {
-                    style: "text-align:center; padding:40px; color:#6b7280; font-size:14px; background:white; border-radius:12px; border:1px dashed #d1d5db;",
-                    "暂无代理配置，点击「批量导入」开始配置"
+                    style: "text-align:center; padding:40px; color:var(--fb-text-muted); font-size:14px; background:var(--fb-surface); border-radius:12px; border:1px dashed var(--fb-border-strong);",
+                    {t("config.no_proxies")}
                 }
             }
 
             div {
                 style: "margin-top:20px; padding-top:20px; border-top:1px solid #86efac; display:flex; justify-content:flex-end;",
-                button {
-                    style: "padding:12px 28px; border-radius:12px; border:none; background:linear-gradient(120deg,#4f46e5,#7c3aed); color:white; font-weight:600; cursor:pointer; box-shadow:0 4px 12px rgba(79, 70, 229, 0.3);",
+                Button {
+                    style: "padding:12px 28px; border-radius:12px;",
                     onclick: move |_| {
-                        let groups = proxy_groups();
-                        if let Ok(json) = serde_json::to_string(&groups) {
-                            let _ = save_config("proxy_groups", &json);
-                        }
+                        notifier.report(repo::save_groups(&proxy_groups()), t("config.proxy_groups_saved"), t("config.save_proxy_groups_failed"));
                     },
-                    "💾 保存分组配置"
+                    {t("config.save_groups")}
                 }
             }
         }
@@ -429,158 +541,139 @@ fn ProxyGroupEditor(group: ProxyGroup, group_index: usize, proxy_groups: Signal<
     }
 }
 
+/// 代理表格单元格；增删行后行号会变化，因此删除时同时清空选择
 #[component]
-fn ProxyTableRow(proxy: ProxyConfig, proxy_index: usize, group_index: usize, proxy_groups: Signal<Vec<ProxyGroup>>) -> Element {
-    rsx! {
-        tr {
-            style: "border-bottom:1px solid #f3f4f6; transition:background 0.15s ease;",
-            td {
-                style: "padding:12px 16px; font-size:14px; color:#6b7280;",
-                "{proxy.id}"
-            }
-            td {
-                style: "padding:12px 16px;",
-                input {
-                    value: proxy.ip.clone(),
-                    oninput: move |evt| {
-                        let mut groups = proxy_groups();
-                        groups[group_index].proxies[proxy_index].ip = evt.value();
-                        proxy_groups.set(groups);
-                    },
-                    style: "width:100%; padding:8px 10px; border-radius:6px; border:1px solid #e5e7eb; font-size:13px;",
-                    placeholder: "127.0.0.1"
-                }
+fn ProxyCell(
+    proxy: ProxyEntry,
+    column: &'static str,
+    proxy_index: usize,
+    group_index: usize,
+    proxy_groups: Signal<Vec<ProxyGroup>>,
+    table: Signal<TableState>,
+) -> Element {
+    let mut update = move |apply: &dyn Fn(&mut ProxyEntry)| {
+        let mut groups = proxy_groups();
+        apply(&mut groups[group_index].proxies[proxy_index]);
+        proxy_groups.set(groups);
+    };
+    let input_style = "width:100%; padding:8px 10px; border-radius:6px; border:1px solid var(--fb-border); font-size:13px;";
+
+    match column {
+        "id" => rsx! {
+            span { style: "font-size:14px; color:var(--fb-text-muted);", "{proxy.id}" }
+        },
+        "ip" => rsx! {
+            input {
+                value: proxy.ip.clone(),
+                oninput: move |evt| update(&|proxy| proxy.ip = evt.value()),
+                style: input_style,
+                placeholder: "127.0.0.1"
             }
-            td {
-                style: "padding:12px 16px;",
-                input {
-                    value: proxy.port.clone(),
-                    oninput: move |evt| {
-                        let mut groups = proxy_groups();
-                        groups[group_index].proxies[proxy_index].port = evt.value();
-                        proxy_groups.set(groups);
-                    },
-                    style: "width:100%; padding:8px 10px; border-radius:6px; border:1px solid #e5e7eb; font-size:13px;",
-                    placeholder: "7897"
-                }
+        },
+        "port" => rsx! {
+            input {
+                value: proxy.port.clone(),
+                oninput: move |evt| update(&|proxy| proxy.port = evt.value()),
+                style: input_style,
+                placeholder: "7897"
             }
-            td {
-                style: "padding:12px 16px;",
-                input {
-                    value: proxy.username.clone(),
-                    oninput: move |evt| {
-                        let mut groups = proxy_groups();
-                        groups[group_index].proxies[proxy_index].username = evt.value();
-                        proxy_groups.set(groups);
-                    },
-                    style: "width:100%; padding:8px 10px; border-radius:6px; border:1px solid #e5e7eb; font-size:13px;",
-                    placeholder: "可选"
-                }
+        },
+        "username" => rsx! {
+            input {
+                value: proxy.username.clone(),
+                oninput: move |evt| update(&|proxy| proxy.username = evt.value()),
+                style: input_style,
+                placeholder: t("config.optional")
             }
-            td {
-                style: "padding:12px 16px;",
-                input {
-                    r#type: "password",
-                    value: proxy.password.clone(),
-                    oninput: move |evt| {
-                        let mut groups = proxy_groups();
-                        groups[group_index].proxies[proxy_index].password = evt.value();
-                        proxy_groups.set(groups);
-                    },
-                    style: "width:100%; padding:8px 10px; border-radius:6px; border:1px solid #e5e7eb; font-size:13px;",
-                    placeholder: "可选"
-                }
+        },
+        "password" => rsx! {
+            SecretInput {
+                value: proxy.password.clone(),
+                placeholder: t("config.optional"),
+                onchange: move |val: String| update(&|proxy| proxy.password = val.clone())
             }
-            td {
-                style: "padding:12px 16px; text-align:center;",
-                button {
-                    style: "padding:6px 12px; border-radius:6px; border:1px solid #fca5a5; background:#fee2e2; color:#dc2626; font-weight:500; cursor:pointer; font-size:12px;",
-                    onclick: move |_| {
-                        let mut groups = proxy_groups();
-                        groups[group_index].proxies.remove(proxy_index);
-                        proxy_groups.set(groups);
-                    },
-                    "删除"
-                }
+        },
+        _ => rsx! {
+            button {
+                style: "padding:6px 12px; border-radius:6px; border:1px solid var(--fb-danger-border); background:var(--fb-danger-bg); color:var(--fb-danger); font-weight:500; cursor:pointer; font-size:12px;",
+                onclick: move |_| {
+                    let mut groups = proxy_groups();
+                    groups[group_index].proxies.remove(proxy_index);
+                    proxy_groups.set(groups);
+                    table.write().selected.clear();
+                },
+                {t("config.delete")}
             }
-        }
+        },
     }
 }
 
 #[component]
 fn BatchImportModal(show_modal: Signal<bool>, batch_input: Signal<String>, group_index: usize, proxy_groups: Signal<Vec<ProxyGroup>>) -> Element {
     rsx! {
-        div {
-            style: "position:fixed; top:0; left:0; right:0; bottom:0; background:rgba(0,0,0,0.5); display:flex; align-items:center; justify-content:center; z-index:9999; backdrop-filter:blur(4px);",
-            onclick: move |_| show_modal.set(false),
-            
+        Modal {
+            title: t("config.import_proxies"),
+            max_width: 700,
+            on_close: move |_| show_modal.set(false),
+
+            p {
+                style: "margin:0 0 20px 0; color:var(--fb-text-muted); font-size:14px; line-height:1.6;",
+                {t("config.import_format")}
+            }
+
+            textarea {
+                class: "fb-input",
+                value: batch_input(),
+                oninput: move |evt| batch_input.set(evt.value()),
+                style: "height:300px; padding:14px; border-radius:12px; font-size:13px; font-family:monospace; resize:vertical; line-height:1.6;",
+                placeholder: "127.0.0.1:7897:user1:pass1\n192.168.1.1:8080:user2:pass2\n10.0.0.1:3128"
+            }
+
             div {
-                style: "background:white; border-radius:20px; padding:32px; max-width:700px; width:90%; box-shadow:0 25px 50px rgba(0,0,0,0.3);",
-                onclick: move |evt| evt.stop_propagation(),
-                
-                h3 {
-                    style: "margin:0 0 8px 0; font-size:22px; font-weight:700; color:#111827;",
-                    "批量导入代理"
-                }
-                p {
-                    style: "margin:0 0 20px 0; color:#6b7280; font-size:14px; line-height:1.6;",
-                    "支持格式：ip:port:username:password 或 ip:port（每行一个）"
-                }
-                
-                textarea {
-                    value: batch_input(),
-                    oninput: move |evt| batch_input.set(evt.value()),
-                    style: "width:100%; height:300px; padding:14px; border-radius:12px; border:1px solid #d1d5db; font-size:13px; font-family:monospace; resize:vertical; line-height:1.6;",
-                    placeholder: "127.0.0.1:7897:user1:pass1\n192.168.1.1:8080:user2:pass2\n10.0.0.1:3128"
+                class: "fb-modal-actions",
+                Button {
+                    variant: ButtonVariant::Secondary,
+                    onclick: move |_| {
+                        show_modal.set(false);
+                        batch_input.set("".to_string());
+                    },
+                    {t("config.cancel")}
                 }
-                
-                div {
-                    style: "margin-top:24px; display:flex; justify-content:flex-end; gap:12px;",
-                    button {
-                        style: "padding:10px 20px; border-radius:10px; border:1px solid #d1d5db; background:white; color:#6b7280; font-weight:600; cursor:pointer;",
-                        onclick: move |_| {
-                            show_modal.set(false);
-                            batch_input.set("".to_string());
-                        },
-                        "取消"
-                    }
-                    button {
-                        style: "padding:10px 20px; border-radius:10px; border:none; background:linear-gradient(120deg,#4f46e5,#7c3aed); color:white; font-weight:600; cursor:pointer; box-shadow:0 4px 12px rgba(79, 70, 229, 0.3);",
-                        onclick: move |_| {
-                            let input = batch_input();
-                            let mut groups = proxy_groups();
-                            let mut next_id = groups[group_index].proxies.iter().map(|p| p.id).max().unwrap_or(0) + 1;
+                Button {
+                    onclick: move |_| {
+                        let input = batch_input();
+                        let mut groups = proxy_groups();
+                        let mut next_id = groups[group_index].proxies.iter().map(|p| p.id).max().unwrap_or(0) + 1;
+                        
+                        for line in input.lines() {
+                            let line = line.trim();
+                            if line.is_empty() {
+                                continue;
+                            }
                             
-                            for line in input.lines() {
-                                let line = line.trim();
-                                if line.is_empty() {
-                                    continue;
-                                }
+                            let parts: Vec<&str> = line.split(':').collect();
+                            if parts.len() >= 2 {
+                                let ip = parts[0].to_string();
+                                let port = parts[1].to_string();
+                                let username = if parts.len() > 2 { parts[2].to_string() } else { "".to_string() };
+                                let password = if parts.len() > 3 { parts[3].to_string() } else { "".to_string() };
                                 
-                                let parts: Vec<&str> = line.split(':').collect();
-                                if parts.len() >= 2 {
-                                    let ip = parts[0].to_string();
-                                    let port = parts[1].to_string();
-                                    let username = if parts.len() > 2 { parts[2].to_string() } else { "".to_string() };
-                                    let password = if parts.len() > 3 { parts[3].to_string() } else { "".to_string() };
-                                    
-                                    groups[group_index].proxies.push(ProxyConfig {
-                                        id: next_id,
-                                        ip,
-                                        port,
-                                        username,
-                                        password,
-                                    });
-                                    next_id += 1;
-                                }
+                                groups[group_index].proxies.push(ProxyEntry {
+                                    id: next_id,
+                                    ip,
+                                    port,
+                                    username,
+                                    password,
+                                });
+                                next_id += 1;
                             }
-                            
-                            proxy_groups.set(groups);
-                            show_modal.set(false);
-                            batch_input.set("".to_string());
-                        },
-                        "导入"
-                    }
+                        }
+                        
+                        proxy_groups.set(groups);
+                        show_modal.set(false);
+                        batch_input.set("".to_string());
+                    },
+                    {t("config.import")}
                 }
             }
         }
@@ -592,34 +685,34 @@ fn OtpGroupSection(otp_groups: Signal<Vec<OtpGroup>>) -> Element {
     let mut selected_group = use_signal(|| 0usize);
 
     rsx! {
-        section {
-            style: "background:white; border-radius:20px; padding:28px 32px; border:1px solid #e5e7eb; box-shadow:0 8px 30px rgba(15,23,42,0.08);",
+        Card {
             
             div {
                 style: "display:flex; justify-content:space-between; align-items:center; margin-bottom:24px;",
                 div {
                     h2 {
-                        style: "font-size:22px; font-weight:700; margin:0 0 6px 0; color:#111827; letter-spacing:-0.02em;",
-                        "OTP 邮箱服务分组"
+                        style: "font-size:22px; font-weight:700; margin:0 0 6px 0; color:var(--fb-text); letter-spacing:-0.02em;",
+                        {t("config.otp_groups")}
                     }
                     p {
-                        style: "margin:0; color:#6b7280; font-size:14px;",
-                        "每个分组可以保存多个 OTP 邮箱配置"
+                        style: "margin:0; color:var(--fb-text-muted); font-size:14px;",
+                        {t("config.otp_groups_desc")}
                     }
                 }
-                button {
-                    style: "padding:11px 20px; border-radius:12px; border:none; background:linear-gradient(120deg,#10b981,#059669); color:white; font-weight:600; cursor:pointer; box-shadow:0 4px 12px rgba(16, 185, 129, 0.3);",
+                Button {
+                    variant: ButtonVariant::Success,
+                    style: "padding:11px 20px; border-radius:12px; box-shadow:0 4px 12px rgba(16, 185, 129, 0.3);",
                     onclick: move |_| {
                         let mut groups = otp_groups();
                         let new_id = groups.iter().map(|g| g.id).max().unwrap_or(0) + 1;
                         groups.push(OtpGroup {
                             id: new_id,
-                            name: format!("分组 {}", new_id),
+                            name: t_args("config.group_name_default", &[&new_id]),
                             configs: vec![],
                         });
                         otp_groups.set(groups);
                     },
-                    "+ 新建分组"
+                    {t("config.new_group")}
                 }
             }
 
@@ -631,7 +724,7 @@ fn OtpGroupSection(otp_groups: Signal<Vec<OtpGroup>>) -> Element {
                         let btn_style = if is_selected {
                             "padding:10px 18px; border-radius:10px; background:#4f46e5; color:white; font-weight:600; cursor:pointer; border:1px solid #4f46e5;"
                         } else {
-                            "padding:10px 18px; border-radius:10px; background:white; color:#6b7280; font-weight:500; cursor:pointer; border:1px solid #d1d5db;"
+                            "padding:10px 18px; border-radius:10px; background:var(--fb-surface); color:var(--fb-text-muted); font-weight:500; cursor:pointer; border:1px solid var(--fb-border-strong);"
                         };
                         rsx! {
                             button {
@@ -652,19 +745,29 @@ fn OtpGroupSection(otp_groups: Signal<Vec<OtpGroup>>) -> Element {
                     otp_groups
                 }
             }
+
+            ConfigHistoryPanel {
+                config_key: "otp_groups",
+                on_restore: move |history_id: i64| {
+                    let groups = repo::restore_history::<OtpGroup>(history_id).map_err(|e| e.to_string())?;
+                    selected_group.set(0);
+                    otp_groups.set(groups);
+                    Ok(())
+                }
+            }
         }
     }
 }
 
 #[component]
 fn OtpGroupEditor(group: OtpGroup, group_index: usize, otp_groups: Signal<Vec<OtpGroup>>) -> Element {
-    let mut page_size = use_signal(|| 20usize);
-    let mut current_page = use_signal(|| 1usize);
-    
-    let total_items = group.configs.len();
-    let total_pages = total_items.div_ceil(page_size());
-    let start_index = (current_page() - 1) * page_size();
-    let end_index = (start_index + page_size()).min(total_items);
+    let mut notifier = use_notifier();
+    let mut table = use_table_state(|| TableState::new(20));
+    // 切换分组后回到第一页并清空选择
+    use_effect(use_reactive!(|group_index| {
+        let _ = group_index;
+        table.write().reset();
+    }));
 
     rsx! {
         div {
@@ -679,198 +782,171 @@ fn OtpGroupEditor(group: OtpGroup, group_index: usize, otp_groups: Signal<Vec<Ot
                         groups[group_index].name = evt.value();
                         otp_groups.set(groups);
                     },
-                    style: "flex:1; max-width:300px; padding:10px 14px; border-radius:10px; border:1px solid #fbbf24; font-size:16px; font-weight:600; background:white;",
-                    placeholder: "分组名称"
+                    style: "flex:1; max-width:300px; padding:10px 14px; border-radius:10px; border:1px solid #fbbf24; font-size:16px; font-weight:600; background:var(--fb-surface);",
+                    placeholder: t("config.group_name")
                 }
                 div {
                     style: "display:flex; gap:12px;",
-                    button {
-                        style: "padding:10px 18px; border-radius:10px; border:none; background:linear-gradient(120deg,#10b981,#059669); color:white; font-weight:600; cursor:pointer;",
+                    Button {
+                        variant: ButtonVariant::Success,
+                        style: "padding:10px 18px;",
                         onclick: move |_| {
                             let mut groups = otp_groups();
                             let new_id = groups[group_index].configs.iter().map(|c| c.id).max().unwrap_or(0) + 1;
                             groups[group_index].configs.push(OtpConfig {
                                 id: new_id,
-                                name: format!("配置 {}", new_id),
+                                name: t_args("config.otp_name_default", &[&new_id]),
                                 email: "".to_string(),
                                 api_key: "".to_string(),
                                 service_provider: "".to_string(),
                             });
                             otp_groups.set(groups);
                         },
-                        "+ 添加配置"
+                        {t("config.add_otp")}
                     }
                     button {
-                        style: "padding:10px 18px; border-radius:10px; border:1px solid #fca5a5; background:#fee2e2; color:#dc2626; font-weight:600; cursor:pointer;",
+                        style: "padding:10px 18px; border-radius:10px; border:1px solid var(--fb-danger-border); background:var(--fb-danger-bg); color:var(--fb-danger); font-weight:600; cursor:pointer;",
                         onclick: move |_| {
                             let mut groups = otp_groups();
                             groups.remove(group_index);
                             otp_groups.set(groups);
                         },
-                        "删除分组"
+                        {t("config.delete_group")}
                     }
                 }
             }
 
             if !group.configs.is_empty() {
-                div {
-                    style: "display:flex; justify-content:space-between; align-items:center; margin-bottom:12px; padding:0 4px;",
-                    div {
-                        style: "font-size:13px; color:#92400e;",
-                        "共 {total_items} 条记录，第 {start_index + 1}-{end_index} 条"
-                    }
-                    div {
-                        style: "display:flex; align-items:center; gap:12px;",
-                        span {
-                            style: "font-size:13px; color:#92400e;",
-                            "每页显示："
-                        }
-                        select {
-                            value: page_size().to_string(),
-                            onchange: move |evt| {
-                                if let Ok(size) = evt.value().parse::<usize>() {
-                                    page_size.set(size);
-                                    current_page.set(1);
-                                }
-                            },
-                            style: "padding:6px 10px; border-radius:6px; border:1px solid #fbbf24; font-size:13px; background:white; cursor:pointer;",
-                            option { value: "20", "20 条" }
-                            option { value: "50", "50 条" }
-                            option { value: "100", "100 条" }
+                DataTable {
+                    columns: vec![
+                        Column::new("id", "#", 70).sortable(),
+                        Column::new("name", t("config.column_name"), 180).sortable().always_visible(),
+                        Column::new("email", t("config.column_email"), 0).sortable(),
+                        Column::new("api_key", "API Key", 200),
+                        Column::new("provider", t("config.column_provider"), 150).sortable(),
+                        Column::new("actions", t("logs.column_actions"), 100).centered(),
+                    ],
+                    row_count: group.configs.len(),
+                    state: table,
+                    selectable: true,
+                    row_height: 56,
+                    page_sizes: vec![20, 50, 100],
+                    sort_key: move |(row, column): (usize, &'static str)| {
+                        let groups = otp_groups.read();
+                        let config = &groups[group_index].configs[row];
+                        match column {
+                            "name" => SortKey::Text(config.name.clone()),
+                            "email" => SortKey::Text(config.email.clone()),
+                            "provider" => SortKey::Text(config.service_provider.clone()),
+                            _ => SortKey::Number(config.id as i64),
                         }
-                    }
-                }
-                
-                div {
-                    style: "background:white; border-radius:12px; overflow:hidden; border:1px solid #e5e7eb;",
-                    table {
-                        style: "width:100%; border-collapse:collapse;",
-                        thead {
-                            tr {
-                                style: "background:#f9fafb;",
-                                th { style: "padding:12px 16px; text-align:left; font-size:13px; font-weight:600; color:#374151; border-bottom:2px solid #e5e7eb;", "#" }
-                                th { style: "padding:12px 16px; text-align:left; font-size:13px; font-weight:600; color:#374151; border-bottom:2px solid #e5e7eb;", "配置名称" }
-                                th { style: "padding:12px 16px; text-align:left; font-size:13px; font-weight:600; color:#374151; border-bottom:2px solid #e5e7eb;", "邮箱地址" }
-                                th { style: "padding:12px 16px; text-align:left; font-size:13px; font-weight:600; color:#374151; border-bottom:2px solid #e5e7eb;", "API Key" }
-                                th { style: "padding:12px 16px; text-align:left; font-size:13px; font-weight:600; color:#374151; border-bottom:2px solid #e5e7eb;", "服务商" }
-                                th { style: "padding:12px 16px; text-align:center; font-size:13px; font-weight:600; color:#374151; border-bottom:2px solid #e5e7eb;", "操作" }
-                            }
+                    },
+                    render_cell: move |(row, column): (usize, &'static str)| {
+                        match otp_groups.read()[group_index].configs.get(row).cloned() {
+                            Some(config) => rsx! { OtpCell { config, column, config_index: row, group_index, otp_groups, table } },
+                            None => rsx! {},
                         }
-                        tbody {
-                            for (config_index, config) in group.configs.iter().enumerate().skip(start_index).take(page_size()) {
-                                OtpTableRow {
-                                    config: config.clone(),
-                                    config_index,
-                                    group_index,
-                                    otp_groups
+                    },
+                    if !table.read().selected.is_empty() {
+                        button {
+                            style: "padding:6px 12px; border-radius:8px; border:1px solid var(--fb-danger-border); background:var(--fb-danger-bg); color:var(--fb-danger); font-weight:500; cursor:pointer; font-size:13px;",
+                            onclick: move |_| {
+                                let selected = std::mem::take(&mut table.write().selected);
+                                let mut groups = otp_groups();
+                                for index in selected.into_iter().rev() {
+                                    groups[group_index].configs.remove(index);
                                 }
-                            }
+                                otp_groups.set(groups);
+                            },
+                            {t("config.delete_selected")}
                         }
                     }
                 }
-                
-                if total_pages > 1 {
-                    Pagination { current_page, total_pages }
-                }
             } else {
                 div {
-                    style: "text-align:center; padding:40px; color:#92400e; font-size:14px; background:white; border-radius:12px; border:1px dashed #fbbf24;",
-                    "暂无 OTP 配置，点击「添加配置」开始配置"
+                    style: "text-align:center; padding:40px; color:#92400e; font-size:14px; background:var(--fb-surface); border-radius:12px; border:1px dashed #fbbf24;",
+                    {t("config.no_otp")}
                 }
             }
 
             div {
                 style: "margin-top:20px; padding-top:20px; border-top:1px solid #fbbf24; display:flex; justify-content:flex-end;",
-                button {
-                    style: "padding:12px 28px; border-radius:12px; border:none; background:linear-gradient(120deg,#4f46e5,#7c3aed); color:white; font-weight:600; cursor:pointer; box-shadow:0 4px 12px rgba(79, 70, 229, 0.3);",
+                Button {
+                    style: "padding:12px 28px; border-radius:12px;",
                     onclick: move |_| {
-                        let groups = otp_groups();
-                        if let Ok(json) = serde_json::to_string(&groups) {
-                            let _ = save_config("otp_groups", &json);
-                        }
+                        notifier.report(repo::save_groups(&otp_groups()), t("config.otp_groups_saved"), t("config.save_otp_groups_failed"));
                     },
-                    "💾 保存分组配置"
+                    {t("config.save_groups")}
                 }
             }
         }
     }
 }
 
+/// OTP 表格单元格
 #[component]
-fn OtpTableRow(config: OtpConfig, config_index: usize, group_index: usize, otp_groups: Signal<Vec<OtpGroup>>) -> Element {
-    rsx! {
-        tr {
-            style: "border-bottom:1px solid #f3f4f6;",
-            td {
-                style: "padding:12px 16px; font-size:14px; color:#6b7280;",
-                "{config.id}"
-            }
-            td {
-                style: "padding:12px 16px;",
-                input {
-                    value: config.name.clone(),
-                    oninput: move |evt| {
-                        let mut groups = otp_groups();
-                        groups[group_index].configs[config_index].name = evt.value();
-                        otp_groups.set(groups);
-                    },
-                    style: "width:100%; padding:8px 10px; border-radius:6px; border:1px solid #e5e7eb; font-size:13px;",
-                    placeholder: "配置名称"
-                }
+fn OtpCell(
+    config: OtpConfig,
+    column: &'static str,
+    config_index: usize,
+    group_index: usize,
+    otp_groups: Signal<Vec<OtpGroup>>,
+    table: Signal<TableState>,
+) -> Element {
+    let mut update = move |apply: &dyn Fn(&mut OtpConfig)| {
+        let mut groups = otp_groups();
+        apply(&mut groups[group_index].configs[config_index]);
+        otp_groups.set(groups);
+    };
+    let input_style = "width:100%; padding:8px 10px; border-radius:6px; border:1px solid var(--fb-border); font-size:13px;";
+
+    match column {
+        "id" => rsx! {
+            span { style: "font-size:14px; color:var(--fb-text-muted);", "{config.id}" }
+        },
+        "name" => rsx! {
+            input {
+                value: config.name.clone(),
+                oninput: move |evt| update(&|config| config.name = evt.value()),
+                style: input_style,
+                placeholder: t("config.column_name")
             }
-            td {
-                style: "padding:12px 16px;",
-                input {
-                    value: config.email.clone(),
-                    oninput: move |evt| {
-                        let mut groups = otp_groups();
-                        groups[group_index].configs[config_index].email = evt.value();
-                        otp_groups.set(groups);
-                    },
-                    style: "width:100%; padding:8px 10px; border-radius:6px; border:1px solid #e5e7eb; font-size:13px;",
-                    placeholder: "example@gmail.com"
-                }
+        },
+        "email" => rsx! {
+            input {
+                value: config.email.clone(),
+                oninput: move |evt| update(&|config| config.email = evt.value()),
+                style: input_style,
+                placeholder: "example@gmail.com"
             }
-            td {
-                style: "padding:12px 16px;",
-                input {
-                    r#type: "password",
-                    value: config.api_key.clone(),
-                    oninput: move |evt| {
-                        let mut groups = otp_groups();
-                        groups[group_index].configs[config_index].api_key = evt.value();
-                        otp_groups.set(groups);
-                    },
-                    style: "width:100%; padding:8px 10px; border-radius:6px; border:1px solid #e5e7eb; font-size:13px;",
-                    placeholder: "API Key"
-                }
+        },
+        "api_key" => rsx! {
+            SecretInput {
+                value: config.api_key.clone(),
+                placeholder: "API Key",
+                onchange: move |val: String| update(&|config| config.api_key = val.clone())
             }
-            td {
-                style: "padding:12px 16px;",
-                input {
-                    value: config.service_provider.clone(),
-                    oninput: move |evt| {
-                        let mut groups = otp_groups();
-                        groups[group_index].configs[config_index].service_provider = evt.value();
-                        otp_groups.set(groups);
-                    },
-                    style: "width:100%; padding:8px 10px; border-radius:6px; border:1px solid #e5e7eb; font-size:13px;",
-                    placeholder: "Gmail"
-                }
+        },
+        "provider" => rsx! {
+            input {
+                value: config.service_provider.clone(),
+                oninput: move |evt| update(&|config| config.service_provider = evt.value()),
+                style: input_style,
+                placeholder: "Gmail"
             }
-            td {
-                style: "padding:12px 16px; text-align:center;",
-                button {
-                    style: "padding:6px 12px; border-radius:6px; border:1px solid #fca5a5; background:#fee2e2; color:#dc2626; font-weight:500; cursor:pointer; font-size:12px;",
-                    onclick: move |_| {
-                        let mut groups = otp_groups();
-                        groups[group_index].configs.remove(config_index);
-                        otp_groups.set(groups);
-                    },
-                    "删除"
-                }
+        },
+        _ => rsx! {
+            button {
+                style: "padding:6px 12px; border-radius:6px; border:1px solid var(--fb-danger-border); background:var(--fb-danger-bg); color:var(--fb-danger); font-weight:500; cursor:pointer; font-size:12px;",
+                onclick: move |_| {
+                    let mut groups = otp_groups();
+                    groups[group_index].configs.remove(config_index);
+                    otp_groups.set(groups);
+                    table.write().selected.clear();
+                },
+                {t("config.delete")}
             }
-        }
+        },
     }
 }
 
@@ -879,34 +955,34 @@ fn CardGroupSection(card_groups: Signal<Vec<CardGroup>>) -> Element {
     let mut selected_group = use_signal(|| 0usize);
 
     rsx! {
-        section {
-            style: "background:white; border-radius:20px; padding:28px 32px; border:1px solid #e5e7eb; box-shadow:0 8px 30px rgba(15,23,42,0.08);",
+        Card {
             
             div {
                 style: "display:flex; justify-content:space-between; align-items:center; margin-bottom:24px;",
                 div {
                     h2 {
-                        style: "font-size:22px; font-weight:700; margin:0 0 6px 0; color:#111827; letter-spacing:-0.02em;",
-                        "支付卡片分组"
+                        style: "font-size:22px; font-weight:700; margin:0 0 6px 0; color:var(--fb-text); letter-spacing:-0.02em;",
+                        {t("config.card_groups")}
                     }
                     p {
-                        style: "margin:0; color:#6b7280; font-size:14px;",
-                        "每个分组可以保存多张卡片配置"
+                        style: "margin:0; color:var(--fb-text-muted); font-size:14px;",
+                        {t("config.card_groups_desc")}
                     }
                 }
-                button {
-                    style: "padding:11px 20px; border-radius:12px; border:none; background:linear-gradient(120deg,#10b981,#059669); color:white; font-weight:600; cursor:pointer; box-shadow:0 4px 12px rgba(16, 185, 129, 0.3);",
+                Button {
+                    variant: ButtonVariant::Success,
+                    style: "padding:11px 20px; border-radius:12px; box-shadow:0 4px 12px rgba(16, 185, 129, 0.3);",
                     onclick: move |_| {
                         let mut groups = card_groups();
                         let new_id = groups.iter().map(|g| g.id).max().unwrap_or(0) + 1;
                         groups.push(CardGroup {
                             id: new_id,
-                            name: format!("分组 {}", new_id),
+                            name: t_args("config.group_name_default", &[&new_id]),
                             cards: vec![],
                         });
                         card_groups.set(groups);
                     },
-                    "+ 新建分组"
+                    {t("config.new_group")}
                 }
             }
 
@@ -918,7 +994,7 @@ fn CardGroupSection(card_groups: Signal<Vec<CardGroup>>) -> Element {
                         let btn_style = if is_selected {
                             "padding:10px 18px; border-radius:10px; background:#4f46e5; color:white; font-weight:600; cursor:pointer; border:1px solid #4f46e5;"
                         } else {
-                            "padding:10px 18px; border-radius:10px; background:white; color:#6b7280; font-weight:500; cursor:pointer; border:1px solid #d1d5db;"
+                            "padding:10px 18px; border-radius:10px; background:var(--fb-surface); color:var(--fb-text-muted); font-weight:500; cursor:pointer; border:1px solid var(--fb-border-strong);"
                         };
                         rsx! {
                             button {
@@ -939,12 +1015,23 @@ fn CardGroupSection(card_groups: Signal<Vec<CardGroup>>) -> Element {
                     card_groups
                 }
             }
+
+            ConfigHistoryPanel {
+                config_key: "card_groups",
+                on_restore: move |history_id: i64| {
+                    let groups = repo::restore_history::<CardGroup>(history_id).map_err(|e| e.to_string())?;
+                    selected_group.set(0);
+                    card_groups.set(groups);
+                    Ok(())
+                }
+            }
         }
     }
 }
 
 #[component]
 fn CardGroupEditor(group: CardGroup, group_index: usize, card_groups: Signal<Vec<CardGroup>>) -> Element {
+    let mut notifier = use_notifier();
     let mut page_size = use_signal(|| 20usize);
     let mut current_page = use_signal(|| 1usize);
     
@@ -966,13 +1053,14 @@ fn CardGroupEditor(group: CardGroup, group_index: usize, card_groups: Signal<Vec
                         groups[group_index].name = evt.value();
                         card_groups.set(groups);
                     },
-                    style: "flex:1; max-width:300px; padding:10px 14px; border-radius:10px; border:1px solid #fbbf24; font-size:16px; font-weight:600; background:white;",
-                    placeholder: "分组名称"
+                    style: "flex:1; max-width:300px; padding:10px 14px; border-radius:10px; border:1px solid #fbbf24; font-size:16px; font-weight:600; background:var(--fb-surface);",
+                    placeholder: t("config.group_name")
                 }
                 div {
                     style: "display:flex; gap:12px;",
-                    button {
-                        style: "padding:10px 18px; border-radius:10px; border:none; background:linear-gradient(120deg,#10b981,#059669); color:white; font-weight:600; cursor:pointer;",
+                    Button {
+                        variant: ButtonVariant::Success,
+                        style: "padding:10px 18px;",
                         onclick: move |_| {
                             let mut groups = card_groups();
                             let new_id = groups[group_index].cards.iter().map(|c| c.id).max().unwrap_or(0) + 1;
@@ -983,19 +1071,20 @@ fn CardGroupEditor(group: CardGroup, group_index: usize, card_groups: Signal<Vec
                                 cvv: "".to_string(),
                                 expiry: "".to_string(),
                                 holder_name: "".to_string(),
+                                expires_at: "".to_string(),
                             });
                             card_groups.set(groups);
                         },
-                        "+ 添加卡片"
+                        {t("config.add_card")}
                     }
                     button {
-                        style: "padding:10px 18px; border-radius:10px; border:1px solid #fca5a5; background:#fee2e2; color:#dc2626; font-weight:600; cursor:pointer;",
+                        style: "padding:10px 18px; border-radius:10px; border:1px solid var(--fb-danger-border); background:var(--fb-danger-bg); color:var(--fb-danger); font-weight:600; cursor:pointer;",
                         onclick: move |_| {
                             let mut groups = card_groups();
                             groups.remove(group_index);
                             card_groups.set(groups);
                         },
-                        "删除分组"
+                        {t("config.delete_group")}
                     }
                 }
             }
@@ -1005,13 +1094,13 @@ fn CardGroupEditor(group: CardGroup, group_index: usize, card_groups: Signal<Vec
                     style: "display:flex; justify-content:space-between; align-items:center; margin-bottom:12px; padding:0 4px;",
                     div {
                         style: "font-size:13px; color:#92400e;",
-                        "共 {total_items} 条记录，第 {start_index + 1}-{end_index} 条"
+                        {t_args("config.records_range", &[&total_items, &(start_index + 1), &end_index])}
                     }
                     div {
                         style: "display:flex; align-items:center; gap:12px;",
                         span {
                             style: "font-size:13px; color:#92400e;",
-                            "每页显示："
+                            {t("table.page_size")}
                         }
                         select {
                             value: page_size().to_string(),
@@ -1021,10 +1110,10 @@ fn CardGroupEditor(group: CardGroup, group_index: usize, card_groups: Signal<Vec
                                     current_page.set(1);
                                 }
                             },
-                            style: "padding:6px 10px; border-radius:6px; border:1px solid #fbbf24; font-size:13px; background:white; cursor:pointer;",
-                            option { value: "20", "20 条" }
-                            option { value: "50", "50 条" }
-                            option { value: "100", "100 条" }
+                            style: "padding:6px 10px; border-radius:6px; border:1px solid #fbbf24; font-size:13px; background:var(--fb-surface); cursor:pointer;",
+                            for size in [20, 50, 100] {
+                                option { value: "{size}", {t_args("table.size_option", &[&size])} }
+                            }
                         }
                     }
                 }
@@ -1043,26 +1132,23 @@ fn CardGroupEditor(group: CardGroup, group_index: usize, card_groups: Signal<Vec
                 if group.cards.is_empty() {
                     div {
                         style: "text-align:center; padding:40px; color:#92400e; font-size:14px;",
-                        "暂无卡片配置，点击「添加卡片」开始配置"
+                        {t("config.no_cards")}
                     }
                 }
             }
             
             if total_pages > 1 {
-                Pagination { current_page, total_pages }
+                Pagination { current_page: current_page(), total_pages, on_change: move |page| current_page.set(page) }
             }
 
             div {
                 style: "margin-top:20px; padding-top:20px; border-top:1px solid #fbbf24; display:flex; justify-content:flex-end;",
-                button {
-                    style: "padding:12px 28px; border-radius:12px; border:none; background:linear-gradient(120deg,#4f46e5,#7c3aed); color:white; font-weight:600; cursor:pointer; box-shadow:0 4px 12px rgba(79, 70, 229, 0.3);",
+                Button {
+                    style: "padding:12px 28px; border-radius:12px;",
                     onclick: move |_| {
-                        let groups = card_groups();
-                        if let Ok(json) = serde_json::to_string(&groups) {
-                            let _ = save_config("card_groups", &json);
-                        }
+                        notifier.report(repo::save_groups(&card_groups()), t("config.card_groups_saved"), t("config.save_card_groups_failed"));
                     },
-                    "💾 保存分组配置"
+                    {t("config.save_groups")}
                 }
             }
         }
@@ -1073,22 +1159,22 @@ fn CardGroupEditor(group: CardGroup, group_index: usize, card_groups: Signal<Vec
 fn CardConfigItem(card: CardConfig, card_index: usize, group_index: usize, card_groups: Signal<Vec<CardGroup>>) -> Element {
     rsx! {
         div {
-            style: "background:white; border-radius:14px; padding:18px; border:1px solid #d1d5db;",
+            style: "background:var(--fb-surface); border-radius:14px; padding:18px; border:1px solid var(--fb-border-strong);",
             
             div {
                 style: "display:flex; justify-content:space-between; align-items:center; margin-bottom:14px;",
                 h4 {
                     style: "margin:0; font-size:15px; font-weight:600; color:#78350f;",
-                    "卡片 #{card.id}"
+                    {t_args("config.card_title", &[&card.id])}
                 }
                 button {
-                    style: "padding:6px 14px; border-radius:8px; border:1px solid #fca5a5; background:#fee2e2; color:#dc2626; font-weight:500; cursor:pointer; font-size:13px;",
+                    style: "padding:6px 14px; border-radius:8px; border:1px solid var(--fb-danger-border); background:var(--fb-danger-bg); color:var(--fb-danger); font-weight:500; cursor:pointer; font-size:13px;",
                     onclick: move |_| {
                         let mut groups = card_groups();
                         groups[group_index].cards.remove(card_index);
                         card_groups.set(groups);
                     },
-                    "删除"
+                    {t("config.delete")}
                 }
             }
 
@@ -1096,25 +1182,25 @@ fn CardConfigItem(card: CardConfig, card_index: usize, group_index: usize, card_
                 style: "margin-bottom:12px;",
                 label {
                     style: "display:block; font-size:13px; font-weight:600; color:#78350f; margin-bottom:8px;",
-                    "卡片类型"
+                    {t("config.card_type")}
                 }
                 select {
-                    style: "width:100%; padding:11px 14px; border-radius:10px; border:1px solid #d97706; font-size:14px; background:white;",
+                    style: "width:100%; padding:11px 14px; border-radius:10px; border:1px solid #d97706; font-size:14px; background:var(--fb-surface);",
                     value: card.card_type.clone(),
                     onchange: move |evt| {
                         let mut groups = card_groups();
                         groups[group_index].cards[card_index].card_type = evt.value();
                         card_groups.set(groups);
                     },
-                    option { value: "credit", "信用卡" }
-                    option { value: "gift", "礼品卡" }
+                    option { value: "credit", {t("config.credit_card")} }
+                    option { value: "gift", {t("config.gift_card")} }
                 }
             }
 
             div {
                 style: "display:grid; grid-template-columns:2fr 1fr 1fr; gap:12px; margin-bottom:12px;",
-                ConfigInput {
-                    label: "卡号",
+                SecretInput {
+                    label: t("config.card_number"),
                     value: card.card_number.clone(),
                     placeholder: "1234 5678 9012 3456",
                     onchange: move |val| {
@@ -1123,11 +1209,10 @@ fn CardConfigItem(card: CardConfig, card_index: usize, group_index: usize, card_
                         card_groups.set(groups);
                     }
                 }
-                ConfigInput {
+                SecretInput {
                     label: "CVV",
                     value: card.cvv.clone(),
                     placeholder: "123",
-                    input_type: "password",
                     onchange: move |val| {
                         let mut groups = card_groups();
                         groups[group_index].cards[card_index].cvv = val;
@@ -1135,7 +1220,7 @@ fn CardConfigItem(card: CardConfig, card_index: usize, group_index: usize, card_
                     }
                 }
                 ConfigInput {
-                    label: "有效期",
+                    label: t("config.card_expiry"),
                     value: card.expiry.clone(),
                     placeholder: "MM/YY",
                     onchange: move |val| {
@@ -1146,14 +1231,28 @@ fn CardConfigItem(card: CardConfig, card_index: usize, group_index: usize, card_
                 }
             }
 
-            ConfigInput {
-                label: "持卡人姓名",
-                value: card.holder_name.clone(),
-                placeholder: "ZHANG SAN",
-                onchange: move |val| {
-                    let mut groups = card_groups();
-                    groups[group_index].cards[card_index].holder_name = val;
-                    card_groups.set(groups);
+            div {
+                style: "display:grid; grid-template-columns:2fr 1fr; gap:12px;",
+                ConfigInput {
+                    label: t("config.card_holder"),
+                    value: card.holder_name.clone(),
+                    placeholder: "ZHANG SAN",
+                    onchange: move |val| {
+                        let mut groups = card_groups();
+                        groups[group_index].cards[card_index].holder_name = val;
+                        card_groups.set(groups);
+                    }
+                }
+                ConfigInput {
+                    label: t("config.record_expiry"),
+                    value: card.expires_at.clone(),
+                    placeholder: "",
+                    input_type: "date",
+                    onchange: move |val| {
+                        let mut groups = card_groups();
+                        groups[group_index].cards[card_index].expires_at = val;
+                        card_groups.set(groups);
+                    }
                 }
             }
         }
@@ -1165,34 +1264,34 @@ fn PassengerGroupSection(passenger_groups: Signal<Vec<PassengerGroup>>) -> Eleme
     let mut selected_group = use_signal(|| 0usize);
 
     rsx! {
-        section {
-            style: "background:white; border-radius:20px; padding:28px 32px; border:1px solid #e5e7eb; box-shadow:0 8px 30px rgba(15,23,42,0.08);",
+        Card {
             
             div {
                 style: "display:flex; justify-content:space-between; align-items:center; margin-bottom:24px;",
                 div {
                     h2 {
-                        style: "font-size:22px; font-weight:700; margin:0 0 6px 0; color:#111827; letter-spacing:-0.02em;",
-                        "购票人信息分组"
+                        style: "font-size:22px; font-weight:700; margin:0 0 6px 0; color:var(--fb-text); letter-spacing:-0.02em;",
+                        {t("config.passenger_groups")}
                     }
                     p {
-                        style: "margin:0; color:#6b7280; font-size:14px;",
-                        "每个分组可以保存多个购票人信息"
+                        style: "margin:0; color:var(--fb-text-muted); font-size:14px;",
+                        {t("config.passenger_groups_desc")}
                     }
                 }
-                button {
-                    style: "padding:11px 20px; border-radius:12px; border:none; background:linear-gradient(120deg,#10b981,#059669); color:white; font-weight:600; cursor:pointer; box-shadow:0 4px 12px rgba(16, 185, 129, 0.3);",
+                Button {
+                    variant: ButtonVariant::Success,
+                    style: "padding:11px 20px; border-radius:12px; box-shadow:0 4px 12px rgba(16, 185, 129, 0.3);",
                     onclick: move |_| {
                         let mut groups = passenger_groups();
                         let new_id = groups.iter().map(|g| g.id).max().unwrap_or(0) + 1;
                         groups.push(PassengerGroup {
                             id: new_id,
-                            name: format!("分组 {}", new_id),
+                            name: t_args("config.group_name_default", &[&new_id]),
                             passengers: vec![],
                         });
                         passenger_groups.set(groups);
                     },
-                    "+ 新建分组"
+                    {t("config.new_group")}
                 }
             }
 
@@ -1204,7 +1303,7 @@ fn PassengerGroupSection(passenger_groups: Signal<Vec<PassengerGroup>>) -> Eleme
                         let btn_style = if is_selected {
                             "padding:10px 18px; border-radius:10px; background:#4f46e5; color:white; font-weight:600; cursor:pointer; border:1px solid #4f46e5;"
                         } else {
-                            "padding:10px 18px; border-radius:10px; background:white; color:#6b7280; font-weight:500; cursor:pointer; border:1px solid #d1d5db;"
+                            "padding:10px 18px; border-radius:10px; background:var(--fb-surface); color:var(--fb-text-muted); font-weight:500; cursor:pointer; border:1px solid var(--fb-border-strong);"
                         };
                         rsx! {
                             button {
@@ -1225,12 +1324,23 @@ fn PassengerGroupSection(passenger_groups: Signal<Vec<PassengerGroup>>) -> Eleme
                     passenger_groups
                 }
             }
+
+            ConfigHistoryPanel {
+                config_key: "passenger_groups",
+                on_restore: move |history_id: i64| {
+                    let groups = repo::restore_history::<PassengerGroup>(history_id).map_err(|e| e.to_string())?;
+                    selected_group.set(0);
+                    passenger_groups.set(groups);
+                    Ok(())
+                }
+            }
         }
     }
 }
 
 #[component]
 fn PassengerGroupEditor(group: PassengerGroup, group_index: usize, passenger_groups: Signal<Vec<PassengerGroup>>) -> Element {
+    let mut notifier = use_notifier();
     let mut page_size = use_signal(|| 20usize);
     let mut current_page = use_signal(|| 1usize);
     
@@ -1252,13 +1362,14 @@ fn PassengerGroupEditor(group: PassengerGroup, group_index: usize, passenger_gro
                         groups[group_index].name = evt.value();
                         passenger_groups.set(groups);
                     },
-                    style: "flex:1; max-width:300px; padding:10px 14px; border-radius:10px; border:1px solid #60a5fa; font-size:16px; font-weight:600; background:white;",
-                    placeholder: "分组名称"
+                    style: "flex:1; max-width:300px; padding:10px 14px; border-radius:10px; border:1px solid #60a5fa; font-size:16px; font-weight:600; background:var(--fb-surface);",
+                    placeholder: t("config.group_name")
                 }
                 div {
                     style: "display:flex; gap:12px;",
-                    button {
-                        style: "padding:10px 18px; border-radius:10px; border:none; background:linear-gradient(120deg,#10b981,#059669); color:white; font-weight:600; cursor:pointer;",
+                    Button {
+                        variant: ButtonVariant::Success,
+                        style: "padding:10px 18px;",
                         onclick: move |_| {
                             let mut groups = passenger_groups();
                             let new_id = groups[group_index].passengers.iter().map(|p| p.id).max().unwrap_or(0) + 1;
@@ -1269,19 +1380,20 @@ fn PassengerGroupEditor(group: PassengerGroup, group_index: usize, passenger_gro
                                 email: "".to_string(),
                                 phone: "".to_string(),
                                 passport: "".to_string(),
+                                expires_at: "".to_string(),
                             });
                             passenger_groups.set(groups);
                         },
-                        "+ 添加购票人"
+                        {t("config.add_passenger")}
                     }
                     button {
-                        style: "padding:10px 18px; border-radius:10px; border:1px solid #fca5a5; background:#fee2e2; color:#dc2626; font-weight:600; cursor:pointer;",
+                        style: "padding:10px 18px; border-radius:10px; border:1px solid var(--fb-danger-border); background:var(--fb-danger-bg); color:var(--fb-danger); font-weight:600; cursor:pointer;",
                         onclick: move |_| {
                             let mut groups = passenger_groups();
                             groups.remove(group_index);
                             passenger_groups.set(groups);
                         },
-                        "删除分组"
+                        {t("config.delete_group")}
                     }
                 }
             }
@@ -1291,13 +1403,13 @@ fn PassengerGroupEditor(group: PassengerGroup, group_index: usize, passenger_gro
                     style: "display:flex; justify-content:space-between; align-items:center; margin-bottom:12px; padding:0 4px;",
                     div {
                         style: "font-size:13px; color:#1e40af;",
-                        "共 {total_items} 条记录，第 {start_index + 1}-{end_index} 条"
+                        {t_args("config.records_range", &[&total_items, &(start_index + 1), &end_index])}
                     }
                     div {
                         style: "display:flex; align-items:center; gap:12px;",
                         span {
                             style: "font-size:13px; color:#1e40af;",
-                            "每页显示："
+                            {t("table.page_size")}
                         }
                         select {
                             value: page_size().to_string(),
@@ -1307,10 +1419,10 @@ fn PassengerGroupEditor(group: PassengerGroup, group_index: usize, passenger_gro
                                     current_page.set(1);
                                 }
                             },
-                            style: "padding:6px 10px; border-radius:6px; border:1px solid #60a5fa; font-size:13px; background:white; cursor:pointer;",
-                            option { value: "20", "20 条" }
-                            option { value: "50", "50 条" }
-                            option { value: "100", "100 条" }
+                            style: "padding:6px 10px; border-radius:6px; border:1px solid #60a5fa; font-size:13px; background:var(--fb-surface); cursor:pointer;",
+                            for size in [20, 50, 100] {
+                                option { value: "{size}", {t_args("table.size_option", &[&size])} }
+                            }
                         }
                     }
                 }
@@ -1329,26 +1441,23 @@ fn PassengerGroupEditor(group: PassengerGroup, group_index: usize, passenger_gro
                 if group.passengers.is_empty() {
                     div {
                         style: "text-align:center; padding:40px; color:#1e3a8a; font-size:14px;",
-                        "暂无购票人信息，点击「添加购票人」开始配置"
+                        {t("config.no_passengers")}
                     }
                 }
             }
 
             if total_pages > 1 {
-                Pagination { current_page, total_pages }
+                Pagination { current_page: current_page(), total_pages, on_change: move |page| current_page.set(page) }
             }
 
             div {
                 style: "margin-top:20px; padding-top:20px; border-top:1px solid #60a5fa; display:flex; justify-content:flex-end;",
-                button {
-                    style: "padding:12px 28px; border-radius:12px; border:none; background:linear-gradient(120deg,#4f46e5,#7c3aed); color:white; font-weight:600; cursor:pointer; box-shadow:0 4px 12px rgba(79, 70, 229, 0.3);",
+                Button {
+                    style: "padding:12px 28px; border-radius:12px;",
                     onclick: move |_| {
-                        let groups = passenger_groups();
-                        if let Ok(json) = serde_json::to_string(&groups) {
-                            let _ = save_config("passenger_groups", &json);
-                        }
+                        notifier.report(repo::save_groups(&passenger_groups()), t("config.passenger_groups_saved"), t("config.save_passenger_groups_failed"));
                     },
-                    "💾 保存分组配置"
+                    {t("config.save_groups")}
                 }
             }
         }
@@ -1359,29 +1468,29 @@ fn PassengerGroupEditor(group: PassengerGroup, group_index: usize, passenger_gro
 fn PassengerConfigItem(passenger: PassengerInfo, passenger_index: usize, group_index: usize, passenger_groups: Signal<Vec<PassengerGroup>>) -> Element {
     rsx! {
         div {
-            style: "background:white; border-radius:14px; padding:18px; border:1px solid #d1d5db;",
+            style: "background:var(--fb-surface); border-radius:14px; padding:18px; border:1px solid var(--fb-border-strong);",
             
             div {
                 style: "display:flex; justify-content:space-between; align-items:center; margin-bottom:14px;",
                 h4 {
                     style: "margin:0; font-size:15px; font-weight:600; color:#1e3a8a;",
-                    "购票人 #{passenger.id}"
+                    {t_args("config.passenger_title", &[&passenger.id])}
                 }
                 button {
-                    style: "padding:6px 14px; border-radius:8px; border:1px solid #fca5a5; background:#fee2e2; color:#dc2626; font-weight:500; cursor:pointer; font-size:13px;",
+                    style: "padding:6px 14px; border-radius:8px; border:1px solid var(--fb-danger-border); background:var(--fb-danger-bg); color:var(--fb-danger); font-weight:500; cursor:pointer; font-size:13px;",
                     onclick: move |_| {
                         let mut groups = passenger_groups();
                         groups[group_index].passengers.remove(passenger_index);
                         passenger_groups.set(groups);
                     },
-                    "删除"
+                    {t("config.delete")}
                 }
             }
 
             div {
                 style: "display:grid; grid-template-columns:1fr 1fr; gap:12px; margin-bottom:12px;",
                 ConfigInput {
-                    label: "名（First Name）",
+                    label: t("config.first_name"),
                     value: passenger.first_name.clone(),
                     placeholder: "SAN",
                     onchange: move |val| {
@@ -1391,7 +1500,7 @@ fn PassengerConfigItem(passenger: PassengerInfo, passenger_index: usize, group_i
                     }
                 }
                 ConfigInput {
-                    label: "姓（Last Name）",
+                    label: t("config.last_name"),
                     value: passenger.last_name.clone(),
                     placeholder: "ZHANG",
                     onchange: move |val| {
@@ -1405,7 +1514,7 @@ fn PassengerConfigItem(passenger: PassengerInfo, passenger_index: usize, group_i
             div {
                 style: "display:grid; grid-template-columns:1fr 1fr; gap:12px; margin-bottom:12px;",
                 ConfigInput {
-                    label: "邮箱",
+                    label: t("config.email"),
                     value: passenger.email.clone(),
                     placeholder: "example@email.com",
                     onchange: move |val| {
@@ -1415,7 +1524,7 @@ fn PassengerConfigItem(passenger: PassengerInfo, passenger_index: usize, group_i
                     }
                 }
                 ConfigInput {
-                    label: "电话",
+                    label: t("config.phone"),
                     value: passenger.phone.clone(),
                     placeholder: "+86 138 0000 0000",
                     onchange: move |val| {
@@ -1426,93 +1535,1218 @@ fn PassengerConfigItem(passenger: PassengerInfo, passenger_index: usize, group_i
                 }
             }
 
-            ConfigInput {
-                label: "护照号码",
-                value: passenger.passport.clone(),
-                placeholder: "E12345678",
-                onchange: move |val| {
-                    let mut groups = passenger_groups();
-                    groups[group_index].passengers[passenger_index].passport = val;
-                    passenger_groups.set(groups);
+            div {
+                style: "display:grid; grid-template-columns:1fr 1fr; gap:12px;",
+                ConfigInput {
+                    label: t("config.passport"),
+                    value: passenger.passport.clone(),
+                    placeholder: "E12345678",
+                    onchange: move |val| {
+                        let mut groups = passenger_groups();
+                        groups[group_index].passengers[passenger_index].passport = val;
+                        passenger_groups.set(groups);
+                    }
+                }
+                ConfigInput {
+                    label: t("config.record_expiry"),
+                    value: passenger.expires_at.clone(),
+                    placeholder: "",
+                    input_type: "date",
+                    onchange: move |val| {
+                        let mut groups = passenger_groups();
+                        groups[group_index].passengers[passenger_index].expires_at = val;
+                        passenger_groups.set(groups);
+                    }
                 }
             }
         }
     }
 }
 
-#[derive(Props, Clone, PartialEq)]
-struct ConfigInputProps {
-    label: &'static str,
-    value: String,
-    placeholder: &'static str,
-    #[props(default = "text")]
-    input_type: &'static str,
-    onchange: EventHandler<String>,
+#[component]
+fn AppLockSection() -> Element {
+    let mut lock = use_app_lock();
+    let enabled = lock.settings.read().enabled;
+    let mut idle_minutes = use_signal(|| lock.settings.peek().idle_minutes);
+    let mut lock_on_minimize = use_signal(|| lock.settings.peek().lock_on_minimize);
+
+    let mut current_passphrase = use_signal(String::new);
+    let mut new_passphrase = use_signal(String::new);
+    let mut confirm_passphrase = use_signal(String::new);
+    // (是否成功, 提示信息)
+    let mut status = use_signal(|| Option::<(bool, String)>::None);
+    let new_passphrase_label = if enabled { t("config.new_passphrase") } else { t("lock.passphrase") };
+
+    let mut reset_inputs = move || {
+        current_passphrase.set(String::new());
+        new_passphrase.set(String::new());
+        confirm_passphrase.set(String::new());
+    };
+
+    rsx! {
+        Card {
+
+            div {
+                style: "margin-bottom:24px;",
+                h2 {
+                    style: "font-size:22px; font-weight:700; margin:0 0 6px 0; color:var(--fb-text); letter-spacing:-0.02em;",
+                    {t("config.app_lock")}
+                }
+                p {
+                    style: "margin:0; color:var(--fb-text-muted); font-size:14px;",
+                    if enabled {
+                        {t("config.app_lock_enabled")}
+                    } else {
+                        {t("config.app_lock_disabled")}
+                    }
+                }
+            }
+
+            div {
+                style: "background:var(--fb-surface-muted); border-radius:16px; padding:24px; border:1px solid var(--fb-border); display:flex; flex-direction:column; gap:16px;",
+
+                div {
+                    style: "display:grid; grid-template-columns:repeat(3, 1fr); gap:12px;",
+                    if enabled {
+                        ConfigInput {
+                            label: t("config.current_passphrase"),
+                            value: current_passphrase(),
+                            placeholder: "",
+                            input_type: "password",
+                            onchange: move |val| current_passphrase.set(val)
+                        }
+                    }
+                    ConfigInput {
+                        label: new_passphrase_label,
+                        value: new_passphrase(),
+                        placeholder: t("config.passphrase_min"),
+                        input_type: "password",
+                        onchange: move |val| new_passphrase.set(val)
+                    }
+                    ConfigInput {
+                        label: t("config.confirm_passphrase"),
+                        value: confirm_passphrase(),
+                        placeholder: "",
+                        input_type: "password",
+                        onchange: move |val| confirm_passphrase.set(val)
+                    }
+                }
+
+                div {
+                    style: "display:flex; gap:12px; justify-content:flex-end;",
+                    if enabled {
+                        button {
+                            style: "padding:10px 18px; border-radius:10px; border:1px solid var(--fb-danger-border); background:var(--fb-danger-bg); color:var(--fb-danger); font-weight:600; cursor:pointer;",
+                            onclick: move |_| {
+                                match app_lock::disable(&current_passphrase()) {
+                                    Ok(()) => {
+                                        lock.reload_settings();
+                                        status.set(Some((true, t("config.app_lock_disabled_done").to_string())));
+                                    }
+                                    Err(e) => status.set(Some((false, e.to_string()))),
+                                }
+                                reset_inputs();
+                            },
+                            {t("config.disable_app_lock")}
+                        }
+                    }
+                    Button {
+                        style: "padding:10px 18px; box-shadow:none;",
+                        onclick: move |_| {
+                            if new_passphrase() != confirm_passphrase() {
+                                status.set(Some((false, t("config.passphrase_mismatch").to_string())));
+                                return;
+                            }
+                            let current = current_passphrase();
+                            match app_lock::set_passphrase(Some(current.as_str()), &new_passphrase()) {
+                                Ok(()) => {
+                                    lock.reload_settings();
+                                    status.set(Some((true, t("config.passphrase_saved").to_string())));
+                                }
+                                Err(e) => status.set(Some((false, e.to_string()))),
+                            }
+                            reset_inputs();
+                        },
+                        if enabled { {t("config.change_passphrase")} } else { {t("config.enable_app_lock")} }
+                    }
+                }
+
+                div {
+                    style: "display:flex; align-items:center; gap:24px; padding-top:16px; border-top:1px solid var(--fb-border); flex-wrap:wrap;",
+                    label {
+                        style: "display:flex; align-items:center; gap:10px; font-size:14px; color:var(--fb-text-secondary);",
+                        {t("config.idle_lock")}
+                        select {
+                            value: idle_minutes().to_string(),
+                            onchange: move |evt| {
+                                if let Ok(minutes) = evt.value().parse::<u32>() {
+                                    idle_minutes.set(minutes);
+                                }
+                            },
+                            style: "padding:8px 10px; border-radius:8px; border:1px solid var(--fb-border-strong); font-size:13px; background:var(--fb-surface); cursor:pointer;",
+                            option { value: "0", {t("config.never")} }
+                            for minutes in [5, 10, 15, 30, 60] {
+                                option { value: "{minutes}", {t_args("config.minutes_option", &[&minutes])} }
+                            }
+                        }
+                    }
+                    label {
+                        style: "display:flex; align-items:center; gap:8px; font-size:14px; color:var(--fb-text-secondary); cursor:pointer;",
+                        input {
+                            r#type: "checkbox",
+                            checked: lock_on_minimize(),
+                            onchange: move |evt| lock_on_minimize.set(evt.checked()),
+                        }
+                        {t("config.lock_on_minimize")}
+                    }
+                    button {
+                        style: "margin-left:auto; padding:10px 18px; border-radius:10px; border:1px solid var(--fb-border-strong); background:var(--fb-surface); color:var(--fb-text-secondary); font-weight:600; cursor:pointer;",
+                        onclick: move |_| {
+                            let settings = AppLockSettings {
+                                enabled,
+                                idle_minutes: idle_minutes(),
+                                lock_on_minimize: lock_on_minimize(),
+                            };
+                            match settings.save() {
+                                Ok(()) => {
+                                    lock.reload_settings();
+                                    status.set(Some((true, t("config.lock_settings_saved").to_string())));
+                                }
+                                Err(e) => status.set(Some((false, e.to_string()))),
+                            }
+                        },
+                        {t("config.save_settings")}
+                    }
+                }
+
+                if let Some((ok, message)) = status() {
+                    div {
+                        style: if ok { "font-size:13px; color:#059669;" } else { "font-size:13px; color:var(--fb-danger);" },
+                        "{message}"
+                    }
+                }
+            }
+
+            p {
+                style: "margin:16px 0 0 0; color:var(--fb-text-subtle); font-size:12px;",
+                {t_args("config.passphrase_note", &[&MIN_PASSPHRASE_LEN])}
+            }
+        }
+    }
 }
 
+/// 窗口设置：托盘图标在下次启动时创建，关闭按钮的行为随之改变
 #[component]
-fn ConfigInput(props: ConfigInputProps) -> Element {
+fn WindowSection() -> Element {
+    let mut notifier = use_notifier();
+    let mut tray_enabled = use_signal(window_state::tray_enabled);
+
     rsx! {
-        label {
-            style: "display:flex; flex-direction:column; gap:6px;",
-            span {
-                style: "font-size:13px; font-weight:600; color:#374151;",
-                "{props.label}"
+        Card {
+
+            div {
+                style: "margin-bottom:24px;",
+                h2 {
+                    style: "font-size:22px; font-weight:700; margin:0 0 6px 0; color:var(--fb-text); letter-spacing:-0.02em;",
+                    {t("config.window")}
+                }
+                p {
+                    style: "margin:0; color:var(--fb-text-muted); font-size:14px;",
+                    {t("config.window_desc")}
+                }
             }
-            input {
-                r#type: props.input_type,
-                value: props.value,
-                placeholder: props.placeholder,
-                oninput: move |evt| props.onchange.call(evt.value()),
-                style: "padding:11px 14px; border-radius:10px; border:1px solid #d1d5db; font-size:14px; transition:border-color 0.2s ease, box-shadow 0.2s ease; background:white;",
+
+            div {
+                style: "background:var(--fb-surface-muted); border-radius:16px; padding:24px; border:1px solid var(--fb-border); display:flex; flex-direction:column; gap:8px;",
+                label {
+                    style: "display:flex; align-items:center; gap:8px; font-size:14px; color:var(--fb-text-secondary); cursor:pointer;",
+                    input {
+                        r#type: "checkbox",
+                        checked: tray_enabled(),
+                        onchange: move |evt| {
+                            let enabled = evt.checked();
+                            let result = window_state::set_tray_enabled(enabled);
+                            if notifier.report(result, t("config.tray_saved"), t("config.tray_save_failed")).is_some() {
+                                tray_enabled.set(enabled);
+                            }
+                        },
+                    }
+                    {t("config.tray_enabled")}
+                }
+                div {
+                    style: "font-size:12px; color:var(--fb-text-subtle);",
+                    {t("config.tray_note")}
+                }
             }
         }
     }
 }
 
+/// 加载拦截规则，读取失败返回 `None`（无法解析的数据已被隔离备份，这时使用默认规则）
+fn load_interceptor_config(mut notifier: Notifier) -> Option<InterceptorConfig> {
+    match InterceptorConfig::load() {
+        Ok(config) => Some(config),
+        Err(e) => {
+            notifier.error(t("config.load_rules_failed"), &e);
+            matches!(e, AppError::ConfigQuarantined { .. }).then(InterceptorConfig::default)
+        }
+    }
+}
+
+/// 请求拦截规则：按顺序检查，第一条命中的规则决定放行或拦截
 #[component]
-fn Pagination(current_page: Signal<usize>, total_pages: usize) -> Element {
+fn InterceptorSection() -> Element {
+    let mut notifier = use_notifier();
+    // 读取失败时不能用空规则顶替，否则保存时会覆盖已保存的规则
+    let (mut config, mut load_failed) = use_hook(|| {
+        let loaded = load_interceptor_config(notifier);
+        (Signal::new(loaded.clone().unwrap_or_default()), Signal::new(loaded.is_none()))
+    });
+    let mut test_method = use_signal(|| "GET".to_string());
+    let mut test_url = use_signal(String::new);
+    // (是否放行, 说明)
+    let mut test_result = use_signal(|| Option::<(bool, String)>::None);
+
+    if load_failed() {
+        return rsx! {
+            LoadFailedNotice {
+                on_retry: move |_| {
+                    if let Some(loaded) = load_interceptor_config(notifier) {
+                        config.set(loaded);
+                        load_failed.set(false);
+                    }
+                }
+            }
+        };
+    }
+
+    let mode = config.read().mode;
+    let rules = config.read().rules.clone();
+    let rule_count = rules.len();
+
     rsx! {
-        div {
-            style: "display:flex; justify-content:center; align-items:center; gap:8px; margin-top:16px;",
-            button {
-                style: "padding:8px 14px; border-radius:8px; border:1px solid #d1d5db; background:white; color:#6b7280; font-weight:500; cursor:pointer; font-size:13px;",
-                disabled: current_page() == 1,
-                onclick: move |_| {
-                    if current_page() > 1 {
-                        current_page.set(current_page() - 1);
+        Card {
+
+            div {
+                style: "display:flex; justify-content:space-between; align-items:center; gap:24px; margin-bottom:24px;",
+                div {
+                    h2 {
+                        style: "font-size:22px; font-weight:700; margin:0 0 6px 0; color:var(--fb-text); letter-spacing:-0.02em;",
+                        {t("config.tab_intercept")}
                     }
-                },
-                "上一页"
+                    p {
+                        style: "margin:0; color:var(--fb-text-muted); font-size:14px; line-height:1.6;",
+                        {t("config.intercept_desc")}
+                    }
+                }
+                label {
+                    style: "display:flex; align-items:center; gap:10px; font-size:14px; color:var(--fb-text-secondary); white-space:nowrap;",
+                    {t("config.mode")}
+                    select {
+                        value: if mode == InterceptMode::Allowlist { "allowlist" } else { "blocklist" },
+                        onchange: move |evt| {
+                            config.write().mode = if evt.value() == "allowlist" {
+                                InterceptMode::Allowlist
+                            } else {
+                                InterceptMode::Blocklist
+                            };
+                        },
+                        style: "padding:8px 10px; border-radius:8px; border:1px solid var(--fb-border-strong); font-size:13px; background:var(--fb-surface); cursor:pointer;",
+                        option { value: "blocklist", {InterceptMode::Blocklist.label()} }
+                        option { value: "allowlist", {InterceptMode::Allowlist.label()} }
+                    }
+                }
             }
-            for page in 1..=total_pages {
-                {
-                    let is_current = page == current_page();
-                    let btn_style = if is_current {
-                        "padding:8px 12px; border-radius:8px; background:#4f46e5; color:white; font-weight:600; cursor:pointer; border:none; font-size:13px;"
-                    } else {
-                        "padding:8px 12px; border-radius:8px; background:white; color:#6b7280; font-weight:500; cursor:pointer; border:1px solid #d1d5db; font-size:13px;"
-                    };
-                    rsx! {
-                        button {
-                            key: "{page}",
-                            style: "{btn_style}",
-                            onclick: move |_| current_page.set(page),
-                            "{page}"
+
+            div {
+                style: "background:var(--fb-surface-muted); border-radius:16px; padding:24px; border:1px solid var(--fb-border); display:flex; flex-direction:column; gap:16px;",
+
+                if rules.is_empty() {
+                    div {
+                        style: "padding:24px; text-align:center; color:var(--fb-text-subtle); font-size:14px;",
+                        {t("config.no_rules")}
+                    }
+                } else {
+                    table {
+                        style: "width:100%; border-collapse:collapse; font-size:13px;",
+                        thead {
+                            tr {
+                                style: "text-align:left; color:var(--fb-text-muted);",
+                                th { style: "padding:8px; width:40px;", "#" }
+                                th { style: "padding:8px; width:60px;", {t("config.rule_enabled")} }
+                                th { style: "padding:8px; width:90px;", {t("config.rule_action")} }
+                                th { style: "padding:8px; width:140px;", {t("config.rule_kind")} }
+                                th { style: "padding:8px;", {t("config.rule_pattern")} }
+                                th { style: "padding:8px; width:150px;", {t("config.rule_methods")} }
+                                th { style: "padding:8px; width:130px;", "" }
+                            }
+                        }
+                        tbody {
+                            for (index, rule) in rules.into_iter().enumerate() {
+                                InterceptRuleRow { key: "{index}", rule, index, rule_count, config }
+                            }
                         }
                     }
                 }
-            }
-            button {
-                style: "padding:8px 14px; border-radius:8px; border:1px solid #d1d5db; background:white; color:#6b7280; font-weight:500; cursor:pointer; font-size:13px;",
-                disabled: current_page() == total_pages,
-                onclick: move |_| {
-                    if current_page() < total_pages {
-                        current_page.set(current_page() + 1);
+
+                div {
+                    style: "display:flex; gap:12px; justify-content:flex-end;",
+                    button {
+                        style: "padding:10px 18px; border-radius:10px; border:1px solid var(--fb-border-strong); background:var(--fb-surface); color:var(--fb-text-secondary); font-weight:600; cursor:pointer;",
+                        onclick: move |_| {
+                            config.write().rules.push(InterceptRule::new(RuleAction::Block, PatternKind::DomainSuffix, ""));
+                        },
+                        {t("config.add_rule")}
                     }
-                },
-                "下一页"
+                    Button {
+                        style: "padding:10px 18px; box-shadow:none;",
+                        onclick: move |_| {
+                            if notifier.report_error(config.read().save(), t("config.save_rules_failed")).is_some() {
+                                notifier.success(t("config.rules_saved"));
+                            }
+                        },
+                        {t("config.save_rules")}
+                    }
+                }
+
+                div {
+                    style: "display:flex; align-items:center; gap:12px; padding-top:16px; border-top:1px solid var(--fb-border);",
+                    span { style: "font-size:14px; color:var(--fb-text-secondary); white-space:nowrap;", {t("config.test_label")} }
+                    select {
+                        value: test_method(),
+                        onchange: move |evt| test_method.set(evt.value()),
+                        style: "padding:8px 10px; border-radius:8px; border:1px solid var(--fb-border-strong); font-size:13px; background:var(--fb-surface); cursor:pointer;",
+                        for method in ["GET", "POST", "PUT", "PATCH", "DELETE", "HEAD", "OPTIONS"] {
+                            option { value: method, "{method}" }
+                        }
+                    }
+                    input {
+                        value: test_url(),
+                        oninput: move |evt| test_url.set(evt.value()),
+                        style: "flex:1; padding:8px 12px; border-radius:8px; border:1px solid var(--fb-border-strong); font-size:13px;",
+                        placeholder: "https://www.example.com/path"
+                    }
+                    button {
+                        style: "padding:8px 16px; border-radius:8px; border:1px solid var(--fb-border-strong); background:var(--fb-surface); color:var(--fb-text-secondary); font-weight:600; cursor:pointer;",
+                        onclick: move |_| {
+                            let result = Interceptor::with_config(config.read().clone()).map(|interceptor| {
+                                let decision = interceptor.check(&test_method(), test_url().trim());
+                                let reason = match decision.rule.and_then(|index| Some((index, interceptor.rule(index)?))) {
+                                    Some((index, rule)) => t_args("config.rule_hit", &[&(index + 1), &rule.describe()]),
+                                    None => t_args("config.no_rule_hit", &[&interceptor.mode().label()]),
+                                };
+                                (decision.allowed, reason)
+                            });
+                            match result {
+                                Ok(outcome) => test_result.set(Some(outcome)),
+                                Err(e) => test_result.set(Some((false, e.to_string()))),
+                            }
+                        },
+                        {t("config.test")}
+                    }
+                }
+
+                if let Some((allowed, reason)) = test_result() {
+                    div {
+                        style: if allowed { "font-size:13px; color:#059669;" } else { "font-size:13px; color:var(--fb-danger);" },
+                        if allowed { {t_args("config.test_allowed", &[&reason])} } else { {t_args("config.test_blocked", &[&reason])} }
+                    }
+                }
+            }
+
+            p {
+                style: "margin:16px 0 0 0; color:var(--fb-text-subtle); font-size:12px;",
+                {t("config.pattern_help")}
+            }
+        }
+    }
+}
+
+#[component]
+fn InterceptRuleRow(rule: InterceptRule, index: usize, rule_count: usize, config: Signal<InterceptorConfig>) -> Element {
+    let cell_input = "width:100%; padding:6px 10px; border-radius:8px; border:1px solid var(--fb-border-strong); font-size:13px; box-sizing:border-box;";
+    let cell_select = "padding:6px 8px; border-radius:8px; border:1px solid var(--fb-border-strong); font-size:13px; background:var(--fb-surface); cursor:pointer;";
+    let small_button = "padding:4px 8px; border-radius:6px; border:1px solid var(--fb-border-strong); background:var(--fb-surface); color:var(--fb-text-secondary); cursor:pointer; font-size:12px;";
+    let methods = rule.methods.join(", ");
+
+    rsx! {
+        tr {
+            style: "border-top:1px solid var(--fb-border);",
+            td { style: "padding:8px; color:var(--fb-text-subtle);", "{index + 1}" }
+            td {
+                style: "padding:8px;",
+                input {
+                    r#type: "checkbox",
+                    checked: rule.enabled,
+                    onchange: move |evt| config.write().rules[index].enabled = evt.checked(),
+                }
+            }
+            td {
+                style: "padding:8px;",
+                select {
+                    value: if rule.action == RuleAction::Allow { "allow" } else { "block" },
+                    onchange: move |evt| {
+                        config.write().rules[index].action = if evt.value() == "allow" { RuleAction::Allow } else { RuleAction::Block };
+                    },
+                    style: cell_select,
+                    option { value: "block", {RuleAction::Block.label()} }
+                    option { value: "allow", {RuleAction::Allow.label()} }
+                }
+            }
+            td {
+                style: "padding:8px;",
+                select {
+                    value: rule.kind.id(),
+                    onchange: move |evt| config.write().rules[index].kind = PatternKind::from_id(&evt.value()),
+                    style: cell_select,
+                    for kind in PatternKind::ALL {
+                        option { value: kind.id(), {kind.label()} }
+                    }
+                }
+            }
+            td {
+                style: "padding:8px;",
+                input {
+                    value: rule.pattern.clone(),
+                    oninput: move |evt| config.write().rules[index].pattern = evt.value(),
+                    style: cell_input,
+                    placeholder: "example.com"
+                }
+            }
+            td {
+                style: "padding:8px;",
+                input {
+                    value: methods,
+                    onchange: move |evt| {
+                        config.write().rules[index].methods = evt
+                            .value()
+                            .split([',', ' '])
+                            .map(|method| method.trim().to_uppercase())
+                            .filter(|method| !method.is_empty())
+                            .collect();
+                    },
+                    style: cell_input,
+                    placeholder: t("config.all_methods")
+                }
+            }
+            td {
+                style: "padding:8px; display:flex; gap:4px;",
+                button {
+                    style: small_button,
+                    disabled: index == 0,
+                    onclick: move |_| config.write().rules.swap(index, index - 1),
+                    "↑"
+                }
+                button {
+                    style: small_button,
+                    disabled: index + 1 >= rule_count,
+                    onclick: move |_| config.write().rules.swap(index, index + 1),
+                    "↓"
+                }
+                button {
+                    style: "padding:4px 8px; border-radius:6px; border:1px solid var(--fb-danger-border); background:var(--fb-danger-bg); color:var(--fb-danger); cursor:pointer; font-size:12px;",
+                    onclick: move |_| {
+                        config.write().rules.remove(index);
+                    },
+                    {t("config.delete")}
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn PrivacySection(
+    card_groups: Signal<Vec<CardGroup>>,
+    passenger_groups: Signal<Vec<PassengerGroup>>,
+    otp_groups: Signal<Vec<OtpGroup>>,
+) -> Element {
+    let mut show_confirm = use_signal(|| false);
+    let mut confirm_input = use_signal(String::new);
+    // 清除前需要输入的确认文字
+    let confirm_text = t("config.wipe_confirm_text");
+    // (是否成功, 提示信息)
+    let mut status = use_signal(|| Option::<(bool, String)>::None);
+
+    rsx! {
+        Card {
+
+            div {
+                style: "display:flex; justify-content:space-between; align-items:center; gap:24px;",
+                div {
+                    h2 {
+                        style: "font-size:22px; font-weight:700; margin:0 0 6px 0; color:var(--fb-text); letter-spacing:-0.02em;",
+                        {t("config.wipe")}
+                    }
+                    p {
+                        style: "margin:0; color:var(--fb-text-muted); font-size:14px; line-height:1.6;",
+                        {t("config.wipe_desc")}
+                    }
+                }
+                button {
+                    style: "padding:11px 20px; border-radius:12px; border:1px solid var(--fb-danger-border); background:var(--fb-danger-bg); color:var(--fb-danger); font-weight:600; cursor:pointer; white-space:nowrap;",
+                    onclick: move |_| {
+                        confirm_input.set(String::new());
+                        show_confirm.set(true);
+                    },
+                    {t("config.wipe_button")}
+                }
+            }
+
+            if let Some((ok, message)) = status() {
+                div {
+                    style: if ok { "margin-top:16px; font-size:13px; color:#059669;" } else { "margin-top:16px; font-size:13px; color:var(--fb-danger);" },
+                    "{message}"
+                }
+            }
+        }
+
+        if show_confirm() {
+            Modal {
+                title: t("config.wipe_confirm_title"),
+                danger: true,
+                on_close: move |_| show_confirm.set(false),
+
+                p {
+                    style: "margin:0 0 20px 0; color:var(--fb-text-muted); font-size:14px; line-height:1.6;",
+                    {t_args("config.wipe_confirm_desc", &[&confirm_text])}
+                }
+                Input {
+                    value: confirm_input(),
+                    oninput: move |value| confirm_input.set(value),
+                    placeholder: confirm_text,
+                }
+                div {
+                    class: "fb-modal-actions",
+                    Button {
+                        variant: ButtonVariant::Secondary,
+                        onclick: move |_| show_confirm.set(false),
+                        {t("config.cancel")}
+                    }
+                    Button {
+                        variant: ButtonVariant::Danger,
+                        disabled: confirm_input().trim() != confirm_text,
+                        onclick: move |_| {
+                            match privacy::wipe_personal_data() {
+                                Ok(()) => {
+                                    card_groups.set(vec![CardGroup {
+                                        id: 1,
+                                        name: t("config.default_group").to_string(),
+                                        cards: vec![],
+                                    }]);
+                                    passenger_groups.set(vec![PassengerGroup {
+                                        id: 1,
+                                        name: t("config.default_group").to_string(),
+                                        passengers: vec![],
+                                    }]);
+                                    otp_groups.set(vec![OtpGroup {
+                                        id: 1,
+                                        name: t("config.default_group").to_string(),
+                                        configs: vec![],
+                                    }]);
+                                    status.set(Some((true, t("config.wiped").to_string())));
+                                }
+                                Err(e) => status.set(Some((false, t_args("config.wipe_failed", &[&e])))),
+                            }
+                            show_confirm.set(false);
+                        },
+                        {t("config.wipe_confirm")}
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 数据目录：显示当前位置，并可把数据库迁移到新目录
+#[component]
+fn DataDirectorySection() -> Element {
+    let mut notifier = use_notifier();
+    let mut current = use_signal(|| location::current().ok());
+    let mut target_dir = use_signal(String::new);
+
+    let mut move_to = move |dir: Option<String>| {
+        let result = location::move_data_to(dir.as_deref().map(std::path::Path::new));
+        if let Some(moved) = notifier.report(result, t("config.data_moved"), t("config.move_failed")) {
+            current.set(Some(moved));
+            target_dir.set(String::new());
+        }
+    };
+
+    let Some(DataLocation { config_dir, log_dir, source, log_fallback }) = current() else {
+        return rsx! {
+            section {
+                style: "background:var(--fb-surface); border-radius:20px; padding:28px 32px; border:1px solid var(--fb-border); color:var(--fb-danger); font-size:14px;",
+                {t("config.no_data_dir")}
+            }
+        };
+    };
+    let config_dir = config_dir.display().to_string();
+    let log_dir = log_dir.display().to_string();
+
+    rsx! {
+        Card {
+
+            div {
+                style: "margin-bottom:24px;",
+                h2 {
+                    style: "font-size:22px; font-weight:700; margin:0 0 6px 0; color:var(--fb-text); letter-spacing:-0.02em;",
+                    {t("config.data_dir")}
+                }
+                p {
+                    style: "margin:0; color:var(--fb-text-muted); font-size:14px;",
+                    {t_args("config.data_dir_desc", &[&source.label()])}
+                }
+            }
+
+            div {
+                style: "background:var(--fb-surface-muted); border-radius:16px; padding:24px; border:1px solid var(--fb-border); display:flex; flex-direction:column; gap:16px;",
+
+                div {
+                    style: "display:grid; grid-template-columns:120px 1fr; gap:10px 16px; font-size:13px;",
+                    span { style: "color:var(--fb-text-muted);", "config.db" }
+                    span { style: "color:var(--fb-text); font-family:monospace; word-break:break-all;", "{config_dir}" }
+                    span { style: "color:var(--fb-text-muted);", "logs.db" }
+                    span { style: "color:var(--fb-text); font-family:monospace; word-break:break-all;", "{log_dir}" }
+                }
+
+                if log_fallback {
+                    div {
+                        style: "font-size:13px; color:#b45309; background:#fffbeb; border:1px solid #fcd34d; border-radius:10px; padding:10px 14px;",
+                        {t("config.log_db_fallback")}
+                    }
+                }
+
+                if source.is_override() {
+                    div {
+                        style: "font-size:13px; color:var(--fb-text-muted);",
+                        {t_args("config.data_dir_override", &[&source.label()])}
+                    }
+                } else {
+                    ConfigInput {
+                        label: t("config.new_data_dir"),
+                        value: target_dir(),
+                        placeholder: t("config.data_dir_example"),
+                        onchange: move |val| target_dir.set(val)
+                    }
+                    div {
+                        style: "font-size:12px; color:var(--fb-text-subtle);",
+                        {t("config.move_desc")}
+                    }
+                    div {
+                        style: "display:flex; gap:12px; justify-content:flex-end;",
+                        if source == location::DataDirSource::Setting {
+                            Button {
+                                variant: ButtonVariant::Secondary,
+                                style: "padding:10px 18px; font-weight:500;",
+                                onclick: move |_| move_to(None),
+                                {t("config.move_back")}
+                            }
+                        }
+                        Button {
+                            style: "padding:10px 18px;",
+                            disabled: target_dir().trim().is_empty(),
+                            onclick: move |_| move_to(Some(target_dir().trim().to_string())),
+                            {t("config.move")}
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 设置备份：导出为加密文件，或从备份文件导入（每部分可选择跳过、合并或替换）
+#[component]
+fn SettingsBackupSection(
+    proxy_groups: Signal<Vec<ProxyGroup>>,
+    otp_groups: Signal<Vec<OtpGroup>>,
+    card_groups: Signal<Vec<CardGroup>>,
+    passenger_groups: Signal<Vec<PassengerGroup>>,
+) -> Element {
+    let mut notifier = use_notifier();
+
+    let mut export_path = use_signal(|| {
+        settings_archive::default_export_path()
+            .map(|path| path.display().to_string())
+            .unwrap_or_default()
+    });
+    let mut export_passphrase = use_signal(String::new);
+    let mut export_confirm = use_signal(String::new);
+
+    let mut import_path = use_signal(String::new);
+    let mut import_passphrase = use_signal(String::new);
+    // 已解密的备份和本地当前配置，用于预览
+    let mut pending = use_signal(|| Option::<(SettingsSnapshot, SettingsSnapshot)>::None);
+    let mut modes = use_signal(BTreeMap::<ArchiveSection, ImportMode>::new);
+
+    let export = move |_| {
+        if export_passphrase() != export_confirm() {
+            notifier.error(t("config.export_failed"), &AppError::InvalidConfig(Reason::new("config.passphrase_mismatch", &[])));
+            return;
+        }
+        let path = PathBuf::from(export_path().trim());
+        let result = settings_archive::export_to_file(&path, &export_passphrase());
+        if let Some(snapshot) = notifier.report_error(result, t("config.export_failed")) {
+            notifier.success(t_args("config.exported", &[&path.display(), &snapshot.summary()]));
+            export_passphrase.set(String::new());
+            export_confirm.set(String::new());
+        }
+    };
+
+    let read_backup = move |_| {
+        let path = PathBuf::from(import_path().trim());
+        let result = settings_archive::read_file(&path, &import_passphrase())
+            .and_then(|incoming| Ok((incoming, SettingsSnapshot::capture()?)));
+        if let Some(snapshots) = notifier.report_error(result, t("config.read_backup_failed")) {
+            // 默认合并备份中有内容的部分
+            modes.set(
+                ArchiveSection::ALL
+                    .iter()
+                    .map(|&section| {
+                        let diff = settings_archive::diff_section(section, &snapshots.1, &snapshots.0);
+                        let mode = if diff.added.is_empty() && diff.updated.is_empty() {
+                            ImportMode::Skip
+                        } else {
+                            ImportMode::Merge
+                        };
+                        (section, mode)
+                    })
+                    .collect(),
+            );
+            pending.set(Some(snapshots));
+        }
+    };
+
+    let apply_import = move |_| {
+        let Some((incoming, _)) = pending() else {
+            return;
+        };
+        let result = settings_archive::import(&incoming, &modes());
+        if let Some(merged) = notifier.report(result, t("config.imported"), t("config.import_failed")) {
+            proxy_groups.set(merged.proxy_groups);
+            otp_groups.set(merged.otp_groups);
+            card_groups.set(merged.card_groups);
+            passenger_groups.set(merged.passenger_groups);
+            pending.set(None);
+            import_passphrase.set(String::new());
+        }
+    };
+
+    rsx! {
+        Card {
+
+            div {
+                style: "margin-bottom:24px;",
+                h2 {
+                    style: "font-size:22px; font-weight:700; margin:0 0 6px 0; color:var(--fb-text); letter-spacing:-0.02em;",
+                    {t("config.backup")}
+                }
+                p {
+                    style: "margin:0; color:var(--fb-text-muted); font-size:14px; line-height:1.6;",
+                    {t("config.backup_desc")}
+                }
+            }
+
+            div {
+                style: "display:grid; grid-template-columns:repeat(auto-fit, minmax(360px, 1fr)); gap:20px;",
+
+                div {
+                    style: "background:var(--fb-surface-muted); border-radius:16px; padding:24px; border:1px solid var(--fb-border); display:flex; flex-direction:column; gap:16px;",
+                    h3 { style: "margin:0; font-size:16px; font-weight:600; color:var(--fb-text);", {t("config.export")} }
+                    ConfigInput {
+                        label: t("config.save_to"),
+                        value: export_path(),
+                        placeholder: t("config.backup_path"),
+                        onchange: move |val| export_path.set(val)
+                    }
+                    ConfigInput {
+                        label: t("config.backup_passphrase"),
+                        value: export_passphrase(),
+                        placeholder: t("config.backup_passphrase_min"),
+                        input_type: "password",
+                        onchange: move |val| export_passphrase.set(val)
+                    }
+                    ConfigInput {
+                        label: t("config.confirm_passphrase"),
+                        value: export_confirm(),
+                        placeholder: t("config.backup_passphrase_again"),
+                        input_type: "password",
+                        onchange: move |val| export_confirm.set(val)
+                    }
+                    div {
+                        style: "display:flex; justify-content:flex-end;",
+                        Button {
+                            style: "padding:10px 18px;",
+                            disabled: export_path().trim().is_empty() || export_passphrase().is_empty(),
+                            onclick: export,
+                            {t("config.export_backup")}
+                        }
+                    }
+                }
+
+                div {
+                    style: "background:var(--fb-surface-muted); border-radius:16px; padding:24px; border:1px solid var(--fb-border); display:flex; flex-direction:column; gap:16px;",
+                    h3 { style: "margin:0; font-size:16px; font-weight:600; color:var(--fb-text);", {t("config.import")} }
+                    ConfigInput {
+                        label: t("config.backup_file"),
+                        value: import_path(),
+                        placeholder: t("config.backup_file_example"),
+                        onchange: move |val| {
+                            import_path.set(val);
+                            pending.set(None);
+                        }
+                    }
+                    ConfigInput {
+                        label: t("config.backup_passphrase"),
+                        value: import_passphrase(),
+                        placeholder: t("config.backup_passphrase_hint"),
+                        input_type: "password",
+                        onchange: move |val| import_passphrase.set(val)
+                    }
+                    div {
+                        style: "display:flex; justify-content:flex-end;",
+                        button {
+                            style: "padding:10px 18px; border-radius:10px; border:1px solid #c7d2fe; background:var(--fb-primary-soft); color:#4338ca; font-weight:600; cursor:pointer;",
+                            disabled: import_path().trim().is_empty() || import_passphrase().is_empty(),
+                            onclick: read_backup,
+                            {t("config.preview_backup")}
+                        }
+                    }
+                }
+            }
+
+            if let Some((incoming, local)) = pending() {
+                div {
+                    style: "margin-top:20px; background:var(--fb-surface-muted); border-radius:16px; padding:24px; border:1px solid #c7d2fe; display:flex; flex-direction:column; gap:12px;",
+                    div {
+                        style: "font-size:14px; color:var(--fb-text-secondary);",
+                        {t_args("config.backup_contents", &[&incoming.created_at, &incoming.summary()])}
+                    }
+
+                    for section in ArchiveSection::ALL {
+                        {
+                            let diff = settings_archive::diff_section(section, &local, &incoming);
+                            let mode = modes().get(&section).copied().unwrap_or(ImportMode::Skip);
+                            let description = diff.describe(mode);
+                            rsx! {
+                                div {
+                                    key: "{section.label()}",
+                                    style: "display:grid; grid-template-columns:120px 110px 1fr; gap:12px; align-items:center; font-size:13px;",
+                                    span { style: "font-weight:600; color:var(--fb-text);", "{section.label()}" }
+                                    select {
+                                        value: mode.value(),
+                                        onchange: move |evt| {
+                                            modes.write().insert(section, ImportMode::from_value(&evt.value()));
+                                        },
+                                        style: "padding:6px 10px; border-radius:6px; border:1px solid var(--fb-border-strong); font-size:13px; background:var(--fb-surface); cursor:pointer;",
+                                        for option_mode in ImportMode::ALL {
+                                            option { value: option_mode.value(), "{option_mode.label()}" }
+                                        }
+                                    }
+                                    span {
+                                        style: if mode == ImportMode::Replace && !diff.local_only.is_empty() { "color:var(--fb-danger); word-break:break-all;" } else { "color:var(--fb-text-muted); word-break:break-all;" },
+                                        "{description}"
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    div {
+                        style: "font-size:12px; color:var(--fb-text-subtle);",
+                        {t("config.import_note")}
+                    }
+                    div {
+                        style: "display:flex; gap:12px; justify-content:flex-end;",
+                        Button {
+                            variant: ButtonVariant::Secondary,
+                            style: "padding:10px 18px; font-weight:500;",
+                            onclick: move |_| pending.set(None),
+                            {t("config.cancel")}
+                        }
+                        Button {
+                            style: "padding:10px 18px;",
+                            disabled: modes().values().all(|mode| *mode == ImportMode::Skip),
+                            onclick: apply_import,
+                            {t("config.import_backup")}
+                        }
+                    }
+                }
             }
         }
     }
 }
+
+/// 配置历史面板：查看每次保存前的版本并恢复
+#[component]
+fn ConfigHistoryPanel(
+    config_key: &'static str,
+    on_restore: Callback<i64, Result<(), String>>,
+) -> Element {
+    let mut expanded = use_signal(|| false);
+    let mut entries = use_signal(Vec::<ConfigHistoryEntry>::new);
+    let mut inspected = use_signal(|| Option::<i64>::None);
+    // (是否成功, 提示信息)
+    let mut status = use_signal(|| Option::<(bool, String)>::None);
+
+    let mut reload = move || match db::get_config_history(config_key) {
+        Ok(list) => entries.set(list),
+        Err(e) => status.set(Some((false, t_args("config.history_failed", &[&e])))),
+    };
+
+    rsx! {
+        div {
+            style: "margin-top:20px; background:var(--fb-surface-muted); border-radius:16px; padding:18px 20px; border:1px solid var(--fb-border);",
+
+            div {
+                style: "display:flex; justify-content:space-between; align-items:center;",
+                button {
+                    style: "padding:0; border:none; background:transparent; font-size:15px; font-weight:600; color:var(--fb-text-secondary); cursor:pointer;",
+                    onclick: move |_| {
+                        let open = !expanded();
+                        expanded.set(open);
+                        if open {
+                            reload();
+                        }
+                    },
+                    if expanded() { {t("config.history_expanded")} } else { {t("config.history_collapsed")} }
+                }
+                if expanded() {
+                    button {
+                        style: "padding:6px 12px; border-radius:8px; border:1px solid var(--fb-border-strong); background:var(--fb-surface); color:var(--fb-text-muted); font-weight:500; cursor:pointer; font-size:12px;",
+                        onclick: move |_| reload(),
+                        {t("config.refresh")}
+                    }
+                }
+            }
+
+            if expanded() {
+                if let Some((ok, message)) = status() {
+                    div {
+                        style: if ok { "margin-top:12px; font-size:13px; color:#059669;" } else { "margin-top:12px; font-size:13px; color:var(--fb-danger);" },
+                        "{message}"
+                    }
+                }
+
+                if entries().is_empty() {
+                    div {
+                        style: "margin-top:12px; font-size:13px; color:var(--fb-text-subtle);",
+                        {t("config.no_history")}
+                    }
+                }
+
+                div {
+                    style: "margin-top:12px; display:flex; flex-direction:column; gap:8px; max-height:360px; overflow-y:auto;",
+                    for entry in entries() {
+                        div {
+                            key: "{entry.id}",
+                            style: "background:var(--fb-surface); border-radius:10px; border:1px solid var(--fb-border); padding:10px 14px;",
+                            div {
+                                style: "display:flex; align-items:center; gap:12px;",
+                                span {
+                                    style: "font-size:12px; color:var(--fb-text-muted); font-family:monospace; white-space:nowrap;",
+                                    "{entry.created_at}"
+                                }
+                                span {
+                                    style: "flex:1; font-size:13px; color:var(--fb-text);",
+                                    {config_history::describe_summary(&entry.summary)}
+                                }
+                                button {
+                                    style: "padding:4px 10px; border-radius:6px; border:1px solid var(--fb-border-strong); background:var(--fb-surface); color:var(--fb-text-muted); cursor:pointer; font-size:12px;",
+                                    onclick: move |_| {
+                                        if inspected() == Some(entry.id) {
+                                            inspected.set(None);
+                                        } else {
+                                            inspected.set(Some(entry.id));
+                                        }
+                                    },
+                                    {t("config.view")}
+                                }
+                                button {
+                                    style: "padding:4px 10px; border-radius:6px; border:none; background:var(--fb-primary-gradient); color:white; cursor:pointer; font-size:12px;",
+                                    onclick: move |_| {
+                                        match on_restore.call(entry.id) {
+                                            Ok(()) => {
+                                                status.set(Some((true, t("config.restored").to_string())));
+                                                reload();
+                                            }
+                                            Err(e) => status.set(Some((false, t_args("config.restore_failed", &[&e])))),
+                                        }
+                                    },
+                                    {t("config.restore")}
+                                }
+                            }
+                            if inspected() == Some(entry.id) {
+                                pre {
+                                    style: "margin:10px 0 0 0; padding:12px; background:#0f172a; color:#e2e8f0; border-radius:8px; font-size:12px; max-height:240px; overflow:auto; white-space:pre-wrap; word-break:break-all;",
+                                    // 预览内容经过脱敏，避免卡号等信息直接显示
+                                    {history_preview(&entry.value)}
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 配置内容预览：格式化 JSON 并脱敏
+fn history_preview(value: &str) -> String {
+    let pretty = serde_json::from_str::<serde_json::Value>(value)
+        .and_then(|json| serde_json::to_string_pretty(&json))
+        .unwrap_or_else(|_| value.to_string());
+    Redactor::default().redact(&pretty)
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct ConfigInputProps {
+    label: &'static str,
+    value: String,
+    placeholder: &'static str,
+    #[props(default = "text")]
+    input_type: &'static str,
+    onchange: EventHandler<String>,
+}
+
+#[component]
+fn ConfigInput(props: ConfigInputProps) -> Element {
+    rsx! {
+        label {
+            style: "display:flex; flex-direction:column; gap:6px;",
+            span {
+                style: "font-size:13px; font-weight:600; color:var(--fb-text-secondary);",
+                "{props.label}"
+            }
+            input {
+                r#type: props.input_type,
+                value: props.value,
+                placeholder: props.placeholder,
+                oninput: move |evt| props.onchange.call(evt.value()),
+                style: "padding:11px 14px; border-radius:10px; border:1px solid var(--fb-border-strong); font-size:14px; transition:border-color 0.2s ease, box-shadow 0.2s ease; background:var(--fb-surface);",
+            }
+        }
+    }
+}
+
+/// 敏感字段明文显示的最长时间（秒），超时自动隐藏
+const SECRET_REVEAL_SECS: u64 = 10;
+/// 复制敏感字段后自动清空剪贴板的时间（秒）
+const CLIPBOARD_CLEAR_SECS: u64 = 30;
+
+#[derive(Props, Clone, PartialEq)]
+struct SecretInputProps {
+    #[props(default)]
+    label: Option<&'static str>,
+    value: String,
+    placeholder: &'static str,
+    onchange: EventHandler<String>,
+}
+
+/// 敏感字段输入框：默认掩码显示，支持临时显示明文和复制（剪贴板定时清空）
+#[component]
+fn SecretInput(props: SecretInputProps) -> Element {
+    let mut revealed = use_signal(|| false);
+    // 每次显示明文递增，旧的定时器发现版本不一致时不再隐藏
+    let mut reveal_generation = use_signal(|| 0u64);
+    let mut copy_feedback = use_signal(|| false);
+
+    let value = props.value.clone();
+    let input_type = if revealed() { "text" } else { "password" };
+    let reveal_title = if revealed() {
+        t("config.hide").to_string()
+    } else {
+        t_args("config.reveal", &[&SECRET_REVEAL_SECS])
+    };
+
+    let field = rsx! {
+        div {
+            style: "display:flex; align-items:center; gap:6px;",
+            input {
+                r#type: input_type,
+                value: props.value.clone(),
+                placeholder: props.placeholder,
+                autocomplete: "off",
+                oninput: move |evt| props.onchange.call(evt.value()),
+                style: if props.label.is_some() {
+                    "flex:1; min-width:0; padding:11px 14px; border-radius:10px; border:1px solid var(--fb-border-strong); font-size:14px; background:var(--fb-surface);"
+                } else {
+                    "flex:1; min-width:0; padding:8px 10px; border-radius:6px; border:1px solid var(--fb-border); font-size:13px;"
+                },
+            }
+            button {
+                r#type: "button",
+                title: "{reveal_title}",
+                style: "padding:6px 8px; border-radius:6px; border:1px solid var(--fb-border-strong); background:var(--fb-surface); cursor:pointer; font-size:12px; flex-shrink:0;",
+                onclick: move |_| {
+                    if revealed() {
+                        revealed.set(false);
+                        return;
+                    }
+                    revealed.set(true);
+                    let generation = reveal_generation() + 1;
+                    reveal_generation.set(generation);
+                    spawn(async move {
+                        tokio::time::sleep(tokio::time::Duration::from_secs(SECRET_REVEAL_SECS)).await;
+                        if reveal_generation() == generation {
+                            revealed.set(false);
+                        }
+                    });
+                },
+                if revealed() { "🙈" } else { "👁" }
+            }
+            button {
+                r#type: "button",
+                title: t_args("config.copy_secret", &[&CLIPBOARD_CLEAR_SECS]),
+                disabled: value.is_empty(),
+                style: "padding:6px 8px; border-radius:6px; border:1px solid var(--fb-border-strong); background:var(--fb-surface); cursor:pointer; font-size:12px; flex-shrink:0;",
+                onclick: move |_| {
+                    copy_secret_to_clipboard(&value);
+                    copy_feedback.set(true);
+                    spawn(async move {
+                        tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
+                        copy_feedback.set(false);
+                    });
+                },
+                if copy_feedback() { "✓" } else { "📋" }
+            }
+        }
+    };
+
+    match props.label {
+        Some(label) => rsx! {
+            label {
+                style: "display:flex; flex-direction:column; gap:6px;",
+                span {
+                    style: "font-size:13px; font-weight:600; color:var(--fb-text-secondary);",
+                    "{label}"
+                }
+                {field}
+            }
+        },
+        None => field,
+    }
+}
+
+/// 复制到剪贴板，并在 `CLIPBOARD_CLEAR_SECS` 秒后清空
+/// 清空前先确认剪贴板内容仍是本次复制的值，避免覆盖用户之后复制的内容
+fn copy_secret_to_clipboard(value: &str) {
+    let text = serde_json::to_string(value).unwrap_or_else(|_| "\"\"".to_string());
+    let script = format!(
+        r#"
+        const text = {text};
+        navigator.clipboard.writeText(text).then(() => {{
+            setTimeout(() => {{
+                navigator.clipboard.readText()
+                    .then(current => {{ if (current === text) navigator.clipboard.writeText(''); }})
+                    .catch(() => navigator.clipboard.writeText(''));
+            }}, {delay});
+        }});
+        "#,
+        text = text,
+        delay = CLIPBOARD_CLEAR_SECS * 1000,
+    );
+    let _ = document::eval(&script);
+}
+
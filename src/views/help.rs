@@ -1,3 +1,4 @@
+use crate::common::i18n::t;
 use crate::Route;
 use dioxus::prelude::*;
 
@@ -21,35 +22,39 @@ pub fn Help() -> Element {
                     style: "background:linear-gradient(135deg, #dbeafe 0%, #bfdbfe 100%); border-radius:20px; padding:28px 32px; border:1px solid #60a5fa;",
                     h1 {
                         style: "font-size:26px; font-weight:700; margin:0 0 10px 0; color:#1e3a8a;",
-                        "❓ 帮助中心"
+                        {t("help.title")}
                     }
                     p {
                         style: "color:#1e40af; font-size:15px; margin:0;",
-                        "了解 FactBot 的各项功能和使用方法"
+                        {t("help.subtitle")}
                     }
                 }
 
                 HelpSection {
-                    title: "✈️ 航司报价查询",
+                    title: t("help.airline"),
+                    topic: HelpTopic::Airline,
                     color: "#3b82f6",
                     route: None,
                 }
 
                 HelpSection {
-                    title: "⚙️ 配置管理",
+                    title: t("help.config"),
+                    topic: HelpTopic::Config,
                     color: "#8b5cf6",
                     route: None,
                 }
 
                 HelpSection {
-                    title: "💾 数据存储",
+                    title: t("help.storage"),
+                    topic: HelpTopic::Storage,
                     color: "#10b981",
                     route: None,
                 }
 
                 // 新增的验证码接口帮助部分
                 HelpSection {
-                    title: "🔐 验证码识别 API",
+                    title: t("help.captcha"),
+                    topic: HelpTopic::Captcha,
                     color: "#fbbf24",
                     route: Some(Route::CaptchaHelp {}),
                 }
@@ -58,11 +63,11 @@ pub fn Help() -> Element {
                     style: "background:linear-gradient(135deg, #f0fdf4 0%, #dcfce7 100%); border-radius:18px; padding:24px 28px; border:1px solid #86efac; text-align:center;",
                     h3 {
                         style: "font-size:18px; font-weight:700; margin:0 0 12px 0; color:#166534;",
-                        "需要更多帮助？"
+                        {t("help.more")}
                     }
                     p {
                         style: "margin:0; color:#15803d; font-size:14px;",
-                        "如有问题或建议，请联系技术支持团队"
+                        {t("help.contact")}
                     }
                 }
             }
@@ -70,11 +75,20 @@ pub fn Help() -> Element {
     }
 }
 
+/// 帮助分区，决定分区下显示的条目（标题随界面语言变化，不能用来区分）
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum HelpTopic {
+    Airline,
+    Config,
+    Storage,
+    Captcha,
+}
+
 #[component]
-fn HelpSection(title: &'static str, color: &'static str, route: Option<Route>) -> Element {
+fn HelpSection(title: &'static str, topic: HelpTopic, color: &'static str, route: Option<Route>) -> Element {
     rsx! {
         section {
-            style: "background:white; border-radius:18px; padding:28px 32px; border:1px solid #e5e7eb; box-shadow:0 6px 20px rgba(15,23,42,0.08);",
+            style: "background:var(--fb-surface); border-radius:18px; padding:28px 32px; border:1px solid var(--fb-border); box-shadow:0 6px 20px rgba(15,23,42,0.08);",
             h2 {
                 style: "font-size:22px; font-weight:700; margin:0 0 20px 0; color:{color};",
                 if let Some(route) = route {
@@ -92,25 +106,25 @@ fn HelpSection(title: &'static str, color: &'static str, route: Option<Route>) -
             div {
                 style: "display:flex; flex-direction:column; gap:14px;",
                 
-                if title.contains("航司") {
-                    HelpItem { label: "选择代理分组", desc: "从配置管理页面设置的代理分组中选择，系统会随机使用分组中的一个代理" }
-                    HelpItem { label: "输入 Token", desc: "配置访问航司 API 所需的认证 Token" }
-                    HelpItem { label: "选择航司", desc: "支持 16 家航司，包括美国航空、韩亚航空、巴拿马航空等" }
-                    HelpItem { label: "立即查询", desc: "点击查询按钮发送请求，系统会随机选择分组中的一个代理使用" }
-                } else if title.contains("配置") {
-                    HelpItem { label: "代理配置", desc: "支持分组管理，每个分组可添加多个代理。支持批量导入，格式：ip:port:username:password" }
-                    HelpItem { label: "OTP 邮箱", desc: "配置用于接收验证码的邮箱服务，支持多个配置分组" }
-                    HelpItem { label: "支付卡片", desc: "管理信用卡和礼品卡信息，支持分组批量保存" }
-                    HelpItem { label: "购票人信息", desc: "保存常用购票人信息，包括姓名、邮箱、电话、护照等" }
-                } else if title.contains("存储") {
-                    HelpItem { label: "存储位置", desc: "所有配置数据存储在：文档/FactBot/config.db" }
-                    HelpItem { label: "自动保存", desc: "点击保存按钮后数据会持久化到数据库" }
-                    HelpItem { label: "自动加载", desc: "应用启动时自动从数据库加载所有配置" }
-                    HelpItem { label: "数据安全", desc: "使用 SQLite 本地数据库，数据仅存储在本地" }
-                } else if title.contains("验证码") {
+                if topic == HelpTopic::Airline {
+                    HelpItem { label: t("help.proxy_group"), desc: t("help.proxy_group_desc") }
+                    HelpItem { label: t("help.token"), desc: t("help.token_desc") }
+                    HelpItem { label: t("help.choose_airline"), desc: t("help.choose_airline_desc") }
+                    HelpItem { label: t("help.query"), desc: t("help.query_desc") }
+                } else if topic == HelpTopic::Config {
+                    HelpItem { label: t("help.proxies"), desc: t("help.proxies_desc") }
+                    HelpItem { label: t("help.otp"), desc: t("help.otp_desc") }
+                    HelpItem { label: t("help.cards"), desc: t("help.cards_desc") }
+                    HelpItem { label: t("help.passengers"), desc: t("help.passengers_desc") }
+                } else if topic == HelpTopic::Storage {
+                    HelpItem { label: t("help.location"), desc: t("help.location_desc") }
+                    HelpItem { label: t("help.autosave"), desc: t("help.autosave_desc") }
+                    HelpItem { label: t("help.autoload"), desc: t("help.autoload_desc") }
+                    HelpItem { label: t("help.security"), desc: t("help.security_desc") }
+                } else if topic == HelpTopic::Captcha {
                     p {
-                        style: "margin:0; font-size:14px; color:#6b7280; line-height:1.6;",
-                        "验证码识别 API 提供了多种编程语言的调用示例，点击标题进入详细页面查看。"
+                        style: "margin:0; font-size:14px; color:var(--fb-text-muted); line-height:1.6;",
+                        {t("help.captcha_desc")}
                     }
                 }
             }
@@ -128,11 +142,11 @@ fn HelpItem(label: &'static str, desc: &'static str) -> Element {
             }
             div {
                 strong {
-                    style: "display:block; font-size:15px; font-weight:600; color:#111827; margin-bottom:4px;",
+                    style: "display:block; font-size:15px; font-weight:600; color:var(--fb-text); margin-bottom:4px;",
                     "{label}"
                 }
                 p {
-                    style: "margin:0; font-size:14px; color:#6b7280; line-height:1.6;",
+                    style: "margin:0; font-size:14px; color:var(--fb-text-muted); line-height:1.6;",
                     "{desc}"
                 }
             }
@@ -1,4 +1,5 @@
 use crate::Route;
+use crate::common::i18n::t;
 use dioxus::prelude::*;
 
 /// 欢迎页：突出产品定位，并引导用户进入航司报价页面
@@ -16,12 +17,12 @@ pub fn Home() -> Element {
                 
                 h1 {
                     style: "margin:28px 0 16px; font-size:36px; font-weight:700; background:linear-gradient(135deg,#f8fafc,#cbd5e1); -webkit-background-clip:text; -webkit-text-fill-color:transparent; background-clip:text;",
-                    "集中管理多家航司报价、代理与接口配置"
+                    {t("home.tagline")}
                 }
                 
                 p {
                     style: "margin:0 auto 32px; max-width:540px; color:#94a3b8; font-size:16px; line-height:1.8;",
-                    "FactBot 提供统一的工作台，帮助你在一个界面完成代理配置、Token 维护以及各航司查询。通过左侧侧边栏即可随时切换到航司功能页。"
+                    {t("home.intro")}
                 }
                 
                 // 功能特性卡片
@@ -30,18 +31,18 @@ pub fn Home() -> Element {
                     
                     FeatureCard {
                         icon: "✈️",
-                        title: "航司报价",
-                        desc: "支持16家航司查询"
+                        title: t("nav.airline"),
+                        desc: t("home.airlines_desc")
                     }
                     FeatureCard {
                         icon: "⚙️",
-                        title: "配置管理",
-                        desc: "统一管理代理和卡片"
+                        title: t("nav.config"),
+                        desc: t("home.config_desc")
                     }
                     FeatureCard {
                         icon: "🔒",
-                        title: "安全可靠",
-                        desc: "本地存储配置信息"
+                        title: t("home.secure"),
+                        desc: t("home.secure_desc")
                     }
                 }
                 
@@ -50,12 +51,12 @@ pub fn Home() -> Element {
                     Link {
                         to: Route::Airline {},
                         style: "padding:14px 34px; border-radius:18px; font-size:15px; font-weight:600; color:white; background:linear-gradient(135deg,#6366f1,#22d3ee); text-decoration:none; box-shadow:0 10px 25px rgba(99,102,241,0.4); transition:transform 0.2s, box-shadow 0.2s;",
-                        "进入航司报价 →"
+                        {t("home.open_airline")}
                     }
                     Link {
                         to: Route::ConfigView {},
                         style: "padding:14px 28px; border-radius:18px; font-size:15px; font-weight:600; color:#22d3ee; background:rgba(34,211,238,0.1); border:1px solid rgba(34,211,238,0.3); text-decoration:none; transition:background 0.2s;",
-                        "配置管理"
+                        {t("nav.config")}
                     }
                 }
             }
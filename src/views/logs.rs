@@ -1,8 +1,28 @@
+use chrono::Utc;
 use dioxus::prelude::*;
+use crate::common::diagnostics::{self, BundleOptions};
+use crate::common::i18n::{self, t, t_args};
+use crate::common::log_query::{self, LogQuery, TimeRange};
+use crate::common::timestamp::{self, DisplayZone};
+use crate::common::AppError;
+use crate::components::{use_notifier, use_table_state, Card, Column, DataTable, SortKey, SortState, TableState};
 use crate::db::{self, LogEntry};
+use crate::Route;
 
+/// 级别筛选按钮：(级别, 图标)，DEBUG 只在 dev 模式显示
+const LEVELS: [(&str, &str); 5] = [
+    ("DEBUG", "🔵"),
+    ("SUCCESS", "🟢"),
+    ("INFO", "⚪"),
+    ("WARN", "🟡"),
+    ("ERROR", "🔴"),
+];
+
+/// 日志页面；筛选条件来自路由的查询字符串，为空时恢复上次的查询
 #[component]
-pub fn Logs() -> Element {
+pub fn Logs(query: LogQuery) -> Element {
+    let mut notifier = use_notifier();
+
     // 状态管理
     let mut logs = use_signal(Vec::<LogEntry>::new);
     let mut task_names = use_signal(Vec::<String>::new);
@@ -11,17 +31,45 @@ pub fn Logs() -> Element {
     // 筛选条件
     let mut selected_task_name = use_signal(String::new);
     let mut selected_task_uuid = use_signal(String::new);
-    let mut selected_log_level = use_signal(String::new);
+    let mut selected_levels = use_signal(Vec::<String>::new);
     let mut keyword = use_signal(String::new);
-    let mut sort_order = use_signal(|| "desc".to_string()); // desc 或 asc
+    let mut time_range = use_signal(TimeRange::default);
+    // 时间选择框的值（按显示时区），快捷范围时显示计算出的起始时间
+    let mut since_input = use_signal(String::new);
+    let mut until_input = use_signal(String::new);
+    // 时间显示时区（日志按 UTC 存储）
+    let mut display_zone = use_signal(DisplayZone::load);
+
+    // 筛选预设
+    let mut presets = use_signal(|| notifier.report_error(log_query::load_presets(), t("logs.load_presets_failed")).unwrap_or_default());
+    let mut selected_preset = use_signal(String::new);
+    let mut preset_name = use_signal(String::new);
     
-    // 分页
-    let mut page_size = use_signal(|| 50usize);
-    let mut current_page = use_signal(|| 1usize);
+    // 诊断包中包含的日志条数
+    let mut bundle_log_limit = use_signal(|| diagnostics::DEFAULT_LOG_LIMIT);
+
+    // 生成诊断包：日志范围为当前选择的任务 / UUID
+    let mut create_bundle = move || {
+        let non_empty = |value: String| Some(value).filter(|value| !value.is_empty());
+        let options = BundleOptions {
+            task_name: non_empty(selected_task_name()),
+            task_uuid: non_empty(selected_task_uuid()),
+            log_limit: bundle_log_limit(),
+        };
+        let result = diagnostics::default_bundle_path()
+            .ok_or_else(|| AppError::Unknown(t("crash.no_documents_dir").to_string()))
+            .and_then(|path| diagnostics::create_bundle(&path, &options).map(|_| path));
+        if let Some(path) = notifier.report_error(result, t("crash.bundle_failed")) {
+            notifier.success(t_args("logs.bundle_saved", &[&path.display()]));
+        }
+    };
+
+    // 表格：分页、排序（默认最新在前）和选中的日志
+    let mut table = use_table_state(|| TableState::new(50).sorted_by("time", false));
 
     // 初始化加载任务名称列表
     use_effect(move || {
-        if let Ok(all_logs) = db::get_all_logs() {
+        if let Some(all_logs) = notifier.report_error(db::get_all_logs(), t("logs.load_failed")) {
             let mut names: Vec<String> = all_logs
                 .iter()
                 .map(|log| log.task_name.clone())
@@ -34,14 +82,13 @@ pub fn Logs() -> Element {
     });
 
     // 当任务名称改变时，更新任务 UUID 列表
-    let mut update_task_uuids = move || {
-        let task_name = selected_task_name();
+    let mut update_task_uuids = move |task_name: &str| {
         if task_name.is_empty() {
             task_uuids.set(Vec::new());
             return;
         }
         
-        if let Ok(all_logs) = db::get_all_logs() {
+        if let Some(all_logs) = notifier.report_error(db::get_all_logs(), t("logs.load_failed")) {
             let mut uuids: Vec<String> = all_logs
                 .iter()
                 .filter(|log| log.task_name == task_name)
@@ -54,60 +101,93 @@ pub fn Logs() -> Element {
         }
     };
 
-    // 加载日志数据
-    let mut load_logs = move || {
-        let task_name = selected_task_name();
-        if task_name.is_empty() {
+    // 快捷范围：时间选择框显示计算出的起止时间
+    let mut set_time_range = move |range: TimeRange| {
+        let zone = *display_zone.peek();
+        let probe = LogQuery { range, ..LogQuery::default() };
+        let (since, until) = probe.bounds(Utc::now(), zone);
+        time_range.set(range);
+        since_input.set(since.map(|time| zone.to_input(time)).unwrap_or_default());
+        until_input.set(until.map(|time| zone.to_input(time)).unwrap_or_default());
+    };
+
+    // 当前界面上的筛选条件
+    let current_query = move || {
+        let zone = *display_zone.peek();
+        let range = time_range();
+        let parse = |value: String| zone.parse_input(&value).map(timestamp::to_storage);
+        LogQuery {
+            task_name: selected_task_name(),
+            task_uuid: selected_task_uuid(),
+            levels: selected_levels(),
+            keyword: keyword(),
+            range,
+            since: (range == TimeRange::Custom).then(|| parse(since_input())).flatten(),
+            until: (range == TimeRange::Custom).then(|| parse(until_input())).flatten(),
+        }
+    };
+
+    // 加载日志数据（只读取参数，不读取界面状态，可在 effect 中调用）
+    let mut run_query = move |query: LogQuery| {
+        if query.task_name.is_empty() {
             logs.set(Vec::new());
             return;
         }
 
-        if let Ok(mut all_logs) = db::get_all_logs() {
-            // 正式版：过滤掉 DEBUG 日志
-            #[cfg(not(feature = "dev"))]
-            {
-                all_logs.retain(|log| log.log_level != "DEBUG");
-            }
-
-            // 按任务名称筛选
-            all_logs.retain(|log| log.task_name == task_name);
-
-            // 按任务 UUID 筛选
-            let task_uuid = selected_task_uuid();
-            if !task_uuid.is_empty() {
-                all_logs.retain(|log| log.task_uuid.contains(&task_uuid));
-            }
-
-            // 按日志级别筛选
-            let log_level = selected_log_level();
-            if !log_level.is_empty() {
-                all_logs.retain(|log| log.log_level == log_level);
-            }
-
-            // 按关键词筛选
-            let kw = keyword();
-            if !kw.is_empty() {
-                all_logs.retain(|log| log.message.contains(&kw));
-            }
+        // 正式版：过滤掉 DEBUG 日志；排序由表格完成
+        let filter = query.to_filter(Utc::now(), *display_zone.peek(), cfg!(feature = "dev"));
+        if let Some(all_logs) = notifier.report_error(db::query_logs(&filter), t("logs.load_failed")) {
+            logs.set(all_logs);
+            table.write().reset();
+        }
+        notifier.report_error(query.save_last(), t("logs.save_query_failed"));
+    };
 
-            // 排序
-            if sort_order() == "asc" {
-                all_logs.sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
-            } else {
-                all_logs.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
-            }
+    // 路由中的条件变化时（打开链接、应用预设、返回）填入界面并查询
+    use_effect(use_reactive!(|query| {
+        let query = if query.is_empty() { LogQuery::load_last() } else { query };
+        let zone = *display_zone.peek();
+        selected_task_name.set(query.task_name.clone());
+        selected_task_uuid.set(query.task_uuid.clone());
+        selected_levels.set(query.levels.clone());
+        keyword.set(query.keyword.clone());
+        if query.range == TimeRange::Custom {
+            let input = |value: &Option<String>| value.as_deref().and_then(timestamp::parse).map(|time| zone.to_input(time));
+            time_range.set(TimeRange::Custom);
+            since_input.set(input(&query.since).unwrap_or_default());
+            until_input.set(input(&query.until).unwrap_or_default());
+        } else {
+            set_time_range(query.range);
+        }
+        update_task_uuids(&query.task_name);
+        run_query(query);
+    }));
 
-            logs.set(all_logs);
-            current_page.set(1);
+    // 查询：条件写入路由，路由不变时直接查询
+    let route_query = use_memo(use_reactive!(|query| query));
+    let mut submit = move |query: LogQuery| {
+        if query == *route_query.peek() {
+            run_query(query);
+        } else {
+            navigator().replace(Route::Logs { query });
         }
     };
 
-    // 分页计算
-    let total_items = logs().len();
-    let total_pages = if total_items == 0 { 1 } else { total_items.div_ceil(page_size()) };
-    let start_index = (current_page() - 1) * page_size();
-    let end_index = (start_index + page_size()).min(total_items);
-    let current_logs: Vec<LogEntry> = logs().into_iter().skip(start_index).take(page_size()).collect();
+    let newest_first = SortState { column: "time", ascending: false };
+    let oldest_first = SortState { column: "time", ascending: true };
+    let current_sort = table.read().sort;
+    let lang = i18n::current();
+    let selected_text = {
+        let logs = logs.read();
+        table
+            .read()
+            .selected
+            .iter()
+            .filter_map(|&row| logs.get(row))
+            .map(|log| format!("{} [{}] {}", display_zone().format_in(&log.timestamp, lang), log.log_level, log.message))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
 
     rsx! {
         document::Style {
@@ -193,21 +273,20 @@ pub fn Logs() -> Element {
                     style: "background:linear-gradient(135deg, #ede9fe 0%, #ddd6fe 100%); border-radius:20px; padding:28px 32px; border:1px solid #a78bfa; box-shadow:0 4px 20px rgba(139, 92, 246, 0.1);",
                     h1 {
                         style: "font-size:26px; font-weight:700; margin:0 0 10px 0; color:#5b21b6; letter-spacing:-0.02em;",
-                        "📋 日志查看器"
+                        {t("logs.title")}
                     }
                     p {
                         style: "color:#7c3aed; font-size:15px; margin:0; line-height:1.6;",
-                        "查看和筛选任务执行日志，支持按任务名称、UUID、级别和关键词过滤"
+                        {t("logs.subtitle")}
                     }
                 }
 
                 // 筛选区域
-                section {
-                    style: "background:white; border-radius:20px; padding:28px 32px; border:1px solid #e5e7eb; box-shadow:0 8px 30px rgba(15,23,42,0.08);",
+                Card {
                     
                     h2 {
-                        style: "font-size:18px; font-weight:700; margin:0 0 20px 0; color:#111827;",
-                        "🔍 筛选条件"
+                        style: "font-size:18px; font-weight:700; margin:0 0 20px 0; color:var(--fb-text);",
+                        {t("logs.filters")}
                     }
 
                     div {
@@ -216,8 +295,8 @@ pub fn Logs() -> Element {
                         // 任务名称（必填）
                         div {
                             label {
-                                style: "display:block; margin-bottom:8px; font-size:14px; font-weight:600; color:#374151;",
-                                "任务名称 ",
+                                style: "display:block; margin-bottom:8px; font-size:14px; font-weight:600; color:var(--fb-text-secondary);",
+                                {t("logs.task_name")},
                                 span { style: "color:#ef4444;", "*" }
                             }
                             select {
@@ -225,10 +304,10 @@ pub fn Logs() -> Element {
                                 onchange: move |evt| {
                                     selected_task_name.set(evt.value());
                                     selected_task_uuid.set(String::new());
-                                    update_task_uuids();
+                                    update_task_uuids(&evt.value());
                                 },
-                                style: "width:100%; padding:10px 14px; border-radius:10px; border:1px solid #d1d5db; font-size:14px; background:white; color:#111827; cursor:pointer; -webkit-appearance:none; -moz-appearance:none; appearance:none; box-shadow:none; outline:none;",
-                                option { value: "", "-- 请选择任务名称 --" }
+                                style: "width:100%; padding:10px 14px; border-radius:10px; border:1px solid var(--fb-border-strong); font-size:14px; background:var(--fb-surface); color:var(--fb-text); cursor:pointer; -webkit-appearance:none; -moz-appearance:none; appearance:none; box-shadow:none; outline:none;",
+                                option { value: "", {t("logs.choose_task")} }
                                 for name in task_names() {
                                     option { value: "{name}", "{name}" }
                                 }
@@ -238,61 +317,189 @@ pub fn Logs() -> Element {
                         // 任务 UUID（选填）
                         div {
                             label {
-                                style: "display:block; margin-bottom:8px; font-size:14px; font-weight:600; color:#374151;",
-                                "任务 UUID"
+                                style: "display:block; margin-bottom:8px; font-size:14px; font-weight:600; color:var(--fb-text-secondary);",
+                                {t("logs.task_uuid")}
                             }
                             select {
                                 value: selected_task_uuid(),
                                 onchange: move |evt| selected_task_uuid.set(evt.value()),
                                 disabled: selected_task_name().is_empty(),
                                 style: if selected_task_name().is_empty() {
-                                    "width:100%; padding:10px 14px; border-radius:10px; border:1px solid #d1d5db; font-size:14px; background:#f3f4f6; color:#9ca3af; cursor:not-allowed; -webkit-appearance:none; -moz-appearance:none; appearance:none; box-shadow:none; outline:none;"
+                                    "width:100%; padding:10px 14px; border-radius:10px; border:1px solid var(--fb-border-strong); font-size:14px; background:var(--fb-surface-hover); color:var(--fb-text-subtle); cursor:not-allowed; -webkit-appearance:none; -moz-appearance:none; appearance:none; box-shadow:none; outline:none;"
                                 } else {
-                                    "width:100%; padding:10px 14px; border-radius:10px; border:1px solid #d1d5db; font-size:14px; background:white; color:#111827; cursor:pointer; -webkit-appearance:none; -moz-appearance:none; appearance:none; box-shadow:none; outline:none;"
+                                    "width:100%; padding:10px 14px; border-radius:10px; border:1px solid var(--fb-border-strong); font-size:14px; background:var(--fb-surface); color:var(--fb-text); cursor:pointer; -webkit-appearance:none; -moz-appearance:none; appearance:none; box-shadow:none; outline:none;"
                                 },
-                                option { value: "", "-- 全部 UUID --" }
+                                option { value: "", {t("logs.all_uuids")} }
                                 for uuid in task_uuids() {
                                     option { value: "{uuid}", "{uuid}" }
                                 }
                             }
                         }
 
-                        // 日志级别（选填）
+                        // 关键词搜索（选填）
                         div {
                             label {
-                                style: "display:block; margin-bottom:8px; font-size:14px; font-weight:600; color:#374151;",
-                                "日志级别"
+                                style: "display:block; margin-bottom:8px; font-size:14px; font-weight:600; color:var(--fb-text-secondary);",
+                                {t("logs.keyword")}
                             }
-                            select {
-                                value: selected_log_level(),
-                                onchange: move |evt| selected_log_level.set(evt.value()),
-                                style: "width:100%; padding:10px 14px; border-radius:10px; border:1px solid #d1d5db; font-size:14px; background:white; color:#111827; cursor:pointer; -webkit-appearance:none; -moz-appearance:none; appearance:none; box-shadow:none; outline:none;",
-                                option { value: "", "-- 全部级别 --" }
-                                // dev 模式：显示 DEBUG 选项
-                                // 正式版：不显示 DEBUG 选项
-                                if cfg!(feature = "dev") {
-                                    option { value: "DEBUG", "🔵 DEBUG" }
+                            input {
+                                value: keyword(),
+                                oninput: move |evt| keyword.set(evt.value()),
+                                style: "width:100%; padding:10px 14px; border-radius:10px; border:1px solid var(--fb-border-strong); font-size:14px;",
+                                placeholder: t("logs.keyword_placeholder")
+                            }
+                        }
+                    }
+
+                    // 日志级别（多选，都不选时不限）
+                    div {
+                        style: "display:flex; align-items:center; gap:8px; flex-wrap:wrap; margin-bottom:16px;",
+                        span {
+                            style: "font-size:14px; font-weight:600; color:var(--fb-text-secondary); margin-right:4px;",
+                            {t("logs.levels")}
+                        }
+                        // 正式版不显示 DEBUG
+                        for (level, icon) in LEVELS.into_iter().filter(|(level, _)| cfg!(feature = "dev") || *level != "DEBUG") {
+                            {
+                                let checked = selected_levels.read().iter().any(|selected| selected == level);
+                                rsx! {
+                                    button {
+                                        key: "{level}",
+                                        style: if checked {
+                                            "padding:6px 14px; border-radius:999px; border:1px solid #4f46e5; background:var(--fb-primary-soft); color:#4338ca; font-size:13px; font-weight:600; cursor:pointer;"
+                                        } else {
+                                            "padding:6px 14px; border-radius:999px; border:1px solid var(--fb-border-strong); background:var(--fb-surface); color:var(--fb-text-muted); font-size:13px; font-weight:500; cursor:pointer;"
+                                        },
+                                        onclick: move |_| {
+                                            let mut levels = selected_levels();
+                                            if checked {
+                                                levels.retain(|selected| selected != level);
+                                            } else {
+                                                levels.push(level.to_string());
+                                            }
+                                            // 保持固定顺序，路由和预设中的条件稳定
+                                            levels.sort_by_key(|selected| LEVELS.iter().position(|(level, _)| level == selected));
+                                            selected_levels.set(levels);
+                                        },
+                                        "{icon} {level}"
+                                    }
                                 }
-                                option { value: "SUCCESS", "🟢 SUCCESS" }
-                                option { value: "INFO", "⚪ INFO" }
-                                option { value: "WARN", "🟡 WARN" }
-                                option { value: "ERROR", "🔴 ERROR" }
                             }
                         }
+                        if !selected_levels.read().is_empty() {
+                            button {
+                                style: "padding:6px 10px; border:none; background:none; color:var(--fb-text-muted); font-size:13px; cursor:pointer; text-decoration:underline;",
+                                onclick: move |_| selected_levels.set(Vec::new()),
+                                {t("logs.all_levels")}
+                            }
+                        }
+                    }
 
-                        // 关键词搜索（选填）
-                        div {
-                            label {
-                                style: "display:block; margin-bottom:8px; font-size:14px; font-weight:600; color:#374151;",
-                                "关键词搜索"
+                    // 时间范围：快捷范围按查询时的当前时间计算，修改起止时间后为自定义范围
+                    div {
+                        style: "display:flex; align-items:center; gap:8px; flex-wrap:wrap; margin-bottom:16px;",
+                        span {
+                            style: "font-size:14px; font-weight:600; color:var(--fb-text-secondary); margin-right:4px;",
+                            {t("logs.time_range")}
+                        }
+                        for range in TimeRange::ALL.into_iter().filter(|range| *range != TimeRange::Custom) {
+                            button {
+                                key: "{range.id()}",
+                                style: if time_range() == range {
+                                    "padding:6px 14px; border-radius:8px; border:none; background:#4f46e5; color:white; font-size:13px; font-weight:600; cursor:pointer;"
+                                } else {
+                                    "padding:6px 14px; border-radius:8px; border:1px solid var(--fb-border-strong); background:var(--fb-surface); color:var(--fb-text-muted); font-size:13px; font-weight:500; cursor:pointer;"
+                                },
+                                onclick: move |_| set_time_range(range),
+                                "{range.label()}"
                             }
-                            input {
-                                value: keyword(),
-                                oninput: move |evt| keyword.set(evt.value()),
-                                style: "width:100%; padding:10px 14px; border-radius:10px; border:1px solid #d1d5db; font-size:14px;",
-                                placeholder: "搜索日志内容..."
+                        }
+                        input {
+                            r#type: "datetime-local",
+                            value: since_input(),
+                            oninput: move |evt| {
+                                since_input.set(evt.value());
+                                time_range.set(TimeRange::Custom);
+                            },
+                            title: t("logs.since_hint"),
+                            style: "margin-left:8px; padding:6px 10px; border-radius:8px; border:1px solid var(--fb-border-strong); font-size:13px; color:var(--fb-text);",
+                        }
+                        span { style: "font-size:13px; color:var(--fb-text-muted);", {t("logs.to")} }
+                        input {
+                            r#type: "datetime-local",
+                            value: until_input(),
+                            oninput: move |evt| {
+                                until_input.set(evt.value());
+                                time_range.set(TimeRange::Custom);
+                            },
+                            title: t("logs.until_hint"),
+                            style: "padding:6px 10px; border-radius:8px; border:1px solid var(--fb-border-strong); font-size:13px; color:var(--fb-text);",
+                        }
+                        if time_range() == TimeRange::Custom {
+                            span { style: "font-size:13px; color:#4f46e5; font-weight:600;", "{TimeRange::Custom.label()}" }
+                        }
+                    }
+
+                    // 筛选预设：选择后立即查询，同名保存时覆盖
+                    div {
+                        style: "display:flex; align-items:center; gap:8px; flex-wrap:wrap; margin-bottom:20px; padding-bottom:20px; border-bottom:1px solid var(--fb-border);",
+                        span {
+                            style: "font-size:14px; font-weight:600; color:var(--fb-text-secondary); margin-right:4px;",
+                            {t("logs.presets")}
+                        }
+                        select {
+                            value: selected_preset(),
+                            onchange: move |evt| {
+                                let name = evt.value();
+                                let preset = presets.read().iter().find(|preset| preset.name == name).cloned();
+                                selected_preset.set(name);
+                                if let Some(preset) = preset {
+                                    preset_name.set(preset.name);
+                                    submit(preset.query);
+                                }
+                            },
+                            style: "min-width:180px; padding:6px 10px; border-radius:8px; border:1px solid var(--fb-border-strong); font-size:13px; background:var(--fb-surface); color:var(--fb-text); cursor:pointer;",
+                            option { value: "", {t("logs.choose_preset")} }
+                            for preset in presets() {
+                                option { value: "{preset.name}", "{preset.name}" }
                             }
                         }
+                        if !selected_preset().is_empty() {
+                            button {
+                                style: "padding:6px 12px; border-radius:8px; border:1px solid var(--fb-danger-border); background:var(--fb-danger-bg); color:var(--fb-danger); font-size:13px; cursor:pointer;",
+                                onclick: move |_| {
+                                    let name = selected_preset();
+                                    let mut updated = presets();
+                                    updated.retain(|preset| preset.name != name);
+                                    if notifier.report(log_query::save_presets(&updated), t("logs.preset_deleted"), t("logs.delete_preset_failed")).is_some() {
+                                        presets.set(updated);
+                                        selected_preset.set(String::new());
+                                    }
+                                },
+                                {t("logs.delete_preset")}
+                            }
+                        }
+                        input {
+                            value: preset_name(),
+                            oninput: move |evt| preset_name.set(evt.value()),
+                            placeholder: t("logs.preset_name"),
+                            style: "margin-left:8px; width:160px; padding:6px 10px; border-radius:8px; border:1px solid var(--fb-border-strong); font-size:13px;",
+                        }
+                        button {
+                            style: "padding:6px 12px; border-radius:8px; border:1px solid var(--fb-border-strong); background:var(--fb-surface); color:var(--fb-text-secondary); font-size:13px; font-weight:600; cursor:pointer;",
+                            title: t("logs.save_preset_hint"),
+                            onclick: move |_| {
+                                let mut updated = presets();
+                                let name = preset_name().trim().to_string();
+                                let result = log_query::upsert_preset(&mut updated, &name, current_query())
+                                    .and_then(|_| log_query::save_presets(&updated));
+                                if notifier.report(result, t("logs.preset_saved"), t("logs.save_preset_failed")).is_some() {
+                                    presets.set(updated);
+                                    selected_preset.set(name);
+                                }
+                            },
+                            {t("logs.save_preset")}
+                        }
                     }
 
                     div {
@@ -302,157 +509,159 @@ pub fn Logs() -> Element {
                         div {
                             style: "display:flex; align-items:center; gap:12px;",
                             span {
-                                style: "font-size:14px; font-weight:600; color:#374151;",
-                                "时间排序："
+                                style: "font-size:14px; font-weight:600; color:var(--fb-text-secondary);",
+                                {t("logs.sort")}
                             }
                             button {
-                                style: if sort_order() == "desc" {
+                                style: if current_sort == Some(newest_first) {
                                     "padding:8px 16px; border-radius:8px; border:none; background:#4f46e5; color:white; font-weight:600; cursor:pointer;"
                                 } else {
-                                    "padding:8px 16px; border-radius:8px; border:1px solid #d1d5db; background:white; color:#6b7280; font-weight:600; cursor:pointer;"
+                                    "padding:8px 16px; border-radius:8px; border:1px solid var(--fb-border-strong); background:var(--fb-surface); color:var(--fb-text-muted); font-weight:600; cursor:pointer;"
                                 },
-                                onclick: move |_| sort_order.set("desc".to_string()),
-                                "⬇ 最新在前"
+                                onclick: move |_| table.write().sort = Some(newest_first),
+                                {t("logs.newest_first")}
                             }
                             button {
-                                style: if sort_order() == "asc" {
+                                style: if current_sort == Some(oldest_first) {
                                     "padding:8px 16px; border-radius:8px; border:none; background:#4f46e5; color:white; font-weight:600; cursor:pointer;"
                                 } else {
-                                    "padding:8px 16px; border-radius:8px; border:1px solid #d1d5db; background:white; color:#6b7280; font-weight:600; cursor:pointer;"
+                                    "padding:8px 16px; border-radius:8px; border:1px solid var(--fb-border-strong); background:var(--fb-surface); color:var(--fb-text-muted); font-weight:600; cursor:pointer;"
                                 },
-                                onclick: move |_| sort_order.set("asc".to_string()),
-                                "⬆ 最旧在前"
+                                onclick: move |_| table.write().sort = Some(oldest_first),
+                                {t("logs.oldest_first")}
                             }
-                        }
 
-                        // 查询按钮
-                        button {
-                            style: "padding:10px 28px; border-radius:10px; border:none; background:linear-gradient(120deg,#4f46e5,#7c3aed); color:white; font-weight:600; cursor:pointer; box-shadow:0 4px 12px rgba(79, 70, 229, 0.3);",
-                            onclick: move |_| load_logs(),
-                            "🔍 查询日志"
-                        }
-                    }
-                }
-
-                // 日志列表
-                if !logs().is_empty() {
-                    section {
-                        style: "background:white; border-radius:20px; padding:28px 32px; border:1px solid #e5e7eb; box-shadow:0 8px 30px rgba(15,23,42,0.08);",
-                        
-                        div {
-                            style: "display:flex; justify-content:space-between; align-items:center; margin-bottom:16px;",
-                            div {
-                                style: "font-size:14px; color:#6b7280;",
-                                "共 {total_items} 条日志，第 {start_index + 1}-{end_index} 条"
+                            span {
+                                style: "margin-left:12px; font-size:14px; font-weight:600; color:var(--fb-text-secondary);",
+                                {t("logs.display_zone")}
                             }
-                            div {
-                                style: "display:flex; align-items:center; gap:12px;",
-                                span {
-                                    style: "font-size:13px; color:#6b7280;",
-                                    "每页显示："
-                                }
-                                select {
-                                    value: page_size().to_string(),
-                                    onchange: move |evt| {
-                                        if let Ok(size) = evt.value().parse::<usize>() {
-                                            page_size.set(size);
-                                            current_page.set(1);
-                                        }
-                                    },
-                                    style: "padding:6px 10px; border-radius:6px; border:1px solid #d1d5db; font-size:13px; background:white; cursor:pointer;",
-                                    option { value: "20", "20 条" }
-                                    option { value: "50", "50 条" }
-                                    option { value: "100", "100 条" }
-                                    option { value: "200", "200 条" }
+                            select {
+                                value: display_zone().id(),
+                                onchange: move |evt| {
+                                    let zone = DisplayZone::from_id(&evt.value());
+                                    display_zone.set(zone);
+                                    notifier.report_error(zone.save(), t("logs.save_zone_failed"));
+                                },
+                                style: "padding:8px 12px; border-radius:8px; border:1px solid var(--fb-border-strong); font-size:14px; background:var(--fb-surface); color:var(--fb-text); cursor:pointer;",
+                                option { value: "local", "{DisplayZone::Local.label()}" }
+                                for tz in DisplayZone::CHOICES {
+                                    option { value: "{tz.name()}", "{tz.name()}" }
                                 }
                             }
                         }
 
                         div {
-                            style: "background:#f9fafb; border-radius:12px; overflow:hidden; border:1px solid #e5e7eb;",
-                            table {
-                                style: "width:100%; border-collapse:collapse;",
-                                thead {
-                                    tr {
-                                        style: "background:#f3f4f6;",
-                                        th { style: "padding:12px 16px; text-align:left; font-size:13px; font-weight:600; color:#374151; border-bottom:2px solid #e5e7eb; width:140px;", "任务 ID" }
-                                        th { style: "padding:12px 16px; text-align:left; font-size:13px; font-weight:600; color:#374151; border-bottom:2px solid #e5e7eb; width:100px;", "级别" }
-                                        th { style: "padding:12px 16px; text-align:left; font-size:13px; font-weight:600; color:#374151; border-bottom:2px solid #e5e7eb; width:160px;", "时间" }
-                                        th { style: "padding:12px 16px; text-align:left; font-size:13px; font-weight:600; color:#374151; border-bottom:2px solid #e5e7eb;", "日志内容" }
-                                        th { style: "padding:12px 16px; text-align:center; font-size:13px; font-weight:600; color:#374151; border-bottom:2px solid #e5e7eb; width:100px;", "操作" }
-                                    }
-                                }
-                                tbody {
-                                    for log in current_logs {
-                                        LogTableRow { log }
+                            style: "display:flex; align-items:center; gap:12px;",
+
+                            // 诊断包：版本、自检结果、脱敏配置和所选任务的最近日志
+                            select {
+                                value: "{bundle_log_limit()}",
+                                onchange: move |evt| {
+                                    if let Ok(limit) = evt.value().parse() {
+                                        bundle_log_limit.set(limit);
                                     }
+                                },
+                                title: t("logs.bundle_limit_hint"),
+                                style: "padding:8px 12px; border-radius:8px; border:1px solid var(--fb-border-strong); font-size:14px; background:var(--fb-surface); color:var(--fb-text); cursor:pointer;",
+                                for limit in [100, 500, 2000] {
+                                    option { value: "{limit}", {t_args("logs.bundle_recent", &[&lang.format_number(limit)])} }
                                 }
                             }
+                            button {
+                                style: "padding:10px 20px; border-radius:10px; border:1px solid var(--fb-border-strong); background:var(--fb-surface); color:var(--fb-text-secondary); font-weight:600; cursor:pointer;",
+                                title: t("logs.bundle_hint"),
+                                onclick: move |_| create_bundle(),
+                                {t("logs.create_bundle")}
+                            }
+
+                            // 查询按钮
+                            button {
+                                style: "padding:10px 28px; border-radius:10px; border:none; background:var(--fb-primary-gradient); color:white; font-weight:600; cursor:pointer; box-shadow:var(--fb-primary-shadow);",
+                                onclick: move |_| submit(current_query()),
+                                {t("logs.query")}
+                            }
                         }
+                    }
+                }
 
-                        if total_pages > 1 {
-                            div {
-                                style: "display:flex; justify-content:center; align-items:center; gap:8px; margin-top:20px;",
-                                
-                                button {
-                                    disabled: current_page() == 1,
-                                    style: "padding:8px 16px; border-radius:8px; border:1px solid #d1d5db; background:white; color:#6b7280; font-weight:600; cursor:pointer; disabled:opacity-50;",
-                                    onclick: move |_| {
-                                        if current_page() > 1 {
-                                            current_page.set(current_page() - 1);
-                                        }
-                                    },
-                                    "← 上一页"
+                // 日志列表
+                if !logs().is_empty() {
+                    Card {
+                        
+                        DataTable {
+                            columns: vec![
+                                Column::new("task", t("logs.column_task"), 140).sortable(),
+                                Column::new("level", t("logs.column_level"), 120).sortable(),
+                                Column::new("time", t("logs.column_time"), 220).sortable(),
+                                Column::new("message", t("logs.column_message"), 0).sortable().always_visible(),
+                                Column::new("actions", t("logs.column_actions"), 110).centered(),
+                            ],
+                            row_count: logs.read().len(),
+                            state: table,
+                            selectable: true,
+                            page_sizes: vec![20, 50, 100, 200, 0],
+                            sort_key: move |(row, column): (usize, &'static str)| {
+                                let logs = logs.read();
+                                let log = &logs[row];
+                                match column {
+                                    "task" => SortKey::Text(log.task_uuid.clone()),
+                                    "level" => SortKey::Number(level_rank(&log.log_level)),
+                                    // 存储格式为 RFC 3339 UTC，按文本排序即按时间排序
+                                    "time" => SortKey::Text(log.timestamp.clone()),
+                                    "message" => SortKey::Text(log.message.clone()),
+                                    _ => SortKey::Number(log.id.unwrap_or_default()),
                                 }
+                            },
+                            render_cell: move |(row, column): (usize, &'static str)| {
+                                let log = logs.read()[row].clone();
+                                rsx! { LogCell { log, column, zone: display_zone() } }
+                            },
 
-                                span {
-                                    style: "padding:8px 16px; font-size:14px; color:#374151; font-weight:600;",
-                                    "{current_page()} / {total_pages}"
+                            if !selected_text.is_empty() {
+                                button {
+                                    "data-copy-text": "{selected_text}",
+                                    style: "padding:6px 12px; border-radius:6px; border:1px solid var(--fb-border-strong); background:var(--fb-surface); color:var(--fb-text-secondary); font-size:13px; cursor:pointer;",
+                                    onclick: move |_| notifier.success(t("logs.selection_copied")),
+                                    {t("logs.copy_selected")}
                                 }
-
                                 button {
-                                    disabled: current_page() >= total_pages,
-                                    style: "padding:8px 16px; border-radius:8px; border:1px solid #d1d5db; background:white; color:#6b7280; font-weight:600; cursor:pointer; disabled:opacity-50;",
-                                    onclick: move |_| {
-                                        if current_page() < total_pages {
-                                            current_page.set(current_page() + 1);
-                                        }
-                                    },
-                                    "下一页 →"
+                                    style: "padding:6px 12px; border-radius:6px; border:1px solid var(--fb-border-strong); background:var(--fb-surface); color:var(--fb-text-muted); font-size:13px; cursor:pointer;",
+                                    onclick: move |_| table.write().selected.clear(),
+                                    {t("logs.clear_selection")}
                                 }
                             }
                         }
                     }
                 } else if !selected_task_name().is_empty() {
-                    section {
-                        style: "background:white; border-radius:20px; padding:60px 32px; border:1px solid #e5e7eb; box-shadow:0 8px 30px rgba(15,23,42,0.08); text-align:center;",
+                    Card {
+                        style: "padding:60px 32px; text-align:center;",
                         div {
                             style: "font-size:48px; margin-bottom:16px;",
                             "📭"
                         }
                         div {
-                            style: "font-size:18px; font-weight:600; color:#374151; margin-bottom:8px;",
-                            "暂无日志数据"
+                            style: "font-size:18px; font-weight:600; color:var(--fb-text-secondary); margin-bottom:8px;",
+                            {t("logs.empty")}
                         }
                         div {
-                            style: "font-size:14px; color:#6b7280;",
-                            "当前筛选条件下没有找到日志记录"
+                            style: "font-size:14px; color:var(--fb-text-muted);",
+                            {t("logs.empty_hint")}
                         }
                     }
                 } else {
-                    section {
-                        style: "background:white; border-radius:20px; padding:60px 32px; border:1px solid #e5e7eb; box-shadow:0 8px 30px rgba(15,23,42,0.08); text-align:center;",
+                    Card {
+                        style: "padding:60px 32px; text-align:center;",
                         div {
                             style: "font-size:48px; margin-bottom:16px;",
                             "👆"
                         }
                         div {
-                            style: "font-size:18px; font-weight:600; color:#374151; margin-bottom:8px;",
-                            "请选择任务名称"
+                            style: "font-size:18px; font-weight:600; color:var(--fb-text-secondary); margin-bottom:8px;",
+                            {t("logs.no_task")}
                         }
                         div {
-                            style: "font-size:14px; color:#6b7280;",
-                            "任务名称是必填项，请先选择一个任务名称开始查询"
+                            style: "font-size:14px; color:var(--fb-text-muted);",
+                            {t("logs.no_task_hint")}
                         }
                     }
                 }
@@ -461,116 +670,115 @@ pub fn Logs() -> Element {
     }
 }
 
+/// 级别排序：DEBUG < SUCCESS < INFO < WARN < ERROR
+fn level_rank(level: &str) -> i64 {
+    match level {
+        "DEBUG" => 0,
+        "SUCCESS" => 1,
+        "INFO" => 2,
+        "WARN" => 3,
+        "ERROR" => 4,
+        _ => 5,
+    }
+}
+
 #[component]
-fn LogTableRow(log: LogEntry) -> Element {
+fn LogCell(log: LogEntry, column: &'static str, zone: DisplayZone) -> Element {
     let (level_color, level_bg, level_icon) = match log.log_level.as_str() {
         "DEBUG" => ("#3b82f6", "#dbeafe", "🔵"),
         "SUCCESS" => ("#10b981", "#d1fae5", "🟢"),
-        "INFO" => ("#6b7280", "#f3f4f6", "⚪"),
+        "INFO" => ("var(--fb-text-muted)", "var(--fb-surface-hover)", "⚪"),
         "WARN" => ("#f59e0b", "#fef3c7", "🟡"),
         "ERROR" => ("#ef4444", "#fee2e2", "🔴"),
-        _ => ("#6b7280", "#f3f4f6", "⚪"),
+        _ => ("var(--fb-text-muted)", "var(--fb-surface-hover)", "⚪"),
     };
 
     let uuid_short = log.task_uuid.chars().take(8).collect::<String>();
     
-    // 截断日志内容，超过 100 个字符显示省略号
-    let max_length = 100;
-    let is_long = log.message.len() > max_length;
-    let display_message = if is_long {
-        format!("{}...", log.message.chars().take(max_length).collect::<String>())
-    } else {
-        log.message.clone()
-    };
+    // 超过 100 个字符的日志悬停时显示完整内容
+    let is_long = log.message.chars().count() > 100;
 
-    let mut show_tooltip = use_signal(|| false);
+    // 气泡位置（窗口坐标），固定定位以免被表格滚动区域裁剪
+    let mut tooltip_at = use_signal(|| Option::<(f64, f64)>::None);
     let mut copy_feedback = use_signal(|| false);
     
     // 生成唯一 ID
     let button_id = format!("copy-btn-{}", log.id.unwrap_or(0));
 
-    rsx! {
-        tr {
-            style: "border-bottom:1px solid #f3f4f6; transition:background 0.15s ease;",
-            
-            // 第一列：任务 ID
-            td {
-                style: "padding:12px 16px; font-size:12px; color:#6b7280; font-family:monospace;",
-                title: "{log.task_uuid}",
+    match column {
+        "task" => rsx! {
+            Link {
+                to: Route::TaskTimeline { uuid: log.task_uuid.clone() },
+                style: "font-size:12px; color:#4f46e5; font-family:monospace; text-decoration:none;",
+                title: t_args("logs.timeline_hint", &[&log.task_uuid]),
                 "{uuid_short}..."
             }
-            
-            // 第二列：级别
-            td {
-                style: "padding:12px 16px;",
-                span {
-                    style: "display:inline-block; padding:4px 12px; border-radius:6px; font-size:12px; font-weight:600; color:{level_color}; background:{level_bg};",
-                    "{level_icon} {log.log_level}"
-                }
+        },
+        "level" => rsx! {
+            span {
+                style: "display:inline-block; padding:4px 12px; border-radius:6px; font-size:12px; font-weight:600; color:{level_color}; background:{level_bg}; white-space:nowrap;",
+                "{level_icon} {log.log_level}"
             }
-            
-            // 第三列：时间
-            td {
-                style: "padding:12px 16px; font-size:13px; color:#6b7280; white-space:nowrap;",
-                "{log.timestamp}"
+        },
+        "time" => rsx! {
+            span {
+                style: "font-size:13px; color:var(--fb-text-muted); white-space:nowrap; font-variant-numeric:tabular-nums;",
+                title: "{log.timestamp}",
+                {zone.format_in(&log.timestamp, i18n::current())}
             }
-            
-            // 第四列：日志内容（带气泡）
-            td {
-                style: "padding:12px 16px; font-size:13px; color:#111827; line-height:1.5; position:relative;",
-                
-                div {
-                    style: if is_long { "cursor:pointer;" } else { "" },
-                    onmouseenter: move |_| if is_long { show_tooltip.set(true); },
-                    onmouseleave: move |_| show_tooltip.set(false),
-                    
-                    "{display_message}"
-                    
-                    // 气泡提示（显示完整内容）
-                    if show_tooltip() && is_long {
+        },
+        "message" => rsx! {
+            div {
+                style: if is_long {
+                    "font-size:13px; color:var(--fb-text); white-space:nowrap; overflow:hidden; text-overflow:ellipsis; cursor:pointer;"
+                } else {
+                    "font-size:13px; color:var(--fb-text); white-space:nowrap; overflow:hidden; text-overflow:ellipsis;"
+                },
+                onmouseenter: move |evt| {
+                    if is_long {
+                        let point = evt.client_coordinates();
+                        tooltip_at.set(Some((point.x, point.y)));
+                    }
+                },
+                onmouseleave: move |_| tooltip_at.set(None),
+
+                "{log.message}"
+
+                // 气泡提示（显示完整内容）
+                if let Some((x, y)) = tooltip_at() {
+                    div {
+                        style: "position:fixed; left:{x}px; top:{y + 16.0}px; background:linear-gradient(135deg,#1e293b,#0f172a); color:#f1f5f9; padding:16px; border-radius:12px; font-size:13px; line-height:1.6; box-shadow:0 10px 40px rgba(0,0,0,0.5); border:1px solid rgba(148,163,184,0.2); z-index:1000; max-width:500px; word-break:break-word; white-space:pre-wrap; cursor:default;",
+                        onclick: move |evt| evt.stop_propagation(),
                         div {
-                            style: "position:absolute; left:0; top:100%; margin-top:8px; background:linear-gradient(135deg,#1e293b,#0f172a); color:#f1f5f9; padding:16px; border-radius:12px; font-size:13px; line-height:1.6; box-shadow:0 10px 40px rgba(0,0,0,0.5); border:1px solid rgba(148,163,184,0.2); z-index:1000; max-width:500px; word-break:break-word; white-space:pre-wrap;",
-                            onclick: move |evt| evt.stop_propagation(),
-                            
-                            // 完整日志内容
-                            div {
-                                style: "margin-bottom:12px; max-height:300px; overflow-y:auto; padding-right:8px;",
-                                "{log.message}"
-                            }
-                            
-                            // 小三角箭头
-                            div {
-                                style: "position:absolute; bottom:100%; left:20px; width:0; height:0; border-left:8px solid transparent; border-right:8px solid transparent; border-bottom:8px solid #1e293b;",
-                            }
+                            style: "max-height:300px; overflow-y:auto; padding-right:8px;",
+                            "{log.message}"
                         }
                     }
                 }
             }
-            
-            // 第五列：复制按钮
-            td {
-                style: "padding:12px 16px; text-align:center;",
-                button {
-                    id: "{button_id}",
-                    class: "copy-log-btn",
-                    "data-copy-text": "{log.message}",
-                    style: "padding:6px 12px; border-radius:6px; border:none; background:linear-gradient(120deg,#4f46e5,#7c3aed); color:white; font-weight:600; cursor:pointer; font-size:12px; transition:all 0.2s ease; white-space:nowrap;",
-                    onclick: move |_| {
-                        copy_feedback.set(true);
-                        
-                        // 2秒后隐藏反馈
-                        spawn(async move {
-                            tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
-                            copy_feedback.set(false);
-                        });
-                    },
-                    if copy_feedback() {
-                        "✓ 已复制"
-                    } else {
-                        "📋 复制"
-                    }
+        },
+        "actions" => rsx! {
+            button {
+                id: "{button_id}",
+                class: "copy-log-btn",
+                "data-copy-text": "{log.message}",
+                style: "padding:6px 12px; border-radius:6px; border:none; background:var(--fb-primary-gradient); color:white; font-weight:600; cursor:pointer; font-size:12px; transition:all 0.2s ease; white-space:nowrap;",
+                onclick: move |_| {
+                    copy_feedback.set(true);
+                    
+                    // 2秒后隐藏反馈
+                    spawn(async move {
+                        tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
+                        copy_feedback.set(false);
+                    });
+                },
+                if copy_feedback() {
+                    {t("common.copied")}
+                } else {
+                    {t("common.copy")}
                 }
             }
-        }
+        },
+        _ => rsx! {},
     }
 }
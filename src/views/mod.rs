@@ -19,6 +19,9 @@ pub use help::Help;
 mod logs;
 pub use logs::Logs;
 
+mod timeline;
+pub use timeline::TaskTimeline;
+
 mod captcha;
 pub use captcha::Captcha;
 
@@ -0,0 +1,254 @@
+use dioxus::prelude::*;
+use std::collections::HashSet;
+use crate::common::i18n::{self, t, t_args};
+use crate::common::log_query::LogQuery;
+use crate::common::log_timeline::{format_duration, Timeline, TimelineItem};
+use crate::common::timestamp::{self, DisplayZone};
+use crate::components::{use_notifier, Card};
+use crate::db::{self, LogEntry, LogFilter};
+use crate::Route;
+
+/// 间隔达到该值（毫秒）时在时间轴上显示间隔标记
+const GAP_MARKER_MS: i64 = 1000;
+
+/// 单个任务运行（UUID）的时间线：请求日志合并为一组，标出间隔，高亮 WARN / ERROR
+#[component]
+pub fn TaskTimeline(uuid: String) -> Element {
+    let mut notifier = use_notifier();
+    let mut logs = use_signal(Vec::<LogEntry>::new);
+    let display_zone = use_signal(DisplayZone::load);
+    // 展开的请求分组
+    let mut expanded = use_signal(HashSet::<usize>::new);
+
+    use_effect(use_reactive!(|uuid| {
+        let filter = LogFilter {
+            task_uuid: Some(uuid.clone()),
+            // 正式版：过滤掉 DEBUG 日志
+            include_debug: cfg!(feature = "dev"),
+            ascending: true,
+            ..LogFilter::default()
+        };
+        if let Some(mut found) = notifier.report_error(db::query_logs(&filter), t("logs.load_failed")) {
+            // UUID 筛选为包含匹配，时间线只看这一次运行
+            found.retain(|log| log.task_uuid == uuid);
+            logs.set(found);
+            expanded.set(HashSet::new());
+        }
+    }));
+
+    let timeline = Timeline::build(logs());
+    let zone = display_zone();
+    let task_name = logs.read().first().map(|log| log.task_name.clone()).unwrap_or_default();
+    let back = Route::Logs {
+        query: LogQuery {
+            task_name: task_name.clone(),
+            task_uuid: uuid.clone(),
+            ..LogQuery::default()
+        },
+    };
+    let warning_color = if timeline.warnings > 0 { "#d97706" } else { "#111827" };
+    let error_color = if timeline.errors > 0 { "#dc2626" } else { "#111827" };
+    let subtitle = if task_name.is_empty() { uuid.clone() } else { format!("{} · {}", task_name, uuid) };
+    let start = timeline.start();
+    let lang = i18n::current();
+    let (started_at, ended_at) = match (timeline.start(), timeline.end()) {
+        (Some(start), Some(end)) => (zone.format_in(&timestamp::to_storage(start), lang), zone.format_in(&timestamp::to_storage(end), lang)),
+        _ => (String::new(), String::new()),
+    };
+
+    rsx! {
+        div {
+            style: "height:100%; overflow-y:auto; overflow-x:hidden; padding:24px 16px 24px 0;",
+
+            div {
+                style: "display:flex; flex-direction:column; gap:24px; max-width:1200px; margin:0 auto;",
+
+                // 页面标题和概要
+                section {
+                    style: "background:linear-gradient(135deg, #ede9fe 0%, #ddd6fe 100%); border-radius:20px; padding:28px 32px; border:1px solid #a78bfa; box-shadow:0 4px 20px rgba(139, 92, 246, 0.1);",
+                    div {
+                        style: "display:flex; justify-content:space-between; align-items:flex-start; gap:16px;",
+                        div {
+                            h1 {
+                                style: "font-size:26px; font-weight:700; margin:0 0 10px 0; color:#5b21b6; letter-spacing:-0.02em;",
+                                {t("timeline.title")}
+                            }
+                            p {
+                                style: "color:#7c3aed; font-size:14px; margin:0; font-family:monospace; word-break:break-all;",
+                                "{subtitle}"
+                            }
+                        }
+                        Link {
+                            to: back,
+                            style: "padding:10px 18px; border-radius:10px; border:1px solid #a78bfa; background:var(--fb-surface); color:#5b21b6; font-weight:600; text-decoration:none; white-space:nowrap;",
+                            {t("timeline.back")}
+                        }
+                    }
+
+                    if !timeline.items.is_empty() {
+                        div {
+                            style: "display:grid; grid-template-columns:repeat(auto-fit, minmax(150px, 1fr)); gap:12px; margin-top:20px;",
+                            TimelineStat { label: t("timeline.total_duration"), value: format_duration(timeline.duration()) }
+                            TimelineStat { label: t("timeline.started"), value: started_at }
+                            TimelineStat { label: t("timeline.ended"), value: ended_at }
+                            TimelineStat { label: t("timeline.requests"), value: timeline.request_count().to_string() }
+                            TimelineStat { label: t("timeline.warnings"), value: timeline.warnings.to_string(), color: warning_color }
+                            TimelineStat { label: t("timeline.errors"), value: timeline.errors.to_string(), color: error_color }
+                        }
+                    }
+                }
+
+                Card {
+
+                    if timeline.items.is_empty() {
+                        div {
+                            style: "text-align:center; padding:40px; color:var(--fb-text-muted); font-size:14px;",
+                            {t("timeline.empty")}
+                        }
+                    }
+
+                    for (index, item) in timeline.items.iter().enumerate() {
+                        if item.gap_before.num_milliseconds() >= GAP_MARKER_MS {
+                            TimelineGap { key: "gap-{index}", millis: item.gap_before.num_milliseconds() }
+                        }
+                        TimelineRow {
+                            key: "{index}",
+                            item: item.clone(),
+                            offset: start.map(|start| format_duration(item.start - start)).unwrap_or_default(),
+                            zone,
+                            expanded: expanded.read().contains(&index),
+                            on_toggle: move |_| {
+                                let mut set = expanded.write();
+                                if !set.remove(&index) {
+                                    set.insert(index);
+                                }
+                            },
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn TimelineStat(label: &'static str, value: String, #[props(default = "#111827")] color: &'static str) -> Element {
+    rsx! {
+        div {
+            style: "background:rgba(255,255,255,0.7); border-radius:12px; padding:12px 16px;",
+            div { style: "font-size:12px; color:#7c3aed; margin-bottom:4px;", "{label}" }
+            div { style: "font-size:16px; font-weight:700; color:{color}; font-variant-numeric:tabular-nums;", "{value}" }
+        }
+    }
+}
+
+/// 间隔标记，高度随间隔对数增长
+#[component]
+fn TimelineGap(millis: i64) -> Element {
+    let height = ((millis as f64 / 1000.0).ln_1p() * 14.0).clamp(12.0, 72.0);
+    let label = format_duration(chrono::Duration::milliseconds(millis));
+
+    rsx! {
+        div {
+            style: "display:flex; align-items:center; height:{height}px; margin-left:120px; border-left:2px dashed #cbd5e1; padding-left:20px;",
+            span {
+                style: "font-size:12px; color:#94a3b8; background:#f8fafc; padding:2px 8px; border-radius:999px;",
+                {t_args("timeline.gap", &[&label])}
+            }
+        }
+    }
+}
+
+#[component]
+fn TimelineRow(item: TimelineItem, offset: String, zone: DisplayZone, expanded: bool, on_toggle: EventHandler<()>) -> Element {
+    let (accent, background) = match item.level() {
+        "ERROR" => ("#ef4444", "rgba(239,68,68,0.08)"),
+        "WARN" => ("#f59e0b", "rgba(245,158,11,0.08)"),
+        "SUCCESS" => ("#10b981", "var(--fb-surface-muted)"),
+        _ => ("#a5b4fc", "var(--fb-surface-muted)"),
+    };
+    let first = item.entries.first().cloned();
+    let time = first.as_ref().map(|entry| zone.format_time(&entry.timestamp)).unwrap_or_default();
+    let full_time = first.as_ref().map(|entry| zone.format_in(&entry.timestamp, i18n::current())).unwrap_or_default();
+    let duration = format_duration(item.duration());
+    let count = item.entries.len();
+
+    rsx! {
+        div {
+            style: "display:flex; gap:0; align-items:stretch;",
+
+            // 相对开始的时间
+            div {
+                style: "width:120px; flex-shrink:0; text-align:right; padding:12px 16px 0 0; font-size:12px; color:var(--fb-text-muted); font-variant-numeric:tabular-nums;",
+                div { style: "font-weight:600; color:var(--fb-text-secondary);", "+{offset}" }
+                div { style: "margin-top:2px;", title: "{full_time}", "{time}" }
+            }
+
+            // 时间轴
+            div {
+                style: "position:relative; width:2px; background:#e5e7eb; flex-shrink:0;",
+                div {
+                    style: "position:absolute; top:14px; left:-5px; width:12px; height:12px; border-radius:50%; background:{accent}; border:2px solid white; box-shadow:0 0 0 1px {accent};",
+                }
+            }
+
+            div {
+                style: "flex:1; min-width:0; margin:6px 0 6px 18px; padding:10px 14px; border-radius:10px; background:{background}; border-left:3px solid {accent};",
+
+                if let Some(request) = item.request.clone() {
+                    div {
+                        style: "display:flex; align-items:center; gap:10px; cursor:pointer;",
+                        onclick: move |_| on_toggle.call(()),
+                        span { style: "font-size:12px; color:var(--fb-text-muted);", if expanded { "▾" } else { "▸" } }
+                        span {
+                            style: "flex:1; min-width:0; font-family:monospace; font-size:13px; color:var(--fb-text); white-space:nowrap; overflow:hidden; text-overflow:ellipsis;",
+                            title: "{request}",
+                            "{request}"
+                        }
+                        if let Some(outcome) = item.outcome.clone() {
+                            span {
+                                style: "font-size:12px; font-weight:600; color:{accent}; background:var(--fb-surface); padding:2px 8px; border-radius:6px; max-width:240px; white-space:nowrap; overflow:hidden; text-overflow:ellipsis;",
+                                title: "{outcome}",
+                                "{outcome}"
+                            }
+                        } else {
+                            span { style: "font-size:12px; color:var(--fb-text-subtle);", {t("timeline.unfinished")} }
+                        }
+                        span { style: "font-size:12px; color:var(--fb-text-muted); white-space:nowrap;", {t_args("timeline.summary", &[&duration, &count])} }
+                    }
+                    if expanded {
+                        div {
+                            style: "margin-top:8px; display:flex; flex-direction:column; gap:4px;",
+                            for (index, entry) in item.entries.iter().enumerate() {
+                                TimelineEntry { key: "{index}", entry: entry.clone(), zone }
+                            }
+                        }
+                    }
+                } else if let Some(entry) = first {
+                    TimelineEntry { entry, zone }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn TimelineEntry(entry: LogEntry, zone: DisplayZone) -> Element {
+    let color = match entry.log_level.as_str() {
+        "ERROR" => "#dc2626",
+        "WARN" => "#d97706",
+        "SUCCESS" => "#059669",
+        "DEBUG" => "#3b82f6",
+        _ => "var(--fb-text-muted)",
+    };
+    let time = zone.format_time(&entry.timestamp);
+
+    rsx! {
+        div {
+            style: "display:flex; gap:10px; font-size:13px; line-height:1.5;",
+            span { style: "flex-shrink:0; color:var(--fb-text-subtle); font-variant-numeric:tabular-nums;", "{time}" }
+            span { style: "flex-shrink:0; width:64px; font-weight:600; color:{color};", "{entry.log_level}" }
+            span { style: "color:var(--fb-text); white-space:pre-wrap; word-break:break-word;", "{entry.message}" }
+        }
+    }
+}